@@ -15,6 +15,8 @@
 
 /// Defines bounding box
 pub mod bounding_box;
+/// The original `cgmath`, for the vectors and points with scalars other than f64.
+pub use cgmath;
 /// Redefines vectors, matrices or points with scalar = f64.
 pub mod cgmath64;
 /// Additional traits for cgmath
@@ -9,6 +9,13 @@ struct Revolution {
 }
 
 /// surface constructed by revoluting a curve
+///
+/// The surface is `S(u, v) = R(v)(C(u) - O) + O`, where `C` is the entity curve,
+/// `O` is the origin and `R(v)` is the rotation by angle `v` around the unit axis `n`.
+/// All derivatives are computed analytically from the derivatives of the entity curve:
+/// - `uder = R(v) C'(u)`, `uuder = R(v) C''(u)`,
+/// - `vder = n × R(v)(C(u) - O)`, `uvder = n × R(v) C'(u)`,
+/// - `vvder = n × (n × R(v)(C(u) - O))`.
 /// # Examples
 /// Revoluted sphere
 /// ```
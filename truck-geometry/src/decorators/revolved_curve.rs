@@ -19,22 +19,6 @@ impl Revolution {
         Matrix4::from_axis_angle(self.axis, Rad(v))
     }
     #[inline(always)]
-    fn derivation_rotation_matrix(self, v: f64) -> Matrix4 {
-        let n = self.axis;
-        Matrix3::new(
-            n[0] * n[0] * f64::sin(v) - f64::sin(v),
-            n[0] * n[1] * f64::sin(v) + n[2] * f64::cos(v),
-            n[0] * n[2] * f64::sin(v) - n[1] * f64::cos(v),
-            n[0] * n[1] * f64::sin(v) - n[2] * f64::cos(v),
-            n[1] * n[1] * f64::sin(v) - f64::sin(v),
-            n[1] * n[2] * f64::sin(v) + n[0] * f64::cos(v),
-            n[0] * n[2] * f64::sin(v) + n[1] * f64::cos(v),
-            n[1] * n[2] * f64::sin(v) - n[0] * f64::cos(v),
-            n[2] * n[2] * f64::sin(v) - f64::sin(v),
-        )
-        .into()
-    }
-    #[inline(always)]
     fn invert(&mut self) { self.axis *= -1.0; }
     #[inline(always)]
     fn inverse(mut self) -> Self {
@@ -97,10 +81,9 @@ impl<C: ParametricCurve3D> ParametricSurface for RevolutedCurve<C> {
     }
     #[inline(always)]
     fn vder(&self, u: f64, v: f64) -> Vector3 {
-        let pt = self.curve.subs(u);
-        let radius = self.axis().cross(pt - self.origin());
+        let radius = self.curve.subs(u) - self.origin();
         let mat = self.revolution.vector_rotation_matrix(v);
-        mat.transform_vector(radius)
+        self.axis().cross(mat.transform_vector(radius))
     }
     #[inline(always)]
     fn uuder(&self, u: f64, v: f64) -> Vector3 {
@@ -108,18 +91,9 @@ impl<C: ParametricCurve3D> ParametricSurface for RevolutedCurve<C> {
         mat.transform_vector(self.curve.der2(u))
     }
     #[inline(always)]
-    fn vvder(&self, u: f64, v: f64) -> Vector3 {
-        let pt = self.curve.subs(u);
-        let z = self.revolution.proj_point(pt).x;
-        let radius = pt - self.origin() - z * self.axis();
-        let mat = self.revolution.vector_rotation_matrix(v);
-        -mat.transform_vector(radius)
-    }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 { self.axis().cross(self.vder(u, v)) }
     #[inline(always)]
-    fn uvder(&self, u: f64, v: f64) -> Vector3 {
-        let mat = self.revolution.derivation_rotation_matrix(v);
-        mat.transform_vector(self.curve.der(u))
-    }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 { self.axis().cross(self.uder(u, v)) }
     #[inline(always)]
     fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
         (
@@ -562,6 +536,41 @@ fn revolve_test() {
     }
 }
 
+#[test]
+fn derivation_test() {
+    let curve = NurbsCurve::new(BSplineCurve::new(
+        KnotVec::bezier_knot(3),
+        vec![
+            Vector4::new(0.0, 3.0, 1.0, 1.0),
+            Vector4::new(1.0, 3.0, 3.0, 0.5),
+            Vector4::new(2.0, 0.0, 0.0, 1.0),
+            Vector4::new(0.0, 1.0, 3.0, 1.0),
+        ],
+    ));
+    let axis = Vector3::new(1.0, 2.0, 3.0);
+    let surface = RevolutedCurve::by_revolution(curve, Point3::new(1.0, -1.0, 2.0), axis);
+    const N: usize = 10;
+    const EPS: f64 = 1.0e-4;
+    for i in 1..N {
+        for j in 0..=N {
+            let u = i as f64 / N as f64;
+            let v = 2.0 * PI * j as f64 / N as f64;
+            let uder = (surface.subs(u + EPS, v) - surface.subs(u - EPS, v)) / (2.0 * EPS);
+            assert!(surface.uder(u, v).distance(uder) < EPS);
+            let vder = (surface.subs(u, v + EPS) - surface.subs(u, v - EPS)) / (2.0 * EPS);
+            assert!(surface.vder(u, v).distance(vder) < EPS);
+            let uuder = (surface.uder(u + EPS, v) - surface.uder(u - EPS, v)) / (2.0 * EPS);
+            assert!(surface.uuder(u, v).distance(uuder) < EPS);
+            let uvder = (surface.uder(u, v + EPS) - surface.uder(u, v - EPS)) / (2.0 * EPS);
+            assert!(surface.uvder(u, v).distance(uvder) < EPS);
+            let vuder = (surface.vder(u + EPS, v) - surface.vder(u - EPS, v)) / (2.0 * EPS);
+            assert!(surface.uvder(u, v).distance(vuder) < EPS);
+            let vvder = (surface.vder(u, v + EPS) - surface.vder(u, v - EPS)) / (2.0 * EPS);
+            assert!(surface.vvder(u, v).distance(vvder) < EPS);
+        }
+    }
+}
+
 #[test]
 fn search_parameter() {
     let line = BSplineCurve::new(
@@ -66,6 +66,18 @@ the front of the second knot vector: {1}")]
     /// ```
     #[error("This knot vector is not sorted.")]
     NotSortedVector,
+    /// The knots of B-spline curves and surfaces must be non-decreasing. Since the knot vectors
+    /// are sorted when they are created, this error is caused by `NaN` knots.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let knot_vec = KnotVec::try_from(vec![0.0, 0.0, f64::NAN, 1.0, 1.0]).unwrap();
+    /// let ctrl_pts = vec![Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 0.0)];
+    /// assert!(matches!(BSplineCurve::try_new(knot_vec, ctrl_pts), Err(Error::NonMonotoneKnots)));
+    /// ```
+    #[error("The knots are not non-decreasing.")]
+    NonMonotoneKnots,
     /// The given degree is too large to calculate bspline basis functions.
     /// # Examples
     /// ```
@@ -169,6 +181,189 @@ the number of control points: {1}"
     /// ```
     #[error("The vector of control points and the one of weights have different length.")]
     DifferentLength,
+    /// The weights of NURBS must not be zero.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point2::new(0.0, 1.0), Point2::new(2.0, 3.0), Point2::new(4.0, 5.0)],
+    /// );
+    /// let weights = vec![1.0, 0.0, 1.0];
+    ///
+    /// assert!(matches!(
+    ///     NurbsCurve::<Vector3>::try_from_bspline_and_weights(bspcurve, weights),
+    ///     Err(Error::ZeroWeight),
+    /// ));
+    /// ```
+    #[error("The weight of a control point is zero.")]
+    ZeroWeight,
+    /// The knot to be inserted is out of the range of the knot vector.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Vector2::new(-1.0, 1.0), Vector2::new(0.0, -1.0), Vector2::new(1.0, 1.0)];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// assert!(matches!(
+    ///     bspcurve.insert_knot(1.5),
+    ///     Err(Error::OutOfKnotRange(1.5, 0.0, 1.0)),
+    /// ));
+    /// ```
+    #[error("The knot {0} is out of the range of the knot vector [{1}, {2}].")]
+    OutOfKnotRange(f64, f64, f64),
+    /// The multiplicity of the knot to be inserted would exceed the degree.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Vector2::new(-1.0, 1.0), Vector2::new(0.0, -1.0), Vector2::new(1.0, 1.0)];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// bspcurve.insert_knot(0.5).unwrap().insert_knot(0.5).unwrap();
+    /// assert!(matches!(
+    ///     bspcurve.insert_knot(0.5),
+    ///     Err(Error::TooLargeMultiplicity(0.5, 2)),
+    /// ));
+    /// ```
+    #[error("The multiplicity of the knot {0} would exceed the degree {1}.")]
+    TooLargeMultiplicity(f64, usize),
+    /// The number of points is too few to interpolate by a B-spline curve with the degree.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), Point2::new(2.0, 0.0)];
+    /// assert!(matches!(
+    ///     BSplineCurve::interpolate(&pts, 3),
+    ///     Err(Error::TooFewPoints(3, 3)),
+    /// ));
+    /// ```
+    #[error(
+        "The number of points is too few compared to the degree.
+the number of points: {0}
+the degree: {1}"
+    )]
+    TooFewPoints(usize, usize),
+    /// Two consecutive points to be interpolated coincide.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), Point2::new(1.0, 1.0)];
+    /// assert!(matches!(
+    ///     BSplineCurve::interpolate(&pts, 1),
+    ///     Err(Error::CoincidentPoints(1)),
+    /// ));
+    /// ```
+    #[error("The {0}th point and the next one coincide.")]
+    CoincidentPoints(usize),
+    /// The number of control points of the approximation must be more than the degree and
+    /// not more than the number of points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), Point2::new(2.0, 0.0)];
+    /// assert!(matches!(
+    ///     BSplineCurve::approximate(&pts, 2, 4, 0.01),
+    ///     Err(Error::InvalidControlPointBudget(4, 2, 3)),
+    /// ));
+    /// ```
+    #[error(
+        "The number of control points must be more than the degree and not more than the number of points.
+the number of control points: {0}
+the degree: {1}
+the number of points: {2}"
+    )]
+    InvalidControlPointBudget(usize, usize, usize),
+    /// The derivation of the curve vanishes, so the normal is not determined.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), Point2::new(0.0, 0.0)];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(2), pts);
+    /// assert!(matches!(
+    ///     bspcurve.offset(0.1, 0.01),
+    ///     Err(Error::ZeroDerivative(_)),
+    /// ));
+    /// ```
+    #[error("The derivation vanishes at the parameter {0}.")]
+    ZeroDerivative(f64),
+    /// The curve degenerates to a point, so the operation requiring the direction is failed.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let pts = vec![Point2::new(1.0, 1.0); 3];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(2), pts);
+    /// assert_eq!(bspcurve.offset(0.1, 0.01), Err(Error::DegenerateCurve));
+    /// ```
+    #[error("The curve degenerates to a point.")]
+    DegenerateCurve,
+    /// The approximation does not achieve the tolerance.
+    #[error("The approximation does not achieve the tolerance {0}.")]
+    ToleranceNotAchieved(f64),
+    /// The degree of the curve cannot be reduced within the tolerance.
+    /// The field is the deviation of the reduced curve, infinity if the degree is less than 2.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let line = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0)],
+    /// );
+    /// assert!(matches!(
+    ///     line.reduce_degree(1.0),
+    ///     Err(Error::DegreeNotReducible(deviation)) if deviation == f64::INFINITY,
+    /// ));
+    /// ```
+    #[error("The degree cannot be reduced within the tolerance; the deviation is {0}.")]
+    DegreeNotReducible(f64),
+    /// The gap between the end of the first curve and the start of the second curve is larger
+    /// than the tolerance. The field is the gap.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let line0 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)],
+    /// );
+    /// let line1 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(1.0, 0.5), Point2::new(2.0, 0.0)],
+    /// );
+    /// assert_eq!(line0.join(&line1, 0.1), Err(Error::DisconnectedCurves(0.5)));
+    /// ```
+    #[error("The gap {0} between the curves is larger than the tolerance.")]
+    DisconnectedCurves(f64),
+    /// The weight of a control point must be positive. The field is the given weight.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let mut curve = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Vector3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 0.0, 1.0)],
+    /// ));
+    /// assert!(matches!(
+    ///     curve.set_weight(1, -2.0),
+    ///     Err(Error::NonPositiveWeight(_)),
+    /// ));
+    /// ```
+    #[error("The weight {0} of a control point is not positive.")]
+    NonPositiveWeight(f64),
+}
+
+/// For the call sites handling errors as strings.
+impl From<Error> for String {
+    #[inline(always)]
+    fn from(error: Error) -> String { error.to_string() }
 }
 
 #[test]
@@ -181,10 +376,24 @@ fn print_messages() {
     writeln!(stderr, "{}\n", Error::DifferentBackFront(0.0, 1.0)).unwrap();
     writeln!(stderr, "{}\n", Error::NotClampedKnotVector).unwrap();
     writeln!(stderr, "{}\n", Error::NotSortedVector).unwrap();
+    writeln!(stderr, "{}\n", Error::NonMonotoneKnots).unwrap();
     writeln!(stderr, "{}\n", Error::TooLargeDegree(1, 2)).unwrap();
     writeln!(stderr, "{}\n", Error::CannotRemoveKnot(7)).unwrap();
     writeln!(stderr, "{}\n", Error::EmptyControlPoints).unwrap();
     writeln!(stderr, "{}\n", Error::TooShortKnotVector(1, 2)).unwrap();
     writeln!(stderr, "{}\n", Error::IrregularControlPoints).unwrap();
+    writeln!(stderr, "{}\n", Error::DifferentLength).unwrap();
+    writeln!(stderr, "{}\n", Error::ZeroWeight).unwrap();
+    writeln!(stderr, "{}\n", Error::OutOfKnotRange(2.0, 0.0, 1.0)).unwrap();
+    writeln!(stderr, "{}\n", Error::TooLargeMultiplicity(0.5, 2)).unwrap();
+    writeln!(stderr, "{}\n", Error::TooFewPoints(3, 3)).unwrap();
+    writeln!(stderr, "{}\n", Error::CoincidentPoints(1)).unwrap();
+    writeln!(stderr, "{}\n", Error::InvalidControlPointBudget(4, 2, 3)).unwrap();
+    writeln!(stderr, "{}\n", Error::ZeroDerivative(0.5)).unwrap();
+    writeln!(stderr, "{}\n", Error::DegenerateCurve).unwrap();
+    writeln!(stderr, "{}\n", Error::ToleranceNotAchieved(0.01)).unwrap();
+    writeln!(stderr, "{}\n", Error::DegreeNotReducible(0.01)).unwrap();
+    writeln!(stderr, "{}\n", Error::DisconnectedCurves(0.1)).unwrap();
+    writeln!(stderr, "{}\n", Error::NonPositiveWeight(-1.0)).unwrap();
     writeln!(stderr, "*******************************************************").unwrap();
 }
@@ -19,6 +19,8 @@ use truck_base::bounding_box::Bounded;
 
 const INCLUDE_CURVE_TRIALS: usize = 100;
 const PRESEARCH_DIVISION: usize = 50;
+const SEED_PRESEARCH_DIVISION: usize = 4;
+const TIGHT_BOX_DIVISION: usize = 4;
 
 /// re-export `truck_base`
 pub mod base {
@@ -24,10 +24,12 @@ impl<P> BSplineCurve<P> {
     /// * If there are no control points, returns [`Error::EmptyControlPoint<f64>s`].
     /// * If the number of knots is more than the one of control points, returns [`Error::TooShortKnotVector`].
     /// * If the range of the knot vector is zero, returns [`Error::ZeroRange`].
+    /// * If the knots are not non-decreasing, e.g. some knot is `NaN`, returns [`Error::NonMonotoneKnots`].
     ///
     /// [`Error::EmptyControlPoint<f64>s`]: errors/enum.Error.html#variant.EmptyControlPoint<f64>s
     /// [`Error::TooShortKnotVector`]: errors/enum.Error.html#variant.TooShortKnotVector
     /// [`Error::ZeroRange`]: errors/enum.Error.html#variant.ZeroRange
+    /// [`Error::NonMonotoneKnots`]: errors/enum.Error.html#variant.NonMonotoneKnots
     pub fn try_new(knot_vec: KnotVec, control_points: Vec<P>) -> Result<BSplineCurve<P>> {
         if control_points.is_empty() {
             Err(Error::EmptyControlPoints)
@@ -38,6 +40,8 @@ impl<P> BSplineCurve<P> {
             ))
         } else if knot_vec.range_length().so_small() {
             Err(Error::ZeroRange)
+        } else if !is_non_decreasing(&knot_vec) {
+            Err(Error::NonMonotoneKnots)
         } else {
             Ok(BSplineCurve::new_unchecked(knot_vec, control_points))
         }
@@ -56,6 +60,7 @@ impl<P> BSplineCurve<P> {
         Self {
             knot_vec,
             control_points,
+            periodic: false,
         }
     }
 
@@ -176,6 +181,160 @@ impl<P: ControlPoint<f64>> BSplineCurve<P> {
     /// ```
     #[inline(always)]
     pub fn get_closure(&self) -> impl Fn(f64) -> P + '_ { move |t| self.subs(t) }
+
+    /// Substitutes many parameters to the curve.
+    ///
+    /// The span of the knot vector is searched forward from the last one, and the knot
+    /// intervals are reused while the parameters stay in the same span, so sorted parameters
+    /// are evaluated fastest. The results coincide with `subs` bit for bit as long as the
+    /// control points are finite.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 3.0, 3.0]);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(2.0, -1.0),
+    ///     Point2::new(3.0, 1.0),
+    ///     Point2::new(4.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// let params = (0..=30).map(|i| i as f64 / 10.0);
+    /// let res: Vec<Point2> = bspcurve.subs_iter(params.clone()).collect();
+    /// let ans: Vec<Point2> = params.map(|t| bspcurve.subs(t)).collect();
+    /// assert_eq!(res, ans);
+    /// ```
+    pub fn subs_iter<'a, I>(&'a self, params: I) -> impl Iterator<Item = P> + 'a
+    where
+        I: IntoIterator<Item = f64>,
+        I::IntoIter: 'a, {
+        let mut cache = BasisSpanCache::new(self.degree());
+        params.into_iter().map(move |t| {
+            let t = self.periodic_parameter(t);
+            let (lo, basis) = cache.basis_functions(&self.knot_vec, t);
+            self.control_points[lo..]
+                .iter()
+                .zip(basis)
+                .fold(P::origin(), |sum, (pt, basis)| sum + pt.to_vec() * *basis)
+        })
+    }
+
+    /// Substitutes `division + 1` parameters dividing the parameter range equally,
+    /// i.e. `t0 + (t1 - t0) * (i as f64) / (division as f64)` for `i` in `0..=division`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, 0.0), Vector2::new(1.0, 1.0)];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// const N: usize = 100; // sample size
+    /// let res: Vec<Vector2> = bspcurve.subs_uniform(N).collect();
+    /// assert_eq!(res.len(), N + 1);
+    /// for (i, v) in res.into_iter().enumerate() {
+    ///     let t = i as f64 / N as f64;
+    ///     assert_eq!(v, bspcurve.subs(t));
+    /// }
+    /// ```
+    pub fn subs_uniform(&self, division: usize) -> impl Iterator<Item = P> + '_ {
+        let (t0, t1) = self.range_tuple();
+        let params = (0..=division).map(move |i| t0 + (t1 - t0) * (i as f64) / (division as f64));
+        self.subs_iter(params)
+    }
+
+    /// Creates the periodic B-spline curve with `degree` whose closed control polygon is
+    /// `control_points`.
+    ///
+    /// The knot vector is uniform and unclamped, and the first `degree` control points are
+    /// appended to the back, so the curve is `C^{degree - 1}` across the seam. The parameter
+    /// range is `[0, 1)` and `subs` treats the parameter modulo `1`.
+    ///
+    /// Only the curves created by this method are periodic. Adding or removing knots makes the
+    /// curve non-periodic, and the control points must not be modified so as to break the seam.
+    /// # Panics
+    /// Panics if `degree` is zero or the number of control points is not more than `degree`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let ctrl_pts = vec![
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(0.0, 1.0),
+    ///     Point2::new(-1.0, 0.0),
+    ///     Point2::new(0.0, -1.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::make_periodic(ctrl_pts, 3);
+    /// assert!(bspcurve.is_periodic());
+    /// assert_eq!(bspcurve.range_tuple(), (0.0, 1.0));
+    /// assert_eq!(bspcurve.period(), Some(1.0));
+    ///
+    /// // the curve is closed smoothly
+    /// assert_near!(bspcurve.front(), bspcurve.back());
+    /// assert_near!(bspcurve.subs(0.3), bspcurve.subs(1.3));
+    /// assert_near!(bspcurve.der(1.0 - 1.0e-10), bspcurve.der(0.0));
+    /// assert_near!(bspcurve.der2(1.0 - 1.0e-10), bspcurve.der2(0.0));
+    /// ```
+    pub fn make_periodic(mut control_points: Vec<P>, degree: usize) -> Self {
+        let len = control_points.len();
+        assert!(degree > 0, "the degree must be positive.");
+        assert!(
+            len > degree,
+            "the control points are too few for the degree."
+        );
+        control_points.extend_from_within(..degree);
+        let knot_vec = KnotVec(
+            (0..len + 2 * degree + 1)
+                .map(|i| (i as f64 - degree as f64) / len as f64)
+                .collect(),
+        );
+        Self {
+            knot_vec,
+            control_points,
+            periodic: true,
+        }
+    }
+
+    /// Returns whether the curve is periodic, i.e. the curve is created by
+    /// [`BSplineCurve::make_periodic`] and its knots have not been changed since then.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0), Point2::new(0.0, 1.0)];
+    /// assert!(BSplineCurve::make_periodic(ctrl_pts.clone(), 2).is_periodic());
+    /// // the clamped curve is not periodic even if it is closed.
+    /// let mut ctrl_pts = ctrl_pts;
+    /// ctrl_pts.push(ctrl_pts[0]);
+    /// assert!(!BSplineCurve::new(KnotVec::uniform_knot(2, 2), ctrl_pts).is_periodic());
+    /// // the unclamped curve is not periodic unless it is made by `make_periodic`.
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(0.0, 1.0),
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    /// ];
+    /// let knot_vec = KnotVec::from((0..8).map(|i| i as f64).collect::<Vec<_>>());
+    /// assert!(!BSplineCurve::new(knot_vec, ctrl_pts).is_periodic());
+    /// ```
+    #[inline(always)]
+    pub const fn is_periodic(&self) -> bool { self.periodic }
+
+    /// Returns the parameter modulo the period if `self` is periodic.
+    #[inline(always)]
+    fn periodic_parameter(&self, t: f64) -> f64 {
+        if !self.periodic {
+            return t;
+        }
+        let (t0, t1) = (
+            self.knot_vec[self.degree()],
+            self.knot_vec[self.control_points.len()],
+        );
+        match (t0..t1).contains(&t) {
+            true => t,
+            false => t0 + (t - t0).rem_euclid(t1 - t0),
+        }
+    }
     #[inline(always)]
     fn delta_control_points(&self, i: usize) -> P::Diff {
         if i == 0 {
@@ -275,6 +434,41 @@ impl<V: Homogeneous<f64>> BSplineCurve<V> {
     }
 }
 
+impl<P: ControlPoint<f32>> BSplineCurve<P> {
+    /// Substitutes to the B-spline curve with single precision control points, e.g. for
+    /// the tessellation sent to GPU.
+    ///
+    /// The basis functions are calculated by [`KnotVec::try_bspline_basis_functions_f32`].
+    /// Unlike `subs` of the double precision curves, the parameter is not wrapped even if
+    /// the curve is periodic.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_base::cgmath::Point2;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Point2::new(0.0f32, 0.0), Point2::new(0.5, 0.0), Point2::new(1.0, 1.0)];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// // bspcurve coincides with (t, t * t).
+    /// const N: usize = 100; // sample size
+    /// for i in 0..=N {
+    ///     let t = i as f32 / N as f32;
+    ///     let p = bspcurve.subs_f32(t);
+    ///     assert!((p.x - t).abs() < 1.0e-6 && (p.y - t * t).abs() < 1.0e-6);
+    /// }
+    /// ```
+    pub fn subs_f32(&self, t: f32) -> P {
+        let basis = self
+            .knot_vec
+            .try_bspline_basis_functions_f32(self.degree(), t)
+            .unwrap();
+        self.control_points
+            .iter()
+            .zip(basis)
+            .fold(P::origin(), |sum, (pt, basis)| sum + pt.to_vec() * basis)
+    }
+}
+
 impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
     type Point = P;
     type Vector = P::Diff;
@@ -295,6 +489,7 @@ impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
     /// ```
     #[inline(always)]
     fn subs(&self, t: f64) -> P {
+        let t = self.periodic_parameter(t);
         let basis = self
             .knot_vec
             .try_bspline_basis_functions(self.degree(), t)
@@ -321,6 +516,7 @@ impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
     /// ```
     #[inline(always)]
     fn der(&self, t: f64) -> P::Diff {
+        let t = self.periodic_parameter(t);
         let k = self.degree();
         let knot_vec = self.knot_vec();
         let closure = move |sum: P::Diff, (i, b): (usize, f64)| {
@@ -360,6 +556,7 @@ impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
         if k < 2 {
             return P::Diff::zero();
         }
+        let t = self.periodic_parameter(t);
         let knot_vec = self.knot_vec();
         let closure = move |sum: P::Diff, (i, b): (usize, f64)| {
             let coef = inv_or_zero(knot_vec[i + k - 1] - knot_vec[i]);
@@ -373,17 +570,113 @@ impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
             * k as f64
             * (k - 1) as f64
     }
+    /// If `self` is periodic, the range is `[knot_vec[degree], knot_vec[len])`, where `len` is
+    /// the number of control points. Otherwise, the range is the whole knot vector.
     #[inline(always)]
     fn parameter_range(&self) -> ParameterRange {
-        (
-            Bound::Included(self.knot_vec[0]),
-            Bound::Included(self.knot_vec[self.knot_vec.len() - 1]),
-        )
+        match self.periodic {
+            true => (
+                Bound::Included(self.knot_vec[self.degree()]),
+                Bound::Excluded(self.knot_vec[self.control_points.len()]),
+            ),
+            false => (
+                Bound::Included(self.knot_vec[0]),
+                Bound::Included(self.knot_vec[self.knot_vec.len() - 1]),
+            ),
+        }
+    }
+    #[inline(always)]
+    fn period(&self) -> Option<f64> {
+        match self.periodic {
+            true => Some(self.knot_vec[self.control_points.len()] - self.knot_vec[self.degree()]),
+            false => None,
+        }
     }
 }
 
 impl<P: ControlPoint<f64>> BoundedCurve for BSplineCurve<P> {}
 
+#[test]
+fn subs_iter_random_test() {
+    let random_point = |_| Point3::new(rand::random(), rand::random(), rand::random());
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.3, 0.3, 0.5, 1.0, 1.0, 1.0, 1.0]);
+    let curves = [
+        BSplineCurve::new(
+            KnotVec::uniform_knot(3, 5),
+            (0..8).map(random_point).collect(),
+        ),
+        BSplineCurve::new(knot_vec, (0..7).map(random_point).collect()),
+        BSplineCurve::new(
+            KnotVec::from((0..10).map(|i| i as f64).collect::<Vec<_>>()),
+            (0..7).map(random_point).collect(),
+        ),
+        BSplineCurve::make_periodic((0..5).map(random_point).collect(), 2),
+    ];
+    for curve in curves {
+        let (t0, t1) = curve.range_tuple();
+        let mut params: Vec<f64> = (0..100)
+            .map(|_| t0 - 0.5 + (t1 - t0 + 1.0) * rand::random::<f64>())
+            .chain(curve.knot_vec().iter().copied())
+            .collect();
+        let check = |params: &[f64]| {
+            let res = curve.subs_iter(params.iter().copied());
+            params.iter().zip(res).for_each(|(t, p)| {
+                assert_eq!(curve.subs(*t), p, "parameter: {t}");
+            });
+        };
+        check(&params);
+        params.sort_by(f64::total_cmp);
+        check(&params);
+        params.reverse();
+        check(&params);
+        curve
+            .subs_uniform(64)
+            .enumerate()
+            .for_each(|(i, p)| assert_eq!(curve.subs(t0 + (t1 - t0) * i as f64 / 64.0), p));
+    }
+}
+
+#[test]
+fn subs_f32_random_test() {
+    use truck_base::cgmath::Point3 as Point3f;
+    let knots = [
+        vec![0.0, 0.0, 0.0, 0.0, 0.3, 0.3, 0.5, 1.0, 1.0, 1.0, 1.0],
+        // The interval `[1000, 1000.00001]` collapses in `f32`. The curve of the rounded knots
+        // differs from the original one only in the collapsed interval.
+        vec![
+            1.0e3,
+            1.0e3,
+            1.0e3,
+            1.0e3,
+            1.0e3 + 1.0e-5,
+            1.0e3 + 2.0,
+            1.0e3 + 2.0,
+            1.0e3 + 2.0,
+        ],
+    ];
+    for knot_vec in knots {
+        let collapsed = knot_vec[4] as f32;
+        let knot_vec = KnotVec::from(knot_vec);
+        let len = knot_vec.len() - 4;
+        let ctrl_pts: Vec<Point3> = (0..len)
+            .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+            .collect();
+        let curve = BSplineCurve::new(knot_vec.clone(), ctrl_pts.clone());
+        let ctrl_pts32: Vec<Point3f<f32>> = ctrl_pts.iter().map(|p| p.cast().unwrap()).collect();
+        let curve32 = BSplineCurve::new(knot_vec, ctrl_pts32);
+        let (t0, t1) = curve.range_tuple();
+        for i in 0..=100 {
+            let t = (t0 + (t1 - t0) * i as f64 / 100.0) as f32;
+            let p = curve.subs(t as f64);
+            let q = curve32.subs_f32(t).cast::<f64>().unwrap();
+            assert!(q.x.is_finite() && q.y.is_finite() && q.z.is_finite());
+            if t != collapsed {
+                assert!(p.distance(q) < 1.0e-3, "{t} {p:?} {q:?}");
+            }
+        }
+    }
+}
+
 impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     /// Returns whether all control points are the same or not.
     /// If the knot vector is clamped, it means whether the curve is constant or not.
@@ -456,6 +749,7 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     /// assert_eq!(bspcurve.back(), Vector2::new(0.0, 0.0));
     /// ```
     pub fn add_knot(&mut self, x: f64) -> &mut Self {
+        self.periodic = false;
         if x < self.knot_vec[0] {
             self.knot_vec.add_knot(x);
             self.control_points.insert(0, P::origin());
@@ -485,6 +779,41 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         self
     }
 
+    /// Inserts a knot `x` by Boehm's algorithm, and do not change `self` as a curve.
+    ///
+    /// Unlike [`BSplineCurve::add_knot`], returns an error if
+    /// - `x` is out of the range of the knot vector, or
+    /// - the multiplicity of `x` would exceed the degree.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(3);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(2.0, -1.0),
+    ///     Point2::new(3.0, 0.0),
+    /// ];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let org_curve = bspcurve.clone();
+    ///
+    /// // the multiplicity of 0.5 becomes the degree.
+    /// for _ in 0..3 {
+    ///     bspcurve.insert_knot(0.5).unwrap();
+    /// }
+    /// assert!(bspcurve.insert_knot(0.5).is_err());
+    /// assert!(bspcurve.insert_knot(-0.5).is_err());
+    /// assert_eq!(bspcurve.knot_vec().len(), org_curve.knot_vec().len() + 3);
+    /// for i in 0..=10 {
+    ///     let t = i as f64 / 10.0;
+    ///     assert_near!(bspcurve.subs(t), org_curve.subs(t));
+    /// }
+    /// ```
+    pub fn insert_knot(&mut self, x: f64) -> Result<&mut Self> {
+        check_knot_insertion(&self.knot_vec, self.degree(), x)?;
+        Ok(self.add_knot(x))
+    }
+
     /// Removes a knot corresponding to the indices `idx`, and do not change `self` as a curve.
     /// If cannot remove the knot, do not change `self` and return `self`.
     /// # Examples
@@ -554,6 +883,7 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
 
         self.control_points.remove(idx);
         self.knot_vec.remove(idx);
+        self.periodic = false;
         Ok(self)
     }
 
@@ -574,6 +904,8 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     }
 
     /// elevate 1 degree.
+    ///
+    /// The multiplicity of each interior knot is increased by one, and the curve is not changed geometrically.
     /// # Examples
     /// ```
     /// use truck_geometry::prelude::*;
@@ -586,11 +918,55 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     /// assert_eq!(bspcurve.control_point(1), &Vector2::new(0.5, 0.5));
     /// ```
     pub fn elevate_degree(&mut self) -> &mut Self {
+        let org_knot_vec = self.knot_vec.clone();
         let mut result = CurveCollector::Singleton;
         for mut bezier in self.bezier_decomposition() {
             result.concat(bezier.elevate_degree_bezier());
         }
         *self = result.unwrap();
+
+        // Bezier decomposition makes all interior knots have full multiplicity.
+        let (knots, _) = self.knot_vec.to_single_multi();
+        for knot in &knots[1..knots.len() - 1] {
+            let mult = org_knot_vec.iter().filter(|t| knot.near(t)).count();
+            loop {
+                let idx = self.knot_vec.iter().position(|t| knot.near(t)).unwrap();
+                if self.knot_vec.multiplicity(idx) <= mult + 1 || self.try_remove_knot(idx).is_err()
+                {
+                    break;
+                }
+            }
+        }
+        self
+    }
+
+    /// Elevates the degree up to `degree`. If the degree of `self` is already `degree` or more, does nothing.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::uniform_knot(2, 2);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(2.0, -1.0),
+    ///     Point2::new(3.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let mut elevated = bspcurve.clone();
+    /// elevated.elevate_to_degree(5);
+    /// assert_eq!(elevated.degree(), 5);
+    ///
+    /// // the interior knot 0.5 has multiplicity 1 + 3.
+    /// assert_eq!(elevated.knot_vec().multiplicity(6), 4);
+    /// for i in 0..=100 {
+    ///     let t = i as f64 / 100.0;
+    ///     assert_near!(elevated.subs(t), bspcurve.subs(t));
+    /// }
+    /// ```
+    pub fn elevate_to_degree(&mut self, degree: usize) -> &mut Self {
+        while self.degree() < degree {
+            self.elevate_degree();
+        }
         self
     }
 
@@ -742,6 +1118,71 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         }
     }
 
+    /// Splits `self` into two curves at the parameter `t`.
+    ///
+    /// The multiplicity of `t` is raised to the degree plus one, and the control points are partitioned.
+    /// The former curve has the knot domain `[front, t]` and the latter `[t, back]`,
+    /// so their concatenation reproduces `self`.
+    /// # Panics
+    /// Panics if `t` is not in the interior of the parameter range. cf. [`BSplineCurve::try_split`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::uniform_knot(3, 3);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(2.0, -1.0),
+    ///     Point2::new(3.0, 1.0),
+    ///     Point2::new(4.0, 0.0),
+    ///     Point2::new(5.0, 2.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let (curve0, curve1) = bspcurve.split(0.4);
+    /// assert_eq!(curve0.range_tuple(), (0.0, 0.4));
+    /// assert_eq!(curve1.range_tuple(), (0.4, 1.0));
+    ///
+    /// // continuity at the seam
+    /// assert_near!(curve0.back(), curve1.front());
+    /// assert_near!(curve0.back(), bspcurve.subs(0.4));
+    ///
+    /// // the concatenation reproduces the original curve.
+    /// let concat = curve0.try_concat(&curve1).unwrap();
+    /// for i in 0..=10 {
+    ///     let t = i as f64 / 10.0;
+    ///     assert_near!(concat.subs(t), bspcurve.subs(t));
+    /// }
+    /// ```
+    pub fn split(&self, t: f64) -> (BSplineCurve<P>, BSplineCurve<P>) {
+        self.try_split(t).unwrap_or_else(|| {
+            let (t0, t1) = self.range_tuple();
+            panic!("the parameter {t} is not in the interior of ({t0}, {t1}).")
+        })
+    }
+
+    /// Splits `self` into two curves at the parameter `t`. cf. [`BSplineCurve::split`]
+    ///
+    /// Returns `None` if `t` is not in the interior of the parameter range,
+    /// including the case where `t` is near the ends, which would yield a zero-range piece.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), Point2::new(2.0, 0.0)];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts);
+    /// assert!(bspcurve.try_split(0.5).is_some());
+    /// assert!(bspcurve.try_split(1.0e-8).is_none());
+    /// assert!(bspcurve.try_split(1.5).is_none());
+    /// ```
+    pub fn try_split(&self, t: f64) -> Option<(BSplineCurve<P>, BSplineCurve<P>)> {
+        let (t0, t1) = self.range_tuple();
+        if t <= t0 || t1 <= t || t.near(&t0) || t.near(&t1) {
+            return None;
+        }
+        let mut curve0 = self.clone();
+        let curve1 = curve0.cut(t);
+        Some((curve0, curve1))
+    }
+
     /// Separates `self` into Bezier curves by each knots.
     /// # Examples
     /// ```
@@ -777,6 +1218,50 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         result
     }
 
+    /// Returns the Bézier segments of `self`: the pairs of the parameter range of each knot span
+    /// and the control points of the Bézier curve on the span.
+    ///
+    /// The knots are inserted to the full multiplicity at every interior knot, so the
+    /// concatenation of the segments reproduces `self` exactly. Each segment has
+    /// `self.degree() + 1` control points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 3.0, 4.0, 4.0, 4.0]);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(2.0, 0.0),
+    ///     Point2::new(3.0, 2.0),
+    ///     Point2::new(4.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let segments = bspcurve.bezier_segments();
+    /// assert_eq!(segments.len(), 3);
+    /// assert_eq!(segments[1].0, (1.0, 3.0));
+    ///
+    /// const N: usize = 10;
+    /// segments.iter().for_each(|((t0, t1), ctrl_pts)| {
+    ///     let bezier = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts.clone());
+    ///     for i in 0..=N {
+    ///         let s = i as f64 / N as f64;
+    ///         let t = t0 + (t1 - t0) * s;
+    ///         assert_near!(bezier.subs(s), bspcurve.subs(t));
+    ///     }
+    /// });
+    /// ```
+    pub fn bezier_segments(&self) -> Vec<((f64, f64), Vec<P>)> {
+        self.bezier_decomposition()
+            .into_iter()
+            .map(|bezier| {
+                let knot_vec = bezier.knot_vec();
+                let range = (knot_vec[0], knot_vec[knot_vec.len() - 1]);
+                (range, bezier.control_points)
+            })
+            .collect()
+    }
+
     /// Makes the curve locally injective.
     /// # Example
     /// ```
@@ -1142,7 +1627,93 @@ where
         }
         Some(hint)
     }
+
+    /// Returns the parameter, the point and the distance of the globally nearest point on the
+    /// curve from `point`.
+    ///
+    /// The Bézier segments are subdivided recursively, skipping the ones which are not nearer
+    /// than the nearest point found so far, and the result is refined by Newton's method.
+    /// The distance is the global minimum within `tol`.
+    /// # Panics
+    /// `tol` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 3.0, 3.0]
+    /// );
+    /// let ctrl_pts = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 1.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// // Newton's method from `0.8` converges to the local solution, cf. `search_nearest_parameter`.
+    /// let pt = Point3::new(0.0, 0.5, 1.0);
+    /// let (t, foot, dist) = bspcurve.closest_point(pt, 1.0e-4);
+    /// assert_near!(t, 3.0);
+    /// assert_near!(foot, Point3::new(0.0, 1.0, 1.0));
+    /// assert_near!(dist, 0.5);
+    /// ```
+    pub fn closest_point(&self, point: P, tol: f64) -> (f64, P, f64) {
+        nonpositive_tolerance!(tol);
+        let eval = |t: f64| {
+            let pt = self.subs(t);
+            (t, pt, pt.distance(point))
+        };
+        let (t0, t1) = self.range_tuple();
+        let (res0, res1) = (eval(t0), eval(t1));
+        let mut nearest = if res0.2 <= res1.2 { res0 } else { res1 };
+        let mut segments = self.bezier_decomposition();
+        while let Some(mut segment) = segments.pop() {
+            // the distance to the chord minus the deviation of the control points is a lower bound.
+            let (front, back) = (segment.front(), segment.back());
+            let deviation = segment
+                .control_points()
+                .iter()
+                .map(|pt| segment_distance(*pt, front, back))
+                .fold(0.0, f64::max);
+            if segment_distance(point, front, back) - deviation >= nearest.2 - tol {
+                continue;
+            }
+            let (s0, s1) = segment.range_tuple();
+            let mid = eval((s0 + s1) / 2.0);
+            if mid.2 < nearest.2 {
+                nearest = mid;
+            }
+            // the knots nearer than `TOLERANCE` cannot be separated.
+            if front.distance(back) + deviation > tol && s1 - s0 > 2.0 * TOLERANCE {
+                let latter = segment.cut((s0 + s1) / 2.0);
+                segments.extend([segment, latter]);
+            }
+        }
+        let refined =
+            algo::curve::search_nearest_parameter(self, point, nearest.0, CLOSEST_POINT_TRIALS)
+                .filter(|t| t0 <= *t && *t <= t1)
+                .map(eval);
+        match refined {
+            Some(refined) if refined.2 < nearest.2 => refined,
+            _ => nearest,
+        }
+    }
+}
+
+/// Returns the distance from `pt` to the segment from `p` to `q`.
+fn segment_distance<P>(pt: P, p: P, q: P) -> f64
+where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+    P::Diff: InnerSpace<Scalar = f64>, {
+    let vec = q - p;
+    let len2 = vec.magnitude2();
+    let t = match len2 > 0.0 {
+        true => f64::clamp((pt - p).dot(vec) / len2, 0.0, 1.0),
+        false => 0.0,
+    };
+    pt.distance(p + vec * t)
 }
+
 impl<P> SearchNearestParameter<D1> for BSplineCurve<P>
 where
     P: ControlPoint<f64>
@@ -1243,52 +1814,597 @@ impl<P: Bounded> BSplineCurve<P> {
     pub fn roughly_bounding_box(&self) -> BoundingBox<P> { self.control_points.iter().collect() }
 }
 
-impl<P: Clone> Invertible for BSplineCurve<P> {
-    #[inline(always)]
-    fn invert(&mut self) { self.invert(); }
-}
-
-impl<M, P> Transformed<M> for BSplineCurve<P>
+impl<P> BSplineCurve<P>
 where
-    P: EuclideanSpace,
-    M: Transform<P>,
+    P: ControlPoint<f64> + Bounded<Scalar = f64>,
+    P::Diff: Array<Element = f64>,
 {
-    #[inline(always)]
-    fn transform_by(&mut self, trans: M) {
-        self.control_points
-            .iter_mut()
-            .for_each(|pt| *pt = trans.transform_point(*pt))
+    /// Returns the bounding box including the curve tightly.
+    ///
+    /// The extrema of each coordinate are searched by the zeros of the derivation,
+    /// and their parameters are determined within `tol`.
+    /// # Panics
+    /// Panics if `tol` is less than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// // the parabola y = 2x(1 - x)
+    /// let bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(0.5, 1.0), Point2::new(1.0, 0.0)],
+    /// );
+    /// assert_near!(bspcurve.roughly_bounding_box().max(), Point2::new(1.0, 1.0));
+    /// let bdd = bspcurve.tight_bounding_box(1.0e-4);
+    /// assert_near!(bdd.min(), Point2::new(0.0, 0.0));
+    /// assert_near!(bdd.max(), Point2::new(1.0, 0.5));
+    /// ```
+    pub fn tight_bounding_box(&self, tol: f64) -> BoundingBox<P> {
+        let division = self.control_points.len() * TIGHT_BOX_DIVISION;
+        algo::curve::tight_bounding_box(self, self.range_tuple(), division, tol)
     }
 }
 
-impl<'de, P> Deserialize<'de> for BSplineCurve<P>
-where P: Deserialize<'de>
+impl<P> BSplineCurve<P>
+where
+    P: ControlPoint<f64>,
+    P::Diff: InnerSpace<Scalar = f64>,
 {
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where D: serde::Deserializer<'de> {
-        #[derive(Deserialize)]
-        struct BSplineCurve_<P> {
-            knot_vec: KnotVec,
-            control_points: Vec<P>,
-        }
-        let BSplineCurve_ {
-            knot_vec,
-            control_points,
-        } = BSplineCurve_::<P>::deserialize(deserializer)?;
-        Self::try_new(knot_vec, control_points).map_err(serde::de::Error::custom)
+    /// Returns whether all control points are within `tol` from the first control point,
+    /// i.e. the curve degenerates to a point.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let ctrl_pts = vec![Point2::new(1.0, 1.0), Point2::new(1.0, 1.001), Point2::new(1.0, 1.0)];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts);
+    /// assert!(bspcurve.is_degenerate(0.01));
+    /// assert!(!bspcurve.is_degenerate(0.0001));
+    /// ```
+    #[inline(always)]
+    pub fn is_degenerate(&self, tol: f64) -> bool {
+        let p = self.control_points[0];
+        self.control_points
+            .iter()
+            .all(|q| (*q - p).magnitude() <= tol)
     }
-}
 
-#[test]
-fn test_near_as_curve() {
-    let knot_vec = KnotVec::from(vec![
-        0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 5.0, 5.0, 5.0,
-    ]);
-    let control_points = vec![
-        Vector4::new(1.0, 0.0, 0.0, 0.0),
-        Vector4::new(0.0, 1.0, 0.0, 0.0),
-        Vector4::new(0.0, 0.0, 1.0, 0.0),
-        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    /// Returns the map from the normalized arc-length `s` in `[0, 1]` to the curve parameter `t`.
+    ///
+    /// The length table is sampled adaptively on each knot span within `tol`,
+    /// and the parameter is searched by the binary search and refined by Newton's method.
+    /// Zero-length segments are skipped, and if the whole curve has zero length,
+    /// the parameter is linearly interpolated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tol` is less than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// // a line segment parametrized by c(t) = (t^2, 0)
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let param = bspcurve.arc_length_parametrization(0.001);
+    /// for i in 0..=10 {
+    ///     let s = i as f64 / 10.0;
+    ///     assert_near!(param(s), f64::sqrt(s));
+    /// }
+    /// ```
+    pub fn arc_length_parametrization(&self, tol: f64) -> impl Fn(f64) -> f64 {
+        let (knots, _) = self.knot_vec.to_single_multi();
+        let (mut params, mut lengths) = (vec![knots[0]], vec![0.0]);
+        knots.windows(2).for_each(|range| {
+            let (span_params, span_lengths) =
+                algo::curve::arc_length_table(self, (range[0], range[1]), tol);
+            let last = *lengths.last().unwrap();
+            params.extend_from_slice(&span_params[1..]);
+            lengths.extend(span_lengths[1..].iter().map(|l| last + l));
+        });
+        let curve = self.clone();
+        move |s| algo::curve::inverse_arc_length(&curve, &params, &lengths, s)
+    }
+
+    /// Creates the clamped B-spline curve with `degree` passing through all `points`.
+    ///
+    /// The points are parametrized by the centripetal method on `[0, 1]`, the knot vector is
+    /// given by averaging the parameters, and the control points are obtained by solving
+    /// the banded linear system.
+    /// # Failures
+    /// - If the number of points is not more than `degree`, returns [`Error::TooFewPoints`].
+    /// - If two consecutive points coincide, returns [`Error::CoincidentPoints`].
+    /// # Panics
+    /// Panics if `degree` is zero.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let pts = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 2.0, 0.0),
+    ///     Point3::new(3.0, 2.0, 1.0),
+    ///     Point3::new(4.0, 0.0, 1.0),
+    ///     Point3::new(6.0, 1.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::interpolate(&pts, 3).unwrap();
+    /// assert_eq!(bspcurve.degree(), 3);
+    /// assert_eq!(bspcurve.control_points().len(), 5);
+    ///
+    /// // the curve passes through all points
+    /// for pt in &pts {
+    ///     let t = bspcurve.search_nearest_parameter(*pt, None, 100).unwrap();
+    ///     assert_near!(bspcurve.subs(t), *pt);
+    /// }
+    /// ```
+    pub fn interpolate(points: &[P], degree: usize) -> Result<Self> {
+        assert!(degree > 0, "the degree must be positive.");
+        let n = points.len();
+        if n <= degree {
+            return Err(Error::TooFewPoints(n, degree));
+        }
+        let mut params = vec![0.0];
+        for (i, pair) in points.windows(2).enumerate() {
+            let dist = (pair[1] - pair[0]).magnitude();
+            if dist.so_small() {
+                return Err(Error::CoincidentPoints(i));
+            }
+            params.push(params[i] + f64::sqrt(dist));
+        }
+        let total = params[n - 1];
+        params.iter_mut().for_each(|t| *t /= total);
+        Ok(Self::interpolate_with_parameters(points, &params, degree))
+    }
+
+    /// Creates the clamped B-spline curve with `degree` passing through `points[i]` at `params[i]`.
+    ///
+    /// The knot vector is given by averaging the parameters as [`BSplineCurve::interpolate`].
+    /// # Panics
+    /// - Panics if `degree` is zero, or the number of points is not more than `degree`.
+    /// - Panics if the lengths of `points` and `params` are different.
+    /// - Panics if `params` is not strictly increasing from `0.0` to `1.0`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(2.0, 0.0),
+    ///     Point2::new(3.0, 1.0),
+    /// ];
+    /// let params = [0.0, 0.2, 0.7, 1.0];
+    /// let bspcurve = BSplineCurve::interpolate_with_parameters(&pts, &params, 2);
+    /// assert_eq!(bspcurve.degree(), 2);
+    /// pts.iter()
+    ///     .zip(params)
+    ///     .for_each(|(pt, t)| assert_near!(bspcurve.subs(t), *pt));
+    /// ```
+    pub fn interpolate_with_parameters(points: &[P], params: &[f64], degree: usize) -> Self {
+        assert!(degree > 0, "the degree must be positive.");
+        assert!(
+            points.len() > degree,
+            "the points are too few for the degree."
+        );
+        assert_eq!(
+            points.len(),
+            params.len(),
+            "the numbers of points and parameters must be the same."
+        );
+        assert!(
+            params[0] == 0.0
+                && params[params.len() - 1] == 1.0
+                && params.windows(2).all(|ts| ts[0] < ts[1]),
+            "the parameters must be strictly increasing from 0 to 1."
+        );
+        let (knot_vec, rows) = interpolation_system(params, degree);
+        let rhs = points.iter().map(|p| *p - points[0]).collect();
+        let control_points = solve_banded_system(rows, rhs)
+            .into_iter()
+            .map(|v| points[0] + v)
+            .collect();
+        Self::new_unchecked(knot_vec, control_points)
+    }
+
+    /// Creates the clamped B-spline curve with `degree` and `num_ctrl` control points
+    /// approximating `points` by the least squares method, and returns it with the achieved
+    /// maximum deviation.
+    ///
+    /// The end points of the curve are pinned to the first and the last points. The points are
+    /// parametrized by the chord length, and the parameters are corrected by Newton's method
+    /// until the maximum deviation is not more than `tol`, up to 10 times. The deviation of
+    /// each point is the distance to the corresponding point on the curve.
+    /// # Failures
+    /// - If `num_ctrl` is not more than `degree` or is more than the number of points,
+    ///   returns [`Error::InvalidControlPointBudget`].
+    /// - If all points coincide, returns [`Error::CoincidentPoints`].
+    /// # Panics
+    /// Panics if `degree` is zero.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// // noisy samples of a helix
+    /// let pts: Vec<Point3> = (0..=100)
+    ///     .map(|i| {
+    ///         let t = 2.0 * PI * i as f64 / 100.0;
+    ///         let noise = 0.001 * f64::sin(37.0 * t);
+    ///         Point3::new(f64::cos(t), f64::sin(t), t / PI + noise)
+    ///     })
+    ///     .collect();
+    /// let (bspcurve, deviation) = BSplineCurve::approximate(&pts, 3, 12, 0.01).unwrap();
+    /// assert_eq!(bspcurve.control_points().len(), 12);
+    /// assert!(deviation < 0.01);
+    ///
+    /// // the end points are pinned.
+    /// assert_near!(bspcurve.front(), pts[0]);
+    /// assert_near!(bspcurve.back(), pts[100]);
+    ///
+    /// // all points are within the deviation from the curve.
+    /// for pt in &pts {
+    ///     let t = bspcurve.search_nearest_parameter(*pt, None, 100).unwrap();
+    ///     assert!(bspcurve.subs(t).distance(*pt) <= deviation + TOLERANCE);
+    /// }
+    /// ```
+    pub fn approximate(
+        points: &[P],
+        degree: usize,
+        num_ctrl: usize,
+        tol: f64,
+    ) -> Result<(Self, f64)> {
+        assert!(degree > 0, "the degree must be positive.");
+        let m = points.len();
+        if num_ctrl <= degree || m < num_ctrl {
+            return Err(Error::InvalidControlPointBudget(num_ctrl, degree, m));
+        }
+        let mut params = vec![0.0];
+        points.windows(2).enumerate().for_each(|(i, pair)| {
+            params.push(params[i] + (pair[1] - pair[0]).magnitude());
+        });
+        let total = params[m - 1];
+        if total.so_small() {
+            return Err(Error::CoincidentPoints(0));
+        }
+        params.iter_mut().for_each(|t| *t /= total);
+
+        let mut knots = vec![0.0; degree + 1];
+        let d = m as f64 / (num_ctrl - degree) as f64;
+        knots.extend((1..num_ctrl - degree).map(|j| {
+            let x = j as f64 * d;
+            let (i, a) = (x as usize, x.fract());
+            (1.0 - a) * params[i - 1] + a * params[i]
+        }));
+        knots.extend(vec![1.0; degree + 1]);
+        let knot_vec = KnotVec(knots);
+
+        let mut res = Self::least_squares(points, &params, knot_vec.clone(), degree);
+        let mut deviation = res.max_deviation(points, &params);
+        for _ in 0..10 {
+            if deviation <= tol {
+                break;
+            }
+            let params1: Vec<f64> = points
+                .iter()
+                .zip(&params)
+                .map(|(pt, &t)| {
+                    let (diff, der) = (res.subs(t) - *pt, res.der(t));
+                    let denom = der.dot(der) + diff.dot(res.der2(t));
+                    match denom.so_small() {
+                        true => t,
+                        false => f64::clamp(t - diff.dot(der) / denom, 0.0, 1.0),
+                    }
+                })
+                .collect();
+            let curve = Self::least_squares(points, &params1, knot_vec.clone(), degree);
+            let deviation1 = curve.max_deviation(points, &params1);
+            if deviation1 >= deviation {
+                break;
+            }
+            (res, params, deviation) = (curve, params1, deviation1);
+        }
+        Ok((res, deviation))
+    }
+
+    /// The least squares fitting with pinned end points.
+    fn least_squares(points: &[P], params: &[f64], knot_vec: KnotVec, degree: usize) -> Self {
+        let (m, n) = (points.len(), knot_vec.len() - degree - 1);
+        let (front, back) = (points[0], points[m - 1]);
+        // the normal matrix for the interior control points
+        let dim = n - 2;
+        let mut rows: Vec<(usize, Vec<f64>)> = (0..dim)
+            .map(|i| {
+                let start = i.saturating_sub(degree);
+                (start, vec![0.0; usize::min(i + degree + 1, dim) - start])
+            })
+            .collect();
+        let mut rhs = vec![P::Diff::zero(); dim];
+        points[1..m - 1]
+            .iter()
+            .zip(&params[1..m - 1])
+            .for_each(|(pt, &t)| {
+                let basis = knot_vec.bspline_basis_functions(degree, t);
+                let r = (*pt - front) - (back - front) * basis[n - 1];
+                (1..n - 1).filter(|&i| basis[i] != 0.0).for_each(|i| {
+                    rhs[i - 1] += r * basis[i];
+                    let (start, band) = &mut rows[i - 1];
+                    (1..n - 1)
+                        .filter(|&j| basis[j] != 0.0)
+                        .for_each(|j| band[j - 1 - *start] += basis[i] * basis[j]);
+                });
+            });
+        let mut control_points = vec![front];
+        control_points.extend(
+            solve_banded_system(rows, rhs)
+                .into_iter()
+                .map(|v| front + v),
+        );
+        control_points.push(back);
+        Self::new_unchecked(knot_vec, control_points)
+    }
+
+    fn max_deviation(&self, points: &[P], params: &[f64]) -> f64 {
+        points
+            .iter()
+            .zip(params)
+            .map(|(pt, &t)| (self.subs(t) - *pt).magnitude())
+            .fold(0.0, f64::max)
+    }
+}
+
+impl<P> BSplineCurve<P>
+where
+    P: ControlPoint<f64> + Tolerance,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    /// Reduces the degree of the Bézier curve by one. Returns the reduced curve and the upper
+    /// bound of the deviation, the distance between the control points of `self` and those of
+    /// the reduced curve elevated again.
+    fn reduce_degree_bezier(&self) -> (Self, f64) {
+        let p = self.degree();
+        let pts = &self.control_points;
+        let a = |i: usize| i as f64 / p as f64;
+        let mut q = vec![pts[0]; p];
+        q[p - 1] = pts[p];
+        // the control points are determined from both ends, and blended at the middle.
+        let r = (p - 1) / 2;
+        let forward = |q: &[P], i: usize| q[i - 1] + (pts[i] - q[i - 1]) / (1.0 - a(i));
+        let backward = |q: &[P], i: usize| q[i + 1] + (pts[i + 1] - q[i + 1]) / a(i + 1);
+        // `p / 2 == r` if and only if `p` is odd.
+        (1..p / 2).for_each(|i| q[i] = forward(&q, i));
+        (r + 1..p - 1).rev().for_each(|i| q[i] = backward(&q, i));
+        if p / 2 == r {
+            let (left, right) = (forward(&q, r), backward(&q, r));
+            q[r] = left + (right - left) / 2.0;
+        }
+        let (t0, t1) = (self.knot_vec[0], self.knot_vec[self.knot_vec.len() - 1]);
+        let knots = std::iter::repeat_n(t0, p).chain(std::iter::repeat_n(t1, p));
+        let reduced = BSplineCurve::new_unchecked(KnotVec::from_iter(knots), q);
+        let mut elevated = reduced.clone();
+        elevated.elevate_degree_bezier();
+        let deviation = pts
+            .iter()
+            .zip(&elevated.control_points)
+            .map(|(p, q)| (*p - *q).magnitude())
+            .fold(0.0, f64::max);
+        (reduced, deviation)
+    }
+
+    /// Reduces the degree by one if the reduced curve is within `tol` from `self`.
+    ///
+    /// Returns the reduced curve and the upper bound of the deviation from `self`. The continuity
+    /// at each interior knot is kept if it is possible, i.e. the multiplicity of the knot is
+    /// decreased by one. If the deviation exceeds `tol`, or the degree is less than 2, returns
+    /// [`Error::DegreeNotReducible`] with the deviation.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(3.0, 2.0),
+    ///     Point2::new(4.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec.clone(), ctrl_pts);
+    /// let mut elevated = bspcurve.clone();
+    /// elevated.elevate_degree();
+    ///
+    /// let (reduced, deviation) = elevated.reduce_degree(1.0e-6).unwrap();
+    /// assert_eq!(reduced.degree(), 2);
+    /// assert_eq!(reduced.knot_vec(), &knot_vec);
+    /// assert!(deviation < 1.0e-6);
+    /// assert!(reduced.near2_as_curve(&bspcurve));
+    ///
+    /// // the genuine quadratic curve cannot be reduced.
+    /// assert!(matches!(
+    ///     bspcurve.reduce_degree(0.1),
+    ///     Err(Error::DegreeNotReducible(deviation)) if deviation > 0.1,
+    /// ));
+    /// ```
+    pub fn reduce_degree(&self, tol: f64) -> Result<(Self, f64)> {
+        if self.degree() < 2 {
+            return Err(Error::DegreeNotReducible(f64::INFINITY));
+        }
+        let mut deviation = 0.0;
+        let mut result = CurveCollector::<Self>::Singleton;
+        for bezier in self.bezier_decomposition() {
+            let (reduced, dev) = bezier.reduce_degree_bezier();
+            deviation = f64::max(deviation, dev);
+            result.concat(&reduced);
+        }
+        if deviation > tol {
+            return Err(Error::DegreeNotReducible(deviation));
+        }
+        let mut reduced = result.unwrap();
+
+        // Bezier decomposition makes all interior knots have full multiplicity.
+        let (knots, _) = reduced.knot_vec.to_single_multi();
+        for knot in &knots[1..knots.len() - 1] {
+            let mult = self.knot_vec.iter().filter(|t| knot.near(t)).count();
+            loop {
+                let idx = reduced.knot_vec.iter().position(|t| knot.near(t)).unwrap();
+                if reduced.knot_vec.multiplicity(idx) < usize::max(mult, 2)
+                    || reduced.try_remove_knot(idx).is_err()
+                {
+                    break;
+                }
+            }
+        }
+        Ok((reduced, deviation))
+    }
+
+    /// Joins `other` to the end of `self`.
+    ///
+    /// The curve of the lower degree is elevated to the degree of the other, and the knot vector
+    /// of `other` is translated so that it starts at the end of the one of `self`. The end of
+    /// `self` and the start of `other` are moved to their midpoint, and the knots at the joint
+    /// are removed as far as the curve is not changed, e.g. the curve split by [`Cut::cut`] is
+    /// recovered. If the gap between the end of `self` and the start of `other` is larger than
+    /// `tol`, returns [`Error::DisconnectedCurves`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::uniform_knot(2, 3);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(2.0, -1.0),
+    ///     Point2::new(3.0, 1.0),
+    ///     Point2::new(4.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let mut part0 = bspcurve.clone();
+    /// let part1 = part0.cut(0.4);
+    ///
+    /// let joined = part0.join(&part1, TOLERANCE).unwrap();
+    /// assert_eq!(joined.knot_vec(), bspcurve.knot_vec());
+    /// assert!(joined.near2_as_curve(&bspcurve));
+    /// ```
+    pub fn join(&self, other: &Self, tol: f64) -> Result<Self> {
+        let gap = (other.front() - self.back()).magnitude();
+        if gap > tol {
+            return Err(Error::DisconnectedCurves(gap));
+        }
+        let mut curve0 = self.clone();
+        let mut curve1 = other.clone();
+        curve0.syncro_degree(&mut curve1);
+        curve0.clamp();
+        curve1.clamp();
+        let joint = curve0.knot_vec[curve0.knot_vec.len() - 1];
+        curve1.knot_translate(joint - curve1.knot_vec[0]);
+        let middle = curve0.back() + (curve1.front() - curve0.back()) / 2.0;
+        *curve0.control_points.last_mut().unwrap() = middle;
+        curve1.control_points[0] = middle;
+
+        let mut curve = curve0.concat(&curve1);
+        while let Some(idx) = curve.knot_vec.iter().position(|t| t.near(&joint)) {
+            if curve.try_remove_knot(idx).is_err() {
+                break;
+            }
+        }
+        Ok(curve)
+    }
+}
+
+impl<P: Clone> Invertible for BSplineCurve<P> {
+    #[inline(always)]
+    fn invert(&mut self) { self.invert(); }
+}
+
+impl<M, P> Transformed<M> for BSplineCurve<P>
+where
+    P: EuclideanSpace,
+    M: Transform<P>,
+{
+    #[inline(always)]
+    fn transform_by(&mut self, trans: M) {
+        self.control_points
+            .iter_mut()
+            .for_each(|pt| *pt = trans.transform_point(*pt))
+    }
+}
+
+/// Returns whether the knots and the control points have the structure made by
+/// [`BSplineCurve::make_periodic`], i.e. the knot vector is uniform, hence unclamped, and the
+/// last `degree` control points repeat the first ones.
+fn is_periodic_structure<P: PartialEq>(knot_vec: &KnotVec, control_points: &[P]) -> bool {
+    let len = control_points.len();
+    let degree = knot_vec.len() - len - 1;
+    let delta = knot_vec[1] - knot_vec[0];
+    degree > 0
+        && len > 2 * degree
+        && knot_vec.windows(2).all(|w| (w[1] - w[0]).near(&delta))
+        && (0..degree).all(|i| control_points[len - degree + i] == control_points[i])
+}
+
+impl<'de, P> Deserialize<'de> for BSplineCurve<P>
+where P: Deserialize<'de> + PartialEq
+{
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct BSplineCurve_<P> {
+            knot_vec: KnotVec,
+            control_points: Vec<P>,
+            #[serde(default)]
+            periodic: bool,
+        }
+        let BSplineCurve_ {
+            knot_vec,
+            control_points,
+            periodic,
+        } = BSplineCurve_::<P>::deserialize(deserializer)?;
+        let mut curve =
+            Self::try_new(knot_vec, control_points).map_err(serde::de::Error::custom)?;
+        if periodic && !is_periodic_structure(&curve.knot_vec, &curve.control_points) {
+            return Err(serde::de::Error::custom(
+                "The knots or the control points are not periodic.",
+            ));
+        }
+        curve.periodic = periodic;
+        Ok(curve)
+    }
+}
+
+#[test]
+fn serde_round_trip() {
+    let bspcurve = BSplineCurve::new(
+        KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]),
+        vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 2.0),
+            Point2::new(2.0, -1.0),
+            Point2::new(3.0, 0.0),
+        ],
+    );
+    let json = serde_json::to_string(&bspcurve).unwrap();
+    let restored: BSplineCurve<Point2> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, bspcurve);
+
+    // the knot vector is too short for the control points.
+    let json = r#"{"knot_vec":[0.0,0.0,1.0,1.0],"control_points":[[0.0,0.0],[1.0,0.0],[2.0,0.0],[3.0,0.0]]}"#;
+    assert!(serde_json::from_str::<BSplineCurve<Point2>>(json).is_err());
+    // the knot vector is not sorted.
+    let json = r#"{"knot_vec":[0.0,0.0,1.0,0.5,1.0,1.0],"control_points":[[0.0,0.0],[1.0,0.0],[2.0,0.0]]}"#;
+    assert!(serde_json::from_str::<BSplineCurve<Point2>>(json).is_err());
+    assert!(serde_json::from_str::<KnotVec>("[0.0, 1.0, 0.5]").is_err());
+}
+
+#[test]
+fn test_near_as_curve() {
+    let knot_vec = KnotVec::from(vec![
+        0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 5.0, 5.0, 5.0,
+    ]);
+    let control_points = vec![
+        Vector4::new(1.0, 0.0, 0.0, 0.0),
+        Vector4::new(0.0, 1.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 1.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
         Vector4::new(1.0, 1.0, 0.0, 0.0),
         Vector4::new(1.0, 0.0, 1.0, 0.0),
         Vector4::new(1.0, 0.0, 0.0, 1.0),
@@ -1486,3 +2602,1057 @@ fn cubic_bezier_interpolation_test() {
     assert_near!(der.front(), der0);
     assert_near!(der.back(), der1);
 }
+
+#[test]
+fn arc_length_parametrization_test() {
+    let knot_vec = KnotVec::uniform_knot(3, 4);
+    let ctrl_pts = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 3.0, 0.0),
+        Point3::new(0.0, 1.0, 2.0),
+        Point3::new(4.0, 1.0, 1.0),
+        Point3::new(2.0, 2.0, 2.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let param = bspcurve.arc_length_parametrization(1.0e-4);
+    let (_, lengths) = algo::curve::arc_length_table(&bspcurve, (0.0, 1.0), 1.0e-4);
+    let total = *lengths.last().unwrap();
+    assert_eq!(param(0.0), 0.0);
+    assert_near!(param(1.0), 1.0);
+    const N: usize = 20;
+    (0..N).for_each(|i| {
+        let t0 = param(i as f64 / N as f64);
+        let t1 = param((i + 1) as f64 / N as f64);
+        let (_, lengths) = algo::curve::arc_length_table(&bspcurve, (t0, t1), 1.0e-4);
+        assert!(f64::abs(lengths.last().unwrap() - total / N as f64) < 1.0e-4);
+    });
+
+    // the middle segment has zero length.
+    let knot_vec = KnotVec::uniform_knot(1, 3);
+    let ctrl_pts = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(2.0, 0.0),
+    ];
+    let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let param = bspcurve.arc_length_parametrization(1.0e-4);
+    assert_near!(param(0.25), 1.0 / 6.0);
+    assert_near!(param(0.5), 1.0 / 3.0);
+    assert_near!(param(0.75), 5.0 / 6.0);
+
+    // constant curve
+    let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(1), vec![Point2::new(1.0, 1.0); 2]);
+    let param = bspcurve.arc_length_parametrization(1.0e-4);
+    assert_near!(param(0.3), 0.3);
+}
+
+#[test]
+fn degenerate_curve_test() {
+    let pt = Point3::new(1.0, 2.0, 3.0);
+    let curve = BSplineCurve::new(KnotVec::uniform_knot(2, 3), vec![pt; 5]);
+    assert!(curve.is_degenerate(TOLERANCE));
+
+    let (curve0, curve1) = curve.split(0.5);
+    assert!(curve0.is_degenerate(TOLERANCE) && curve1.is_degenerate(TOLERANCE));
+    assert_near!(curve0.subs(0.2), pt);
+    assert_near!(curve1.subs(0.7), pt);
+    assert!(curve.try_split(TOLERANCE / 2.0).is_none());
+
+    let concat = curve0.try_concat(&curve1).unwrap();
+    assert_near!(concat.subs(0.7), pt);
+
+    let inverse = curve.inverse();
+    assert_near!(inverse.subs(0.3), pt);
+
+    assert_eq!(curve.length(TOLERANCE), 0.0);
+    let param = curve.arc_length_parametrization(TOLERANCE);
+    (0..=10).for_each(|i| {
+        let s = i as f64 / 10.0;
+        assert_near!(param(s), s);
+    });
+}
+
+#[test]
+fn elevate_degree_random_test() {
+    let knot_vec = KnotVec::from(vec![
+        0.0, 0.0, 0.0, 0.0, 0.2, 0.4, 0.4, 0.7, 0.7, 0.7, 1.0, 1.0, 1.0, 1.0,
+    ]);
+    let ctrl_pts: Vec<Point3> = (0..10)
+        .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+        .collect();
+    let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let mut elevated = bspcurve.clone();
+    elevated.elevate_degree();
+    assert_eq!(elevated.degree(), 4);
+    let (knots, mults) = elevated.knot_vec().to_single_multi();
+    assert_eq!(knots, vec![0.0, 0.2, 0.4, 0.7, 1.0]);
+    assert_eq!(mults, vec![5, 2, 3, 4, 5]);
+    const N: usize = 100;
+    (0..=N).for_each(|i| {
+        let t = i as f64 / N as f64;
+        assert_near!(elevated.subs(t), bspcurve.subs(t));
+    });
+}
+
+#[test]
+fn reduce_degree_test() {
+    // an elevated line
+    let line = BSplineCurve::new(
+        KnotVec::from(vec![0.0, 0.0, 0.4, 1.0, 1.0]),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(0.4, 0.8, 1.2),
+            Point3::new(1.0, 2.0, 3.0),
+        ],
+    );
+    let mut elevated = line.clone();
+    elevated.elevate_to_degree(3);
+    let (reduced, deviation) = elevated.reduce_degree(TOLERANCE).unwrap();
+    let (reduced, deviation0) = reduced.reduce_degree(TOLERANCE).unwrap();
+    assert_eq!(reduced.degree(), 1);
+    assert!(deviation < TOLERANCE && deviation0 < TOLERANCE);
+    assert_eq!(reduced.knot_vec(), line.knot_vec());
+    reduced
+        .control_points()
+        .iter()
+        .zip(line.control_points())
+        .for_each(|(p, q)| assert_near!(p, q));
+    assert!(matches!(
+        reduced.reduce_degree(1.0),
+        Err(Error::DegreeNotReducible(_))
+    ));
+
+    // random curve with interior knots of the various multiplicities
+    let knot_vec = KnotVec::from(vec![
+        0.0, 0.0, 0.0, 0.0, 0.2, 0.4, 0.4, 0.7, 0.7, 0.7, 1.0, 1.0, 1.0, 1.0,
+    ]);
+    let ctrl_pts: Vec<Point3> = (0..10)
+        .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+        .collect();
+    let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let mut elevated = bspcurve.clone();
+    elevated.elevate_degree();
+    let (reduced, _) = elevated.reduce_degree(TOLERANCE).unwrap();
+    assert_eq!(reduced.knot_vec(), bspcurve.knot_vec());
+    assert!(reduced.near2_as_curve(&bspcurve));
+
+    // the deviation is the bound of the distance.
+    let (reduced, deviation) = bspcurve.reduce_degree(f64::INFINITY).unwrap();
+    assert_eq!(reduced.degree(), 2);
+    const N: usize = 100;
+    let max = (0..=N)
+        .map(|i| i as f64 / N as f64)
+        .map(|t| reduced.subs(t).distance(bspcurve.subs(t)))
+        .fold(0.0, f64::max);
+    assert!(max <= deviation + TOLERANCE);
+    let err = bspcurve.reduce_degree(deviation / 2.0).unwrap_err();
+    assert_eq!(err, Error::DegreeNotReducible(deviation));
+}
+
+#[test]
+fn join_test() {
+    let bspcurve = BSplineCurve::new(
+        KnotVec::uniform_knot(3, 4),
+        (0..7)
+            .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+            .collect(),
+    );
+    // splitting at the knot and the non-knot parameters
+    for t in [0.5, 0.56] {
+        let mut part0 = bspcurve.clone();
+        let part1 = part0.cut(t);
+        let joined = part0.join(&part1, TOLERANCE).unwrap();
+        assert_eq!(joined.knot_vec(), bspcurve.knot_vec());
+        assert!(joined.near2_as_curve(&bspcurve));
+    }
+
+    // a line of the different degree and the different parameter range
+    let line = BSplineCurve::new(
+        KnotVec::from(vec![5.0, 5.0, 7.0, 7.0]),
+        vec![
+            bspcurve.back() + Vector3::new(0.0, 0.0, 0.001),
+            Point3::new(2.0, 2.0, 2.0),
+        ],
+    );
+    let joined = bspcurve.join(&line, 0.01).unwrap();
+    assert_eq!(joined.degree(), 3);
+    assert_eq!(joined.range_tuple(), (0.0, 3.0));
+    let middle = bspcurve.back() + Vector3::new(0.0, 0.0, 0.0005);
+    assert_near!(joined.subs(1.0), middle);
+    // the curves are moved at most the half of the gap.
+    assert!(joined.subs(2.0).distance(line.subs(6.0)) < 0.0005);
+    assert!(joined.subs(0.5).distance(bspcurve.subs(0.5)) < 0.0005);
+    let end = Point3::new(2.0, 2.0, 2.0);
+    assert_near!(joined.back(), end);
+
+    match bspcurve.join(&line, 0.0001) {
+        Err(Error::DisconnectedCurves(gap)) => assert_near!(gap, 0.001),
+        _ => panic!("the curves must be disconnected"),
+    }
+}
+
+#[test]
+fn bezier_segments_random_test() {
+    let knot_vecs = [
+        KnotVec::from(vec![
+            0.0, 0.0, 0.0, 0.0, 0.2, 0.4, 0.4, 0.7, 0.7, 0.7, 1.0, 1.0, 1.0, 1.0,
+        ]),
+        KnotVec::from((0..14).map(|i| i as f64).collect::<Vec<_>>()),
+    ];
+    knot_vecs.into_iter().for_each(|knot_vec| {
+        let ctrl_pts: Vec<Point3> = (0..10)
+            .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+            .collect();
+        let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+        let (t0, t1) = bspcurve.range_tuple();
+        let segments = bspcurve.bezier_segments();
+        assert_eq!(segments[0].0 .0, t0);
+        assert_eq!(segments[segments.len() - 1].0 .1, t1);
+        segments
+            .windows(2)
+            .for_each(|x| assert_eq!(x[0].0 .1, x[1].0 .0));
+        segments.iter().for_each(|&((s0, s1), ref ctrl_pts)| {
+            assert_eq!(ctrl_pts.len(), 4);
+            let bezier = BSplineCurve::new(KnotVec::bezier_knot(3), ctrl_pts.clone());
+            const N: usize = 10;
+            (0..=N).for_each(|i| {
+                let s = i as f64 / N as f64;
+                assert_near!(bezier.subs(s), bspcurve.subs(s0 + (s1 - s0) * s));
+            });
+        });
+    });
+}
+
+#[test]
+fn interpolate_random_test() {
+    let pts: Vec<Point3> = (0..20)
+        .map(|i| Point3::new(i as f64, rand::random(), rand::random()))
+        .collect();
+    (1..=5).for_each(|degree| {
+        let bspcurve = BSplineCurve::interpolate(&pts, degree).unwrap();
+        assert_eq!(bspcurve.degree(), degree);
+        assert!(bspcurve.is_clamped());
+        let mut t = 0.0;
+        let dists: Vec<f64> = pts.windows(2).map(|p| p[0].distance(p[1]).sqrt()).collect();
+        let total: f64 = dists.iter().sum();
+        assert_near!(bspcurve.subs(t), pts[0]);
+        dists.iter().zip(&pts[1..]).for_each(|(d, pt)| {
+            t += d / total;
+            assert_near!(bspcurve.subs(t), *pt);
+        });
+    });
+    assert!(matches!(
+        BSplineCurve::interpolate(&pts[..4], 4),
+        Err(Error::TooFewPoints(4, 4)),
+    ));
+    let mut pts = pts;
+    pts[10] = pts[9];
+    assert!(matches!(
+        BSplineCurve::interpolate(&pts, 3),
+        Err(Error::CoincidentPoints(9)),
+    ));
+}
+
+#[test]
+fn approximate_test() {
+    let pts: Vec<Point3> = (0..=50)
+        .map(|i| {
+            let t = i as f64 / 50.0;
+            let noise = Vector3::new(rand::random(), rand::random(), rand::random()) * 0.001;
+            Point3::new(t, f64::sin(4.0 * t), t * t) + noise
+        })
+        .collect();
+
+    // the segment between the end points
+    let (line, _) = BSplineCurve::approximate(&pts, 1, 2, 0.0).unwrap();
+    assert_eq!(line.control_points(), &vec![pts[0], pts[50]]);
+
+    // points on a segment are reproduced, since the chord length parametrization is linear.
+    let segment: Vec<Point3> = (0..=50)
+        .map(|i| Point3::new(1.0, 2.0, 3.0) * f64::powi(i as f64 / 50.0, 2))
+        .collect();
+    let (_, deviation) = BSplineCurve::approximate(&segment, 3, 10, 0.0).unwrap();
+    assert!(deviation < TOLERANCE, "{deviation}");
+
+    let deviations: Vec<f64> = [4, 8, 16]
+        .into_iter()
+        .map(|num_ctrl| {
+            let (bspcurve, deviation) = BSplineCurve::approximate(&pts, 3, num_ctrl, 0.0).unwrap();
+            assert_eq!(bspcurve.degree(), 3);
+            assert_eq!(bspcurve.control_points().len(), num_ctrl);
+            assert_near!(bspcurve.front(), pts[0]);
+            assert_near!(bspcurve.back(), pts[50]);
+            deviation
+        })
+        .collect();
+    assert!(deviations[0] > deviations[1] && deviations[1] > deviations[2]);
+    assert!(deviations[2] < 0.01, "{deviations:?}");
+}
+
+/// the number of the trials of Newton's method refining the closest point
+const CLOSEST_POINT_TRIALS: usize = 100;
+/// the maximum depth of the subdivision in the intersection
+const INTERSECTION_MAX_DEPTH: usize = 100;
+/// the number of sample points in checking overlaps
+const OVERLAP_SAMPLES: usize = 5;
+/// the initial number of samples in each knot span in offsetting
+const OFFSET_INITIAL_DIVISION: usize = 4;
+/// the maximum number of doubling the samples in offsetting
+const OFFSET_MAX_REFINEMENT: usize = 10;
+
+impl BSplineCurve<Point2> {
+    /// Returns the intersections of two planar curves within `tol`.
+    ///
+    /// Each intersection is given by the parameter on `self`, the one on `other`, and the point.
+    /// The curves are subdivided recursively while the bounding boxes of the control points
+    /// intersect, and the intersections are refined by Newton's method. If the curves overlap,
+    /// i.e. a part of one curve is within `tol` from the other, only the end points of the
+    /// overlap are returned. The intersections are sorted by the parameter on `self`.
+    /// # Panics
+    /// Panics if `tol` is less than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// // the parabola y = 2x(1 - x) and the line y = 1/4
+    /// let parabola = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(0.5, 1.0), Point2::new(1.0, 0.0)],
+    /// );
+    /// let line = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(0.0, 0.25), Point2::new(1.0, 0.25)],
+    /// );
+    /// let res = parabola.intersect(&line, 1.0e-4);
+    /// assert_eq!(res.len(), 2);
+    /// let x = (1.0 - f64::sqrt(0.5)) / 2.0;
+    /// assert_near!(res[0].2, Point2::new(x, 0.25));
+    /// assert_near!(res[1].2, Point2::new(1.0 - x, 0.25));
+    /// res.iter().for_each(|&(s, t, p)| {
+    ///     assert_near!(parabola.subs(s), p);
+    ///     assert_near!(line.subs(t), p);
+    /// });
+    /// ```
+    /// The overlap is returned by its end points.
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// let line0 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(2.0, 2.0)],
+    /// );
+    /// let line1 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point2::new(1.0, 1.0), Point2::new(2.0, 2.0), Point2::new(3.0, 3.0)],
+    /// );
+    /// let res = line0.intersect(&line1, 1.0e-4);
+    /// assert_eq!(res.len(), 2);
+    /// assert_near!(res[0].2, Point2::new(1.0, 1.0));
+    /// assert_near!(res[1].2, Point2::new(2.0, 2.0));
+    /// ```
+    pub fn intersect(&self, other: &Self, tol: f64) -> Vec<(f64, f64, Point2)> {
+        nonpositive_tolerance!(tol);
+        let mut candidates = Vec::new();
+        let beziers1 = other.bezier_decomposition();
+        self.bezier_decomposition().into_iter().for_each(|bezier0| {
+            beziers1.iter().for_each(|bezier1| {
+                let parts = (bezier0.clone(), bezier1.clone());
+                self.sub_intersect(other, parts, tol, INTERSECTION_MAX_DEPTH, &mut candidates)
+            })
+        });
+        // The end points of false overlaps near transversal intersections are refined here.
+        // Those of true overlaps are kept, since they are already intersections.
+        candidates.iter_mut().for_each(|x| {
+            if let Some((s, t)) = newton_intersection(self, other, (x.0, x.1)) {
+                *x = (s, t, self.subs(s));
+            }
+        });
+        candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        // removes the interior points of the overlaps
+        let len = candidates.len();
+        let connected: Vec<bool> = candidates
+            .windows(2)
+            .map(|x| connected_by(self, other, x[0].0, x[1].0, tol))
+            .collect();
+        let candidates = candidates
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| *i == 0 || *i == len - 1 || !(connected[i - 1] && connected[*i]))
+            .map(|(_, x)| x);
+
+        // removes the duplicated points
+        let mut res: Vec<(f64, f64, Point2)> = Vec::new();
+        candidates.for_each(|x| match res.last() {
+            Some(y) if x.2.distance(y.2) <= tol => {}
+            _ => res.push(x),
+        });
+        res
+    }
+
+    /// Returns the cubic B-spline curve approximating the offset of `self` by `distance`
+    /// within `tol`.
+    ///
+    /// The offset direction is the normal `(der.y, -der.x)`, i.e. the right-hand side of the
+    /// tangent, so a positive `distance` expands a counter-clockwise closed curve. The exact
+    /// offset points are sampled uniformly in each knot span and interpolated, and the samples
+    /// are doubled until the midpoints between them are within `tol` from the result.
+    ///
+    /// `self` must be tangent continuous. Self-intersections of the offset, which arise in
+    /// concave regions whose radius of curvature is less than `distance.abs()`, are left in
+    /// the result.
+    /// # Failures
+    /// - If all the control points of `self` coincide, returns [`Error::DegenerateCurve`].
+    /// - If the derivation of `self` vanishes at a sample, returns [`Error::ZeroDerivative`].
+    /// - If two consecutive offset points coincide, e.g. the offset has a cusp,
+    ///   returns [`Error::CoincidentPoints`].
+    /// - If the samples are doubled 10 times but the result is not within `tol`,
+    ///   returns [`Error::ToleranceNotAchieved`].
+    /// # Panics
+    /// Panics if `tol` is less than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// // the parabola y = 2x(1 - x), whose normal at the apex is (0, 1) after inverting.
+    /// let mut parabola = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(0.5, 1.0), Point2::new(1.0, 0.0)],
+    /// );
+    /// parabola.invert();
+    /// let offset = parabola.offset(0.1, 1.0e-4).unwrap();
+    /// assert_near!(offset.front(), Point2::new(1.0, 0.0) + Vector2::new(2.0, 1.0).normalize() * 0.1);
+    /// let t = offset.search_nearest_parameter(Point2::new(0.5, 0.6), None, 100).unwrap();
+    /// assert!(offset.subs(t).distance(Point2::new(0.5, 0.6)) < 1.0e-4);
+    /// ```
+    pub fn offset(&self, distance: f64, tol: f64) -> Result<Self> {
+        nonpositive_tolerance!(tol);
+        let p = self.control_points[0];
+        if self.control_points.iter().all(|q| q.near(&p)) {
+            return Err(Error::DegenerateCurve);
+        }
+        let offset_point = |t: f64| {
+            let der = self.der(t);
+            match der.so_small() {
+                true => Err(Error::ZeroDerivative(t)),
+                false => Ok(self.subs(t) + Vector2::new(der.y, -der.x).normalize() * distance),
+            }
+        };
+        let (knots, _) = self.knot_vec.to_single_multi();
+        let mut division = OFFSET_INITIAL_DIVISION;
+        for _ in 0..=OFFSET_MAX_REFINEMENT {
+            let params: Vec<f64> = std::iter::once(knots[0])
+                .chain(knots.windows(2).flat_map(|range| {
+                    (1..=division)
+                        .map(move |i| range[0] + (range[1] - range[0]) * i as f64 / division as f64)
+                }))
+                .collect();
+            let points = params
+                .iter()
+                .map(|&t| offset_point(t))
+                .collect::<Result<Vec<_>>>()?;
+            let curve = Self::interpolate(&points, 3)?;
+            // the centripetal parameters of the points in the interpolation
+            let mut hints = vec![0.0];
+            points.windows(2).for_each(|p| {
+                let last = hints[hints.len() - 1];
+                hints.push(last + f64::sqrt(p[0].distance(p[1])));
+            });
+            let total = hints[hints.len() - 1];
+            let accurate = params
+                .windows(2)
+                .zip(hints.windows(2))
+                .map(|(t, s)| {
+                    let mid = offset_point((t[0] + t[1]) / 2.0)?;
+                    let hint = (s[0] + s[1]) / 2.0 / total;
+                    Ok(match curve.search_nearest_parameter(mid, Some(hint), 100) {
+                        Some(s) => curve.subs(s).distance(mid) <= tol,
+                        None => false,
+                    })
+                })
+                .collect::<Result<Vec<bool>>>()?;
+            if accurate.into_iter().all(|x| x) {
+                return Ok(curve);
+            }
+            division *= 2;
+        }
+        Err(Error::ToleranceNotAchieved(tol))
+    }
+
+    fn sub_intersect(
+        &self,
+        other: &Self,
+        (part0, part1): (Self, Self),
+        tol: f64,
+        depth: usize,
+        res: &mut Vec<(f64, f64, Point2)>,
+    ) {
+        let (box0, box1) = (part0.roughly_bounding_box(), part1.roughly_bounding_box());
+        let (min0, max0, min1, max1) = (box0.min(), box0.max(), box1.min(), box1.max());
+        if min0.x > max1.x + tol
+            || min1.x > max0.x + tol
+            || min0.y > max1.y + tol
+            || min1.y > max0.y + tol
+        {
+            return;
+        }
+        let (diam0, diam1) = (box0.diameter(), box1.diameter());
+        if diam0 > tol && overlap_ends(&part0, other, tol, res, false) {
+            return;
+        }
+        if diam1 > tol && overlap_ends(&part1, self, tol, res, true) {
+            return;
+        }
+        let (s, t) = (part0.middle_parameter(), part1.middle_parameter());
+        if depth == 0 || (diam0 <= tol && diam1 <= tol) {
+            let (s, t) = newton_intersection(self, other, (s, t)).unwrap_or((s, t));
+            let pt = self.subs(s);
+            if pt.distance(other.subs(t)) <= tol {
+                res.push((s, t, pt));
+            }
+        } else if diam0 >= diam1 {
+            let mut part00 = part0;
+            let part01 = part00.cut(s);
+            self.sub_intersect(other, (part00, part1.clone()), tol, depth - 1, res);
+            self.sub_intersect(other, (part01, part1), tol, depth - 1, res);
+        } else {
+            let mut part10 = part1;
+            let part11 = part10.cut(t);
+            self.sub_intersect(other, (part0.clone(), part10), tol, depth - 1, res);
+            self.sub_intersect(other, (part0, part11), tol, depth - 1, res);
+        }
+    }
+
+    fn middle_parameter(&self) -> f64 {
+        let (t0, t1) = self.range_tuple();
+        (t0 + t1) / 2.0
+    }
+}
+
+/// Returns the nearest parameter on `curve` in its range.
+fn nearest_parameter(curve: &BSplineCurve<Point2>, pt: Point2) -> Option<f64> {
+    let (t0, t1) = curve.range_tuple();
+    let t = curve.search_nearest_parameter(pt, None, 100)?;
+    Some(f64::clamp(t, t0, t1))
+}
+
+fn distance_to_curve(curve: &BSplineCurve<Point2>, pt: Point2) -> f64 {
+    match nearest_parameter(curve, pt) {
+        Some(t) => curve.subs(t).distance(pt),
+        None => f64::INFINITY,
+    }
+}
+
+/// Returns whether the part of `curve0` between `s0` and `s1` is on `curve1`.
+fn connected_by(
+    curve0: &BSplineCurve<Point2>,
+    curve1: &BSplineCurve<Point2>,
+    s0: f64,
+    s1: f64,
+    tol: f64,
+) -> bool {
+    (1..=OVERLAP_SAMPLES).all(|i| {
+        let p = i as f64 / (OVERLAP_SAMPLES + 1) as f64;
+        distance_to_curve(curve1, curve0.subs(s0 * (1.0 - p) + s1 * p)) <= tol
+    })
+}
+
+/// If `part` is on `curve`, pushes the end points of `part` to `res` and returns `true`.
+fn overlap_ends(
+    part: &BSplineCurve<Point2>,
+    curve: &BSplineCurve<Point2>,
+    tol: f64,
+    res: &mut Vec<(f64, f64, Point2)>,
+    swap: bool,
+) -> bool {
+    let (t0, t1) = part.range_tuple();
+    let params = (0..=OVERLAP_SAMPLES + 1)
+        .map(|i| {
+            let p = i as f64 / (OVERLAP_SAMPLES + 1) as f64;
+            let pt = part.subs(t0 * (1.0 - p) + t1 * p);
+            let t = nearest_parameter(curve, pt)?;
+            match curve.subs(t).distance(pt) <= tol {
+                true => Some(t),
+                false => None,
+            }
+        })
+        .collect::<Option<Vec<_>>>();
+    let Some(params) = params else {
+        return false;
+    };
+    [(t0, params[0]), (t1, params[OVERLAP_SAMPLES + 1])]
+        .into_iter()
+        .for_each(|(s, t)| match swap {
+            false => res.push((s, t, part.subs(s))),
+            true => res.push((t, s, curve.subs(t))),
+        });
+    true
+}
+
+/// Solves `curve0(s) = curve1(t)` by Newton's method.
+fn newton_intersection(
+    curve0: &BSplineCurve<Point2>,
+    curve1: &BSplineCurve<Point2>,
+    (mut s, mut t): (f64, f64),
+) -> Option<(f64, f64)> {
+    let (range0, range1) = (curve0.range_tuple(), curve1.range_tuple());
+    for _ in 0..16 {
+        let vec = curve0.subs(s) - curve1.subs(t);
+        if vec.so_small2() {
+            return Some((s, t));
+        }
+        let mat = Matrix2::from_cols(curve0.der(s), -curve1.der(t));
+        let delta = mat.invert()? * vec;
+        s = f64::clamp(s - delta.x, range0.0, range0.1);
+        t = f64::clamp(t - delta.y, range1.0, range1.1);
+    }
+    match (curve0.subs(s) - curve1.subs(t)).so_small() {
+        true => Some((s, t)),
+        false => None,
+    }
+}
+
+#[test]
+fn intersect_test() {
+    // tangency
+    let parabola = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(0.5, 1.0),
+            Point2::new(1.0, 0.0),
+        ],
+    );
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point2::new(0.0, 0.5), Point2::new(1.0, 0.5)],
+    );
+    let res = parabola.intersect(&line, 1.0e-3);
+    assert!(!res.is_empty() && res.len() <= 2, "{res:?}");
+    res.iter()
+        .for_each(|x| assert!(x.2.distance(Point2::new(0.5, 0.5)) < 0.05, "{res:?}"));
+
+    // curves with some knot spans
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.3, 0.6, 1.0, 1.0, 1.0, 1.0]);
+    let wave = BSplineCurve::new(
+        knot_vec,
+        (0..6)
+            .map(|i| Point2::new(i as f64, (-1.0f64).powi(i)))
+            .collect(),
+    );
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point2::new(0.0, 0.1), Point2::new(5.0, -0.1)],
+    );
+    let res = wave.intersect(&line, 1.0e-4);
+    assert_eq!(res.len(), 5, "{res:?}");
+    res.iter().for_each(|&(s, t, pt)| {
+        assert_near!(wave.subs(s), pt);
+        assert_near!(line.subs(t), pt);
+    });
+
+    // random cubic Bezier curves are compared with the polylines.
+    const N: usize = 300;
+    for _ in 0..10 {
+        let random_curve = || {
+            let ctrl_pts = (0..4)
+                .map(|_| Point2::new(rand::random(), rand::random()))
+                .collect();
+            BSplineCurve::new(KnotVec::bezier_knot(3), ctrl_pts)
+        };
+        let (curve0, curve1) = (random_curve(), random_curve());
+        let res = curve0.intersect(&curve1, 1.0e-4);
+        res.iter().for_each(|&(s, t, pt)| {
+            assert!(curve0.subs(s).distance(pt) < 1.0e-4);
+            assert!(curve1.subs(t).distance(pt) < 1.0e-4);
+        });
+        // near tangencies are returned as overlaps, which the polylines cannot count.
+        if !res
+            .iter()
+            .all(|&(s, t, _)| curve0.subs(s).near(&curve1.subs(t)))
+        {
+            continue;
+        }
+        let polyline = |curve: &BSplineCurve<Point2>| {
+            (0..=N)
+                .map(|i| curve.subs(i as f64 / N as f64))
+                .collect::<Vec<_>>()
+        };
+        let (poly0, poly1) = (polyline(&curve0), polyline(&curve1));
+        let count = poly0
+            .windows(2)
+            .flat_map(|p| poly1.windows(2).map(move |q| (p, q)))
+            .filter(|(p, q)| {
+                let line = Line(p[0], p[1]);
+                match line.intersection(Line(q[0], q[1])) {
+                    Some((s, t, _)) => (0.0..1.0).contains(&s) && (0.0..1.0).contains(&t),
+                    None => false,
+                }
+            })
+            .count();
+        assert_eq!(res.len(), count, "{curve0:?}\n{curve1:?}\n{res:?}");
+    }
+}
+
+#[test]
+fn offset_circle_test() {
+    const N: usize = 200;
+    let radius = 1.0 + rand::random::<f64>();
+    // the counter-clockwise circle approximated by the interpolation
+    let pts: Vec<Point2> = (0..=N)
+        .map(|i| {
+            let theta = 2.0 * std::f64::consts::PI * i as f64 / N as f64;
+            Point2::new(radius * f64::cos(theta), radius * f64::sin(theta))
+        })
+        .collect();
+    let circle = BSplineCurve::interpolate(&pts, 3).unwrap();
+    [0.5, -0.5, 0.25 * rand::random::<f64>()]
+        .into_iter()
+        .for_each(|distance| {
+            let offset = circle.offset(distance, 1.0e-4).unwrap();
+            (0..=1000).for_each(|i| {
+                let pt = offset.subs(i as f64 / 1000.0);
+                let r = f64::sqrt(pt.x * pt.x + pt.y * pt.y);
+                assert!(
+                    f64::abs(r - radius - distance) < 1.0e-4,
+                    "{r} {radius} {distance}"
+                );
+            });
+        });
+}
+
+#[test]
+fn periodic_random_test() {
+    (2..=4).for_each(|degree| {
+        let ctrl_pts: Vec<Point3> = (0..8)
+            .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+            .collect();
+        let bspcurve = BSplineCurve::make_periodic(ctrl_pts, degree);
+        assert!(bspcurve.is_periodic());
+        let (t0, t1) = bspcurve.range_tuple();
+        assert_eq!(bspcurve.period(), Some(t1 - t0));
+
+        // evaluates the back end without modulo
+        let knot_vec = bspcurve.knot_vec();
+        let basis = knot_vec.bspline_basis_functions(degree, t1 - 1.0e-12);
+        let back = bspcurve
+            .control_points()
+            .iter()
+            .zip(basis)
+            .fold(Vector3::zero(), |sum, (pt, b)| {
+                sum + Vector3::new(pt.x, pt.y, pt.z) * b
+            });
+        assert_near!(Point3::from_vec(back), bspcurve.front());
+        assert_near!(bspcurve.der(t1 - 1.0e-12), bspcurve.der(t0));
+        if degree > 2 {
+            assert_near!(bspcurve.der2(t1 - 1.0e-12), bspcurve.der2(t0));
+        }
+
+        const N: usize = 20;
+        (0..=N).for_each(|i| {
+            let t = -2.0 + 4.0 * i as f64 / N as f64;
+            let s = t0 + (t - t0).rem_euclid(t1 - t0);
+            assert_near!(bspcurve.subs(t), bspcurve.subs(s));
+            let pt = bspcurve.subs(s);
+            let u = bspcurve.search_parameter(pt, None, 100).unwrap();
+            assert_near!(bspcurve.subs(u), pt);
+        });
+    });
+}
+
+#[test]
+fn periodic_flag_test() {
+    let ctrl_pts: Vec<Point3> = (0..6)
+        .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+        .collect();
+    let periodic = BSplineCurve::make_periodic(ctrl_pts, 3);
+
+    // the same knots and control points without `make_periodic` are not wrapped.
+    let curve = BSplineCurve::new(
+        periodic.knot_vec().clone(),
+        periodic.control_points().clone(),
+    );
+    assert!(!curve.is_periodic());
+    assert_eq!(curve.period(), None);
+    assert_eq!(
+        curve.range_tuple(),
+        (curve.knot(0), curve.knot(curve.knot_vec().len() - 1))
+    );
+    let t = curve.knot(0) + 1.0e-3;
+    assert!(!curve.subs(t).near(&periodic.subs(t)));
+
+    // the flag survives the serialization.
+    let json = serde_json::to_string(&periodic).unwrap();
+    let restored: BSplineCurve<Point3> = serde_json::from_str(&json).unwrap();
+    assert!(restored.is_periodic());
+    assert!(restored.near2_as_curve(&periodic));
+    let json = serde_json::to_string(&curve).unwrap();
+    assert!(!json.contains("periodic"));
+
+    // the flag is rejected if the curve does not have the structure made by `make_periodic`.
+    let mut ctrl_pts = periodic.control_points().clone();
+    ctrl_pts[0] += Vector3::unit_x();
+    let json = serde_json::to_string(&BSplineCurve::new(periodic.knot_vec().clone(), ctrl_pts))
+        .unwrap()
+        .replace('}', r#","periodic":true}"#);
+    assert!(serde_json::from_str::<BSplineCurve<Point3>>(&json).is_err());
+    let clamped = BSplineCurve::new(KnotVec::bezier_knot(1), vec![Point3::new(0.0, 0.0, 0.0); 2]);
+    let json = serde_json::to_string(&clamped)
+        .unwrap()
+        .replace('}', r#","periodic":true}"#);
+    assert!(serde_json::from_str::<BSplineCurve<Point3>>(&json).is_err());
+
+    // adding a knot makes the curve non-periodic.
+    let mut curve = periodic.clone();
+    curve.add_knot(0.5);
+    assert!(!curve.is_periodic());
+    assert_near!(curve.subs(0.3), periodic.subs(0.3));
+}
+
+/// the number of the samples per control point in searching the non-degenerate Frenet frame
+const FRENET_SAMPLES: usize = 8;
+/// the number of the steps of the rotation-minimizing frame at the inflection points
+const FRENET_RMF_STEPS: usize = 8;
+
+impl BSplineCurve<Point3> {
+    /// Returns the curvature at `t`.
+    ///
+    /// If the derivation vanishes at `t`, returns `NaN`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// // the parabola (t, t^2, 0) on [0, 1]
+    /// let bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.5, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    /// );
+    /// assert_near!(bspcurve.curvature(0.0), 2.0);
+    /// assert_near!(bspcurve.curvature(0.5), 2.0 / f64::powf(2.0, 1.5));
+    /// ```
+    pub fn curvature(&self, t: f64) -> f64 {
+        let der = self.der(t);
+        der.cross(self.der2(t)).magnitude() / der.magnitude().powi(3)
+    }
+
+    /// Returns the torsion at `t`.
+    ///
+    /// The torsion is positive if the curve turns right-handedly around the tangent. If the
+    /// curvature vanishes at `t`, returns `0.0`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// // the twisted cubic (t, t^2, t^3) on [0, 1]
+    /// let bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(3),
+    ///     vec![
+    ///         Point3::new(0.0, 0.0, 0.0),
+    ///         Point3::new(1.0 / 3.0, 0.0, 0.0),
+    ///         Point3::new(2.0 / 3.0, 1.0 / 3.0, 0.0),
+    ///         Point3::new(1.0, 1.0, 1.0),
+    ///     ],
+    /// );
+    /// assert_near!(bspcurve.torsion(0.0), 3.0);
+    /// ```
+    pub fn torsion(&self, t: f64) -> f64 {
+        let (der, der2) = (self.der(t), self.der2(t));
+        let binormal = der.cross(der2);
+        if binormal.magnitude2() <= TOLERANCE2 * der.magnitude2().powi(3) {
+            return 0.0;
+        }
+        binormal.dot(self.der3(t)) / binormal.magnitude2()
+    }
+
+    /// Returns the Frenet frame, i.e. the unit tangent, the principal normal and the binormal
+    /// at `t`.
+    ///
+    /// At the points where the curvature vanishes, e.g. the inflection points, the Frenet frame
+    /// is not determined. Then, the normal is transported from the nearest sample parameter
+    /// with the non-vanishing curvature by the rotation-minimizing frame, so that the frame is
+    /// continuous around the inflection points. If the whole curve is straight, an arbitrary
+    /// normal is chosen. The derivation must not vanish at `t`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// // the twisted cubic (t, t^2, t^3) on [0, 1]
+    /// let bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(3),
+    ///     vec![
+    ///         Point3::new(0.0, 0.0, 0.0),
+    ///         Point3::new(1.0 / 3.0, 0.0, 0.0),
+    ///         Point3::new(2.0 / 3.0, 1.0 / 3.0, 0.0),
+    ///         Point3::new(1.0, 1.0, 1.0),
+    ///     ],
+    /// );
+    /// let (tangent, normal, binormal) = bspcurve.frenet_frame(0.0);
+    /// assert_near!(tangent, Vector3::unit_x());
+    /// assert_near!(normal, Vector3::unit_y());
+    /// assert_near!(binormal, Vector3::unit_z());
+    ///
+    /// // the straight line
+    /// let line = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0)],
+    /// );
+    /// let (tangent, normal, binormal) = line.frenet_frame(0.5);
+    /// assert_near!(tangent, Vector3::new(1.0, 1.0, 1.0).normalize());
+    /// assert!(normal.dot(tangent).so_small());
+    /// assert_near!(tangent.cross(normal), binormal);
+    /// ```
+    pub fn frenet_frame(&self, t: f64) -> (Vector3, Vector3, Vector3) {
+        let tangent = self.der(t).normalize();
+        let normal = self.frenet_normal(t).unwrap_or_else(|| {
+            let (t0, t1) = self.range_tuple();
+            let n = self.control_points.len() * FRENET_SAMPLES;
+            let nearest = (0..=n)
+                .map(|i| t0 + (t1 - t0) * i as f64 / n as f64)
+                .filter_map(|s| Some((s, self.frenet_normal(s)?)))
+                .min_by(|(s0, _), (s1, _)| f64::total_cmp(&(s0 - t).abs(), &(s1 - t).abs()));
+            match nearest {
+                Some((s, normal)) => self.transport_normal(normal, s, t),
+                None => orthogonal_unit_vector(tangent),
+            }
+        });
+        (tangent, normal, tangent.cross(normal))
+    }
+
+    /// Substitutes to the 3rd-ord derived B-spline curve.
+    fn der3(&self, t: f64) -> Vector3 {
+        match self.degree() < 3 {
+            true => Vector3::zero(),
+            false => self.derivation().der2(t),
+        }
+    }
+
+    /// Returns the principal normal at `t`, or `None` if the curvature vanishes.
+    fn frenet_normal(&self, t: f64) -> Option<Vector3> {
+        let (der, der2) = (self.der(t), self.der2(t));
+        let binormal = der.cross(der2);
+        match binormal.magnitude2() <= TOLERANCE2 * der.magnitude2().powi(3) {
+            true => None,
+            false => Some(binormal.cross(der).normalize()),
+        }
+    }
+
+    /// Transports `normal` at `s` to `t` by the double reflection method.
+    fn transport_normal(&self, mut normal: Vector3, s: f64, t: f64) -> Vector3 {
+        let (mut pt, mut tangent) = (self.subs(s), self.der(s).normalize());
+        (1..=FRENET_RMF_STEPS).for_each(|i| {
+            let u = s + (t - s) * i as f64 / FRENET_RMF_STEPS as f64;
+            let (next_pt, next_tangent) = (self.subs(u), self.der(u).normalize());
+            let v0 = next_pt - pt;
+            if !v0.so_small() {
+                let c0 = v0.magnitude2();
+                normal -= v0 * (2.0 * v0.dot(normal) / c0);
+                tangent -= v0 * (2.0 * v0.dot(tangent) / c0);
+            }
+            let v1 = next_tangent - tangent;
+            if !v1.so_small() {
+                normal -= v1 * (2.0 * v1.dot(normal) / v1.magnitude2());
+            }
+            (pt, tangent) = (next_pt, next_tangent);
+        });
+        (normal - tangent * tangent.dot(normal)).normalize()
+    }
+}
+
+/// Returns a unit vector orthogonal to `vec`.
+fn orthogonal_unit_vector(vec: Vector3) -> Vector3 {
+    let abs = vec.map(f64::abs);
+    let axis = if abs.x <= abs.y && abs.x <= abs.z {
+        Vector3::unit_x()
+    } else if abs.y <= abs.z {
+        Vector3::unit_y()
+    } else {
+        Vector3::unit_z()
+    };
+    vec.cross(axis).normalize()
+}
+
+#[test]
+fn frenet_frame_test() {
+    // the helix (r cos t, r sin t, c t) on [0, 4π]
+    let (r, c) = (2.0, 0.5);
+    let helix = |t: f64| Point3::new(r * f64::cos(t), r * f64::sin(t), c * t);
+    const N: usize = 100;
+    let pts: Vec<Point3> = (0..=N)
+        .map(|i| helix(4.0 * std::f64::consts::PI * i as f64 / N as f64))
+        .collect();
+    let bspcurve = BSplineCurve::interpolate(&pts, 5).unwrap();
+    let (t0, t1) = bspcurve.range_tuple();
+    let (curvature, torsion) = (r / (r * r + c * c), c / (r * r + c * c));
+    (1..10).for_each(|i| {
+        let t = t0 + (t1 - t0) * i as f64 / 10.0;
+        assert!(f64::abs(bspcurve.curvature(t) - curvature) < 1.0e-3);
+        assert!(f64::abs(bspcurve.torsion(t) - torsion) < 1.0e-3);
+        let (tangent, normal, binormal) = bspcurve.frenet_frame(t);
+        // the normal points to the axis of the helix.
+        let pt = bspcurve.subs(t);
+        assert!(normal.near(&-Vector3::new(pt.x, pt.y, 0.0).normalize()));
+        assert!(tangent.dot(normal).so_small() && tangent.cross(normal).near(&binormal));
+        assert!(binormal.near(&Vector3::new(pt.y * c / r, -pt.x * c / r, r).normalize()));
+    });
+
+    // the S-shaped planar curve has the inflection point at the middle.
+    let bspcurve = BSplineCurve::new(
+        KnotVec::bezier_knot(3),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 0.0),
+            Point3::new(2.0, -1.0, 0.0),
+            Point3::new(3.0, 0.0, 0.0),
+        ],
+    );
+    assert!(bspcurve.curvature(0.5).so_small());
+    assert_eq!(bspcurve.torsion(0.5), 0.0);
+    let (tangent, normal, binormal) = bspcurve.frenet_frame(0.5);
+    assert!(tangent.near(&Vector3::new(2.0, -1.0, 0.0).normalize()));
+    assert!(normal.dot(tangent).so_small() && normal.z.so_small());
+    assert!(binormal.near(&Vector3::unit_z()) || binormal.near(&-Vector3::unit_z()));
+    assert!(normal.x.is_finite() && normal.y.is_finite());
+}
+
+#[test]
+fn closest_point_test() {
+    // the narrow spike in the short parameter range is missed by the presearch.
+    let bspcurve = BSplineCurve::new(
+        KnotVec::from(vec![0.0, 0.0, 1.0, 1.01, 1.02, 2.0, 2.0]),
+        vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(100.0, 0.0),
+            Point2::new(100.01, 1.0),
+            Point2::new(100.02, 0.0),
+            Point2::new(200.0, 0.0),
+        ],
+    );
+    let pt = Point2::new(100.01, 1.5);
+    let res = bspcurve.search_nearest_parameter(pt, None, 100);
+    assert!(!matches!(res, Some(t) if bspcurve.subs(t).distance(pt) < 1.0));
+    let (t, foot, dist) = bspcurve.closest_point(pt, 1.0e-4);
+    // the tip is not smooth, so the result is not refined by Newton's method.
+    assert!(f64::abs(t - 1.01) < 1.0e-4);
+    assert!(foot.distance(Point2::new(100.01, 1.0)) < 1.0e-3);
+    assert!(f64::abs(dist - 0.5) < 1.0e-4);
+
+    // compared with the dense samples
+    (0..5).for_each(|_| {
+        let ctrl_pts: Vec<Point3> = (0..8)
+            .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+            .collect();
+        let bspcurve = BSplineCurve::new(KnotVec::uniform_knot(3, 5), ctrl_pts);
+        let pt = Point3::new(rand::random(), rand::random(), rand::random());
+        let (t, foot, dist) = bspcurve.closest_point(pt, 1.0e-4);
+        assert_near!(bspcurve.subs(t), foot);
+        assert_near!(foot.distance(pt), dist);
+        const N: usize = 10000;
+        (0..=N).for_each(|i| {
+            let sample = bspcurve.subs(i as f64 / N as f64);
+            assert!(dist <= sample.distance(pt) + 1.0e-4);
+        });
+    });
+}
@@ -27,6 +27,8 @@ impl<P> BSplineSurface<P> {
     /// * The number of knots is more than the one of control points.
     /// * There exist at least two different knots.
     /// * There are at least one control point.
+    ///
+    /// Moreover, the knots must be non-decreasing, i.e. no knot is `NaN`.
     #[inline(always)]
     pub fn try_new(
         knot_vecs: (KnotVec, KnotVec),
@@ -46,6 +48,8 @@ impl<P> BSplineSurface<P> {
             ))
         } else if knot_vecs.0.range_length().so_small() || knot_vecs.1.range_length().so_small() {
             Err(Error::ZeroRange)
+        } else if !is_non_decreasing(&knot_vecs.0) || !is_non_decreasing(&knot_vecs.1) {
+            Err(Error::NonMonotoneKnots)
         } else {
             let len = control_points[0].len();
             if control_points.iter().any(|vec| vec.len() != len) {
@@ -276,6 +280,54 @@ impl<P> BSplineSurface<P> {
         self
     }
 
+    /// Inverts the direction of the u-parameter.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(0.5, 0.0, 1.0), Point3::new(0.5, 1.0, 1.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.5)],
+    /// ];
+    /// let bspsurface0 = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let mut bspsurface1 = bspsurface0.clone();
+    /// bspsurface1.uinvert();
+    /// assert_near!(bspsurface1.subs(0.2, 0.7), bspsurface0.subs(0.8, 0.7));
+    /// // the normal is reversed.
+    /// assert_near!(bspsurface1.normal(0.2, 0.7), -bspsurface0.normal(0.8, 0.7));
+    /// ```
+    #[inline(always)]
+    pub fn uinvert(&mut self) -> &mut Self {
+        self.knot_vecs.0.invert();
+        self.control_points.reverse();
+        self
+    }
+
+    /// Inverts the direction of the v-parameter.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(0.5, 0.0, 1.0), Point3::new(0.5, 1.0, 1.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.5)],
+    /// ];
+    /// let bspsurface0 = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let mut bspsurface1 = bspsurface0.clone();
+    /// bspsurface1.vinvert();
+    /// assert_near!(bspsurface1.subs(0.2, 0.7), bspsurface0.subs(0.2, 0.3));
+    /// // the normal is reversed.
+    /// assert_near!(bspsurface1.normal(0.2, 0.7), -bspsurface0.normal(0.2, 0.3));
+    /// ```
+    #[inline(always)]
+    pub fn vinvert(&mut self) -> &mut Self {
+        self.knot_vecs.1.invert();
+        self.control_points.iter_mut().for_each(|vec| vec.reverse());
+        self
+    }
+
     /// The range of the parameter of the surface.
     #[inline(always)]
     pub fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
@@ -349,6 +401,83 @@ impl<P: ControlPoint<f64>> BSplineSurface<P> {
     #[inline(always)]
     pub fn get_closure(&self) -> impl Fn(f64, f64) -> P + '_ { move |u, v| self.subs(u, v) }
 
+    /// Returns the isocurve `v -> self.subs(u, v)` with the constant `u`.
+    ///
+    /// The control points of the isocurve are the ones of `self` combined in the `u` direction
+    /// by the B-spline basis functions at `u`, so the isocurve is exact and its knot vector is
+    /// the one of `self` in the `v` direction.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::uniform_knot(2, 2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.5, 1.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(0.3, 0.0, 1.0), Point3::new(0.3, 0.5, 0.0), Point3::new(0.3, 1.0, 1.0)],
+    ///     vec![Point3::new(0.7, 0.0, 0.0), Point3::new(0.7, 0.5, 2.0), Point3::new(0.7, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 0.5, 0.0), Point3::new(1.0, 1.0, 1.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    ///
+    /// let uiso = bspsurface.uiso(0.3);
+    /// assert_eq!(uiso.knot_vec(), bspsurface.vknot_vec());
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     let v = i as f64 / N as f64;
+    ///     assert_near!(uiso.subs(v), bspsurface.subs(0.3, v));
+    /// }
+    /// ```
+    pub fn uiso(&self, u: f64) -> BSplineCurve<P> {
+        let basis = self.uknot_vec().bspline_basis_functions(self.udegree(), u);
+        let ctrl_pts = (0..self.control_points[0].len())
+            .map(|j| {
+                self.control_points
+                    .iter()
+                    .zip(&basis)
+                    .fold(P::origin(), |sum, (vec, b)| sum + vec[j].to_vec() * *b)
+            })
+            .collect();
+        BSplineCurve::new_unchecked(self.vknot_vec().clone(), ctrl_pts)
+    }
+
+    /// Returns the isocurve `u -> self.subs(u, v)` with the constant `v`.
+    ///
+    /// The control points of the isocurve are the ones of `self` combined in the `v` direction
+    /// by the B-spline basis functions at `v`, so the isocurve is exact and its knot vector is
+    /// the one of `self` in the `u` direction.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::uniform_knot(2, 2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.5, 1.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(0.3, 0.0, 1.0), Point3::new(0.3, 0.5, 0.0), Point3::new(0.3, 1.0, 1.0)],
+    ///     vec![Point3::new(0.7, 0.0, 0.0), Point3::new(0.7, 0.5, 2.0), Point3::new(0.7, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 0.5, 0.0), Point3::new(1.0, 1.0, 1.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    ///
+    /// let viso = bspsurface.viso(0.6);
+    /// assert_eq!(viso.knot_vec(), bspsurface.uknot_vec());
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     let u = i as f64 / N as f64;
+    ///     assert_near!(viso.subs(u), bspsurface.subs(u, 0.6));
+    /// }
+    /// ```
+    pub fn viso(&self, v: f64) -> BSplineCurve<P> {
+        let basis = self.vknot_vec().bspline_basis_functions(self.vdegree(), v);
+        let ctrl_pts = self
+            .control_points
+            .iter()
+            .map(|vec| {
+                vec.iter()
+                    .zip(&basis)
+                    .fold(P::origin(), |sum, (pt, b)| sum + pt.to_vec() * *b)
+            })
+            .collect();
+        BSplineCurve::new_unchecked(self.uknot_vec().clone(), ctrl_pts)
+    }
+
     #[inline(always)]
     fn udelta_control_points(&self, i: usize, j: usize) -> P::Diff {
         if i == 0 {
@@ -942,6 +1071,34 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         self
     }
 
+    /// Inserts a knot `x` of the first parameter `u` by Boehm's algorithm,
+    /// and do not change `self` as a surface.
+    ///
+    /// Unlike [`BSplineSurface::add_uknot`], returns an error if
+    /// - `x` is out of the range of the knot vector, or
+    /// - the multiplicity of `x` would exceed the degree.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(0.5, 0.0, 1.0), Point3::new(0.5, 1.0, -1.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    /// bspsurface.insert_uknot(0.3).unwrap().insert_uknot(0.3).unwrap();
+    /// assert!(bspsurface.insert_uknot(0.3).is_err());
+    /// assert!(bspsurface.insert_uknot(1.2).is_err());
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// assert_eq!(bspsurface.uknot_vec().len(), org_surface.uknot_vec().len() + 2);
+    /// ```
+    pub fn insert_uknot(&mut self, x: f64) -> Result<&mut Self> {
+        check_knot_insertion(&self.knot_vecs.0, self.udegree(), x)?;
+        Ok(self.add_uknot(x))
+    }
+
     /// Adds a knot `x` for the second parameter, and do not change `self` as a surface.
     /// Return `false` if cannot add the knot, i.e.
     /// * the index of `x` will be lower than the degree, or
@@ -998,6 +1155,33 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         self
     }
 
+    /// Inserts a knot `x` of the second parameter `v` by Boehm's algorithm,
+    /// and do not change `self` as a surface.
+    ///
+    /// Unlike [`BSplineSurface::add_vknot`], returns an error if
+    /// - `x` is out of the range of the knot vector, or
+    /// - the multiplicity of `x` would exceed the degree.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.5, 1.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.5, -1.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    /// bspsurface.insert_vknot(0.7).unwrap().insert_vknot(0.7).unwrap();
+    /// assert!(bspsurface.insert_vknot(0.7).is_err());
+    /// assert!(bspsurface.insert_vknot(-0.2).is_err());
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// assert_eq!(bspsurface.vknot_vec().len(), org_surface.vknot_vec().len() + 2);
+    /// ```
+    pub fn insert_vknot(&mut self, x: f64) -> Result<&mut Self> {
+        check_knot_insertion(&self.knot_vecs.1, self.vdegree(), x)?;
+        Ok(self.add_vknot(x))
+    }
+
     /// Removes the uknot corresponding to the indice `idx`, and do not change `self` as a curve.
     /// If the knot cannot be removed, returns
     /// [`Error::CannotRemoveKnot`](./errors/enum.Error.html#variant.CannotRemoveKnot).
@@ -1843,6 +2027,149 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
     }
 }
 
+impl<P> BSplineSurface<P>
+where
+    P: ControlPoint<f64> + Tolerance,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    /// Creates the skinned surface with `vdegree` interpolating all `curves` in sequence,
+    /// which generalizes [`BSplineSurface::homotopy`] to more than two curves.
+    ///
+    /// The degrees and the knot vectors of the curves are synchronized, and the curves are
+    /// parametrized on `[0, 1]` by the centripetal method with the mean distance of the control
+    /// points. The `i`th curve is the `v`-iso curve at the `i`th parameter, and the surface is
+    /// `C^{vdegree - 1}` across the curves.
+    /// # Failures
+    /// - If the number of curves is not more than `vdegree`, returns [`Error::TooFewPoints`].
+    /// - If two consecutive curves coincide, returns [`Error::CoincidentPoints`].
+    /// # Panics
+    /// Panics if `vdegree` is zero.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curves: Vec<BSplineCurve<Point3>> = [0.0, 1.0, 3.0]
+    ///     .into_iter()
+    ///     .map(|z| {
+    ///         let ctrl_pts = vec![
+    ///             Point3::new(0.0, 0.0, z),
+    ///             Point3::new(1.0, z, z),
+    ///             Point3::new(2.0, 0.0, z),
+    ///         ];
+    ///         BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts)
+    ///     })
+    ///     .collect();
+    /// let surface = BSplineSurface::skin(curves.clone(), 2).unwrap();
+    /// assert_eq!(surface.vdegree(), 2);
+    ///
+    /// // the surface passes through all curves
+    /// for curve in &curves {
+    ///     let pt = curve.subs(0.3);
+    ///     let (u, v) = surface.search_parameter(pt, None, 100).unwrap();
+    ///     assert_near!(surface.subs(u, v), pt);
+    /// }
+    /// ```
+    pub fn skin(mut curves: Vec<BSplineCurve<P>>, vdegree: usize) -> Result<BSplineSurface<P>> {
+        assert!(vdegree > 0, "the degree must be positive.");
+        let n = curves.len();
+        if n <= vdegree {
+            return Err(Error::TooFewPoints(n, vdegree));
+        }
+        // the first curve collects all the degrees and knots, and distributes them.
+        let (first, others) = curves.split_at_mut(1);
+        (0..2).for_each(|_| {
+            others.iter_mut().for_each(|curve| {
+                first[0].syncro_degree(curve);
+                first[0].syncro_knots(curve);
+            })
+        });
+
+        let len = curves[0].control_points().len();
+        let mut params = vec![0.0];
+        for (i, pair) in curves.windows(2).enumerate() {
+            let sum = (0..len).fold(0.0, |sum, j| {
+                sum + (*pair[1].control_point(j) - *pair[0].control_point(j)).magnitude()
+            });
+            let dist = sum / len as f64;
+            if dist.so_small() {
+                return Err(Error::CoincidentPoints(i));
+            }
+            params.push(params[i] + f64::sqrt(dist));
+        }
+        let total = params[n - 1];
+        params.iter_mut().for_each(|t| *t /= total);
+        Ok(Self::skin_with_parameters(curves, &params, vdegree))
+    }
+
+    /// Creates the skinned surface with `vdegree` interpolating all `curves` at `params`.
+    ///
+    /// The degrees and the knot vectors of the curves are synchronized, and the `i`th curve is
+    /// the `v`-iso curve at `params[i]`. Since the interpolation is linear, the surfaces skinned
+    /// with the same parameters are joined along their boundaries if the ends of the curves are
+    /// shared. cf. [`BSplineSurface::skin`]
+    /// # Panics
+    /// - Panics if `vdegree` is zero, or the number of curves is not more than `vdegree`.
+    /// - Panics if the lengths of `curves` and `params` are different.
+    /// - Panics if `params` is not strictly increasing from `0.0` to `1.0`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curves: Vec<BSplineCurve<Point2>> = [0.0, 1.0, 3.0]
+    ///     .into_iter()
+    ///     .map(|y| BSplineCurve::new(KnotVec::bezier_knot(1), vec![Point2::new(0.0, y), Point2::new(1.0, y)]))
+    ///     .collect();
+    /// let surface = BSplineSurface::skin_with_parameters(curves, &[0.0, 0.25, 1.0], 2);
+    /// assert_near!(surface.subs(0.5, 0.25), Point2::new(0.5, 1.0));
+    /// ```
+    pub fn skin_with_parameters(
+        mut curves: Vec<BSplineCurve<P>>,
+        params: &[f64],
+        vdegree: usize,
+    ) -> BSplineSurface<P> {
+        assert!(vdegree > 0, "the degree must be positive.");
+        assert!(
+            curves.len() > vdegree,
+            "the curves are too few for the degree."
+        );
+        assert_eq!(
+            curves.len(),
+            params.len(),
+            "the numbers of curves and parameters must be the same."
+        );
+        assert!(
+            params[0] == 0.0
+                && params[params.len() - 1] == 1.0
+                && params.windows(2).all(|ts| ts[0] < ts[1]),
+            "the parameters must be strictly increasing from 0 to 1."
+        );
+        // the first curve collects all the degrees and knots, and distributes them.
+        let (first, others) = curves.split_at_mut(1);
+        (0..2).for_each(|_| {
+            others.iter_mut().for_each(|curve| {
+                first[0].syncro_degree(curve);
+                first[0].syncro_knots(curve);
+            })
+        });
+        let (vknot_vec, rows) = interpolation_system(params, vdegree);
+        let len = curves[0].control_points().len();
+
+        let control_points = (0..len)
+            .map(|j| {
+                let origin = *curves[0].control_point(j);
+                let rhs = curves
+                    .iter()
+                    .map(|curve| *curve.control_point(j) - origin)
+                    .collect();
+                solve_banded_system(rows.clone(), rhs)
+                    .into_iter()
+                    .map(|v| origin + v)
+                    .collect()
+            })
+            .collect();
+        let uknot_vec = curves[0].knot_vec().clone();
+        BSplineSurface::new_unchecked((uknot_vec, vknot_vec), control_points)
+    }
+}
+
 impl<V: Bounded> BSplineSurface<V> {
     /// Returns the bounding box including all control points.
     #[inline(always)]
@@ -1851,6 +2178,95 @@ impl<V: Bounded> BSplineSurface<V> {
     }
 }
 
+impl<P> BSplineSurface<P>
+where
+    P: ControlPoint<f64> + Bounded<Scalar = f64>,
+    P::Diff: Array<Element = f64>,
+{
+    /// Returns the bounding box including the surface tightly.
+    ///
+    /// The extrema of each coordinate are searched on the boundary and at the critical points in
+    /// the interior, and their parameters are determined within `tol`.
+    /// # Panics
+    /// `tol` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// // the bump z = 16u(1 - u)v(1 - v) / 4 on [0, 1] x [0, 1]
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = (0..3)
+    ///     .map(|i| {
+    ///         (0..3)
+    ///             .map(|j| {
+    ///                 let z = if i == 1 && j == 1 { 1.0 } else { 0.0 };
+    ///                 Point3::new(i as f64 / 2.0, j as f64 / 2.0, z)
+    ///             })
+    ///             .collect()
+    ///     })
+    ///     .collect();
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// assert_near!(bspsurface.roughly_bounding_box().max(), Point3::new(1.0, 1.0, 1.0));
+    /// let bdd = bspsurface.tight_bounding_box(1.0e-4);
+    /// assert_near!(bdd.min(), Point3::new(0.0, 0.0, 0.0));
+    /// assert_near!(bdd.max(), Point3::new(1.0, 1.0, 0.25));
+    /// ```
+    pub fn tight_bounding_box(&self, tol: f64) -> BoundingBox<P> {
+        let division = (
+            self.control_points.len() * TIGHT_BOX_DIVISION,
+            self.control_points[0].len() * TIGHT_BOX_DIVISION,
+        );
+        algo::surface::tight_bounding_box(self, self.range_tuple(), division, tol)
+    }
+}
+
+impl<P> BSplineSurface<P>
+where
+    P: ControlPoint<f64> + Bounded<Scalar = f64>,
+    BSplineSurface<P>: ParametricSurface<Point = P>,
+{
+    /// Returns the initial guesses for searching the parameter of `point`, sorted by the distance.
+    ///
+    /// Each guess is presearched in a Bézier patch whose control net's bounding box is
+    /// `TOLERANCE`-close to `point`. By the convex hull property, the patches including `point`
+    /// are not missed.
+    fn seeded_hints(&self, point: P) -> Vec<(f64, f64)> {
+        let (uknot_vec, vknot_vec) = self.knot_vecs();
+        let (udegree, vdegree) = self.degrees();
+        let (ulen, vlen) = (self.control_points.len(), self.control_points[0].len());
+        let spans = |knot_vec: &KnotVec, degree: usize, len: usize| {
+            (degree..len)
+                .filter(|&i| knot_vec[i] < knot_vec[i + 1])
+                .map(|i| (i, (knot_vec[i], knot_vec[i + 1])))
+                .collect::<Vec<_>>()
+        };
+        let uspans = spans(uknot_vec, udegree, ulen);
+        let vspans = spans(vknot_vec, vdegree, vlen);
+        let mut hints = Vec::new();
+        uspans.iter().for_each(|&(i, urange)| {
+            vspans.iter().for_each(|&(j, vrange)| {
+                let bdb: BoundingBox<P> = self.control_points[i - udegree..=i]
+                    .iter()
+                    .flat_map(|row| &row[j - vdegree..=j])
+                    .collect();
+                let closest = Bounded::min(Bounded::max(point, bdb.min()), bdb.max());
+                if point.distance(closest) <= TOLERANCE {
+                    let hint = algo::surface::presearch(
+                        self,
+                        point,
+                        (urange, vrange),
+                        SEED_PRESEARCH_DIVISION,
+                    );
+                    let dist = self.subs(hint.0, hint.1).distance2(point);
+                    hints.push((dist, hint));
+                }
+            })
+        });
+        hints.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(std::cmp::Ordering::Equal));
+        hints.into_iter().map(|(_, hint)| hint).collect()
+    }
+}
+
 impl<P: ControlPoint<f64>> ParameterDivision2D for BSplineSurface<P>
 where P: EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
         + MetricSpace<Metric = f64>
@@ -1868,6 +2284,71 @@ where P: EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
 
 impl ParametricSurface3D for BSplineSurface<Point3> {}
 
+impl BSplineSurface<Point3> {
+    /// Returns the Gaussian curvature and the mean curvature at `(u, v)`.
+    ///
+    /// The sign of the mean curvature follows the normal `uder x vder`. If the surface
+    /// degenerates at `(u, v)`, returns `(NaN, NaN)`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// // the paraboloid z = x^2 + y^2 on [-1, 1] x [-1, 1]
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let z = [1.0, -1.0, 1.0];
+    /// let ctrl_pts = (0..3)
+    ///     .map(|i| {
+    ///         (0..3)
+    ///             .map(|j| Point3::new(i as f64 - 1.0, j as f64 - 1.0, z[i] + z[j]))
+    ///             .collect()
+    ///     })
+    ///     .collect();
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    ///
+    /// // the apex
+    /// let (gaussian, mean) = bspsurface.curvatures(0.5, 0.5);
+    /// assert_near!(gaussian, 4.0);
+    /// assert_near!(mean, 2.0);
+    /// ```
+    #[inline(always)]
+    pub fn curvatures(&self, u: f64, v: f64) -> (f64, f64) { algo::surface::curvatures(self, u, v) }
+
+    /// Searches the parameter of `point` by Newton's method starting from `(u0, v0)`.
+    ///
+    /// Unlike `search_parameter` with no hint, the surface is not presearched.
+    /// Returns `None` if the iteration does not converge in `trials` times
+    /// or the found point is not near `point`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.5, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0)],
+    ///     vec![Point3::new(0.0, 0.5, 1.0), Point3::new(0.5, 0.5, 2.0), Point3::new(1.0, 0.5, 1.0)],
+    ///     vec![Point3::new(0.0, 1.0, 0.0), Point3::new(0.5, 1.0, 1.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    ///
+    /// let pt = bspsurface.subs(0.3, 0.7);
+    /// let (u, v) = bspsurface.search_parameter_from(pt, (0.5, 0.5), 100).unwrap();
+    /// assert_near!(u, 0.3);
+    /// assert_near!(v, 0.7);
+    ///
+    /// // the point is not on the surface
+    /// let pt = Point3::new(0.5, 0.5, 3.0);
+    /// assert!(bspsurface.search_parameter_from(pt, (0.5, 0.5), 100).is_none());
+    /// ```
+    #[inline(always)]
+    pub fn search_parameter_from(
+        &self,
+        point: Point3,
+        (u0, v0): (f64, f64),
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        algo::surface::search_parameter3d(self, point, (u0, v0), trials)
+    }
+}
+
 impl<V> BoundedSurface for BSplineSurface<V> where BSplineSurface<V>: ParametricSurface {}
 
 impl<V: Clone> Invertible for BSplineSurface<V> {
@@ -1889,6 +2370,13 @@ impl SearchParameter<D2> for BSplineSurface<Point2> {
                 algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
             }
             SPHint2D::None => {
+                let found = self
+                    .seeded_hints(point)
+                    .into_iter()
+                    .find_map(|hint| algo::surface::search_parameter2d(self, point, hint, trials));
+                if found.is_some() {
+                    return found;
+                }
                 algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
             }
         };
@@ -1910,6 +2398,13 @@ impl SearchParameter<D2> for BSplineSurface<Point3> {
                 algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
             }
             SPHint2D::None => {
+                let found = self
+                    .seeded_hints(point)
+                    .into_iter()
+                    .find_map(|hint| algo::surface::search_parameter3d(self, point, hint, trials));
+                if found.is_some() {
+                    return found;
+                }
                 algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
             }
         };
@@ -2123,6 +2618,28 @@ where P: Deserialize<'de>
     }
 }
 
+#[test]
+fn serde_round_trip() {
+    let surface = BSplineSurface::new(
+        (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1)),
+        vec![
+            vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+            vec![Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 1.0, 1.0)],
+            vec![Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 1.0, 0.0)],
+        ],
+    );
+    let json = serde_json::to_string(&surface).unwrap();
+    let restored: BSplineSurface<Point3> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, surface);
+
+    // the rows of the control points have different lengths.
+    let json = r#"{"knot_vecs":[[0.0,0.0,1.0,1.0],[0.0,0.0,1.0,1.0]],"control_points":[[[0.0,0.0,0.0],[0.0,1.0,0.0]],[[1.0,0.0,0.0]]]}"#;
+    assert!(serde_json::from_str::<BSplineSurface<Point3>>(json).is_err());
+    // the knot vector is too short for the control points.
+    let json = r#"{"knot_vecs":[[0.0,1.0],[0.0,0.0,1.0,1.0]],"control_points":[[[0.0,0.0,0.0],[0.0,1.0,0.0]],[[1.0,0.0,0.0],[1.0,1.0,0.0]]]}"#;
+    assert!(serde_json::from_str::<BSplineSurface<Point3>>(json).is_err());
+}
+
 fn combinatorial(n: usize) -> Vec<usize> {
     let mut res = vec![1];
     for i in 1..=n {
@@ -2228,3 +2745,92 @@ fn test_include_bspcurve3() {
     *curve.control_point_mut(2) += Vector3::new(0.0, 0.0, 0.001);
     assert!(!surface.include(&curve));
 }
+
+#[test]
+fn test_search_parameter_torus() {
+    // the control net of the torus `rsweep`ed in the modeling tests: the circle of radius 0.25
+    // centered at (0.75, 0, 0) in the xz-plane is rotated around the z-axis.
+    const DIV: usize = 8;
+    let ctrl_pts = (0..=DIV)
+        .map(|i| {
+            let theta = 2.0 * std::f64::consts::PI * i as f64 / DIV as f64;
+            (0..=DIV)
+                .map(|j| {
+                    let phi = 2.0 * std::f64::consts::PI * j as f64 / DIV as f64;
+                    let r = 0.75 + 0.25 * f64::cos(phi);
+                    Point3::new(
+                        r * f64::cos(theta),
+                        r * f64::sin(theta),
+                        0.25 * f64::sin(phi),
+                    )
+                })
+                .collect()
+        })
+        .collect();
+    let knot_vec = KnotVec::uniform_knot(3, DIV - 2);
+    let surface = BSplineSurface::new((knot_vec.clone(), knot_vec), ctrl_pts);
+
+    const N: usize = 50;
+    for i in 0..=N {
+        for j in 0..=N {
+            let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+            let pt = surface.subs(u, v);
+            let (u0, v0) = surface
+                .search_parameter(pt, None, 100)
+                .unwrap_or_else(|| panic!("failed to search the parameter of ({u}, {v})."));
+            assert_near!(surface.subs(u0, v0), pt);
+            let (u0, v0) = surface.search_parameter_from(pt, (u, v), 10).unwrap();
+            assert_near!(surface.subs(u0, v0), pt);
+        }
+    }
+}
+
+#[test]
+fn orientation_test() {
+    let knot_vecs = (
+        KnotVec::from(vec![0.0, 0.0, 0.0, 0.25, 1.0, 1.0, 1.0]),
+        KnotVec::from(vec![-1.0, -1.0, -1.0, -1.0, 0.5, 2.0, 2.0, 2.0, 2.0]),
+    );
+    let ctrl_pts: Vec<Vec<Point3>> = (0..4)
+        .map(|_| {
+            (0..5)
+                .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+                .collect()
+        })
+        .collect();
+    let surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    let mut swapped = surface.clone();
+    swapped.swap_axes();
+    let mut uinverted = surface.clone();
+    uinverted.uinvert();
+    let mut vinverted = surface.clone();
+    vinverted.vinvert();
+    let mut both = surface.clone();
+    both.uinvert().vinvert();
+    assert_eq!(uinverted.range_tuple(), surface.range_tuple());
+    assert_eq!(vinverted.range_tuple(), surface.range_tuple());
+
+    const N: usize = 10;
+    for i in 0..=N {
+        for j in 0..=N {
+            let u = i as f64 / N as f64;
+            let v = -1.0 + 3.0 * j as f64 / N as f64;
+            let (u1, v1) = (1.0 - u, 1.0 - v);
+            assert_near!(swapped.subs(v, u), surface.subs(u, v));
+            assert_near!(uinverted.subs(u1, v), surface.subs(u, v));
+            assert_near!(vinverted.subs(u, v1), surface.subs(u, v));
+            assert_near!(both.subs(u1, v1), surface.subs(u, v));
+            // one inversion reverses the orientation, and two inversions keep it.
+            let normal = surface.normal(u, v);
+            assert_near!(swapped.normal(v, u), -normal);
+            assert_near!(uinverted.normal(u1, v), -normal);
+            assert_near!(vinverted.normal(u, v1), -normal);
+            assert_near!(both.normal(u1, v1), normal);
+        }
+    }
+    // inverting twice is the identity.
+    uinverted.uinvert();
+    vinverted.vinvert();
+    assert_eq!(uinverted, surface);
+    assert_eq!(vinverted, surface);
+}
@@ -2,6 +2,7 @@ use super::*;
 use crate::errors::Error;
 use std::slice::SliceIndex;
 use std::vec::Vec;
+use truck_base::cgmath::BaseFloat;
 
 impl KnotVec {
     /// empty constructor
@@ -193,41 +194,92 @@ impl KnotVec {
     /// }
     /// ```
     pub fn try_bspline_basis_functions(&self, degree: usize, t: f64) -> Result<Vec<f64>> {
+        let idx = self.basis_span(degree, t, |knot| knot, |s, t| s.near(&t))?;
+        Ok(self.basis_recurrence(degree, idx, t, |knot| knot, inv_or_zero))
+    }
+
+    /// Calculates B-spline basis functions at `t` with degree `degree` in single precision.
+    ///
+    /// The knots are rounded to `f32` both in the search of the span and in the recurrence.
+    /// The knot intervals collapsed by the rounding are regarded as zero, the same as
+    /// the intervals shorter than the tolerance.
+    /// # Failures
+    /// The same as [`KnotVec::try_bspline_basis_functions`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    /// for i in 0..=10 {
+    ///     let t = 2.0 + 0.4 * i as f64;
+    ///     let res = knot_vec.try_bspline_basis_functions_f32(2, t as f32).unwrap();
+    ///     let ans = knot_vec.try_bspline_basis_functions(2, t).unwrap();
+    ///     res.iter().zip(ans).for_each(|(x, y)| assert!((*x as f64 - y).abs() < 1.0e-6));
+    /// }
+    /// ```
+    pub fn try_bspline_basis_functions_f32(&self, degree: usize, t: f32) -> Result<Vec<f32>> {
+        let near = |s: f32, t: f32| (s as f64).near(&(t as f64));
+        let idx = self.basis_span(degree, t, |knot| knot as f32, near)?;
+        let inv_or_zero = |delta: f32| match (delta as f64).so_small() {
+            true => 0.0,
+            false => 1.0 / delta,
+        };
+        Ok(self.basis_recurrence(degree, idx, t, |knot| knot as f32, inv_or_zero))
+    }
+
+    /// Returns the index of the span including `t` for the basis functions, comparing
+    /// the knots converted by `knot`.
+    fn basis_span<S: BaseFloat>(
+        &self,
+        degree: usize,
+        t: S,
+        knot: impl Fn(f64) -> S,
+        near: impl Fn(S, S) -> bool,
+    ) -> Result<usize> {
         let n = self.len() - 1;
         if self[0].near(&self[n]) {
             return Err(Error::ZeroRange);
         } else if n < degree {
             return Err(Error::TooLargeDegree(n + 1, degree));
         }
-
-        let idx = {
-            let idx = self
-                .floor(t)
-                .unwrap_or_else(|| self.floor(self[0]).unwrap());
-            if idx == n {
-                n - self.multiplicity(n)
-            } else {
-                idx
+        let floor = |t: S| self.iter().rposition(|s| knot(*s) <= t);
+        let idx = floor(t).unwrap_or_else(|| floor(knot(self[0])).unwrap());
+        match idx == n {
+            true => {
+                let last = knot(self[n]);
+                Ok(n - self.iter().filter(|s| near(last, knot(**s))).count())
             }
-        };
-        let mut res = vec![0.0; n];
-        res[idx] = 1.0;
+            false => Ok(idx),
+        }
+    }
+
+    /// The recurrence of the basis functions with the scalar `S`.
+    fn basis_recurrence<S: BaseFloat>(
+        &self,
+        degree: usize,
+        idx: usize,
+        t: S,
+        knot: impl Fn(f64) -> S,
+        inv_or_zero: impl Fn(S) -> S,
+    ) -> Vec<S> {
+        let n = self.len() - 1;
+        let mut res = vec![S::zero(); n];
+        res[idx] = S::one();
 
         for k in 1..=degree {
-            let base = if idx < k { 0 } else { idx - k };
-            let delta = self[base + k] - self[base];
+            let base = idx.saturating_sub(k);
+            let delta = knot(self[base + k]) - knot(self[base]);
             let max = if idx + k < n { idx } else { n - k - 1 };
-            let mut a = inv_or_zero(delta) * (t - self[base]);
+            let mut a = inv_or_zero(delta) * (t - knot(self[base]));
             for i in base..=max {
-                let delta = self[i + k + 1] - self[i + 1];
-                let b = inv_or_zero(delta) * (self[i + k + 1] - t);
+                let delta = knot(self[i + k + 1]) - knot(self[i + 1]);
+                let b = inv_or_zero(delta) * (knot(self[i + k + 1]) - t);
                 res[i] = a * res[i] + b * res[i + 1];
-                a = 1.0 - b;
+                a = S::one() - b;
             }
         }
 
         res.truncate(n - degree);
-        Ok(res)
+        res
     }
 
     #[doc(hidden)]
@@ -594,3 +646,85 @@ impl<'de> Deserialize<'de> for KnotVec {
         Self::try_from(vec).map_err(serde::de::Error::custom)
     }
 }
+
+/// Keeps the span of the last evaluated parameter and the reciprocals of the knot intervals
+/// used in the recurrence, for evaluating many basis functions in order.
+///
+/// The basis functions are the same as [`KnotVec::try_bspline_basis_functions`] bit for bit,
+/// but only the non-zero ones are calculated.
+#[derive(Clone, Debug)]
+pub(super) struct BasisSpanCache {
+    degree: usize,
+    floor: Option<usize>,
+    span: Option<usize>,
+    inverses: Vec<f64>,
+    values: Vec<f64>,
+}
+
+impl BasisSpanCache {
+    pub(super) fn new(degree: usize) -> Self {
+        Self {
+            degree,
+            floor: Some(0),
+            span: None,
+            inverses: Vec::new(),
+            values: vec![0.0; degree + 2],
+        }
+    }
+
+    /// Returns the index of the first non-zero basis function and the non-zero values.
+    pub(super) fn basis_functions(&mut self, knot_vec: &KnotVec, t: f64) -> (usize, &[f64]) {
+        let (degree, n) = (self.degree, knot_vec.len() - 1);
+        // the parameters are expected to be sorted, so search forward from the last span.
+        self.floor = match self.floor {
+            Some(mut idx) if knot_vec[idx] <= t => {
+                while idx < n && knot_vec[idx + 1] <= t {
+                    idx += 1;
+                }
+                Some(idx)
+            }
+            _ => knot_vec.floor(t),
+        };
+        let idx = {
+            let idx = self
+                .floor
+                .unwrap_or_else(|| knot_vec.floor(knot_vec[0]).unwrap());
+            if idx == n {
+                n - knot_vec.multiplicity(n)
+            } else {
+                idx
+            }
+        };
+        if self.span != Some(idx) {
+            self.span = Some(idx);
+            self.inverses.clear();
+            for k in 1..=degree {
+                let base = idx.saturating_sub(k);
+                let max = if idx + k < n { idx } else { n - k - 1 };
+                self.inverses
+                    .push(inv_or_zero(knot_vec[base + k] - knot_vec[base]));
+                self.inverses.extend(
+                    (base..=max).map(|i| inv_or_zero(knot_vec[i + k + 1] - knot_vec[i + 1])),
+                );
+            }
+        }
+
+        let lo = idx.saturating_sub(degree);
+        let res = &mut self.values;
+        res.iter_mut().for_each(|x| *x = 0.0);
+        res[idx - lo] = 1.0;
+        let mut inverses = self.inverses.iter();
+        for k in 1..=degree {
+            let base = idx.saturating_sub(k);
+            let max = if idx + k < n { idx } else { n - k - 1 };
+            let mut a = inverses.next().unwrap() * (t - knot_vec[base]);
+            for i in base..=max {
+                let b = inverses.next().unwrap() * (knot_vec[i + k + 1] - t);
+                res[i - lo] = a * res[i - lo] + b * res[i + 1 - lo];
+                a = 1.0 - b;
+            }
+        }
+        let end = usize::min(idx + 1, n - degree);
+        (lo, &res[..end - lo])
+    }
+}
@@ -44,6 +44,8 @@ pub struct KnotVec(Vec<f64>);
 pub struct BSplineCurve<P> {
     knot_vec: KnotVec,      // the knot vector
     control_points: Vec<P>, // the indices of control points
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    periodic: bool, // whether the curve is made by `make_periodic`
 }
 
 /// B-spline surface
@@ -112,6 +114,7 @@ pub struct BSplineSurface<P> {
 
 /// NURBS curve
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound(deserialize = "V: Deserialize<'de> + PartialEq"))]
 pub struct NurbsCurve<V>(BSplineCurve<V>);
 
 /// NURBS surface
@@ -123,6 +126,8 @@ mod bspsurface;
 mod knot_vec;
 mod nurbscurve;
 mod nurbssurface;
+mod plane_section;
+use knot_vec::BasisSpanCache;
 
 #[doc(hidden)]
 #[inline(always)]
@@ -133,3 +138,89 @@ pub fn inv_or_zero(delta: f64) -> f64 {
         1.0 / delta
     }
 }
+
+/// Checks that the knot `x` can be inserted into `knot_vec` of the B-spline with `degree`.
+fn check_knot_insertion(knot_vec: &KnotVec, degree: usize, x: f64) -> Result<()> {
+    let (front, back) = (knot_vec[0], knot_vec[knot_vec.len() - 1]);
+    if x < front || back < x {
+        return Err(Error::OutOfKnotRange(x, front, back));
+    }
+    if knot_vec.iter().filter(|t| x.near(t)).count() >= degree {
+        return Err(Error::TooLargeMultiplicity(x, degree));
+    }
+    Ok(())
+}
+
+/// Returns whether the knots are non-decreasing. `NaN` knots are rejected.
+fn is_non_decreasing(knot_vec: &KnotVec) -> bool { knot_vec.windows(2).all(|w| w[0] <= w[1]) }
+
+/// Returns the clamped knot vector by averaging `params` and the collocation matrix of the
+/// B-spline basis functions at `params` as the rows of the banded system.
+///
+/// `params` must be strictly increasing from `0.0` to `1.0`, and its length must be more
+/// than `degree`.
+fn interpolation_system(params: &[f64], degree: usize) -> (KnotVec, Vec<(usize, Vec<f64>)>) {
+    let n = params.len();
+    let mut knots = vec![0.0; degree + 1];
+    knots.extend(
+        params[1..n - 1]
+            .windows(degree)
+            .map(|ts| ts.iter().sum::<f64>() / degree as f64),
+    );
+    knots.extend(vec![1.0; degree + 1]);
+    let knot_vec = KnotVec(knots);
+
+    // the basis functions vanish at the end, since they are right-continuous.
+    let rows = params
+        .iter()
+        .enumerate()
+        .map(|(i, &t)| match i == n - 1 {
+            true => {
+                let mut band = vec![0.0; degree + 1];
+                band[degree] = 1.0;
+                (n - 1 - degree, band)
+            }
+            false => {
+                let start = knot_vec.floor(t).unwrap() - degree;
+                let basis = knot_vec.bspline_basis_functions(degree, t);
+                (start, basis[start..=start + degree].to_vec())
+            }
+        })
+        .collect();
+    (knot_vec, rows)
+}
+
+/// Solves the banded linear system by the Gaussian elimination without pivoting.
+///
+/// The `i`th row of the matrix is given by `(start, band)`, i.e. the entries from the
+/// `start`th column are `band`. Both the starts and the ends of rows must be non-decreasing.
+/// The matrix is assumed to be totally positive or positive definite, e.g. the collocation
+/// matrix of the B-spline basis functions or its normal matrix, so that no pivoting is needed.
+fn solve_banded_system<V>(mut rows: Vec<(usize, Vec<f64>)>, mut rhs: Vec<V>) -> Vec<V>
+where V: Copy
+        + std::ops::Sub<Output = V>
+        + std::ops::Mul<f64, Output = V>
+        + std::ops::Div<f64, Output = V> {
+    let n = rhs.len();
+    for k in 0..n {
+        let (start, band) = rows[k].clone();
+        let pivot = band[k - start];
+        for i in k + 1..n {
+            let row = &mut rows[i];
+            if row.0 > k {
+                break;
+            }
+            let factor = row.1[k - row.0] / pivot;
+            (k..start + band.len()).for_each(|j| row.1[j - row.0] -= factor * band[j - start]);
+            rhs[i] = rhs[i] - rhs[k] * factor;
+        }
+    }
+    let mut res = rhs.clone();
+    for k in (0..n).rev() {
+        let (start, band) = &rows[k];
+        let end = usize::min(start + band.len(), n);
+        let x = (k + 1..end).fold(rhs[k], |x, j| x - res[j] * band[j - start]);
+        res[k] = x / band[k - start];
+    }
+    res
+}
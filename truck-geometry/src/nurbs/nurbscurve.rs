@@ -80,7 +80,8 @@ impl<V> NurbsCurve<V> {
 impl<V: Homogeneous<f64>> NurbsCurve<V> {
     /// Constructs a rationalization curve from the non-rationalized curve and weights.
     /// # Failures
-    /// the length of `curve.control_points()` and `weights` must be the same.
+    /// - the length of `curve.control_points()` and `weights` must be the same.
+    /// - the weights must not be zero.
     #[inline(always)]
     pub fn try_from_bspline_and_weights(
         curve: BSplineCurve<V::Point>,
@@ -89,10 +90,14 @@ impl<V: Homogeneous<f64>> NurbsCurve<V> {
         let BSplineCurve {
             knot_vec,
             control_points,
+            ..
         } = curve;
         if control_points.len() != weights.len() {
             return Err(Error::DifferentLength);
         }
+        if weights.contains(&0.0) {
+            return Err(Error::ZeroWeight);
+        }
         let control_points = control_points
             .into_iter()
             .zip(weights)
@@ -100,6 +105,94 @@ impl<V: Homogeneous<f64>> NurbsCurve<V> {
             .collect();
         Ok(Self(BSplineCurve::new_unchecked(knot_vec, control_points)))
     }
+
+    /// Returns the weights of the control points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curve = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Vector3::new(1.0, 0.0, 1.0),
+    ///         Vector3::new(2.0, 2.0, 2.0),
+    ///         Vector3::new(0.0, 0.5, 0.5),
+    ///     ],
+    /// ));
+    /// assert_eq!(curve.weights(), vec![1.0, 2.0, 0.5]);
+    /// ```
+    #[inline(always)]
+    pub fn weights(&self) -> Vec<f64> { self.0.control_points.iter().map(|v| v.weight()).collect() }
+
+    /// Sets the weight of the `idx`th control point without moving the control point.
+    /// # Failures
+    /// If `weight` is not positive or is NaN, returns [`Error::NonPositiveWeight`].
+    /// # Panics
+    /// Panics if `idx` is out of range of the control points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let mut curve = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Vector3::new(1.0, 0.0, 1.0),
+    ///         Vector3::new(1.0, 1.0, 1.0),
+    ///         Vector3::new(0.0, 1.0, 1.0),
+    ///     ],
+    /// ));
+    /// curve.set_weight(1, f64::sqrt(0.5)).unwrap();
+    /// assert_near!(curve.control_point(1).to_point(), Point2::new(1.0, 1.0));
+    /// // the quarter circle
+    /// assert_near!(curve.subs(0.5).to_vec().magnitude(), 1.0);
+    ///
+    /// assert!(curve.set_weight(0, 0.0).is_err());
+    /// assert!(curve.set_weight(0, f64::NAN).is_err());
+    /// ```
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    pub fn set_weight(&mut self, idx: usize, weight: f64) -> Result<&mut Self> {
+        // NaN is also rejected.
+        if !(weight > 0.0) {
+            return Err(Error::NonPositiveWeight(weight));
+        }
+        let pt = &mut self.0.control_points[idx];
+        *pt = V::from_point_weight(pt.to_point(), weight);
+        Ok(self)
+    }
+
+    /// Scales all homogeneous control points so that the weight of the `idx`th control point is
+    /// `1.0`. The curve does not change.
+    /// # Panics
+    /// Panics if `idx` is out of range of the control points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curve0 = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Vector3::new(2.0, 0.0, 2.0),
+    ///         Vector3::new(2.0, 2.0, 4.0),
+    ///         Vector3::new(0.0, 1.0, 1.0),
+    ///     ],
+    /// ));
+    /// let mut curve1 = curve0.clone();
+    /// curve1.normalize_weights(0);
+    /// assert_eq!(curve1.weights(), vec![1.0, 2.0, 0.5]);
+    /// for i in 0..=10 {
+    ///     let t = i as f64 / 10.0;
+    ///     assert_near!(curve0.subs(t), curve1.subs(t));
+    /// }
+    ///
+    /// // normalized by the middle control point
+    /// curve1.normalize_weights(1);
+    /// assert_eq!(curve1.weights(), vec![0.5, 1.0, 0.25]);
+    /// ```
+    pub fn normalize_weights(&mut self, idx: usize) -> &mut Self {
+        let weight = self.0.control_points[idx].weight();
+        self.0
+            .control_points
+            .iter_mut()
+            .for_each(|v| *v = *v / weight);
+        self
+    }
 }
 
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> NurbsCurve<V> {
@@ -525,6 +618,42 @@ where V::Point: Bounded<Scalar = f64>
     }
 }
 
+impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> NurbsCurve<V>
+where
+    V::Point: Bounded<Scalar = f64>,
+    <V::Point as EuclideanSpace>::Diff: Array<Element = f64>,
+{
+    /// Returns the bounding box including the curve tightly.
+    ///
+    /// The extrema of each coordinate are searched by the zeros of the derivation,
+    /// and their parameters are determined within `tol`.
+    /// # Panics
+    /// `tol` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// // the quarter circle from -45 degrees to 45 degrees
+    /// let arc = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Vector3::new(FRAC_1_SQRT_2, -FRAC_1_SQRT_2, 1.0),
+    ///         Vector3::new(1.0, 0.0, FRAC_1_SQRT_2),
+    ///         Vector3::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 1.0),
+    ///     ],
+    /// ));
+    /// assert_near!(arc.roughly_bounding_box().max().x, f64::sqrt(2.0));
+    /// let bdd = arc.tight_bounding_box(1.0e-4);
+    /// assert_near!(bdd.min(), Point2::new(FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+    /// assert_near!(bdd.max(), Point2::new(1.0, FRAC_1_SQRT_2));
+    /// ```
+    pub fn tight_bounding_box(&self, tol: f64) -> BoundingBox<V::Point> {
+        let division = self.0.control_points.len() * TIGHT_BOX_DIVISION;
+        algo::curve::tight_bounding_box(self, self.range_tuple(), division, tol)
+    }
+}
+
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> ParametricCurve for NurbsCurve<V> {
     type Point = V::Point;
     type Vector = <V::Point as EuclideanSpace>::Diff;
@@ -617,3 +746,111 @@ fn test_parameter_division() {
         assert!(dist < tol, "large distance: {dist}");
     }
 }
+
+#[test]
+fn length_test() {
+    let knot_vec = KnotVec::from(vec![
+        0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0,
+    ]);
+    let w = f64::sqrt(2.0) / 2.0;
+    let ctrl_pts = vec![
+        Vector4::new(1.0, 0.0, 0.0, 1.0),
+        Vector4::new(w, w, 0.0, w),
+        Vector4::new(0.0, 1.0, 0.0, 1.0),
+        Vector4::new(-w, w, 0.0, w),
+        Vector4::new(-1.0, 0.0, 0.0, 1.0),
+        Vector4::new(-w, -w, 0.0, w),
+        Vector4::new(0.0, -1.0, 0.0, 1.0),
+        Vector4::new(w, -w, 0.0, w),
+        Vector4::new(1.0, 0.0, 0.0, 1.0),
+    ];
+    let circle = NurbsCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    assert_near!(circle.length(TOLERANCE), 2.0 * std::f64::consts::PI);
+    let quarter = circle.length_between(0.25, 0.5, TOLERANCE);
+    assert_near!(quarter, std::f64::consts::PI / 2.0);
+    assert_eq!(circle.length_between(0.5, 0.25, TOLERANCE), quarter);
+
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(3),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(0.1, 0.2, 0.2),
+            Point3::new(0.2, 0.4, 0.4),
+            Point3::new(1.0, 2.0, 2.0),
+        ],
+    );
+    assert_near!(line.length(TOLERANCE), 3.0);
+}
+
+#[test]
+fn tight_bounding_box_test() {
+    use std::f64::consts::PI;
+    let w = f64::sqrt(2.0) / 2.0;
+    (0..16).for_each(|i| {
+        // the quarter arc of the unit circle from `a` to `a + π/2`.
+        let a = 2.0 * PI * i as f64 / 16.0 + 0.1;
+        let m = a + PI / 4.0;
+        let arc = NurbsCurve::new(BSplineCurve::new(
+            KnotVec::bezier_knot(2),
+            vec![
+                Vector3::new(f64::cos(a), f64::sin(a), 1.0),
+                Vector3::new(f64::cos(m), f64::sin(m), w),
+                Vector3::new(-f64::sin(a), f64::cos(a), 1.0),
+            ],
+        ));
+        // the end points and the points on the axes in the arc
+        let answer: BoundingBox<Point2> = (0..8)
+            .map(|j| PI / 2.0 * j as f64)
+            .filter(|t| a < *t && *t < a + PI / 2.0)
+            .chain([a, a + PI / 2.0])
+            .map(|t| Point2::new(f64::cos(t), f64::sin(t)))
+            .collect();
+        let bdd = arc.tight_bounding_box(TOLERANCE);
+        assert_near!(bdd.min(), answer.min());
+        assert_near!(bdd.max(), answer.max());
+    });
+}
+
+#[test]
+fn weights_test() {
+    let ctrl_pts: Vec<Point3> = (0..6)
+        .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+        .collect();
+    let weights: Vec<f64> = (0..6).map(|_| 0.5 + rand::random::<f64>()).collect();
+    let bspcurve = BSplineCurve::new(KnotVec::uniform_knot(3, 3), ctrl_pts);
+    let curve =
+        NurbsCurve::<Vector4>::try_from_bspline_and_weights(bspcurve, weights.clone()).unwrap();
+    assert_eq!(curve.weights(), weights);
+
+    // the curve is invariant under the uniform scaling of the weights.
+    let mut scaled = curve.clone();
+    scaled.transform_control_points(|v| *v *= 3.5);
+    let mut normalized = scaled.clone();
+    normalized.normalize_weights(3);
+    assert_near!(normalized.weights()[3], 1.0);
+    const N: usize = 20;
+    (0..=N).for_each(|i| {
+        let t = i as f64 / N as f64;
+        assert_near!(scaled.subs(t), curve.subs(t));
+        assert_near!(normalized.subs(t), curve.subs(t));
+        assert_near!(normalized.der(t), curve.der(t));
+    });
+
+    // the control points do not move.
+    let mut edited = curve.clone();
+    edited.set_weight(2, 10.0).unwrap();
+    assert_eq!(edited.weights()[2], 10.0);
+    assert_near!(
+        edited.control_point(2).to_point(),
+        curve.control_point(2).to_point()
+    );
+    // the curve is pulled to the control point.
+    let pt = curve.control_point(2).to_point();
+    let t = 0.4;
+    assert!(edited.subs(t).distance(pt) < curve.subs(t).distance(pt));
+    assert_eq!(
+        edited.set_weight(3, -1.0),
+        Err(Error::NonPositiveWeight(-1.0))
+    );
+    assert_eq!(edited.weights()[3], weights[3]);
+}
@@ -168,6 +168,18 @@ impl<V> NurbsSurface<V> {
         self.0.swap_axes();
         self
     }
+    /// Inverts the direction of the u-parameter.
+    #[inline(always)]
+    pub fn uinvert(&mut self) -> &mut Self {
+        self.0.uinvert();
+        self
+    }
+    /// Inverts the direction of the v-parameter.
+    #[inline(always)]
+    pub fn vinvert(&mut self) -> &mut Self {
+        self.0.vinvert();
+        self
+    }
     /// The range of the parameter of the surface.
     #[inline(always)]
     pub fn parameter_range(&self) -> (ParameterRange, ParameterRange) { self.0.parameter_range() }
@@ -188,7 +200,8 @@ impl<V> NurbsSurface<V> {
 impl<V: Homogeneous<f64>> NurbsSurface<V> {
     /// Constructs a rationalization surface from the non-rationalized surface and weights.
     /// # Failures
-    /// the length of `surface.control_points()` and `weights` must be the same.
+    /// - the length of `surface.control_points()` and `weights` must be the same.
+    /// - the weights must not be zero.
     #[inline(always)]
     pub fn try_from_bspline_and_weights(
         surface: BSplineSurface<V::Point>,
@@ -208,6 +221,9 @@ impl<V: Homogeneous<f64>> NurbsSurface<V> {
                 if control_points.len() != weights.len() {
                     return Err(Error::DifferentLength);
                 }
+                if weights.contains(&0.0) {
+                    return Err(Error::ZeroWeight);
+                }
                 Ok(control_points
                     .into_iter()
                     .zip(weights)
@@ -226,6 +242,14 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> NurbsSurface<V> {
     /// Substitutes to a NURBS surface.
     #[inline(always)]
     pub fn subs(&self, u: f64, v: f64) -> V::Point { self.0.subs(u, v).to_point() }
+    /// Returns the isocurve `v -> self.subs(u, v)` with the constant `u`.
+    /// See [`BSplineSurface::uiso`].
+    #[inline(always)]
+    pub fn uiso(&self, u: f64) -> NurbsCurve<V> { NurbsCurve(self.0.uiso(u)) }
+    /// Returns the isocurve `u -> self.subs(u, v)` with the constant `v`.
+    /// See [`BSplineSurface::viso`].
+    #[inline(always)]
+    pub fn viso(&self, v: f64) -> NurbsCurve<V> { NurbsCurve(self.0.viso(v)) }
     /// Substitutes derived NURBS surface by the first parameter `u`.
     #[inline(always)]
     pub fn uder(&self, u: f64, v: f64) -> <V::Point as EuclideanSpace>::Diff {
@@ -665,6 +689,16 @@ impl ParametricSurface3D for NurbsSurface<Vector4> {
     }
 }
 
+impl NurbsSurface<Vector4> {
+    /// Returns the Gaussian curvature and the mean curvature at `(u, v)`.
+    ///
+    /// The derivatives are computed from the homogeneous ones by the quotient rule.
+    /// The sign of the mean curvature follows the normal `uder x vder`. If the surface
+    /// degenerates at `(u, v)`, e.g. at the poles of a sphere, returns `(NaN, NaN)`.
+    #[inline(always)]
+    pub fn curvatures(&self, u: f64, v: f64) -> (f64, f64) { algo::surface::curvatures(self, u, v) }
+}
+
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> ParameterDivision2D for NurbsSurface<V>
 where V::Point: MetricSpace<Metric = f64> + HashGen<f64>
 {
@@ -918,3 +952,87 @@ fn test_include3d() {
     *curve.control_point_mut(1) += Vector4::new(0.0, 0.0, 0.00001, 0.0);
     assert!(!surface.include(&curve));
 }
+
+#[test]
+fn sphere_curvatures() {
+    let radius = 0.5 + rand::random::<f64>() * 10.0;
+    let w = f64::sqrt(0.5);
+    // the semicircle from the north pole to the south pole, and the whole circle
+    let meridian = [
+        (0.0, 1.0, 1.0),
+        (1.0, 1.0, w),
+        (1.0, 0.0, 1.0),
+        (1.0, -1.0, w),
+        (0.0, -1.0, 1.0),
+    ];
+    let parallel = [
+        (1.0, 0.0, 1.0),
+        (1.0, 1.0, w),
+        (0.0, 1.0, 1.0),
+        (-1.0, 1.0, w),
+        (-1.0, 0.0, 1.0),
+        (-1.0, -1.0, w),
+        (0.0, -1.0, 1.0),
+        (1.0, -1.0, w),
+        (1.0, 0.0, 1.0),
+    ];
+    let ctrl_pts = meridian
+        .iter()
+        .map(|&(r, z, w0)| {
+            parallel
+                .iter()
+                .map(|&(x, y, w1)| {
+                    let w = w0 * w1;
+                    Vector4::new(r * x * w, r * y * w, z * w, w / radius)
+                })
+                .collect()
+        })
+        .collect();
+    let knot_vecs = (
+        KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 0.5, 1.0, 1.0, 1.0]),
+        KnotVec::from(vec![
+            0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0,
+        ]),
+    );
+    let sphere = NurbsSurface::new(BSplineSurface::new(knot_vecs, ctrl_pts));
+
+    const N: usize = 10;
+    for i in 1..N {
+        for j in 0..=N {
+            let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+            let pt: Point3 = sphere.subs(u, v);
+            assert_near!(pt.x * pt.x + pt.y * pt.y + pt.z * pt.z, radius * radius);
+            let (gaussian, mean) = sphere.curvatures(u, v);
+            assert_near!(gaussian, 1.0 / (radius * radius));
+            assert_near!(mean.abs(), 1.0 / radius);
+        }
+    }
+    let (gaussian, mean) = sphere.curvatures(0.0, 0.3);
+    assert!(gaussian.is_nan() && mean.is_nan());
+    let (gaussian, mean) = sphere.curvatures(1.0, 0.7);
+    assert!(gaussian.is_nan() && mean.is_nan());
+}
+
+#[test]
+fn iso_random_test() {
+    let knot_vecs = (KnotVec::uniform_knot(2, 3), KnotVec::uniform_knot(3, 2));
+    let ctrl_pts: Vec<Vec<Vector4>> = (0..5)
+        .map(|_| {
+            (0..5)
+                .map(|_| {
+                    let w = 0.5 + rand::random::<f64>();
+                    Vector4::new(rand::random(), rand::random(), rand::random(), 1.0) * w
+                })
+                .collect()
+        })
+        .collect();
+    let surface = NurbsSurface::new(BSplineSurface::new(knot_vecs, ctrl_pts));
+    let (u, v) = (rand::random::<f64>(), rand::random::<f64>());
+    let (uiso, viso) = (surface.uiso(u), surface.viso(v));
+    const N: usize = 10;
+    (0..=N).for_each(|i| {
+        let t = i as f64 / N as f64;
+        assert_near!(uiso.subs(t), surface.subs(u, t));
+        assert_near!(viso.subs(t), surface.subs(t, v));
+    });
+}
@@ -0,0 +1,516 @@
+use super::*;
+use std::collections::{HashMap, HashSet};
+
+/// the number of the grid cells per control point in marching the contours
+const SECTION_DIVISION: usize = 8;
+/// the number of the iterations of bisection searching the crossing on a grid edge
+const BISECTION_TRIALS: usize = 64;
+/// the number of the iterations of Newton's method projecting a parameter onto the contour
+const PROJECTION_TRIALS: usize = 8;
+/// the maximum depth of the subdivision of the contour segments
+const REFINEMENT_DEPTH: usize = 10;
+/// the number of the trials for checking the fitted curves
+const CHECK_TRIALS: usize = 100;
+
+/// An edge of the parameter grid: `U(i, j)` joins `(i, j)` and `(i + 1, j)`, and `V(i, j)`
+/// joins `(i, j)` and `(i, j + 1)`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum GridEdge {
+    U(usize, usize),
+    V(usize, usize),
+}
+
+struct PlaneSection<'a> {
+    surface: &'a BSplineSurface<Point3>,
+    origin: Point3,
+    normal: Vector3,
+    urange: (f64, f64),
+    vrange: (f64, f64),
+    tol: f64,
+}
+
+impl PlaneSection<'_> {
+    /// Returns the signed distance from the plane to the surface at `uv`.
+    #[inline(always)]
+    fn distance(&self, uv: Point2) -> f64 {
+        self.normal.dot(self.surface.subs(uv.x, uv.y) - self.origin)
+    }
+
+    /// Returns the parameter of the grid point.
+    fn grid_point(&self, (i, j): (usize, usize), (nu, nv): (usize, usize)) -> Point2 {
+        let (u0, u1) = self.urange;
+        let (v0, v1) = self.vrange;
+        Point2::new(
+            u0 + (u1 - u0) * i as f64 / nu as f64,
+            v0 + (v1 - v0) * j as f64 / nv as f64,
+        )
+    }
+
+    /// Searches the crossing of the segment from `uv0` to `uv1` by bisection.
+    fn crossing(&self, mut uv0: Point2, mut uv1: Point2) -> Point2 {
+        let positive = self.distance(uv0) >= 0.0;
+        for _ in 0..BISECTION_TRIALS {
+            let mid = uv0.midpoint(uv1);
+            match (self.distance(mid) >= 0.0) == positive {
+                true => uv0 = mid,
+                false => uv1 = mid,
+            }
+        }
+        uv0.midpoint(uv1)
+    }
+
+    /// Projects `uv` onto the contour by Newton's method along the gradient.
+    fn project(&self, mut uv: Point2) -> Point2 {
+        for _ in 0..PROJECTION_TRIALS {
+            let dist = self.distance(uv);
+            let grad = self.gradient(uv);
+            if dist.so_small() || grad.so_small() {
+                break;
+            }
+            uv -= grad * (dist / grad.magnitude2());
+            uv.x = f64::clamp(uv.x, self.urange.0, self.urange.1);
+            uv.y = f64::clamp(uv.y, self.vrange.0, self.vrange.1);
+        }
+        uv
+    }
+
+    /// Returns the numbers of the grid cells and the signed distances at the grid points.
+    fn grid(&self) -> ((usize, usize), Vec<Vec<f64>>) {
+        let (nu, nv) = (
+            self.surface.control_points.len() * SECTION_DIVISION,
+            self.surface.control_points[0].len() * SECTION_DIVISION,
+        );
+        let distances = (0..=nu)
+            .map(|i| {
+                (0..=nv)
+                    .map(|j| self.distance(self.grid_point((i, j), (nu, nv))))
+                    .collect()
+            })
+            .collect();
+        ((nu, nv), distances)
+    }
+
+    /// Marches the contours on the grid and returns the polylines of the parameters.
+    fn contours(&self, (nu, nv): (usize, usize), distances: &[Vec<f64>]) -> Vec<Vec<Point2>> {
+        let signs: Vec<Vec<bool>> = distances
+            .iter()
+            .map(|row| row.iter().map(|dist| *dist >= 0.0).collect())
+            .collect();
+        let mut crossings = HashMap::<GridEdge, Point2>::new();
+        let mut order = Vec::<GridEdge>::new();
+        let mut adjacency = HashMap::<GridEdge, Vec<GridEdge>>::new();
+        (0..nu).for_each(|i| {
+            (0..nv).for_each(|j| {
+                let edges = [
+                    (GridEdge::U(i, j), (i, j), (i + 1, j)),
+                    (GridEdge::V(i + 1, j), (i + 1, j), (i + 1, j + 1)),
+                    (GridEdge::U(i, j + 1), (i, j + 1), (i + 1, j + 1)),
+                    (GridEdge::V(i, j), (i, j), (i, j + 1)),
+                ];
+                let crossed: Vec<GridEdge> = edges
+                    .into_iter()
+                    .filter(|(_, a, b)| signs[a.0][a.1] != signs[b.0][b.1])
+                    .map(|(edge, a, b)| {
+                        crossings.entry(edge).or_insert_with(|| {
+                            order.push(edge);
+                            let (p, q) =
+                                (self.grid_point(a, (nu, nv)), self.grid_point(b, (nu, nv)));
+                            self.crossing(p, q)
+                        });
+                        edge
+                    })
+                    .collect();
+                let segments = match crossed.len() {
+                    2 => vec![(crossed[0], crossed[1])],
+                    4 => {
+                        // the saddle is resolved by the sign at the center of the cell.
+                        let center = self.grid_point((2 * i + 1, 2 * j + 1), (2 * nu, 2 * nv));
+                        match (self.distance(center) >= 0.0) == signs[i][j] {
+                            true => vec![(crossed[0], crossed[1]), (crossed[2], crossed[3])],
+                            false => vec![(crossed[0], crossed[3]), (crossed[1], crossed[2])],
+                        }
+                    }
+                    _ => Vec::new(),
+                };
+                segments.into_iter().for_each(|(a, b)| {
+                    adjacency.entry(a).or_default().push(b);
+                    adjacency.entry(b).or_default().push(a);
+                });
+            });
+        });
+
+        // the open contours are traced from the boundary first, and the rest are loops.
+        let mut visited = HashSet::<GridEdge>::new();
+        let open_starts = order.iter().filter(|edge| adjacency[edge].len() == 1);
+        let starts: Vec<GridEdge> = open_starts.chain(&order).copied().collect();
+        starts
+            .into_iter()
+            .filter_map(|start| {
+                if !visited.insert(start) {
+                    return None;
+                }
+                let mut chain = vec![start];
+                let mut current = start;
+                while let Some(next) = adjacency[&current].iter().find(|e| !visited.contains(e)) {
+                    visited.insert(*next);
+                    chain.push(*next);
+                    current = *next;
+                }
+                if chain.len() > 2 && adjacency[&current].contains(&start) {
+                    chain.push(start);
+                }
+                Some(chain.into_iter().map(|edge| crossings[&edge]).collect())
+            })
+            .collect()
+    }
+
+    /// Searches the points at which the surface touches the plane from the local extrema of
+    /// the distances on the grid, by Newton's method for the stationary points of the distance.
+    fn contacts(&self, (nu, nv): (usize, usize), distances: &[Vec<f64>]) -> Vec<Point3> {
+        let is_extremum = |i: usize, j: usize| {
+            let neighbors = (i.saturating_sub(1)..=usize::min(i + 1, nu))
+                .flat_map(|k| (j.saturating_sub(1)..=usize::min(j + 1, nv)).map(move |l| (k, l)))
+                .map(|(k, l)| distances[k][l]);
+            let dist = distances[i][j];
+            neighbors.clone().all(|x| x <= dist) || neighbors.into_iter().all(|x| x >= dist)
+        };
+        let mut contacts = Vec::<Point3>::new();
+        (0..=nu)
+            .flat_map(|i| (0..=nv).map(move |j| (i, j)))
+            .filter(|&(i, j)| distances[i][j].abs() < self.tol && is_extremum(i, j))
+            .for_each(|(i, j)| {
+                let uv = self.stationary_point(self.grid_point((i, j), (nu, nv)));
+                let pt = self.surface.subs(uv.x, uv.y);
+                let dist = self.normal.dot(pt - self.origin);
+                let is_new = !contacts.iter().any(|p| p.distance(pt) < self.tol);
+                if dist.abs() < self.tol && self.gradient(uv).so_small() && is_new {
+                    contacts.push(pt);
+                }
+            });
+        contacts
+    }
+
+    /// Returns the gradient of the signed distance in the parameter space.
+    #[inline(always)]
+    fn gradient(&self, uv: Point2) -> Vector2 {
+        Vector2::new(
+            self.normal.dot(self.surface.uder(uv.x, uv.y)),
+            self.normal.dot(self.surface.vder(uv.x, uv.y)),
+        )
+    }
+
+    /// Searches the stationary point of the signed distance by Newton's method.
+    fn stationary_point(&self, mut uv: Point2) -> Point2 {
+        for _ in 0..PROJECTION_TRIALS {
+            let grad = self.gradient(uv);
+            let (uu, uv_, vv) = (
+                self.normal.dot(self.surface.uuder(uv.x, uv.y)),
+                self.normal.dot(self.surface.uvder(uv.x, uv.y)),
+                self.normal.dot(self.surface.vvder(uv.x, uv.y)),
+            );
+            let hessian = Matrix2::new(uu, uv_, uv_, vv);
+            let Some(inv) = hessian.invert() else {
+                break;
+            };
+            if grad.so_small() {
+                break;
+            }
+            uv -= inv * grad;
+            uv.x = f64::clamp(uv.x, self.urange.0, self.urange.1);
+            uv.y = f64::clamp(uv.y, self.vrange.0, self.vrange.1);
+        }
+        uv
+    }
+
+    /// Subdivides the polyline until the chords are within the tolerance from the contour.
+    ///
+    /// Returns the points on the contour and the middle points of the chords for checking.
+    fn refine(&self, uvs: &[Point2]) -> (Vec<Point3>, Vec<Point3>) {
+        let mut pts = vec![self.surface.subs(uvs[0].x, uvs[0].y)];
+        let mut checks = Vec::new();
+        uvs.windows(2).for_each(|pair| {
+            self.sub_refine((pair[0], pair[1]), REFINEMENT_DEPTH, &mut pts, &mut checks)
+        });
+        (pts, checks)
+    }
+
+    fn sub_refine(
+        &self,
+        (uv0, uv1): (Point2, Point2),
+        depth: usize,
+        pts: &mut Vec<Point3>,
+        checks: &mut Vec<Point3>,
+    ) {
+        let (pt0, pt1) = (
+            self.surface.subs(uv0.x, uv0.y),
+            self.surface.subs(uv1.x, uv1.y),
+        );
+        let mid = self.project(uv0.midpoint(uv1));
+        let mid_pt = self.surface.subs(mid.x, mid.y);
+        if depth == 0 || mid_pt.distance(pt0.midpoint(pt1)) < self.tol {
+            checks.push(mid_pt);
+            pts.push(pt1);
+        } else {
+            self.sub_refine((uv0, mid), depth - 1, pts, checks);
+            self.sub_refine((mid, uv1), depth - 1, pts, checks);
+        }
+    }
+}
+
+/// Joins the open polylines whose end points coincide, e.g. the contours across the seam of
+/// the closed surfaces.
+fn merge_polylines(
+    mut polylines: Vec<(Vec<Point3>, Vec<Point3>)>,
+) -> Vec<(Vec<Point3>, Vec<Point3>)> {
+    let ends = |pts: &[Point3]| (pts[0], pts[pts.len() - 1]);
+    loop {
+        let len = polylines.len();
+        let found = (0..len)
+            .flat_map(|i| (i + 1..len).map(move |j| (i, j)))
+            .find_map(|(i, j)| {
+                let ((a0, a1), (b0, b1)) = (ends(&polylines[i].0), ends(&polylines[j].0));
+                if a0.near(&a1) || b0.near(&b1) {
+                    return None;
+                }
+                [
+                    (a1, b0, false, false),
+                    (a1, b1, false, true),
+                    (a0, b0, true, false),
+                    (a0, b1, true, true),
+                ]
+                .into_iter()
+                .find(|(p, q, _, _)| p.near(q))
+                .map(|(_, _, reverse0, reverse1)| (i, j, reverse0, reverse1))
+            });
+        let Some((i, j, reverse0, reverse1)) = found else {
+            return polylines;
+        };
+        let (mut pts, checks) = polylines.remove(j);
+        if reverse0 {
+            polylines[i].0.reverse();
+        }
+        if reverse1 {
+            pts.reverse();
+        }
+        polylines[i].0.extend(pts.into_iter().skip(1));
+        polylines[i].1.extend(checks);
+    }
+}
+
+/// Fits the B-spline curve to the points, and falls back to the polyline if the fitted curve
+/// is farther than `tol` from the check points.
+fn fit_curve(mut pts: Vec<Point3>, checks: &[Point3], tol: f64) -> Option<BSplineCurve<Point3>> {
+    pts.dedup_by(|a, b| Point3::near(a, b));
+    if pts.len() < 2 {
+        return None;
+    }
+    let degree = usize::min(3, pts.len() - 1);
+    let curve = BSplineCurve::interpolate(&pts, degree).ok()?;
+    let is_fitted = checks.iter().all(|pt| {
+        curve
+            .search_nearest_parameter(*pt, None, CHECK_TRIALS)
+            .is_some_and(|t| curve.subs(t).distance(*pt) < tol)
+    });
+    match is_fitted || degree == 1 {
+        true => Some(curve),
+        false => BSplineCurve::interpolate(&pts, 1).ok(),
+    }
+}
+
+impl BSplineSurface<Point3> {
+    /// Returns the intersection curves of the surface and `plane`, and the points at which the
+    /// surface touches `plane` without crossing it.
+    ///
+    /// The contours of the signed distance from the plane are marched on the grid of the
+    /// parameter space, refined until the chords are within `tol` from the intersection,
+    /// and interpolated by cubic B-spline curves. If the interpolation is not within `tol`,
+    /// the polyline is returned instead. All disjoint branches are returned, and the closed
+    /// loops have the same front and back points. The branches across the boundary of a closed
+    /// surface are joined into one curve.
+    ///
+    /// The contacts at which the surface touches the plane without crossing it, e.g. the top of
+    /// a bump, are not curves, so they are returned as the points separately. The contacts are
+    /// searched at the stationary points of the distance from the plane within `tol` of the
+    /// plane which are not on the curves. The tangential contacts along curves are not detected.
+    /// # Panics
+    /// `tol` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// // the bump z = 4u(1 - u)v(1 - v) on [0, 1] x [0, 1]
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = (0..3)
+    ///     .map(|i| {
+    ///         (0..3)
+    ///             .map(|j| {
+    ///                 let z = if i == 1 && j == 1 { 1.0 } else { 0.0 };
+    ///                 Point3::new(i as f64 / 2.0, j as f64 / 2.0, z)
+    ///             })
+    ///             .collect()
+    ///     })
+    ///     .collect();
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    ///
+    /// // the horizontal plane cuts the bump in a loop.
+    /// let plane = Plane::new(
+    ///     Point3::new(0.0, 0.0, 0.1),
+    ///     Point3::new(1.0, 0.0, 0.1),
+    ///     Point3::new(0.0, 1.0, 0.1),
+    /// );
+    /// let (curves, contacts) = bspsurface.intersect_plane(&plane, 1.0e-3);
+    /// assert_eq!(curves.len(), 1);
+    /// assert!(contacts.is_empty());
+    /// assert_near!(curves[0].front(), curves[0].back());
+    /// for i in 0..=10 {
+    ///     let pt = curves[0].subs(i as f64 / 10.0);
+    ///     assert!(f64::abs(pt.z - 0.1) < 1.0e-3);
+    /// }
+    ///
+    /// // the plane touching the top gives the contact point.
+    /// let plane = Plane::new(
+    ///     Point3::new(0.0, 0.0, 0.25),
+    ///     Point3::new(1.0, 0.0, 0.25),
+    ///     Point3::new(0.0, 1.0, 0.25),
+    /// );
+    /// let (curves, contacts) = bspsurface.intersect_plane(&plane, 1.0e-3);
+    /// assert!(curves.is_empty());
+    /// assert_eq!(contacts.len(), 1);
+    /// assert_near!(contacts[0], Point3::new(0.5, 0.5, 0.25));
+    /// ```
+    pub fn intersect_plane(
+        &self,
+        plane: &Plane,
+        tol: f64,
+    ) -> (Vec<BSplineCurve<Point3>>, Vec<Point3>) {
+        nonpositive_tolerance!(tol);
+        let (urange, vrange) = self.range_tuple();
+        let section = PlaneSection {
+            surface: self,
+            origin: plane.origin(),
+            normal: plane.normal(),
+            urange,
+            vrange,
+            tol,
+        };
+        let (division, distances) = section.grid();
+        let polylines = section
+            .contours(division, &distances)
+            .into_iter()
+            .map(|uvs| section.refine(&uvs))
+            .collect();
+        let fitted: Vec<_> = merge_polylines(polylines)
+            .into_iter()
+            .filter_map(|(pts, checks)| Some((fit_curve(pts.clone(), &checks, tol)?, pts)))
+            .collect();
+        let contacts = section
+            .contacts(division, &distances)
+            .into_iter()
+            .filter(|pt| {
+                let on_curve = |pts: &[Point3]| pts.iter().any(|p| p.distance(*pt) < tol);
+                !fitted.iter().any(|(_, pts)| on_curve(pts))
+            })
+            .collect();
+        let curves = fitted.into_iter().map(|(curve, _)| curve).collect();
+        (curves, contacts)
+    }
+}
+
+#[test]
+fn intersect_plane_test() {
+    // two bumps on [0, 1] x [0, 1]
+    let knot_vecs = (KnotVec::uniform_knot(2, 4), KnotVec::bezier_knot(2));
+    let ctrl_pts = (0..6)
+        .map(|i| {
+            (0..3)
+                .map(|j| {
+                    let z = if (i == 1 || i == 4) && j == 1 {
+                        1.0
+                    } else {
+                        0.0
+                    };
+                    Point3::new(i as f64 / 5.0, j as f64 / 2.0, z)
+                })
+                .collect()
+        })
+        .collect();
+    let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    let tol = 1.0e-3;
+    let check = |plane: &Plane, curves: &[BSplineCurve<Point3>]| {
+        curves.iter().for_each(|curve| {
+            (0..=20).for_each(|i| {
+                let pt = curve.subs(i as f64 / 20.0);
+                assert!(plane.normal().dot(pt - plane.origin()).abs() < tol);
+                let (u, v) = bspsurface.search_nearest_parameter(pt, None, 100).unwrap();
+                assert!(bspsurface.subs(u, v).distance(pt) < tol);
+            })
+        })
+    };
+
+    // two loops
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 0.1),
+        Point3::new(1.0, 0.0, 0.1),
+        Point3::new(0.0, 1.0, 0.1),
+    );
+    let (curves, contacts) = bspsurface.intersect_plane(&plane, tol);
+    assert_eq!(curves.len(), 2);
+    assert!(contacts.is_empty());
+    curves
+        .iter()
+        .for_each(|curve| assert_near!(curve.front(), curve.back()));
+    check(&plane, &curves);
+
+    // an open curve from the boundary to the boundary across the bump
+    let plane = Plane::new(
+        Point3::new(0.3, 0.0, 0.0),
+        Point3::new(0.3, 1.0, 0.0),
+        Point3::new(0.3, 0.0, 1.0),
+    );
+    let (curves, _) = bspsurface.intersect_plane(&plane, tol);
+    assert_eq!(curves.len(), 1);
+    let (front, back) = (curves[0].front(), curves[0].back());
+    assert!(front.y.so_small() && back.y.near(&1.0) || front.y.near(&1.0) && back.y.so_small());
+    check(&plane, &curves);
+
+    // the tilted plane crossing the both bumps and the flat parts
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.1),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let (curves, _) = bspsurface.intersect_plane(&plane, tol);
+    assert!(!curves.is_empty());
+    check(&plane, &curves);
+
+    // the plane apart from the surface
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    );
+    let (curves, contacts) = bspsurface.intersect_plane(&plane, tol);
+    assert!(curves.is_empty() && contacts.is_empty());
+
+    // the plane touching the tops of the both bumps, where the sampled maximum is raised
+    // not to cut the bumps in small loops.
+    let top = (0..=10000)
+        .map(|i| bspsurface.subs(i as f64 / 10000.0, 0.5).z)
+        .fold(f64::NEG_INFINITY, f64::max)
+        + 1.0e-4;
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, top),
+        Point3::new(1.0, 0.0, top),
+        Point3::new(0.0, 1.0, top),
+    );
+    let (curves, contacts) = bspsurface.intersect_plane(&plane, tol);
+    assert!(curves.is_empty());
+    assert_eq!(contacts.len(), 2);
+    assert!(
+        contacts[0].x < 0.5 && contacts[1].x > 0.5 || contacts[0].x > 0.5 && contacts[1].x < 0.5
+    );
+    contacts.iter().for_each(|pt| {
+        assert!(f64::abs(pt.z - top) < tol);
+        assert_near!(pt.y, 0.5);
+    });
+}
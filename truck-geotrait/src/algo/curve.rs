@@ -126,3 +126,149 @@ where
         (params, pts)
     }
 }
+
+/// The nodes and weights of the 5-point Gauss-Legendre quadrature on `[-1, 1]`.
+const GAUSS_LEGENDRE_5: [(f64, f64); 5] = [
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.0, 0.568_888_888_888_888_9),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+/// Integrates the speed `|curve.der(t)|` on `range` by the 5-point Gauss-Legendre quadrature.
+pub fn gauss_legendre_length<C>(curve: &C, range: (f64, f64)) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let (t0, t1) = range;
+    let (half, mid) = ((t1 - t0) / 2.0, (t0 + t1) / 2.0);
+    GAUSS_LEGENDRE_5
+        .iter()
+        .map(|(x, w)| w * curve.der(mid + half * x).magnitude())
+        .sum::<f64>()
+        * half
+}
+
+/// Divides `range` adaptively until the length of each piece is computed within `tol`,
+/// and returns the pair of the dividing parameters and the accumulated lengths from `range.0`.
+///
+/// # Panics
+///
+/// Panics if `tol` is less than `TOLERANCE`.
+pub fn arc_length_table<C>(curve: &C, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<f64>)
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    nonpositive_tolerance!(tol);
+    let mut params = vec![range.0];
+    let mut lengths = vec![0.0];
+    let whole = gauss_legendre_length(curve, range);
+    sub_arc_length_table(curve, range, whole, tol, 30, &mut params, &mut lengths);
+    (params, lengths)
+}
+
+fn sub_arc_length_table<C>(
+    curve: &C,
+    range: (f64, f64),
+    whole: f64,
+    tol: f64,
+    trials: usize,
+    params: &mut Vec<f64>,
+    lengths: &mut Vec<f64>,
+) where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    let mid = (range.0 + range.1) / 2.0;
+    let former = gauss_legendre_length(curve, (range.0, mid));
+    let latter = gauss_legendre_length(curve, (mid, range.1));
+    if f64::abs(former + latter - whole) < tol || trials == 0 {
+        let last = *lengths.last().unwrap();
+        params.extend([mid, range.1]);
+        lengths.extend([last + former, last + former + latter]);
+    } else {
+        let tol = tol / 2.0;
+        sub_arc_length_table(
+            curve,
+            (range.0, mid),
+            former,
+            tol,
+            trials - 1,
+            params,
+            lengths,
+        );
+        sub_arc_length_table(
+            curve,
+            (mid, range.1),
+            latter,
+            tol,
+            trials - 1,
+            params,
+            lengths,
+        );
+    }
+}
+
+/// Returns the parameter `t` such that the length from `params[0]` to `t` is `s` times the whole length.
+///
+/// `params` and `lengths` are the output of [`arc_length_table`]. `s` is clamped to `[0, 1]`.
+/// If the whole length is zero, the parameter is linearly interpolated.
+pub fn inverse_arc_length<C>(curve: &C, params: &[f64], lengths: &[f64], s: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let (t0, t1) = (params[0], params[params.len() - 1]);
+    let s = s.clamp(0.0, 1.0);
+    let total = lengths[lengths.len() - 1];
+    if total.so_small() {
+        return t0 * (1.0 - s) + t1 * s;
+    }
+    let target = total * s;
+    let idx = lengths
+        .partition_point(|l| *l < target)
+        .clamp(1, lengths.len() - 1);
+    let (ta, tb) = (params[idx - 1], params[idx]);
+    let (la, lb) = (lengths[idx - 1], lengths[idx]);
+    if (lb - la).so_small() {
+        return ta;
+    }
+    let rest = target - la;
+    let mut t = ta + (tb - ta) * rest / (lb - la);
+    for _ in 0..16 {
+        let f = gauss_legendre_length(curve, (ta, t)) - rest;
+        let speed = curve.der(t).magnitude();
+        if f64::abs(f) < TOLERANCE || speed.so_small() {
+            break;
+        }
+        t = f64::clamp(t - f / speed, ta, tb);
+    }
+    t
+}
+
+/// Returns the bounding box of `curve` on `range`, which includes the curve tightly.
+///
+/// The range is divided into `division` parts, and the extrema of each coordinate are searched
+/// in the parts where the derivation of the coordinate changes its sign, by Newton's method
+/// safeguarded by the bisection. The parameters of the extrema are determined within `tol`.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn tight_bounding_box<C>(
+    curve: &C,
+    range: (f64, f64),
+    division: usize,
+    tol: f64,
+) -> BoundingBox<C::Point>
+where
+    C: ParametricCurve,
+    C::Point: Bounded<Scalar = f64>,
+    C::Vector: Array<Element = f64>,
+{
+    nonpositive_tolerance!(tol);
+    let mut bdd = BoundingBox::new();
+    let f = |t: f64| (curve.subs(t), curve.der(t), curve.der2(t));
+    push_extrema_1d(&mut bdd, f, range, division, tol);
+    bdd
+}
@@ -1,7 +1,12 @@
 #![allow(clippy::many_single_char_names)]
 
 use crate::traits::*;
-use truck_base::{cgmath64::*, hash::HashGen, tolerance::*};
+use truck_base::{
+    bounding_box::{Bounded, BoundingBox},
+    cgmath64::*,
+    hash::HashGen,
+    tolerance::*,
+};
 
 /// A structure that stores logs for debugging.
 #[doc(hidden)]
@@ -27,6 +32,73 @@ impl<T: std::fmt::Debug> NewtonLog<T> {
     }
 }
 
+/// Searches the root of `f` in `range` by Newton's method safeguarded by the bisection.
+///
+/// `f` returns the value and the derivation, and the signs of the values at the ends of `range`
+/// must be different. The iteration stops if the step is less than `tol`.
+fn search_root_in_range(f: impl Fn(f64) -> (f64, f64), range: (f64, f64), tol: f64) -> f64 {
+    let (mut a, mut b) = range;
+    let positive = f(a).0 > 0.0;
+    let mut t = (a + b) / 2.0;
+    for _ in 0..100 {
+        let (value, der) = f(t);
+        if value == 0.0 {
+            return t;
+        }
+        match (value > 0.0) == positive {
+            true => a = t,
+            false => b = t,
+        }
+        let next = t - value / der;
+        let next = match a < next && next < b {
+            true => next,
+            false => (a + b) / 2.0,
+        };
+        if f64::abs(next - t) < tol {
+            return next;
+        }
+        t = next;
+    }
+    t
+}
+
+/// Adds the points of the one-parameter family `f` on `range` to `bdd`, at the `division + 1`
+/// sample parameters and at the extrema of each coordinate.
+///
+/// `f` returns the point and the first and the second derivations.
+fn push_extrema_1d<P, V>(
+    bdd: &mut BoundingBox<P>,
+    f: impl Fn(f64) -> (P, V, V),
+    range: (f64, f64),
+    division: usize,
+    tol: f64,
+) where
+    P: Bounded<Scalar = f64>,
+    V: Array<Element = f64>,
+{
+    let (t0, t1) = range;
+    let samples: Vec<(f64, V)> = (0..=division)
+        .map(|i| {
+            let t = t0 + (t1 - t0) * i as f64 / division as f64;
+            let (pt, der, _) = f(t);
+            bdd.push(pt);
+            (t, der)
+        })
+        .collect();
+    (0..V::len()).for_each(|i| {
+        samples.windows(2).for_each(|pair| {
+            let ((s, der0), (t, der1)) = (&pair[0], &pair[1]);
+            if der0[i] * der1[i] < 0.0 {
+                let g = |t: f64| {
+                    let (_, der, der2) = f(t);
+                    (der[i], der2[i])
+                };
+                bdd.push(f(search_root_in_range(g, (*s, *t), tol)).0);
+            }
+        });
+    });
+}
+
 /// curve algorithms
 pub mod curve;
 /// surface algorithms
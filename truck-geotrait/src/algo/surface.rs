@@ -174,6 +174,95 @@ pub fn search_parameter3d<S: ParametricSurface3D>(
     })
 }
 
+/// Returns the Gaussian curvature and the mean curvature of `surface` at `(u, v)`,
+/// computed by the first and the second fundamental forms.
+///
+/// The sign of the mean curvature follows the normal `uder x vder`, i.e. it is positive
+/// if the surface bends toward the normal. If the surface degenerates at `(u, v)`, e.g. at
+/// the poles of a sphere, returns `(NaN, NaN)`.
+pub fn curvatures<S: ParametricSurface3D>(surface: &S, u: f64, v: f64) -> (f64, f64) {
+    let (uder, vder) = (surface.uder(u, v), surface.vder(u, v));
+    let (e, f, g) = (uder.dot(uder), uder.dot(vder), vder.dot(vder));
+    let det = e * g - f * f;
+    if det <= TOLERANCE2 * (e + g) * (e + g) {
+        return (f64::NAN, f64::NAN);
+    }
+    let normal = uder.cross(vder) / f64::sqrt(det);
+    let l = surface.uuder(u, v).dot(normal);
+    let m = surface.uvder(u, v).dot(normal);
+    let n = surface.vvder(u, v).dot(normal);
+    let gaussian = (l * n - m * m) / det;
+    let mean = (e * n - 2.0 * f * m + g * l) / (2.0 * det);
+    (gaussian, mean)
+}
+
+/// Returns the bounding box of `surface` on `(urange, vrange)`, which includes the surface
+/// tightly.
+///
+/// The extrema of each coordinate on the boundary are searched as [`curve::tight_bounding_box`],
+/// and the ones in the interior, i.e. the critical points of the coordinate, are searched by
+/// Newton's method starting from the centers of the `udivision x vdivision` grid.
+/// The parameters of the extrema are determined within `tol`.
+///
+/// [`curve::tight_bounding_box`]: super::curve::tight_bounding_box
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn tight_bounding_box<S>(
+    surface: &S,
+    (urange, vrange): ((f64, f64), (f64, f64)),
+    (udivision, vdivision): (usize, usize),
+    tol: f64,
+) -> BoundingBox<S::Point>
+where
+    S: ParametricSurface,
+    S::Point: Bounded<Scalar = f64>,
+    S::Vector: Array<Element = f64>,
+{
+    nonpositive_tolerance!(tol);
+    let mut bdd = BoundingBox::new();
+    [vrange.0, vrange.1].into_iter().for_each(|v| {
+        let f = |u: f64| (surface.subs(u, v), surface.uder(u, v), surface.uuder(u, v));
+        push_extrema_1d(&mut bdd, f, urange, udivision, tol);
+    });
+    [urange.0, urange.1].into_iter().for_each(|u| {
+        let f = |v: f64| (surface.subs(u, v), surface.vder(u, v), surface.vvder(u, v));
+        push_extrema_1d(&mut bdd, f, vrange, vdivision, tol);
+    });
+    let contains =
+        |u: f64, v: f64| urange.0 <= u && u <= urange.1 && vrange.0 <= v && v <= vrange.1;
+    (0..S::Vector::len()).for_each(|i| {
+        (0..udivision).for_each(|j| {
+            (0..vdivision).for_each(|k| {
+                let p = (j as f64 + 0.5) / udivision as f64;
+                let q = (k as f64 + 0.5) / vdivision as f64;
+                let mut u = urange.0 + (urange.1 - urange.0) * p;
+                let mut v = vrange.0 + (vrange.1 - vrange.0) * q;
+                for _ in 0..16 {
+                    let (gu, gv) = (surface.uder(u, v)[i], surface.vder(u, v)[i]);
+                    let huu = surface.uuder(u, v)[i];
+                    let huv = surface.uvder(u, v)[i];
+                    let hvv = surface.vvder(u, v)[i];
+                    let det = huu * hvv - huv * huv;
+                    if det.so_small() {
+                        break;
+                    }
+                    let (du, dv) = ((hvv * gu - huv * gv) / det, (huu * gv - huv * gu) / det);
+                    (u, v) = (u - du, v - dv);
+                    if !contains(u, v) {
+                        break;
+                    } else if f64::abs(du) < tol && f64::abs(dv) < tol {
+                        bdd.push(surface.subs(u, v));
+                        break;
+                    }
+                }
+            });
+        });
+    });
+    bdd
+}
+
 /// Creates the surface division
 ///
 /// # Panics
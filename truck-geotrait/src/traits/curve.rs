@@ -32,6 +32,30 @@ pub trait ParametricCurve: Clone {
     /// `None` in default implementation; `Some(period)` if periodic.
     #[inline(always)]
     fn period(&self) -> Option<f64> { None }
+    /// Returns the length of the curve on `parameter_range`.
+    ///
+    /// The length is computed by adaptive Gauss-Legendre quadrature of `|der|`,
+    /// which subdivides the range until the error is less than `tol`.
+    /// # Panics
+    /// Panics if the parameter range is unbounded or `tol` is less than `TOLERANCE`.
+    #[inline(always)]
+    fn length(&self, tol: f64) -> f64
+    where Self::Vector: InnerSpace<Scalar = f64> {
+        let (t0, t1) = self.try_range_tuple().expect(UNBOUNDED_ERROR);
+        self.length_between(t0, t1, tol)
+    }
+    /// Returns the length of the curve between the parameters `t0` and `t1`.
+    ///
+    /// The length is computed by adaptive Gauss-Legendre quadrature of `|der|`,
+    /// which subdivides the range until the error is less than `tol`.
+    /// # Panics
+    /// Panics if `tol` is less than `TOLERANCE`.
+    fn length_between(&self, t0: f64, t1: f64, tol: f64) -> f64
+    where Self::Vector: InnerSpace<Scalar = f64> {
+        let range = (f64::min(t0, t1), f64::max(t0, t1));
+        let (_, lengths) = crate::algo::curve::arc_length_table(self, range, tol);
+        lengths[lengths.len() - 1]
+    }
 }
 
 /// bounded parametric curves i.e. it is guaranteed that the return value of `parameter_range` is not `Bound::Unbounded`.
@@ -3,6 +3,7 @@ use crate::*;
 mod collision;
 mod in_out_judge;
 mod point_cloud;
+mod ray_cast;
 mod splitting;
 mod topology;
 mod volume;
@@ -10,8 +11,11 @@ mod volume;
 pub use collision::Collision;
 pub use in_out_judge::IncludingPointInDomain;
 pub use point_cloud::WithPointCloud;
+pub use ray_cast::{MeshBvh, RayCast};
 pub use splitting::ExperimentalSplitters;
 pub use splitting::Splitting;
-pub use topology::Topology;
+pub use topology::{Topology, Watertight};
 pub use truck_topology::shell::ShellCondition;
-pub use volume::CalcVolume;
+#[cfg(feature = "tessellation")]
+pub use volume::CalcMassProperties;
+pub use volume::{CalcVolume, MassProperties};
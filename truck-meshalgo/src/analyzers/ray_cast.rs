@@ -0,0 +1,218 @@
+use super::*;
+
+/// the maximum number of triangles in a leaf of BVH
+const LEAF_SIZE: usize = 4;
+
+/// Returns the ray parameter and the intersection point by the Möller-Trumbore algorithm.
+fn ray_triangle(origin: Point3, dir: Vector3, tri: [Point3; 3]) -> Option<(f64, Point3)> {
+    let (e1, e2) = (tri[1] - tri[0], tri[2] - tri[0]);
+    let p = dir.cross(e2);
+    let det = e1.dot(p);
+    if det.abs() < f64::EPSILON * e1.magnitude() * e2.magnitude() * dir.magnitude() {
+        return None;
+    }
+    let s = origin - tri[0];
+    let u = s.dot(p) / det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = s.cross(e1);
+    let v = dir.dot(q) / det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = e2.dot(q) / det;
+    match t >= 0.0 {
+        true => Some((t, origin + dir * t)),
+        false => None,
+    }
+}
+
+/// Iterates the triangles of the faces with the indices of the faces.
+fn triangles(mesh: &PolygonMesh) -> impl Iterator<Item = (usize, [Point3; 3])> + '_ {
+    mesh.face_iter().enumerate().flat_map(move |(i, face)| {
+        (2..face.len()).map(move |k| {
+            let tri = [face[0], face[k - 1], face[k]];
+            (i, tri.map(|v| mesh.positions()[v.pos]))
+        })
+    })
+}
+
+fn nearest_hit(
+    origin: Point3,
+    dir: Vector3,
+    iter: impl IntoIterator<Item = (usize, [Point3; 3])>,
+) -> Option<(usize, f64, Point3)> {
+    iter.into_iter()
+        .filter_map(|(i, tri)| ray_triangle(origin, dir, tri).map(|(t, p)| (i, t, p)))
+        .min_by(compare_hits)
+}
+
+/// Intersection queries of rays and polygon meshes.
+pub trait RayCast {
+    /// Returns the nearest intersection of the ray and the mesh as the tuple of the index of
+    /// the face, the parameter of the ray, and the intersection point.
+    ///
+    /// The ray is `origin + t * dir` with `t >= 0`, and both sides of faces are hit.
+    /// The polygons are triangulated on the fly. For many queries on a large mesh,
+    /// use [`MeshBvh`].
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///             Point3::new(1.0, 0.0, 1.0),
+    ///             Point3::new(1.0, 1.0, 1.0),
+    ///             Point3::new(0.0, 1.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2, 3], [4, 5, 6, 7]]),
+    /// );
+    ///
+    /// let origin = Point3::new(0.25, 0.5, 2.0);
+    /// let (face, t, point) = mesh.cast_ray(origin, -Vector3::unit_z()).unwrap();
+    /// assert_eq!(face, 1);
+    /// assert_near!(t, 1.0);
+    /// assert_near!(point, Point3::new(0.25, 0.5, 1.0));
+    /// assert!(mesh.cast_ray(origin, Vector3::unit_z()).is_none());
+    /// ```
+    fn cast_ray(&self, origin: Point3, dir: Vector3) -> Option<(usize, f64, Point3)>;
+}
+
+impl RayCast for PolygonMesh {
+    fn cast_ray(&self, origin: Point3, dir: Vector3) -> Option<(usize, f64, Point3)> {
+        nearest_hit(origin, dir, triangles(self))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum BvhNode {
+    /// the range of the triangles
+    Leaf(usize, usize),
+    /// the indices of the children
+    Inner(usize, usize),
+}
+
+/// Bounding volume hierarchy of the triangles of a polygon mesh, accelerating [`RayCast`].
+///
+/// The BVH holds the copy of the triangles, so it must be rebuilt after the mesh is edited.
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///             Point3::new(0.0, 0.0, 1.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]]),
+/// );
+///
+/// let bvh = MeshBvh::new(&mesh);
+/// let origin = Point3::new(1.0, 1.0, 1.0);
+/// let dir = Vector3::new(-1.0, -1.0, -1.0);
+/// let (face, t, point) = bvh.cast_ray(origin, dir).unwrap();
+/// assert_eq!(face, 3);
+/// assert_near!(t, 2.0 / 3.0);
+/// assert_near!(point, Point3::new(1.0, 1.0, 1.0) / 3.0);
+/// assert_eq!(bvh.cast_ray(origin, dir), mesh.cast_ray(origin, dir));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MeshBvh {
+    triangles: Vec<(usize, [Point3; 3])>,
+    nodes: Vec<(BoundingBox<Point3>, BvhNode)>,
+}
+
+impl MeshBvh {
+    /// Builds the BVH of the triangulated faces of `mesh`.
+    pub fn new(mesh: &PolygonMesh) -> Self {
+        let mut bvh = Self {
+            triangles: triangles(mesh).collect(),
+            nodes: Vec::new(),
+        };
+        if !bvh.triangles.is_empty() {
+            bvh.build(0, bvh.triangles.len());
+        }
+        bvh
+    }
+
+    /// Builds the node of the triangles in `start..end`, and returns the index of the node.
+    fn build(&mut self, start: usize, end: usize) -> usize {
+        let triangles = &mut self.triangles[start..end];
+        let bdb: BoundingBox<Point3> = triangles.iter().flat_map(|(_, tri)| *tri).collect();
+        let idx = self.nodes.len();
+        if triangles.len() <= LEAF_SIZE {
+            self.nodes.push((bdb, BvhNode::Leaf(start, end)));
+            return idx;
+        }
+        // splits at the median of the centroids along the longest axis
+        let diag = bdb.diagonal();
+        let axis = (0..3).fold(0, |axis, i| if diag[i] > diag[axis] { i } else { axis });
+        let centroid = |tri: &[Point3; 3]| tri[0][axis] + tri[1][axis] + tri[2][axis];
+        let mid = triangles.len() / 2;
+        triangles.select_nth_unstable_by(mid, |(_, a), (_, b)| centroid(a).total_cmp(&centroid(b)));
+        self.nodes.push((bdb, BvhNode::Leaf(start, end)));
+        let left = self.build(start, start + mid);
+        let right = self.build(start + mid, end);
+        self.nodes[idx].1 = BvhNode::Inner(left, right);
+        idx
+    }
+
+    /// Returns the nearest intersection of the ray and the mesh.
+    ///
+    /// The output is the same as [`RayCast::cast_ray`] of the original mesh.
+    pub fn cast_ray(&self, origin: Point3, dir: Vector3) -> Option<(usize, f64, Point3)> {
+        let mut res: Option<(usize, f64, Point3)> = None;
+        let mut stack = match self.nodes.is_empty() {
+            true => Vec::new(),
+            false => vec![0],
+        };
+        while let Some(idx) = stack.pop() {
+            let (bdb, node) = self.nodes[idx];
+            let t_max = res.map_or(f64::INFINITY, |(_, t, _)| t);
+            if !ray_box(origin, dir, bdb, t_max) {
+                continue;
+            }
+            match node {
+                BvhNode::Leaf(start, end) => {
+                    let hit = nearest_hit(origin, dir, self.triangles[start..end].iter().copied());
+                    res = res.into_iter().chain(hit).min_by(compare_hits);
+                }
+                BvhNode::Inner(left, right) => stack.extend([right, left]),
+            }
+        }
+        res
+    }
+}
+
+/// Orders the hits by the parameters, and the indices of faces for the same parameters.
+fn compare_hits(a: &(usize, f64, Point3), b: &(usize, f64, Point3)) -> std::cmp::Ordering {
+    a.1.total_cmp(&b.1).then(a.0.cmp(&b.0))
+}
+
+/// Whether the ray hits the box before `t_max` by the slab method.
+fn ray_box(origin: Point3, dir: Vector3, bdb: BoundingBox<Point3>, t_max: f64) -> bool {
+    let (min, max) = (bdb.min(), bdb.max());
+    let (mut t0, mut t1) = (0.0, t_max);
+    for i in 0..3 {
+        let (a, b) = ((min[i] - origin[i]) / dir[i], (max[i] - origin[i]) / dir[i]);
+        if a.is_nan() || b.is_nan() {
+            // the ray is on the plane of the slab
+            continue;
+        }
+        t0 = f64::max(t0, f64::min(a, b));
+        t1 = f64::min(t1, f64::max(a, b));
+    }
+    t0 <= t1
+}
@@ -13,6 +13,66 @@ pub trait Topology {
     /// Examples for each condition can be found on the page of
     /// [`ShellCondition`](https://docs.rs/truck-topology/0.2.0/truck_topology/shell/enum.ShellCondition.html).
     fn shell_condition(&self) -> ShellCondition;
+    /// Returns the edges referenced by exactly one face.
+    ///
+    /// Each edge is oriented along the face which refers it.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let faces = Faces::from_iter(&[[0, 1, 2], [0, 2, 3]]);
+    /// assert_eq!(
+    ///     faces.boundary_edges(),
+    ///     vec![[0, 1], [1, 2], [2, 3], [3, 0]],
+    /// );
+    /// ```
+    fn boundary_edges(&self) -> Vec<[usize; 2]>;
+    /// Chains the boundary edges into the ordered loops.
+    ///
+    /// Different from [`extract_boundaries`](Topology::extract_boundaries), the order of
+    /// the output is deterministic, and the vertices shared by several holes are allowed.
+    /// If the boundary edges cannot be closed, the chain is returned as it is.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // two triangles sharing only the vertex 0
+    /// let faces = Faces::from_iter(&[[0, 1, 2], [0, 3, 4]]);
+    /// assert_eq!(faces.boundary_loops(), vec![vec![0, 1, 2], vec![0, 3, 4]]);
+    /// ```
+    fn boundary_loops(&self) -> Vec<Vec<usize>>;
+}
+
+/// Checks whether the mesh bounds a solid.
+pub trait Watertight {
+    /// Returns `true` if every edge is shared by exactly two faces with the opposite directions.
+    ///
+    /// The positions within the distance `tol` are identified, so the meshes whose positions
+    /// are repeated per face can be checked. If `tol` is not positive, only the indices are
+    /// compared. The signed volume of a watertight mesh is given by
+    /// [`CalcVolume::volume`], which is negative if the winding is inverted.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 0.0, 1.0),
+    /// ];
+    /// let faces = Faces::from_iter(&[[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]]);
+    /// let mut simplex = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// assert!(simplex.is_watertight(TOLERANCE));
+    /// assert_near!(simplex.volume(), 1.0 / 6.0);
+    ///
+    /// simplex.editor().faces.tri_faces_mut()[3].swap(1, 2);
+    /// assert!(!simplex.is_watertight(TOLERANCE));
+    /// ```
+    fn is_watertight(&self, tol: f64) -> bool;
 }
 
 #[derive(Clone, Debug)]
@@ -109,9 +169,100 @@ impl Topology for Faces {
             .collect::<Boundaries>()
             .condition()
     }
+    fn boundary_edges(&self) -> Vec<[usize; 2]> {
+        let pos_edges = || {
+            self.face_iter()
+                .flat_map(face_edge_iter)
+                .map(|[v, w]| [v.pos, w.pos])
+        };
+        let mut counter = HashMap::<[usize; 2], usize>::default();
+        pos_edges().for_each(|[v, w]| {
+            *counter
+                .entry([usize::min(v, w), usize::max(v, w)])
+                .or_insert(0) += 1;
+        });
+        pos_edges()
+            .filter(|[v, w]| counter[&[usize::min(*v, *w), usize::max(*v, *w)]] == 1)
+            .collect()
+    }
+    fn boundary_loops(&self) -> Vec<Vec<usize>> {
+        let edges = self.boundary_edges();
+        let mut nexts = HashMap::<usize, Vec<usize>>::default();
+        edges
+            .iter()
+            .enumerate()
+            .for_each(|(i, edge)| nexts.entry(edge[0]).or_default().push(i));
+        let mut used = vec![false; edges.len()];
+        let mut res = Vec::new();
+        for i in 0..edges.len() {
+            if used[i] {
+                continue;
+            }
+            let front = edges[i][0];
+            let mut wire = vec![front];
+            let mut current = Some(i);
+            while let Some(i) = current {
+                used[i] = true;
+                let cursor = edges[i][1];
+                if cursor == front {
+                    break;
+                }
+                wire.push(cursor);
+                current = nexts
+                    .get(&cursor)
+                    .and_then(|js| js.iter().copied().find(|j| !used[*j]));
+            }
+            res.push(wire);
+        }
+        res
+    }
 }
 
 impl Topology for PolygonMesh {
     fn extract_boundaries(&self) -> Vec<Vec<usize>> { self.faces().extract_boundaries() }
     fn shell_condition(&self) -> ShellCondition { self.faces().shell_condition() }
+    fn boundary_edges(&self) -> Vec<[usize; 2]> { self.faces().boundary_edges() }
+    fn boundary_loops(&self) -> Vec<Vec<usize>> { self.faces().boundary_loops() }
+}
+
+impl Watertight for PolygonMesh {
+    fn is_watertight(&self, tol: f64) -> bool {
+        let indices = identify_positions(self.positions(), tol);
+        let mut edges = HashMap::<[usize; 2], usize>::default();
+        self.face_iter()
+            .flat_map(face_edge_iter)
+            .map(|[v, w]| [indices[v.pos], indices[w.pos]])
+            .filter(|[v, w]| v != w)
+            .for_each(|edge| *edges.entry(edge).or_insert(0) += 1);
+        !edges.is_empty()
+            && edges
+                .iter()
+                .all(|([v, w], count)| *count == 1 && edges.get(&[*w, *v]) == Some(&1))
+    }
+}
+
+/// Returns the indices of the representatives of the positions within `tol`, by the hash grid.
+fn identify_positions(positions: &[Point3], tol: f64) -> Vec<usize> {
+    if tol <= 0.0 {
+        return (0..positions.len()).collect();
+    }
+    let cell = |p: Point3| p.map(|x| f64::floor(x / tol) as i64);
+    let mut grid = HashMap::<[i64; 3], Vec<usize>>::default();
+    positions
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let c = cell(*p);
+            let neighbor = (0..27)
+                .map(|k| [c.x + k % 3 - 1, c.y + k / 3 % 3 - 1, c.z + k / 9 - 1])
+                .filter_map(|key| grid.get(&key))
+                .flatten()
+                .find(|j| positions[**j].distance2(*p) <= tol * tol)
+                .copied();
+            neighbor.unwrap_or_else(|| {
+                grid.entry(c.into()).or_default().push(i);
+                i
+            })
+        })
+        .collect()
 }
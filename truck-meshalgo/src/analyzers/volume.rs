@@ -1,6 +1,74 @@
 use super::*;
 use array_macro::array;
 
+/// The mass properties of a solid with uniform density.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MassProperties {
+    /// the volume
+    pub volume: f64,
+    /// the mass, i.e. the volume times the density
+    pub mass: f64,
+    /// the center of mass
+    pub centroid: Point3,
+    /// the inertia tensor around the centroid
+    pub inertia: Matrix3,
+}
+
+/// The integrals of `1`, `x`, and `x x^T` over the domain.
+#[derive(Clone, Copy, Debug)]
+struct Moments(f64, Vector3, Matrix3);
+
+impl std::ops::Add for Moments {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, other: Self) -> Self { Self(self.0 + other.0, self.1 + other.1, self.2 + other.2) }
+}
+
+impl std::ops::Neg for Moments {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self { Self(-self.0, -self.1, -self.2) }
+}
+
+impl Moments {
+    const ZERO: Self = Self(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Matrix3::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0),
+    );
+
+    /// Returns the moments of the signed tetrahedron spanned by the origin and the triangle.
+    fn tetrahedron([p, q, r]: [Point3; 3]) -> Self {
+        let (p, q, r) = (p.to_vec(), q.to_vec(), r.to_vec());
+        let det = p.dot(q.cross(r));
+        let sum = p + q + r;
+        let outer = |a: Vector3, b: Vector3| Matrix3::from_cols(a * b.x, a * b.y, a * b.z);
+        let second = outer(sum, sum) + outer(p, p) + outer(q, q) + outer(r, r);
+        Self(det / 6.0, sum * det / 24.0, second * det / 120.0)
+    }
+
+    /// Returns `None` if the volume is zero, since the centroid is not determined.
+    fn mass_properties(self, density: f64) -> Option<MassProperties> {
+        let Moments(volume, first, second) = self;
+        if volume.so_small2() {
+            return None;
+        }
+        let centroid = Point3::from_vec(first / volume);
+        let c = centroid.to_vec();
+        // the second moment around the centroid
+        let outer = Matrix3::from_cols(c * c.x, c * c.y, c * c.z);
+        let second = second - outer * volume;
+        let trace = second.x.x + second.y.y + second.z.z;
+        let inertia = (Matrix3::from_value(trace) - second) * density;
+        Some(MassProperties {
+            volume,
+            mass: volume * density,
+            centroid,
+            inertia,
+        })
+    }
+}
+
 /// Calculate the volume and the center of gravity of mesh.
 pub trait CalcVolume {
     /// Returns the volume of the mesh if the mesh is closed.
@@ -123,6 +191,35 @@ pub trait CalcVolume {
     /// assert_near!(homog.to_point(), Point3::new(-1.0, 2.0, 1.0));
     /// ```
     fn center_of_gravity(&self) -> Vector4;
+    /// Returns the volume, the mass, the center of mass, and the inertia tensor around the center
+    /// of the mesh with uniform `density` if the mesh is closed. Returns `None` if the volume is
+    /// zero, e.g. the mesh is flat.
+    ///
+    /// # Details
+    /// The properties are integrated over the signed tetrahedra spanned by the origin and the
+    /// triangles, i.e. by the divergence theorem, so they are exact for the mesh.
+    /// The inertia tensor is `density` times the integral of `|x|^2 I - x x^T` around the center.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::builder;
+    ///
+    /// let cube = builder::cube(
+    ///     Point3::origin(),
+    ///     Vector3::unit_x(),
+    ///     Vector3::unit_y(),
+    ///     Vector3::unit_z(),
+    /// );
+    /// let mesh = cube.triangulation(0.01).collect_option().unwrap();
+    /// let props = mesh.mass_properties(2.0).unwrap();
+    /// assert_near!(props.volume, 1.0);
+    /// assert_near!(props.mass, 2.0);
+    /// assert_near!(props.centroid, Point3::new(0.5, 0.5, 0.5));
+    /// // the inertia tensor of the cube is (m / 6) I.
+    /// assert_near!(props.inertia, Matrix3::from_value(1.0 / 3.0));
+    /// ```
+    fn mass_properties(&self, density: f64) -> Option<MassProperties>;
 }
 
 impl CalcVolume for PolygonMesh {
@@ -147,6 +244,15 @@ impl CalcVolume for PolygonMesh {
         });
         Vector4::new(arr[0] / 24.0, arr[1] / 24.0, arr[2] / 24.0, arr[3] / 6.0)
     }
+    fn mass_properties(&self, density: f64) -> Option<MassProperties> {
+        moments(self).mass_properties(density)
+    }
+}
+
+fn moments(poly: &PolygonMesh) -> Moments {
+    point_triangles(poly)
+        .map(Moments::tetrahedron)
+        .fold(Moments::ZERO, std::ops::Add::add)
 }
 
 fn point_triangles(poly: &PolygonMesh) -> impl Iterator<Item = [Point3; 3]> + '_ {
@@ -172,4 +278,74 @@ impl CalcVolume for truck_topology::Solid<Point3, PolylineCurve<Point3>, Polygon
             })
             .sum::<Vector4>()
     }
+    fn mass_properties(&self, density: f64) -> Option<MassProperties> {
+        self.face_iter()
+            .map(|face| match face.orientation() {
+                true => moments(&face.surface()),
+                false => -moments(&face.surface()),
+            })
+            .fold(Moments::ZERO, std::ops::Add::add)
+            .mass_properties(density)
+    }
+}
+
+/// Calculate the mass properties of the solids by tessellation.
+#[cfg(feature = "tessellation")]
+pub trait CalcMassProperties {
+    /// Returns the volume, the mass, the center of mass, and the inertia tensor around the center
+    /// of the solid with uniform `density`.
+    ///
+    /// # Details
+    /// The boundary faces are tessellated with `tol` by [`MeshableShape::triangulation`], and the
+    /// signed contributions of the triangles are accumulated by [`CalcVolume::mass_properties`],
+    /// so the solid must be closed and consistently oriented. The smaller `tol` is, the more
+    /// accurate the properties of the curved solids are.
+    ///
+    /// Returns `None` if the tessellation of a face fails or the volume is zero.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be more than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// let cube = builder::cube(
+    ///     Point3::origin(),
+    ///     Vector3::unit_x(),
+    ///     Vector3::unit_y(),
+    ///     Vector3::unit_z(),
+    /// );
+    /// let props = cube.mass_properties(1.0, 0.01).unwrap();
+    /// assert_near!(props.volume, 1.0);
+    /// assert_near!(props.centroid, Point3::new(0.5, 0.5, 0.5));
+    ///
+    /// // the cylinder with radius 1 and height 2
+    /// let v = builder::vertex(Point3::new(1.0, 2.0, 0.0));
+    /// let circle = builder::rsweep(&v, Point3::new(1.0, 1.0, 0.0), Vector3::unit_z(), Rad(7.0));
+    /// let disk = builder::try_attach_plane(&[circle]).unwrap();
+    /// let cylinder: Solid = builder::tsweep(&disk, Vector3::new(0.0, 0.0, 2.0));
+    /// let props = cylinder.mass_properties(1.0, 0.001).unwrap();
+    /// assert!(f64::abs(props.volume - 2.0 * PI) < 0.01);
+    /// assert_near!(props.centroid, Point3::new(1.0, 1.0, 1.0));
+    /// // the moment of inertia around the axis is m r^2 / 2.
+    /// assert!(f64::abs(props.inertia[2][2] - PI) < 0.01);
+    /// ```
+    fn mass_properties(&self, density: f64, tol: f64) -> Option<MassProperties>;
+}
+
+#[cfg(feature = "tessellation")]
+impl<C, S> CalcMassProperties for truck_topology::Solid<Point3, C, S>
+where
+    C: tessellation::PolylineableCurve,
+    S: tessellation::MeshableSurface,
+{
+    fn mass_properties(&self, density: f64, tol: f64) -> Option<MassProperties> {
+        use tessellation::MeshableShape;
+        let solid = self.triangulation(tol).collect_option()?;
+        CalcVolume::mass_properties(&solid, density)
+    }
 }
@@ -0,0 +1,343 @@
+use super::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// mesh simplification algorithms
+pub trait Decimation {
+    /// Decimates the mesh by the quadric error metric edge collapses (Garland-Heckbert).
+    ///
+    /// The edges are collapsed in ascending order of the error until the number of faces is
+    /// at most `target_faces` or the error of the next collapse is over `max_error`, whichever
+    /// comes first. The error is measured by the sum of the squared distances from the planes
+    /// of the original faces. Returns the maximum error of the performed collapses.
+    ///
+    /// # Remarks
+    /// - The faces are triangulated, and the uv coordinates and the normals are removed.
+    ///   One can recompute the normals by [`NormalFilters`].
+    /// - The boundary edges and the non-manifold edges are preserved.
+    /// - The edges whose collapse makes non-manifold configurations or flipped faces are
+    ///   not collapsed.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a flat grid with 8 x 8 quadrangles
+    /// const N: usize = 8;
+    /// let positions = (0..=N)
+    ///     .flat_map(|i| (0..=N).map(move |j| Point3::new(i as f64, j as f64, 0.0)))
+    ///     .collect::<Vec<_>>();
+    /// let faces = (0..N)
+    ///     .flat_map(|i| (0..N).map(move |j| (i, j)))
+    ///     .map(|(i, j)| {
+    ///         let k = i * (N + 1) + j;
+    ///         [k, k + N + 1, k + N + 2, k + 1]
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&faces),
+    /// );
+    ///
+    /// let error = mesh.decimate(40, 1.0e-6);
+    /// assert!(mesh.faces().len() <= 40);
+    /// assert!(error < 1.0e-6);
+    /// // the boundary is preserved.
+    /// assert_eq!(mesh.extract_boundaries()[0].len(), 4 * N);
+    /// ```
+    fn decimate(&mut self, target_faces: usize, max_error: f64) -> f64;
+}
+
+/// a candidate of the collapse `from` -> `into`
+#[derive(Clone, Copy, Debug)]
+struct Collapse {
+    cost: f64,
+    into: usize,
+    from: usize,
+    position: Point3,
+    versions: (usize, usize),
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Collapse {
+    // reversed for the min-heap
+    fn cmp(&self, other: &Self) -> Ordering { other.cost.total_cmp(&self.cost) }
+}
+
+#[derive(Clone, Debug)]
+struct Decimator {
+    positions: Vec<Point3>,
+    triangles: Vec<Option<[usize; 3]>>,
+    vertex_faces: Vec<HashSet<usize>>,
+    quadrics: Vec<Matrix4>,
+    fixed: Vec<bool>,
+    versions: Vec<usize>,
+    num_faces: usize,
+}
+
+#[inline(always)]
+fn quadric_error(quadric: &Matrix4, p: Point3) -> f64 {
+    let v = p.to_homogeneous();
+    f64::max(v.dot(quadric * v), 0.0)
+}
+
+#[inline(always)]
+fn face_normal(p: [Point3; 3]) -> Vector3 { (p[1] - p[0]).cross(p[2] - p[0]) }
+
+impl Decimator {
+    fn new(mesh: &PolygonMesh) -> Self {
+        let positions = mesh.positions().to_vec();
+        let triangles = mesh
+            .faces()
+            .triangle_iter()
+            .map(|tri| tri.map(|v| v.pos))
+            .filter(|tri| tri[0] != tri[1] && tri[1] != tri[2] && tri[2] != tri[0])
+            .map(Some)
+            .collect::<Vec<_>>();
+        let mut vertex_faces = vec![HashSet::default(); positions.len()];
+        let mut quadrics = vec![Matrix4::zero(); positions.len()];
+        let mut edge_count = HashMap::<[usize; 2], usize>::default();
+        triangles.iter().enumerate().for_each(|(i, tri)| {
+            let tri = tri.unwrap();
+            let n = face_normal(tri.map(|i| positions[i]));
+            let quadric = match n.so_small() {
+                true => Matrix4::zero(),
+                false => {
+                    let n = n.normalize();
+                    let plane = n.extend(-n.dot(positions[tri[0]].to_vec()));
+                    Matrix4::from_cols(
+                        plane * plane.x,
+                        plane * plane.y,
+                        plane * plane.z,
+                        plane * plane.w,
+                    )
+                }
+            };
+            (0..3).for_each(|k| {
+                let (v, w) = (tri[k], tri[(k + 1) % 3]);
+                vertex_faces[v].insert(i);
+                quadrics[v] += quadric;
+                *edge_count
+                    .entry([usize::min(v, w), usize::max(v, w)])
+                    .or_insert(0) += 1;
+            });
+        });
+        let mut fixed = vec![false; positions.len()];
+        edge_count
+            .into_iter()
+            .filter(|(_, count)| *count != 2)
+            .for_each(|([v, w], _)| {
+                fixed[v] = true;
+                fixed[w] = true;
+            });
+        Self {
+            versions: vec![0; positions.len()],
+            num_faces: triangles.len(),
+            positions,
+            triangles,
+            vertex_faces,
+            quadrics,
+            fixed,
+        }
+    }
+
+    fn neighbors(&self, v: usize) -> HashSet<usize> {
+        self.vertex_faces[v]
+            .iter()
+            .flat_map(|i| self.triangles[*i].unwrap())
+            .filter(|w| *w != v)
+            .collect()
+    }
+
+    /// Returns the collapse of the edge `v`-`w`, or `None` if the edge must be preserved.
+    fn candidate(&self, v: usize, w: usize) -> Option<Collapse> {
+        let (into, from) = match (self.fixed[v], self.fixed[w]) {
+            (true, true) => return None,
+            (false, true) => (w, v),
+            _ => (v, w),
+        };
+        let quadric = self.quadrics[v] + self.quadrics[w];
+        let position = match self.fixed[into] {
+            true => self.positions[into],
+            false => self.optimal_position(&quadric, v, w),
+        };
+        Some(Collapse {
+            cost: quadric_error(&quadric, position),
+            into,
+            from,
+            position,
+            versions: (self.versions[into], self.versions[from]),
+        })
+    }
+
+    fn optimal_position(&self, quadric: &Matrix4, v: usize, w: usize) -> Point3 {
+        let a = Matrix3::from_cols(
+            quadric.x.truncate(),
+            quadric.y.truncate(),
+            quadric.z.truncate(),
+        );
+        let solution = match a.determinant().so_small() {
+            true => None,
+            false => a
+                .invert()
+                .map(|inv| Point3::from_vec(-(inv * quadric.w.truncate()))),
+        };
+        let (p, q) = (self.positions[v], self.positions[w]);
+        solution.unwrap_or_else(|| {
+            [p, q, p.midpoint(q)]
+                .into_iter()
+                .min_by(|p, q| quadric_error(quadric, *p).total_cmp(&quadric_error(quadric, *q)))
+                .unwrap()
+        })
+    }
+
+    /// Checks the link condition and the face flipping.
+    fn is_collapsible(&self, collapse: &Collapse) -> bool {
+        let Collapse {
+            into,
+            from,
+            position,
+            ..
+        } = *collapse;
+        let (into_neighbors, from_neighbors) = (self.neighbors(into), self.neighbors(from));
+        let opposites = self.vertex_faces[from]
+            .iter()
+            .map(|i| self.triangles[*i].unwrap())
+            .filter(|tri| tri.contains(&into))
+            .flat_map(|tri| tri.into_iter().filter(|u| *u != into && *u != from))
+            .collect::<HashSet<_>>();
+        let common = into_neighbors.intersection(&from_neighbors).count();
+        if common != opposites.len() || opposites.len() != 2 {
+            return false;
+        }
+        // the collapsed vertex must have at least three neighbors.
+        if into_neighbors.union(&from_neighbors).count() < 5 {
+            return false;
+        }
+        [into, from].into_iter().all(|v| {
+            self.vertex_faces[v].iter().all(|i| {
+                let tri = self.triangles[*i].unwrap();
+                if tri.contains(&into) && tri.contains(&from) {
+                    return true;
+                }
+                let before = face_normal(tri.map(|u| self.positions[u]));
+                let after = face_normal(tri.map(|u| match u == v {
+                    true => position,
+                    false => self.positions[u],
+                }));
+                !after.so_small() && before.dot(after) > 0.0
+            })
+        })
+    }
+
+    fn collapse(&mut self, collapse: &Collapse) {
+        let Collapse {
+            into,
+            from,
+            position,
+            ..
+        } = *collapse;
+        let from_faces = std::mem::take(&mut self.vertex_faces[from]);
+        from_faces.into_iter().for_each(|i| {
+            let tri = self.triangles[i].as_mut().unwrap();
+            if tri.contains(&into) {
+                let tri = self.triangles[i].take().unwrap();
+                tri.into_iter().filter(|u| *u != from).for_each(|u| {
+                    self.vertex_faces[u].remove(&i);
+                });
+                self.num_faces -= 1;
+            } else {
+                tri.iter_mut()
+                    .filter(|u| **u == from)
+                    .for_each(|u| *u = into);
+                self.vertex_faces[into].insert(i);
+            }
+        });
+        self.positions[into] = position;
+        self.quadrics[into] = self.quadrics[into] + self.quadrics[from];
+        self.versions[into] += 1;
+        self.versions[from] += 1;
+    }
+
+    fn is_valid(&self, collapse: &Collapse) -> bool {
+        collapse.versions == (self.versions[collapse.into], self.versions[collapse.from])
+            && !self.vertex_faces[collapse.from].is_empty()
+    }
+
+    fn push_candidates(&self, heap: &mut BinaryHeap<Collapse>, v: usize) {
+        self.neighbors(v)
+            .into_iter()
+            .filter_map(|w| self.candidate(v, w))
+            .for_each(|collapse| heap.push(collapse));
+    }
+
+    fn into_mesh(self) -> PolygonMesh {
+        let mut indices = vec![usize::MAX; self.positions.len()];
+        let mut positions = Vec::new();
+        let tri_faces = self
+            .triangles
+            .into_iter()
+            .flatten()
+            .map(|tri| {
+                tri.map(|v| {
+                    if indices[v] == usize::MAX {
+                        indices[v] = positions.len();
+                        positions.push(self.positions[v]);
+                    }
+                    Vertex::from(indices[v])
+                })
+            })
+            .collect::<Vec<_>>();
+        PolygonMesh::debug_new(
+            StandardAttributes {
+                positions,
+                ..Default::default()
+            },
+            Faces::from_tri_and_quad_faces(tri_faces, Vec::new()),
+        )
+    }
+}
+
+impl Decimation for PolygonMesh {
+    fn decimate(&mut self, target_faces: usize, max_error: f64) -> f64 {
+        let mut decimator = Decimator::new(self);
+        let mut heap = BinaryHeap::new();
+        decimator.triangles.iter().flatten().for_each(|tri| {
+            (0..3).for_each(|k| {
+                let (v, w) = (tri[k], tri[(k + 1) % 3]);
+                if let Some(collapse) = decimator.candidate(v, w) {
+                    heap.push(collapse);
+                }
+            });
+        });
+        let mut error = 0.0;
+        while decimator.num_faces > target_faces {
+            let Some(collapse) = heap.pop() else { break };
+            if !decimator.is_valid(&collapse) {
+                continue;
+            }
+            if collapse.cost > max_error {
+                break;
+            }
+            if !decimator.is_collapsible(&collapse) {
+                continue;
+            }
+            decimator.collapse(&collapse);
+            error = f64::max(error, collapse.cost);
+            decimator.push_candidates(&mut heap, collapse.into);
+        }
+        *self = decimator.into_mesh();
+        error
+    }
+}
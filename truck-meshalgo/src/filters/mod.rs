@@ -1,12 +1,17 @@
 use crate::*;
 use polygon_mesh::PolygonMeshEditor;
 
+mod decimation;
 mod normal_filters;
 mod optimizing;
 mod structuring;
 mod subdivision;
+mod uv_filters;
 
+pub use decimation::Decimation;
 pub use normal_filters::NormalFilters;
+pub(crate) use optimizing::put_together_points;
 pub use optimizing::OptimizingFilter;
 pub use structuring::StructuringFilter;
 pub use subdivision::Subdivision;
+pub use uv_filters::UVFilters;
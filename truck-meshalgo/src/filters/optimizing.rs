@@ -94,6 +94,47 @@ pub trait OptimizingFilter {
     /// assert_eq!(mesh.positions().len(), 4);
     /// ```
     fn put_together_same_attrs(&mut self, tol: f64) -> &mut Self;
+    /// Merges the positions within the distance `tol`, and removes the orphaned attributes.
+    /// Returns the number of the removed positions.
+    ///
+    /// Different from [`put_together_same_attrs`], `tol` is the absolute distance and
+    /// the unused uv coordinates and normals are also removed.
+    ///
+    /// [`put_together_same_attrs`]: ./trait.OptimizingFilter.html#tymethod.put_together_same_attrs
+    ///
+    /// # Remarks
+    /// Welding may make degenerate faces. One can remove them by [`remove_degenerate_faces`].
+    ///
+    /// [`remove_degenerate_faces`]: ./trait.OptimizingFilter.html#tymethod.remove_degenerate_faces
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a square whose positions are repeated per face
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 1.0e-8),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         &[0, 1, 2],
+    ///         &[3, 4, 5],
+    ///     ]),
+    /// );
+    ///
+    /// assert_eq!(mesh.weld_vertices(1.0e-6), 2);
+    /// assert_eq!(mesh.positions().len(), 4);
+    /// assert_eq!(mesh.faces()[0][0], mesh.faces()[1][0]);
+    /// assert_eq!(mesh.faces()[0][2], mesh.faces()[1][1]);
+    /// ```
+    fn weld_vertices(&mut self, tol: f64) -> usize;
 }
 
 fn all_pos_mut(faces: &mut Faces) -> impl Iterator<Item = &mut usize> {
@@ -191,6 +232,22 @@ impl OptimizingFilter for PolygonMesh {
         drop(mesh);
         self
     }
+
+    fn weld_vertices(&mut self, tol: f64) -> usize {
+        let len = self.positions().len();
+        let mesh = self.debug_editor();
+        let pos_map = sub_put_together_same_attrs(mesh.attributes.positions.as_slice(), tol);
+        all_pos_mut(mesh.faces).for_each(|idx| *idx = pos_map[*idx]);
+        drop(mesh);
+        self.remove_unused_attrs();
+        len - self.positions().len()
+    }
+}
+
+/// Returns the map from the indices of `points` to the ones of the representatives of the points
+/// within the distance `tol`.
+pub(crate) fn put_together_points(points: &[Point3], tol: f64) -> Vec<usize> {
+    sub_put_together_same_attrs(points, tol)
 }
 
 fn sub_remove_unused_attrs<'a, I: Iterator<Item = &'a mut usize>>(
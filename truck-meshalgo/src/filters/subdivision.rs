@@ -12,6 +12,36 @@ pub trait Subdivision {
     /// - `self.shell_condition()` is `Oriented` or `Closed` before use.
     /// This method does NOT check these conditions.
     fn loop_subdivision(&mut self) -> &mut Self;
+    /// Applies [`loop_subdivision`](Subdivision::loop_subdivision) `levels` times,
+    /// and recomputes the smooth normals.
+    ///
+    /// Each level adds a vertex on each edge and splits each triangle into four.
+    ///
+    /// # Remarks
+    /// The same conditions as [`loop_subdivision`](Subdivision::loop_subdivision) are required.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]]),
+    /// );
+    /// mesh.subdivide_loop(2);
+    /// // (V, E, F) = (4, 6, 4) -> (10, 24, 16) -> (34, 96, 64)
+    /// assert_eq!(mesh.positions().len(), 34);
+    /// assert_eq!(mesh.faces().len(), 64);
+    /// assert_eq!(mesh.normals().len(), 34);
+    /// ```
+    fn subdivide_loop(&mut self, levels: usize) -> &mut Self;
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -82,6 +112,16 @@ impl Subdivision for PolygonMesh {
         }
         self
     }
+    fn subdivide_loop(&mut self, levels: usize) -> &mut Self {
+        if levels == 0 {
+            return self;
+        }
+        (0..levels).for_each(|_| {
+            self.loop_subdivision();
+        });
+        // the limit surface is smooth, so all normals around a vertex are averaged.
+        self.add_smooth_normals(PI, true)
+    }
 }
 
 fn add_vertex_edge(
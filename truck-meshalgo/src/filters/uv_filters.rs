@@ -0,0 +1,235 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::f64::consts::PI;
+
+/// Filters for generating the texture coordinates
+///
+/// All filters overwrite the uv coordinates of the mesh and the `uv` indices of all vertices.
+/// The generated coordinates are right-handed: the texture is not mirrored when the faces are
+/// looked at from the front, i.e. from the side to which the face normals point.
+pub trait UVFilters {
+    /// Generates the uv coordinates by projecting the positions onto the plane.
+    /// # Details
+    /// The uv coordinates of the position `p` are `(p - origin).dot(u_axis) / scale` and
+    /// `(p - origin).dot(v_axis) / scale`. The texture is not mirrored when it is looked at from
+    /// the direction of `u_axis.cross(v_axis)`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(3.0, 0.0, 0.0),
+    ///             Point3::new(3.0, 2.0, 1.0),
+    ///             Point3::new(1.0, 2.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[&[0, 1, 2, 3]]),
+    /// );
+    /// mesh.generate_uv_planar(
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Vector3::unit_x(),
+    ///     Vector3::unit_y(),
+    ///     2.0,
+    /// );
+    /// let uv = |i: usize| mesh.uv_coords()[mesh.faces()[0][i].uv.unwrap()];
+    /// assert_near!(uv(0), Vector2::new(0.0, 0.0));
+    /// assert_near!(uv(1), Vector2::new(1.0, 0.0));
+    /// assert_near!(uv(2), Vector2::new(1.0, 1.0));
+    /// assert_near!(uv(3), Vector2::new(0.0, 1.0));
+    /// ```
+    fn generate_uv_planar(
+        &mut self,
+        origin: Point3,
+        u_axis: Vector3,
+        v_axis: Vector3,
+        scale: f64,
+    ) -> &mut Self;
+    /// Generates the uv coordinates by projecting each face onto the face of the axis-aligned box
+    /// which is the most parallel to the face.
+    /// # Details
+    /// The uv axes of the box faces are the followings, divided by `scale`.
+    ///
+    /// | face normal | u axis | v axis |
+    /// |:-----------:|:------:|:------:|
+    /// | `+x`        | `+y`   | `+z`   |
+    /// | `-x`        | `-y`   | `+z`   |
+    /// | `+y`        | `-x`   | `+z`   |
+    /// | `-y`        | `+x`   | `+z`   |
+    /// | `+z`        | `+x`   | `+y`   |
+    /// | `-z`        | `-x`   | `+y`   |
+    ///
+    /// The positions shared by the faces projected onto the different box faces have different
+    /// uv coordinates.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     // the top face and the side face facing +x.
+    ///     Faces::from_iter(&[&[0, 1, 2], &[1, 2, 3]]),
+    /// );
+    /// mesh.generate_uv_box(Point3::origin(), 1.0);
+    /// let uv = |i: usize, j: usize| mesh.uv_coords()[mesh.faces()[i][j].uv.unwrap()];
+    /// assert_near!(uv(1, 0), Vector2::new(0.0, 0.0));
+    /// assert_near!(uv(1, 2), Vector2::new(0.0, 1.0));
+    /// // the position 2 has the different uvs on the two faces.
+    /// assert_near!(uv(0, 2), Vector2::new(1.0, 1.0));
+    /// assert_near!(uv(1, 1), Vector2::new(1.0, 0.0));
+    /// ```
+    fn generate_uv_box(&mut self, origin: Point3, scale: f64) -> &mut Self;
+    /// Generates the uv coordinates by the longitude and the latitude around `center`.
+    /// # Details
+    /// The pole is the `z`-axis and the longitude is measured from the `x`-axis counterclockwise
+    /// viewed from `+z`. The u coordinate is the longitude mapped to `[0, 1]` from `-π` to `π`,
+    /// and the v coordinate is the latitude mapped to `[0, 1]` from the south pole to the north
+    /// pole, so the texture is not mirrored when the mesh is looked at from outside.
+    ///
+    /// The faces across the seam at the longitude `±π` have the u coordinates greater than 1 so
+    /// that the texture is not stretched, and the positions at the poles have the mean of the u
+    /// coordinates of the other vertices in each face.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[&[0, 1, 2]]),
+    /// );
+    /// mesh.generate_uv_spherical(Point3::origin());
+    /// let uv = |i: usize| mesh.uv_coords()[mesh.faces()[0][i].uv.unwrap()];
+    /// assert_near!(uv(0), Vector2::new(0.5, 0.5));
+    /// assert_near!(uv(1), Vector2::new(0.75, 0.5));
+    /// assert_near!(uv(2), Vector2::new(0.625, 1.0));
+    /// ```
+    fn generate_uv_spherical(&mut self, center: Point3) -> &mut Self;
+}
+
+impl UVFilters for PolygonMesh {
+    fn generate_uv_planar(
+        &mut self,
+        origin: Point3,
+        u_axis: Vector3,
+        v_axis: Vector3,
+        scale: f64,
+    ) -> &mut Self {
+        let project = |p: Point3| {
+            let vec = p - origin;
+            Vector2::new(vec.dot(u_axis), vec.dot(v_axis)) / scale
+        };
+        set_uv_by_faces(self, |positions, face| {
+            face.iter().map(|v| project(positions[v.pos])).collect()
+        });
+        self
+    }
+    fn generate_uv_box(&mut self, origin: Point3, scale: f64) -> &mut Self {
+        set_uv_by_faces(self, |positions, face| {
+            let normal = FaceNormal::new(positions, face, 0).normal;
+            let (u_axis, v_axis) = box_axes(normal);
+            face.iter()
+                .map(|v| {
+                    let vec = positions[v.pos] - origin;
+                    Vector2::new(vec.dot(u_axis), vec.dot(v_axis)) / scale
+                })
+                .collect()
+        });
+        self
+    }
+    fn generate_uv_spherical(&mut self, center: Point3) -> &mut Self {
+        set_uv_by_faces(self, |positions, face| {
+            let mut uvs: Vec<Option<f64>> = face
+                .iter()
+                .map(|v| {
+                    let vec = positions[v.pos] - center;
+                    let on_pole = f64::hypot(vec.x, vec.y).so_small();
+                    (!on_pole).then(|| 0.5 + f64::atan2(vec.y, vec.x) / (2.0 * PI))
+                })
+                .collect();
+            // the vertices across the date line are moved to the right side.
+            let (min, max) = uvs.iter().flatten().fold((1.0, 0.0), |(min, max), u| {
+                (f64::min(min, *u), f64::max(max, *u))
+            });
+            if max - min > 0.5 {
+                uvs.iter_mut().flatten().for_each(|u| {
+                    if *u < 0.5 {
+                        *u += 1.0
+                    }
+                });
+            }
+            let (sum, count) = uvs
+                .iter()
+                .flatten()
+                .fold((0.0, 0), |(sum, count), u| (sum + u, count + 1));
+            let mean = if count == 0 { 0.5 } else { sum / count as f64 };
+            face.iter()
+                .zip(uvs)
+                .map(|(v, u)| {
+                    let vec = positions[v.pos] - center;
+                    let lat = f64::atan2(vec.z, f64::hypot(vec.x, vec.y));
+                    Vector2::new(u.unwrap_or(mean), 0.5 + lat / PI)
+                })
+                .collect()
+        });
+        self
+    }
+}
+
+/// Returns the uv axes of the box face whose normal is the nearest to `normal`.
+fn box_axes(normal: Vector3) -> (Vector3, Vector3) {
+    let abs = normal.map(f64::abs);
+    if abs.x >= abs.y && abs.x >= abs.z {
+        (Vector3::unit_y() * normal.x.signum(), Vector3::unit_z())
+    } else if abs.y >= abs.z {
+        (-Vector3::unit_x() * normal.y.signum(), Vector3::unit_z())
+    } else {
+        (Vector3::unit_x() * normal.z.signum(), Vector3::unit_y())
+    }
+}
+
+/// Overwrites the uv coordinates by the ones computed face by face by `face_uv`.
+/// The same uv coordinates at the same position are shared between the faces.
+fn set_uv_by_faces(
+    mesh: &mut PolygonMesh,
+    mut face_uv: impl FnMut(&[Point3], &[Vertex]) -> Vec<Vector2>,
+) {
+    let mut mesh = mesh.debug_editor();
+    let PolygonMeshEditor {
+        attributes:
+            StandardAttributes {
+                positions,
+                uv_coords,
+                ..
+            },
+        faces,
+        ..
+    } = &mut mesh;
+    uv_coords.clear();
+    let mut uv_map = HashMap::<(usize, [u64; 2]), usize>::default();
+    faces.face_iter_mut().for_each(|face| {
+        let uvs = face_uv(positions, face);
+        face.iter_mut().zip(uvs).for_each(|(v, uv)| {
+            let key = (v.pos, [uv.x.to_bits(), uv.y.to_bits()]);
+            let idx = *uv_map.entry(key).or_insert_with(|| {
+                uv_coords.push(uv);
+                uv_coords.len() - 1
+            });
+            v.uv = Some(idx);
+        });
+    });
+}
@@ -1,4 +1,6 @@
 use crate::*;
+use filters::OptimizingFilter;
+use rustc_hash::FxHashMap as HashMap;
 use spade::{iterators::*, *};
 use truck_topology::{compress::*, *};
 
@@ -50,6 +52,41 @@ pub trait MeshedShape {
     fn to_polygon(&self) -> PolygonMesh;
 }
 
+/// Trait for converting tessellated shape into polygon with the ids of faces.
+pub trait MeshedShapeWithFaceIds: MeshedShape {
+    /// Converts tessellated shape into polygon with the ids of faces.
+    ///
+    /// The `i`th element of the returned ids is the index of the face, in the order of
+    /// `face_iter` of the shape, from which the `i`th polygon of `PolygonMesh::face_iter` came.
+    fn to_polygon_with_face_ids(&self) -> (PolygonMesh, Vec<usize>);
+}
+
+/// Merges polygons with the ids of their faces.
+/// The ids of each polygon are ordered by `PolygonMesh::face_iter`.
+fn merge_with_face_ids(
+    polygons: impl IntoIterator<Item = (PolygonMesh, Vec<usize>)>,
+) -> (PolygonMesh, Vec<usize>) {
+    let mut polygon = PolygonMesh::default();
+    let (mut tri_ids, mut quad_ids, mut other_ids) = (Vec::new(), Vec::new(), Vec::new());
+    polygons.into_iter().for_each(|(poly, ids)| {
+        let faces = poly.faces();
+        let (n_tri, n_quad) = (faces.tri_faces().len(), faces.quad_faces().len());
+        tri_ids.extend_from_slice(&ids[..n_tri]);
+        quad_ids.extend_from_slice(&ids[n_tri..n_tri + n_quad]);
+        other_ids.extend_from_slice(&ids[n_tri + n_quad..]);
+        polygon.merge(poly);
+    });
+    tri_ids.extend(quad_ids);
+    tri_ids.extend(other_ids);
+    (polygon, tri_ids)
+}
+
+/// Polygon of a face with its face id.
+fn polygon_with_face_id(poly: PolygonMesh, id: usize) -> (PolygonMesh, Vec<usize>) {
+    let len = poly.faces().len();
+    (poly, vec![id; len])
+}
+
 impl MeshedShape for Shell<Point3, PolylineCurve, PolygonMesh> {
     fn to_polygon(&self) -> PolygonMesh {
         let mut polygon = PolygonMesh::default();
@@ -60,6 +97,16 @@ impl MeshedShape for Shell<Point3, PolylineCurve, PolygonMesh> {
     }
 }
 
+impl MeshedShapeWithFaceIds for Shell<Point3, PolylineCurve, PolygonMesh> {
+    fn to_polygon_with_face_ids(&self) -> (PolygonMesh, Vec<usize>) {
+        merge_with_face_ids(
+            self.face_iter()
+                .enumerate()
+                .map(|(i, face)| polygon_with_face_id(face.oriented_surface(), i)),
+        )
+    }
+}
+
 impl MeshedShape for Shell<Point3, PolylineCurve, Option<PolygonMesh>> {
     fn to_polygon(&self) -> PolygonMesh {
         let mut polygon = PolygonMesh::default();
@@ -75,6 +122,18 @@ impl MeshedShape for Shell<Point3, PolylineCurve, Option<PolygonMesh>> {
     }
 }
 
+impl MeshedShapeWithFaceIds for Shell<Point3, PolylineCurve, Option<PolygonMesh>> {
+    fn to_polygon_with_face_ids(&self) -> (PolygonMesh, Vec<usize>) {
+        merge_with_face_ids(self.face_iter().enumerate().filter_map(|(i, face)| {
+            let mut poly = face.surface()?;
+            if !face.orientation() {
+                poly.invert();
+            }
+            Some(polygon_with_face_id(poly, i))
+        }))
+    }
+}
+
 impl<P, C, S> MeshedShape for Solid<P, C, S>
 where Shell<P, C, S>: MeshedShape
 {
@@ -87,6 +146,20 @@ where Shell<P, C, S>: MeshedShape
     }
 }
 
+impl<P, C, S> MeshedShapeWithFaceIds for Solid<P, C, S>
+where Shell<P, C, S>: MeshedShapeWithFaceIds
+{
+    fn to_polygon_with_face_ids(&self) -> (PolygonMesh, Vec<usize>) {
+        let mut offset = 0;
+        merge_with_face_ids(self.boundaries().iter().map(|shell| {
+            let (poly, ids) = shell.to_polygon_with_face_ids();
+            let ids = ids.into_iter().map(|i| i + offset).collect();
+            offset += shell.len();
+            (poly, ids)
+        }))
+    }
+}
+
 impl MeshedShape for CompressedShell<Point3, PolylineCurve, PolygonMesh> {
     fn to_polygon(&self) -> PolygonMesh {
         let mut polygon = PolygonMesh::default();
@@ -98,6 +171,18 @@ impl MeshedShape for CompressedShell<Point3, PolylineCurve, PolygonMesh> {
     }
 }
 
+impl MeshedShapeWithFaceIds for CompressedShell<Point3, PolylineCurve, PolygonMesh> {
+    fn to_polygon_with_face_ids(&self) -> (PolygonMesh, Vec<usize>) {
+        merge_with_face_ids(self.faces.iter().enumerate().map(|(i, face)| {
+            let poly = match face.orientation {
+                true => face.surface.clone(),
+                false => face.surface.inverse(),
+            };
+            polygon_with_face_id(poly, i)
+        }))
+    }
+}
+
 impl MeshedShape for CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>> {
     fn to_polygon(&self) -> PolygonMesh {
         let mut polygon = PolygonMesh::default();
@@ -113,6 +198,18 @@ impl MeshedShape for CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>>
     }
 }
 
+impl MeshedShapeWithFaceIds for CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>> {
+    fn to_polygon_with_face_ids(&self) -> (PolygonMesh, Vec<usize>) {
+        merge_with_face_ids(self.faces.iter().enumerate().filter_map(|(i, face)| {
+            let poly = match face.orientation {
+                true => face.surface.clone()?,
+                false => face.surface.as_ref()?.inverse(),
+            };
+            Some(polygon_with_face_id(poly, i))
+        }))
+    }
+}
+
 impl<P, C, S> MeshedShape for CompressedSolid<P, C, S>
 where CompressedShell<P, C, S>: MeshedShape
 {
@@ -125,6 +222,20 @@ where CompressedShell<P, C, S>: MeshedShape
     }
 }
 
+impl<P, C, S> MeshedShapeWithFaceIds for CompressedSolid<P, C, S>
+where CompressedShell<P, C, S>: MeshedShapeWithFaceIds
+{
+    fn to_polygon_with_face_ids(&self) -> (PolygonMesh, Vec<usize>) {
+        let mut offset = 0;
+        merge_with_face_ids(self.boundaries.iter().map(|shell| {
+            let (poly, ids) = shell.to_polygon_with_face_ids();
+            let ids = ids.into_iter().map(|i| i + offset).collect();
+            offset += shell.faces.len();
+            (poly, ids)
+        }))
+    }
+}
+
 /// Trait for tessellating `Shell` and `Solid`.
 pub trait MeshableShape {
     /// Shape whose edges are made polylines and faces polygon surface.
@@ -165,6 +276,121 @@ pub trait MeshableShape {
     /// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
     /// ```
     fn triangulation(&self, tol: f64) -> Self::MeshedShape;
+    /// Tessellates shapes and returns the polygon mesh with the ids of faces.
+    ///
+    /// The `i`th element of the returned ids is the index of the B-rep face, in the order of
+    /// `face_iter`, from which the `i`th polygon of `PolygonMesh::face_iter` came.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be more than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::builder;
+    ///
+    /// // modeling a unit cube
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let (mesh, ids) = cube.tessellate_with_face_ids(0.01);
+    /// assert_eq!(mesh.faces().len(), ids.len());
+    ///
+    /// for id in 0..6 {
+    ///     let pts: Vec<Point3> = mesh
+    ///         .face_iter()
+    ///         .zip(&ids)
+    ///         .filter(|(_, i)| **i == id)
+    ///         .flat_map(|(face, _)| face)
+    ///         .map(|v| mesh.positions()[v.pos])
+    ///         .collect();
+    ///     // all polygons with the same id are on one square of the cube.
+    ///     assert!(!pts.is_empty());
+    ///     assert!((0..3).any(|k| pts.iter().all(|p| p[k].near(&pts[0][k]))));
+    /// }
+    /// ```
+    #[inline(always)]
+    fn tessellate_with_face_ids(&self, tol: f64) -> (PolygonMesh, Vec<usize>)
+    where Self::MeshedShape: MeshedShapeWithFaceIds {
+        self.triangulation(tol).to_polygon_with_face_ids()
+    }
+    /// Tessellates shapes and merges the polygons of all faces into one mesh, welding the
+    /// vertices on the boundaries of the faces within `tol`.
+    ///
+    /// Different from `self.triangulation(tol).to_polygon()`, the polygons are connected across
+    /// the boundaries of the faces. Only the positions are welded, so the normals and the uv
+    /// coordinates are kept per face, and the normals at the sharp edges are preserved.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be more than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::builder;
+    /// use truck_topology::shell::ShellCondition;
+    ///
+    /// // modeling a unit cube
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let mesh = cube.to_polygon(0.01);
+    /// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    /// // the eight corners are welded.
+    /// assert_eq!(mesh.positions().len(), 8);
+    /// // the normals are not averaged at the sharp edges.
+    /// let axes = [Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()];
+    /// mesh.face_iter()
+    ///     .flatten()
+    ///     .map(|v| mesh.normals()[v.nor.unwrap()])
+    ///     .for_each(|n| assert!(axes.iter().any(|a| n.near(a) || n.near(&-*a))));
+    /// ```
+    fn to_polygon(&self, tol: f64) -> PolygonMesh {
+        let mut polygon = self.triangulation(tol).to_polygon();
+        weld_boundaries(&mut polygon, tol);
+        polygon
+    }
+}
+
+/// Welds the positions on the boundary of `polygon` within `tol`, and removes the degenerate faces
+/// and the unused attributes.
+fn weld_boundaries(polygon: &mut PolygonMesh, tol: f64) {
+    let mut edge_count = HashMap::<(usize, usize), usize>::default();
+    polygon.face_iter().for_each(|face| {
+        (0..face.len()).for_each(|i| {
+            let (p, q) = (face[i].pos, face[(i + 1) % face.len()].pos);
+            *edge_count
+                .entry((usize::min(p, q), usize::max(p, q)))
+                .or_default() += 1;
+        })
+    });
+    let mut boundary: Vec<usize> = edge_count
+        .into_iter()
+        .filter(|(_, count)| *count == 1)
+        .flat_map(|((p, q), _)| [p, q])
+        .collect();
+    boundary.sort_unstable();
+    boundary.dedup();
+    let points: Vec<Point3> = boundary.iter().map(|i| polygon.positions()[*i]).collect();
+    let mut pos_map: Vec<usize> = (0..polygon.positions().len()).collect();
+    filters::put_together_points(&points, tol)
+        .into_iter()
+        .zip(&boundary)
+        .for_each(|(j, i)| pos_map[*i] = boundary[j]);
+    let editor = polygon.debug_editor();
+    editor
+        .faces
+        .face_iter_mut()
+        .flatten()
+        .for_each(|v| v.pos = pos_map[v.pos]);
+    drop(editor);
+    polygon.remove_degenerate_faces().remove_unused_attrs();
 }
 
 /// Trait for tessellating `Shell` and `Solid` in `truck-modeling`.
@@ -253,6 +479,71 @@ pub trait RobustMeshableShape {
     fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape;
 }
 
+/// Trait for measuring the area of `Face`, `Shell`, and `Solid` by tessellation.
+pub trait MeasurableShape {
+    /// Returns the area of the trimmed surfaces, i.e. the sum of the areas of the triangles
+    /// of the mesh by [`MeshableShape::triangulation`] with `tol`.
+    ///
+    /// # Details
+    /// The vertices of the triangles are on the surfaces and the triangles are within `tol` of
+    /// the surfaces, so the smaller `tol` is, the more accurate the area is. The areas of curved
+    /// surfaces tend to be slightly underestimated since the triangles are inscribed in them.
+    /// The faces which fail to be tessellated are ignored.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be more than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::builder;
+    /// use std::f64::consts::PI;
+    ///
+    /// let cube = builder::cube(
+    ///     Point3::origin(),
+    ///     Vector3::unit_x(),
+    ///     Vector3::unit_y(),
+    ///     Vector3::unit_z() * 2.0,
+    /// );
+    /// assert_near!(cube.area(0.01), 10.0);
+    /// let face = &cube.boundaries()[0][0];
+    /// assert_near!(face.area(0.01), 1.0);
+    ///
+    /// let sphere = builder::sphere(Point3::origin(), 2.0);
+    /// let area = sphere.area(0.001);
+    /// assert!(area < 16.0 * PI && 16.0 * PI - area < 0.05);
+    /// ```
+    fn area(&self, tol: f64) -> f64;
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> MeasurableShape for Face<Point3, C, S> {
+    fn area(&self, tol: f64) -> f64 { Shell::from(vec![self.clone()]).area(tol) }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> MeasurableShape for Shell<Point3, C, S> {
+    fn area(&self, tol: f64) -> f64 { polygon_area(&self.triangulation(tol).to_polygon()) }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> MeasurableShape for Solid<Point3, C, S> {
+    fn area(&self, tol: f64) -> f64 { self.boundaries().iter().map(|shell| shell.area(tol)).sum() }
+}
+
+fn polygon_area(poly: &PolygonMesh) -> f64 {
+    let positions = poly.positions();
+    poly.faces()
+        .triangle_iter()
+        .map(|tri| {
+            let (p, q, r) = (
+                positions[tri[0].pos],
+                positions[tri[1].pos],
+                positions[tri[2].pos],
+            );
+            (q - p).cross(r - p).magnitude() / 2.0
+        })
+        .sum()
+}
+
 impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for Shell<Point3, C, S> {
     type MeshedShape = Shell<Point3, PolylineCurve, Option<PolygonMesh>>;
     fn triangulation(&self, tol: f64) -> Self::MeshedShape {
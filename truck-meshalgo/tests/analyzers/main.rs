@@ -4,6 +4,7 @@ mod collision;
 #[path = "../common/mod.rs"]
 mod common;
 mod point_cloud;
+mod ray_cast;
 mod splitting;
 mod topology;
 mod volume;
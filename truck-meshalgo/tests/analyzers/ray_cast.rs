@@ -0,0 +1,46 @@
+use super::*;
+use rand::prelude::*;
+
+#[test]
+fn ray_to_sphere() {
+    let sphere = common::shapes::sphere(Point3::origin(), 10.0, 40, 40);
+    let bvh = MeshBvh::new(&sphere);
+    // the rays from the outside toward the center
+    (0..10).for_each(|i| {
+        let dir = Vector3::new(1.0, i as f64 / 10.0, 0.3).normalize();
+        let origin = Point3::from_vec(dir * 20.0);
+        let (face, t, point) = sphere.cast_ray(origin, -dir).unwrap();
+        assert!(f64::abs(t - 10.0) < 0.1);
+        assert!(f64::abs(point.to_vec().magnitude() - 10.0) < 0.1);
+        assert!(sphere.faces()[face]
+            .iter()
+            .all(|v| sphere.positions()[v.pos].distance(point) < 2.0));
+        assert_eq!(bvh.cast_ray(origin, -dir), Some((face, t, point)));
+        // the rays from the center hit the sphere at the distance 10.
+        let (_, t, _) = bvh.cast_ray(Point3::origin(), dir * 2.0).unwrap();
+        assert!(f64::abs(t - 5.0) < 0.05);
+        // the rays away from the sphere
+        assert!(sphere.cast_ray(origin, dir).is_none());
+        assert!(bvh.cast_ray(origin, dir).is_none());
+    });
+}
+
+#[test]
+fn bvh_agrees_with_brute_force() {
+    let sphere = common::shapes::sphere(Point3::new(1.0, 2.0, 3.0), 5.0, 30, 20);
+    let bvh = MeshBvh::new(&sphere);
+    let mut rng = StdRng::seed_from_u64(559);
+    (0..300).for_each(|_| {
+        let origin = Point3::new(
+            rng.gen_range(-10.0..10.0),
+            rng.gen_range(-10.0..10.0),
+            rng.gen_range(-10.0..10.0),
+        );
+        let dir = Vector3::new(
+            rng.gen_range(-1.0..1.0),
+            rng.gen_range(-1.0..1.0),
+            rng.gen_range(-1.0..1.0),
+        );
+        assert_eq!(bvh.cast_ray(origin, dir), sphere.cast_ray(origin, dir));
+    });
+}
@@ -1,4 +1,5 @@
 use super::*;
+use itertools::Itertools;
 
 #[test]
 fn extract_boundaries0() {
@@ -69,3 +70,46 @@ fn shell_condition() {
     ]);
     assert_eq!(faces.shell_condition(), ShellCondition::Closed);
 }
+
+#[test]
+fn square_hole() {
+    // a 3 x 3 grid of quadrangles without the center one
+    let faces = (0..3)
+        .flat_map(|i| (0..3).map(move |j| (i, j)))
+        .filter(|(i, j)| (*i, *j) != (1, 1))
+        .map(|(i, j)| {
+            let k = i * 4 + j;
+            [k, k + 4, k + 5, k + 1]
+        })
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter(&faces);
+
+    let edges = faces.boundary_edges();
+    // 12 edges in the outer boundary and 4 edges around the hole
+    assert_eq!(edges.len(), 16);
+    assert!(edges.contains(&[5, 6]));
+    assert!(!edges.contains(&[1, 5]));
+
+    let mut loops = faces.boundary_loops();
+    assert_eq!(loops.len(), 2);
+    loops.sort_by_key(Vec::len);
+    let hole = &loops[0];
+    assert_eq!(hole.len(), 4);
+    let start = hole.iter().position(|v| *v == 5).unwrap();
+    let hole: Vec<usize> = (0..4).map(|i| hole[(start + i) % 4]).collect();
+    // oriented along the adjacent faces
+    assert_eq!(hole, vec![5, 6, 10, 9]);
+    assert_eq!(loops[1].len(), 12);
+    loops[1]
+        .iter()
+        .circular_tuple_windows()
+        .for_each(|(v, w)| assert!(edges.contains(&[*v, *w])));
+}
+
+#[test]
+fn boundary_loops_inconsistent_winding() {
+    // the shared edge has the same direction in both faces.
+    let faces = Faces::from_iter(&[[0, 1, 2], [1, 2, 3]]);
+    let loops = faces.boundary_loops();
+    assert_eq!(loops, vec![vec![0, 1], vec![2, 0], vec![2, 3, 1]]);
+}
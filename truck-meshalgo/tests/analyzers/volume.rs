@@ -43,4 +43,173 @@ proptest! {
         assert_near!(msolid.volume(), volume);
         assert_near!(msolid.center_of_gravity().to_point(), trans.transform_point(grav));
     }
+
+    #[test]
+    fn triangle_prism_mass_properties(
+        p in array::uniform3(array::uniform2(-100.0f64..100.0f64)),
+        h in 0.1f64..100.0f64,
+        dir_array in array::uniform2(0.0f64..1.0f64),
+        angle in 0.0..PI * 2.0,
+        vec in array::uniform3(-100.0f64..100.0f64),
+    ) {
+        let p = array![i => Point3::new(p[i][0], p[i][1], -h / 2.0); 3];
+        let (a, b) = (p[1] - p[0], p[2] - p[0]);
+        let volume = (a.x * b.y - a.y * b.x) * h * 0.5;
+        let mut grav = p[0] + a / 3.0 + b / 3.0;
+        grav.z = 0.0;
+
+        if volume.is_zero() {
+            return Ok(());
+        }
+
+        let v = Vertex::news(p);
+        let edge = array![i => builder::line(&v[i], &v[(i + 1) % 3]); 3];
+        let face = builder::try_attach_plane(&[edge.to_vec().into()]).unwrap();
+        let base_solid = builder::tsweep(&face, h * Vector3::unit_z());
+        let axis = dir_from_array(dir_array);
+        let trans = Matrix4::from_translation(vec.into()) * Matrix4::from_axis_angle(axis, Rad(angle));
+        let solid = builder::transformed(&base_solid, trans);
+        let msolid = solid.triangulation(0.05).collect_option().unwrap();
+
+        let props = CalcVolume::mass_properties(&msolid, 2.0).unwrap();
+        assert_near!(props.volume, volume);
+        assert_near!(props.mass, 2.0 * volume);
+        assert_near!(props.centroid, trans.transform_point(grav));
+        // the inertia tensor rotates with the solid
+        // the mesh mapped back, so that the comparison does not depend on the tessellation
+        let inv = trans.invert().unwrap();
+        let base_mesh = msolid.mapped(
+            |p| inv.transform_point(*p),
+            |c| c.transformed(inv),
+            |s| {
+                let mut s = s.clone();
+                s.positions_mut().iter_mut().for_each(|p| *p = inv.transform_point(*p));
+                s
+            },
+        );
+        let base_props = CalcVolume::mass_properties(&base_mesh, 2.0).unwrap();
+        let rot = Matrix3::from_axis_angle(axis, Rad(angle));
+        let rotated = rot * base_props.inertia * rot.transpose();
+        assert!((0..3).all(|i| (0..3).all(|j| {
+            f64::abs(props.inertia[i][j] - rotated[i][j]) < 1.0e-6 * f64::max(1.0, base_props.inertia[i][i].abs())
+        })));
+    }
+}
+
+fn unit_cube() -> PolygonMesh {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    ];
+    let faces = Faces::from_iter(&[
+        [0, 3, 2, 1],
+        [0, 1, 5, 4],
+        [1, 2, 6, 5],
+        [2, 3, 7, 6],
+        [3, 0, 4, 7],
+        [4, 5, 6, 7],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+#[test]
+fn watertight_cube() {
+    let mut cube = unit_cube();
+    assert!(cube.is_watertight(TOLERANCE));
+    assert_near!(cube.volume(), 1.0);
+
+    // the inverted winding gives the negative volume.
+    cube.invert();
+    assert!(cube.is_watertight(TOLERANCE));
+    assert_near!(cube.volume(), -1.0);
+
+    // positions repeated per face are identified by the tolerance.
+    let positions: Vec<Point3> = cube
+        .face_iter()
+        .flatten()
+        .map(|v| cube.positions()[v.pos] + Vector3::new(1.0e-9, 0.0, 0.0))
+        .collect();
+    let faces = Faces::from_iter((0..6).map(|i| [4 * i, 4 * i + 1, 4 * i + 2, 4 * i + 3]));
+    let separated = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    assert!(separated.is_watertight(1.0e-6));
+    assert!(!separated.is_watertight(0.0));
+    assert_near!(separated.volume(), -1.0);
+}
+
+#[test]
+fn cube_missing_face() {
+    let cube = unit_cube();
+    let faces = Faces::from_iter(cube.face_iter().take(5));
+    let open_cube = PolygonMesh::new(cube.attributes().clone(), faces);
+    assert!(!open_cube.is_watertight(TOLERANCE));
+    assert_eq!(open_cube.extract_boundaries().len(), 1);
+}
+
+#[test]
+fn mass_properties_of_solid() {
+    let cube = builder::cube(
+        Point3::new(1.0, 2.0, 3.0),
+        Vector3::unit_x() * 2.0,
+        Vector3::unit_y() * 2.0,
+        Vector3::unit_z() * 2.0,
+    );
+    let props = cube.mass_properties(3.0, 0.01).unwrap();
+    assert_near!(props.volume, 8.0);
+    assert_near!(props.mass, 24.0);
+    assert_near!(props.centroid, Point3::new(2.0, 3.0, 4.0));
+    // the inertia tensor of the cube with side `a` is (m a^2 / 6) I.
+    assert_near!(props.inertia, Matrix3::from_value(16.0));
+
+    // the cube with a cavity
+    let cavity = builder::cube(
+        Point3::new(1.5, 2.5, 3.5),
+        Vector3::unit_x(),
+        Vector3::unit_y(),
+        Vector3::unit_z(),
+    );
+    let mut boundaries = cube.into_boundaries();
+    let mut inner = cavity.into_boundaries().pop().unwrap();
+    inner.face_iter_mut().for_each(|face| {
+        face.invert();
+    });
+    boundaries.push(inner);
+    let hollow = Solid::new(boundaries);
+    let props = hollow.mass_properties(1.0, 0.01).unwrap();
+    assert_near!(props.volume, 7.0);
+    assert_near!(props.centroid, Point3::new(2.0, 3.0, 4.0));
+}
+
+#[test]
+fn mass_properties_of_flat_mesh() {
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter([[0, 1, 2], [0, 2, 1]]),
+    );
+    assert_eq!(mesh.volume(), 0.0);
+    assert!(CalcVolume::mass_properties(&mesh, 1.0).is_none());
 }
@@ -0,0 +1,120 @@
+use truck_meshalgo::analyzers::*;
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+
+/// the octahedron subdivided and projected onto the unit sphere
+fn sphere(level: usize) -> PolygonMesh {
+    let positions = vec![
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, 0.0, -1.0),
+    ];
+    let faces = Faces::from_iter(&[
+        [0, 1, 4],
+        [1, 2, 4],
+        [2, 3, 4],
+        [3, 0, 4],
+        [1, 0, 5],
+        [2, 1, 5],
+        [3, 2, 5],
+        [0, 3, 5],
+    ]);
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    (0..level).for_each(|_| {
+        mesh.loop_subdivision();
+    });
+    mesh.positions_mut()
+        .iter_mut()
+        .for_each(|p| *p = Point3::from_vec(p.to_vec().normalize()));
+    mesh
+}
+
+fn assert_closed_manifold(mesh: &PolygonMesh) {
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    // Euler's formula of the sphere: V - E + F = 2 with 2E = 3F
+    assert_eq!(2 * mesh.positions().len(), mesh.faces().len() + 4);
+    mesh.faces().triangle_iter().for_each(|tri| {
+        let [p, q, r] = tri.map(|v| mesh.positions()[v.pos]);
+        assert!(!(q - p).cross(r - p).so_small());
+    });
+}
+
+#[test]
+fn decimate_to_target_faces() {
+    let mut mesh = sphere(3);
+    assert_eq!(mesh.faces().len(), 512);
+    let error = mesh.decimate(100, f64::INFINITY);
+    assert!(mesh.faces().len() <= 100);
+    assert!(error > 0.0);
+    assert_closed_manifold(&mesh);
+    // the vertices stay near the sphere.
+    mesh.positions()
+        .iter()
+        .for_each(|p| assert!(f64::abs(p.to_vec().magnitude() - 1.0) < 0.1));
+}
+
+#[test]
+fn decimate_to_max_error() {
+    let mut mesh = sphere(3);
+    let max_error = 1.0e-3;
+    let error = mesh.decimate(0, max_error);
+    assert!(error <= max_error);
+    assert!(mesh.faces().len() < 512);
+    assert_closed_manifold(&mesh);
+
+    // nothing is collapsed with the zero error.
+    let mut mesh = sphere(3);
+    assert_eq!(mesh.decimate(0, 0.0), 0.0);
+    assert_eq!(mesh.faces().len(), 512);
+}
+
+#[test]
+fn preserve_boundary() {
+    // a bumpy grid
+    const N: usize = 16;
+    let positions = (0..=N)
+        .flat_map(|i| (0..=N).map(move |j| (i as f64 / N as f64, j as f64 / N as f64)))
+        .map(|(x, y)| Point3::new(x, y, 0.1 * f64::sin(4.0 * x) * f64::cos(3.0 * y)))
+        .collect::<Vec<_>>();
+    let faces = (0..N)
+        .flat_map(|i| (0..N).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let k = i * (N + 1) + j;
+            [k, k + N + 1, k + N + 2, k + 1]
+        })
+        .collect::<Vec<_>>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    );
+    let boundary = |mesh: &PolygonMesh| {
+        let boundaries = mesh.extract_boundaries();
+        assert_eq!(boundaries.len(), 1);
+        let mut points = boundaries[0]
+            .iter()
+            .map(|i| mesh.positions()[*i].into())
+            .collect::<Vec<[f64; 3]>>();
+        points.sort_by(|p, q| p.partial_cmp(q).unwrap());
+        points
+    };
+    let original = boundary(&mesh);
+
+    mesh.decimate(0, f64::INFINITY);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    assert_eq!(boundary(&mesh), original);
+    // the polygon with 4N vertices is divided into 4N - 2 triangles at least.
+    assert!(mesh.faces().len() < 2 * N * N);
+    assert!(mesh.faces().len() >= 4 * N - 2);
+}
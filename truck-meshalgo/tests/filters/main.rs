@@ -1,4 +1,8 @@
+#[path = "../common/mod.rs"]
+mod common;
+mod decimation;
 mod normal_filter;
 mod optimizing;
 mod structuring;
 mod subdivision;
+mod uv_filter;
@@ -1,7 +1,6 @@
+use super::common;
 use truck_meshalgo::filters::*;
 use truck_polymesh::*;
-#[path = "../common/mod.rs"]
-mod common;
 
 #[test]
 fn normalize_normals_test() {
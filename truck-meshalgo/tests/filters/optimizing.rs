@@ -190,3 +190,46 @@ fn put_together_same_attrs_test() {
     assert_eq!(mesh.uv_coords().len(), 18);
     assert_eq!(mesh.normals().len(), 17);
 }
+
+#[test]
+fn weld_vertices_test() {
+    // a grid whose positions are repeated per face with small noises
+    const N: usize = 10;
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut faces = Faces::default();
+    (0..N).for_each(|i| {
+        (0..N).for_each(|j| {
+            let len = positions.len();
+            let noise = (len % 7) as f64 * 1.0e-8;
+            positions.extend([
+                Point3::new(i as f64, j as f64, noise),
+                Point3::new(i as f64 + 1.0, j as f64, -noise),
+                Point3::new(i as f64 + 1.0, j as f64 + 1.0, noise),
+                Point3::new(i as f64, j as f64 + 1.0, -noise),
+            ]);
+            normals.push(Vector3::unit_z());
+            let nor = Some(normals.len() - 1);
+            faces.push([
+                (len, None, nor),
+                (len + 1, None, nor),
+                (len + 2, None, nor),
+                (len + 3, None, nor),
+            ]);
+        });
+    });
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords: Vec::new(),
+            normals,
+        },
+        faces,
+    );
+    assert_eq!(mesh.weld_vertices(1.0e-6), 4 * N * N - (N + 1) * (N + 1));
+    assert_eq!(mesh.positions().len(), (N + 1) * (N + 1));
+    assert_eq!(mesh.normals().len(), N * N);
+    assert_eq!(mesh.faces().len(), N * N);
+    // the positions far from each other are not welded.
+    assert_eq!(mesh.weld_vertices(0.5), 0);
+}
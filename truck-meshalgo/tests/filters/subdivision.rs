@@ -38,3 +38,42 @@ fn loop_subdivision() {
         ],
     );
 }
+
+#[test]
+fn subdivide_loop_counts() {
+    // an open mesh: a square divided into two triangles
+    let mut square = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2], [0, 2, 3]]),
+    );
+    // (V, E, F) -> (V + E, 2E + 3F, 4F)
+    let mut counts = (4, 5, 2);
+    (0..3).for_each(|_| {
+        square.subdivide_loop(1);
+        let (v, e, f) = counts;
+        counts = (v + e, 2 * e + 3 * f, 4 * f);
+        assert_eq!(square.positions().len(), counts.0);
+        assert_eq!(square.faces().len(), counts.2);
+    });
+    // the corners with two edges are fixed, and all vertices stay on the plane.
+    assert!(square.positions().iter().all(|p| p.z.so_small()));
+    assert_eq!(square.positions()[1], Point3::new(1.0, 0.0, 0.0));
+    assert_eq!(square.positions()[3], Point3::new(0.0, 1.0, 0.0));
+    square
+        .face_iter()
+        .flatten()
+        .for_each(|v| assert_near!(square.normals()[v.nor.unwrap()], Vector3::unit_z()));
+
+    // zero level does nothing.
+    let len = square.positions().len();
+    square.subdivide_loop(0);
+    assert_eq!(square.positions().len(), len);
+}
@@ -0,0 +1,87 @@
+use super::common;
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+
+/// Returns the twice of the signed area of the face in the uv space and the area vector.
+fn signed_areas(mesh: &PolygonMesh, face: &[StandardVertex]) -> (f64, Vector3) {
+    let uvs: Vec<Vector2> = face
+        .iter()
+        .map(|v| mesh.uv_coords()[v.uv.unwrap()])
+        .collect();
+    let uv_area = (0..uvs.len()).fold(0.0, |sum, i| {
+        let (p, q) = (uvs[i], uvs[(i + 1) % uvs.len()]);
+        sum + (p.x * q.y - p.y * q.x)
+    });
+    let pts: Vec<Point3> = face.iter().map(|v| mesh.positions()[v.pos]).collect();
+    let area = (0..pts.len()).fold(Vector3::zero(), |sum, i| {
+        sum + pts[i].to_vec().cross(pts[(i + 1) % pts.len()].to_vec())
+    });
+    (uv_area, area)
+}
+
+#[test]
+fn planar_uv() {
+    let mut mesh = common::shapes::sphere(Point3::new(1.0, 2.0, 3.0), 2.0, 16, 9);
+    let (origin, u_axis, v_axis) = (
+        Point3::new(1.0, 0.0, 0.0),
+        Vector3::unit_y(),
+        Vector3::unit_z(),
+    );
+    mesh.generate_uv_planar(origin, u_axis, v_axis, 0.5);
+    assert_eq!(mesh.uv_coords().len(), mesh.positions().len());
+    mesh.face_iter().flatten().for_each(|v| {
+        let p = mesh.positions()[v.pos] - origin;
+        let uv = mesh.uv_coords()[v.uv.unwrap()];
+        assert_near!(uv, Vector2::new(p.dot(u_axis), p.dot(v_axis)) * 2.0);
+    });
+    // not mirrored when looked at from the direction of `u_axis.cross(v_axis)`.
+    mesh.face_iter().for_each(|face| {
+        let (uv_area, area) = signed_areas(&mesh, face);
+        assert!(uv_area * area.dot(u_axis.cross(v_axis)) >= -TOLERANCE);
+    });
+}
+
+#[test]
+fn box_uv() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 16, 9);
+    mesh.generate_uv_box(Point3::new(-1.0, -1.0, -1.0), 2.0);
+    mesh.face_iter().for_each(|face| {
+        let (uv_area, area) = signed_areas(&mesh, face);
+        // each face is projected onto the most parallel face of the box.
+        let projected = f64::max(f64::max(area.x.abs(), area.y.abs()), area.z.abs());
+        assert!(uv_area.abs().near(&(projected / 4.0)));
+        // the texture is not mirrored when looked at from outside.
+        let center = face.iter().fold(Vector3::zero(), |sum, v| {
+            sum + mesh.positions()[v.pos].to_vec()
+        });
+        assert!(uv_area * area.dot(center) >= -TOLERANCE);
+    });
+}
+
+#[test]
+fn spherical_uv() {
+    let center = Point3::new(1.0, 2.0, 3.0);
+    let mut mesh = common::shapes::sphere(center, 2.0, 16, 9);
+    mesh.generate_uv_spherical(center);
+    mesh.face_iter().for_each(|face| {
+        let uvs: Vec<Vector2> = face
+            .iter()
+            .map(|v| mesh.uv_coords()[v.uv.unwrap()])
+            .collect();
+        // no faces are stretched across the seam.
+        let (min, max) = uvs
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), uv| {
+                (f64::min(min, uv.x), f64::max(max, uv.x))
+            });
+        assert!(max - min < 0.1);
+        assert!(uvs.iter().all(|uv| (0.0..=1.0).contains(&uv.y)));
+        // not mirrored when looked at from outside.
+        let dir = face.iter().fold(Vector3::zero(), |sum, v| {
+            sum + (mesh.positions()[v.pos] - center)
+        });
+        let (uv_area, area) = signed_areas(&mesh, face);
+        assert!(uv_area * area.dot(dir) >= -TOLERANCE);
+        assert!(!uv_area.so_small());
+    });
+}
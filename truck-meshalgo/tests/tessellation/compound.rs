@@ -0,0 +1,42 @@
+use super::*;
+
+fn assert_box(bdd_box: &BoundingBox<Point3>, min: Point3, max: Point3) {
+    // the tessellation is within the tolerance 0.01 from the surfaces.
+    assert!(bdd_box.min().distance(min) < 0.01, "{bdd_box:?}");
+    assert!(bdd_box.max().distance(max) < 0.01, "{bdd_box:?}");
+}
+
+#[test]
+fn compound_tessellation() {
+    let cylinder = builder::cylinder(Point3::origin(), 1.0, 2.0, Vector3::unit_z());
+    let mut compound = Compound::new();
+    compound.push_named("base", Matrix4::identity(), cylinder.clone());
+    let matrix = Matrix4::from_translation(Vector3::new(5.0, 0.0, 0.0))
+        * Matrix4::from_angle_y(Rad(std::f64::consts::PI / 2.0));
+    compound.push_named("arm", matrix, cylinder);
+    let compound = compound.moved(Matrix4::from_translation(Vector3::new(0.0, 0.0, 1.0)));
+
+    // the meshes are kept in the local coordinates of the parts.
+    let meshes = compound.map_parts(|solid| solid.triangulation(0.01).to_polygon());
+    assert_eq!(meshes.len(), 2);
+    assert_eq!(meshes.name(1), Some("arm"));
+    meshes.iter().for_each(|(_, mesh)| {
+        let (min, max) = (Point3::new(-1.0, -1.0, 0.0), Point3::new(1.0, 1.0, 2.0));
+        assert_box(&mesh.bounding_box(), min, max);
+    });
+
+    let bdd_box = meshes.bounding_box();
+    assert_box(
+        &bdd_box,
+        Point3::new(-1.0, -1.0, 0.0),
+        Point3::new(7.0, 1.0, 3.0),
+    );
+
+    // flattening the solids and tessellating them gives the same bounding box.
+    let global: BoundingBox<Point3> = compound
+        .flatten()
+        .iter()
+        .flat_map(|solid| solid.triangulation(0.01).to_polygon().positions().to_vec())
+        .collect();
+    assert_box(&global, bdd_box.min(), bdd_box.max());
+}
@@ -2,4 +2,6 @@ use truck_meshalgo::prelude::*;
 use truck_modeling::*;
 use truck_topology::shell::ShellCondition;
 
+mod compound;
+mod measure;
 mod triangulation;
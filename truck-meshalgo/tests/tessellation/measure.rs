@@ -0,0 +1,51 @@
+use super::*;
+use std::f64::consts::PI;
+
+#[test]
+fn cylinder_measures() {
+    let (radius, height) = (1.5, 2.0);
+    let cylinder = builder::cylinder(Point3::origin(), radius, height, Vector3::unit_z());
+    let shell = &cylinder.boundaries()[0];
+    let area = 2.0 * PI * radius * (radius + height);
+    let res = cylinder.area(0.001);
+    assert!(res < area && area - res < area * 1.0e-3, "{res} {area}");
+
+    let (disks, sides): (Vec<&Face>, Vec<&Face>) = shell
+        .face_iter()
+        .partition(|face| matches!(face.surface(), Surface::Plane(_)));
+    disks.iter().for_each(|disk| {
+        let area = PI * radius * radius;
+        let res = disk.area(0.001);
+        assert!((res - area).abs() < area * 1.0e-3, "{res} {area}");
+        let perimeter = 2.0 * PI * radius;
+        assert!((disk.perimeter(1.0e-6) - perimeter).abs() < 1.0e-5);
+        assert!((disk.boundaries()[0].length(1.0e-6) - perimeter).abs() < 1.0e-5);
+    });
+    let side_area: f64 = sides.iter().map(|face| face.area(0.001)).sum();
+    let area = 2.0 * PI * radius * height;
+    assert!((side_area - area).abs() < area * 1.0e-3);
+}
+
+#[test]
+fn cube_measures() {
+    let cube = builder::cube(
+        Point3::new(1.0, 2.0, 3.0),
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(0.0, 3.0, 0.0),
+        Vector3::new(0.0, 0.0, 4.0),
+    );
+    assert_near!(cube.area(0.01), 52.0);
+    let mut lengths: Vec<f64> = cube.edge_iter().map(|edge| edge.length(1.0e-6)).collect();
+    lengths.sort_by(f64::total_cmp);
+    lengths.dedup_by(|a, b| f64::near(a, b));
+    assert_near!(lengths[0], 2.0);
+    assert_near!(lengths[1], 3.0);
+    assert_near!(lengths[2], 4.0);
+    let mut perimeters: Vec<f64> = cube
+        .face_iter()
+        .map(|face| face.perimeter(1.0e-6))
+        .collect();
+    perimeters.sort_by(f64::total_cmp);
+    assert_near!(perimeters[0], 10.0);
+    assert_near!(perimeters[5], 14.0);
+}
@@ -49,6 +49,69 @@ fn csolid_is_closed() {
     }
 }
 
+#[test]
+fn tessellate_with_face_ids() {
+    for (i, json) in read_jsons().into_iter().enumerate() {
+        let solid: Solid = serde_json::from_reader(json.as_slice()).unwrap();
+        let (poly, ids) = solid.tessellate_with_face_ids(0.01);
+        assert_eq!(poly.faces().len(), ids.len(), "file no. {i}");
+        assert_eq!(
+            poly.faces().len(),
+            solid.triangulation(0.01).to_polygon().faces().len()
+        );
+        let n_faces = solid
+            .boundaries()
+            .iter()
+            .map(|shell| shell.len())
+            .sum::<usize>();
+        assert!(ids.iter().all(|id| *id < n_faces), "file no. {i}");
+
+        let csolid: CompressedSolid = serde_json::from_reader(json.as_slice()).unwrap();
+        let (_, cids) = csolid.tessellate_with_face_ids(0.01);
+        assert_eq!(ids, cids, "file no. {i}");
+    }
+}
+
+#[test]
+fn welded_polygon() {
+    // a cylinder with the sharp edges along the rims
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let disk = builder::try_attach_plane(&[circle]).unwrap();
+    let cylinder: Solid = builder::tsweep(&disk, Vector3::unit_z() * 2.0);
+    // a torus, smooth everywhere
+    let v = builder::vertex(Point3::new(3.0, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::new(2.0, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+    let torus = builder::rsweep(&circle, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let torus = Solid::new(vec![torus]);
+
+    for solid in [&cylinder, &torus] {
+        let concatenated = solid.triangulation(0.01).to_polygon();
+        assert_ne!(concatenated.shell_condition(), ShellCondition::Closed);
+        let poly = solid.to_polygon(0.01);
+        assert_eq!(poly.shell_condition(), ShellCondition::Closed);
+        assert!(poly.positions().len() < concatenated.positions().len());
+        assert_eq!(poly.faces().len(), concatenated.faces().len());
+        assert!(poly.face_iter().flatten().all(|v| v.nor.is_some()));
+    }
+
+    // the rims have both the normals of the caps and the ones of the side.
+    let poly = cylinder.to_polygon(0.01);
+    let rim = poly
+        .positions()
+        .iter()
+        .position(|p| p.near(&Point3::new(1.0, 0.0, 2.0)))
+        .unwrap();
+    let normals: Vec<Vector3> = poly
+        .face_iter()
+        .flatten()
+        .filter(|v| v.pos == rim)
+        .map(|v| poly.normals()[v.nor.unwrap()])
+        .collect();
+    assert!(normals.iter().any(|n| n.near(&Vector3::unit_z())));
+    assert!(normals.iter().any(|n| n.near(&Vector3::unit_x())));
+}
+
 #[test]
 fn compare_occt_mesh() {
     let jsons = read_jsons();
@@ -0,0 +1,389 @@
+use crate::*;
+use errors::Error;
+
+/// The cross section of the blend.
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Profile {
+    /// the circle arc by the rolling ball with the radius
+    Round(f64),
+    /// the line segment between the points at the distance from the edge
+    Bevel(f64),
+}
+
+/// The end of the blended edge: the edges trimmed there, the new vertices on the two adjacent
+/// faces, and the edge between them.
+#[derive(Clone, Debug)]
+struct BlendEnd {
+    trimmed: [(Edge, Edge); 2],
+    contacts: [Vertex; 2],
+    profile: Edge,
+}
+
+fn unsupported<T>(reason: &'static str) -> Result<T> { Err(Error::UnsupportedBlend(reason)) }
+
+fn line_of(edge: &Edge) -> Result<Line<Point3>> {
+    match edge.curve() {
+        Curve::Line(line) => Ok(line),
+        _ => unsupported("the edges around the blend must be lines."),
+    }
+}
+
+fn plane_normal(face: &Face) -> Result<Vector3> {
+    match face.oriented_surface() {
+        Surface::Plane(plane) => Ok(plane.normal()),
+        _ => unsupported("the faces around the blend must be planes."),
+    }
+}
+
+/// Returns the edge on `face` incident to `vertex` other than `edge`.
+fn adjacent_edge(face: &Face, edge: &Edge, vertex: &Vertex) -> Option<Edge> {
+    face.edge_iter()
+        .find(|e| !e.is_same(edge) && (e.absolute_front() == vertex || e.absolute_back() == vertex))
+}
+
+/// Returns the edge whose end `vertex` is replaced by `new_vertex`.
+fn trimmed_edge(edge: &Edge, vertex: &Vertex, new_vertex: &Vertex) -> Edge {
+    let (front, back) = edge.absolute_ends();
+    let (front, back) = match front == vertex {
+        true => (new_vertex, back),
+        false => (front, new_vertex),
+    };
+    Edge::new(front, back, Line(front.point(), back.point()).into())
+}
+
+#[inline(always)]
+fn oriented(edge: &Edge, orientation: bool) -> Edge {
+    match orientation {
+        true => edge.clone(),
+        false => edge.inverse(),
+    }
+}
+
+pub(super) fn blend_edge(solid: &Solid, edge: &Edge, profile: Profile) -> Result<Solid> {
+    let (Profile::Round(size) | Profile::Bevel(size)) = profile;
+    if size < TOLERANCE {
+        return unsupported("the radius or the distance must be positive.");
+    }
+    let mut shells = solid.boundaries().clone();
+    let shell = shells
+        .iter_mut()
+        .find(|shell| shell.edge_iter().any(|e| e.is_same(edge)))
+        .ok_or(Error::EdgeNotFound)?;
+    let adjacents: Vec<(usize, bool)> = shell
+        .face_iter()
+        .enumerate()
+        .flat_map(|(i, face)| {
+            face.edge_iter()
+                .filter(|e| e.is_same(edge))
+                .map(move |e| (i, e.orientation()))
+        })
+        .collect();
+    let [(idx0, ori0), (idx1, ori1)] = adjacents[..] else {
+        return unsupported("the edge must be shared by exactly two faces.");
+    };
+    if idx0 == idx1 {
+        return unsupported("the edge must be shared by exactly two faces.");
+    }
+    line_of(edge)?;
+    let faces = [&shell[idx0], &shell[idx1]];
+    let normals = [plane_normal(faces[0])?, plane_normal(faces[1])?];
+    let (p0, p1) = (edge.absolute_front().point(), edge.absolute_back().point());
+    let dir = (p1 - p0).normalize();
+
+    // The interior of a face is on the left of its boundary.
+    let inwards =
+        [(0, ori0), (1, ori1)].map(|(i, ori)| normals[i].cross(dir * if ori { 1.0 } else { -1.0 }));
+    let cos = normals[0].dot(normals[1]);
+    if inwards[1].dot(normals[0]) > -TOLERANCE || f64::abs(cos + 1.0) < TOLERANCE {
+        return unsupported("the edge must be convex.");
+    }
+    // the center of the rolling ball from the edge
+    let center = -(normals[0] + normals[1]) * size / (1.0 + cos);
+    let axis = normals[0].cross(normals[1]).normalize();
+    let angle = normals[0].angle(normals[1]);
+    // the contact points on the adjacent faces from the edge
+    let contacts = match profile {
+        Profile::Round(radius) => [center + normals[0] * radius, center + normals[1] * radius],
+        Profile::Bevel(distance) => [inwards[0] * distance, inwards[1] * distance],
+    };
+
+    let ends = [edge.absolute_front(), edge.absolute_back()]
+        .into_iter()
+        .map(|vertex| {
+            let trimmed = [0, 1].map(|i| {
+                let old = adjacent_edge(faces[i], edge, vertex)
+                    .ok_or(Error::UnsupportedBlend("the faces must be closed."))?;
+                let line = line_of(&old)?;
+                let vec = line.1 - line.0;
+                if !vec.normalize().dot(dir).so_small() {
+                    return unsupported("the edges at the ends must be perpendicular to the edge.");
+                }
+                if vec.magnitude() < contacts[i].magnitude() + TOLERANCE {
+                    return unsupported(
+                        "the radius or the distance is too large for the adjacent faces.",
+                    );
+                }
+                Ok(old)
+            });
+            let [old0, old1] = trimmed;
+            let (old0, old1) = (old0?, old1?);
+            let degree = shell
+                .edge_iter()
+                .filter(|e| e.absolute_front() == vertex || e.absolute_back() == vertex)
+                .map(|e| e.id())
+                .collect::<std::collections::HashSet<_>>()
+                .len();
+            if degree != 3 {
+                return unsupported("the ends of the edge must be shared by exactly three edges.");
+            }
+            let pt = vertex.point();
+            let contact_vertices = contacts.map(|vec| Vertex::new(pt + vec));
+            let (c0, c1) = (contact_vertices[0].point(), contact_vertices[1].point());
+            let curve = match profile {
+                Profile::Round(_) => geom_impls::circle_arc(c0, pt + center, axis, angle).into(),
+                Profile::Bevel(_) => Line(c0, c1).into(),
+            };
+            let profile = Edge::new(&contact_vertices[0], &contact_vertices[1], curve);
+            Ok(BlendEnd {
+                trimmed: [
+                    (
+                        old0.clone(),
+                        trimmed_edge(&old0, vertex, &contact_vertices[0]),
+                    ),
+                    (
+                        old1.clone(),
+                        trimmed_edge(&old1, vertex, &contact_vertices[1]),
+                    ),
+                ],
+                contacts: contact_vertices,
+                profile,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if ends[0].trimmed[0].0.is_same(&ends[1].trimmed[0].0)
+        || ends[0].trimmed[1].0.is_same(&ends[1].trimmed[1].0)
+    {
+        return unsupported("the adjacent faces must have at least four edges.");
+    }
+    let new_edges = [0, 1].map(|i| {
+        let (v0, v1) = (&ends[0].contacts[i], &ends[1].contacts[i]);
+        Edge::new(v0, v1, Line(v0.point(), v1.point()).into())
+    });
+
+    let rebuild_wire = |wire: &Wire, face_idx: usize| -> Wire {
+        let mut new_wire = Wire::new();
+        wire.edge_iter().for_each(|e| {
+            if e.is_same(edge) {
+                let i = usize::from(face_idx == idx1);
+                new_wire.push_back(oriented(&new_edges[i], e.orientation()));
+                return;
+            }
+            let replaced = ends
+                .iter()
+                .flat_map(|end| &end.trimmed)
+                .find(|(old, _)| old.is_same(e));
+            let Some((_, new)) = replaced else {
+                new_wire.push_back(e.clone());
+                return;
+            };
+            let new = oriented(new, e.orientation());
+            let back = new.back().clone();
+            new_wire.push_back(new);
+            if face_idx != idx0 && face_idx != idx1 {
+                // the end face: goes around the profile
+                ends.iter().for_each(|end| {
+                    if back == end.contacts[0] {
+                        new_wire.push_back(end.profile.clone());
+                    } else if back == end.contacts[1] {
+                        new_wire.push_back(end.profile.inverse());
+                    }
+                });
+            }
+        });
+        new_wire
+    };
+    let old_edges: Vec<&Edge> = std::iter::once(edge)
+        .chain(
+            ends.iter()
+                .flat_map(|end| end.trimmed.iter().map(|(old, _)| old)),
+        )
+        .collect();
+    let mut new_faces: Vec<Face> = shell
+        .face_iter()
+        .enumerate()
+        .map(|(i, face)| {
+            if !face
+                .edge_iter()
+                .any(|e| old_edges.iter().any(|old| old.is_same(&e)))
+            {
+                return Ok(face.clone());
+            }
+            let boundaries = face
+                .boundaries()
+                .iter()
+                .map(|wire| rebuild_wire(wire, i))
+                .collect();
+            if i != idx0 && i != idx1 {
+                plane_normal(face)?;
+            }
+            Ok(Face::try_new(boundaries, face.oriented_surface())?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut wire: Wire = vec![
+        new_edges[0].clone(),
+        ends[1].profile.clone(),
+        new_edges[1].inverse(),
+        ends[0].profile.inverse(),
+    ]
+    .into();
+    // each edge is shared by two faces with the opposite orientations
+    if ori0 {
+        wire.invert();
+    }
+    let (c00, c01) = (ends[0].contacts[0].point(), ends[0].contacts[1].point());
+    let c10 = ends[1].contacts[0].point();
+    let (mut surface, normal) = match profile {
+        Profile::Round(_) => {
+            let origin = p0 + center;
+            let entity = Curve::BSplineCurve(Line(c00, c10).to_bspline());
+            let revoluted = RevolutedCurve::by_revolution(entity, origin, axis);
+            let surface = Surface::RevolutedCurve(Processor::new(revoluted));
+            let normal = surface.normal(0.5, angle.0 / 2.0);
+            (surface, normal)
+        }
+        Profile::Bevel(_) => {
+            let plane = Plane::new(c00, c10, c01);
+            (Surface::Plane(plane), plane.normal())
+        }
+    };
+    // the blend surface faces outward
+    if normal.dot(normals[0] + normals[1]) < 0.0 {
+        surface.invert();
+    }
+    new_faces.push(Face::try_new(vec![wire], surface)?);
+    *shell = new_faces.into();
+    Ok(Solid::try_new(shells)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn unit_cube() -> Solid {
+        let v = builder::vertex(Point3::origin());
+        let e = builder::tsweep(&v, Vector3::unit_x());
+        let f = builder::tsweep(&e, Vector3::unit_y());
+        builder::tsweep(&f, Vector3::unit_z())
+    }
+
+    #[test]
+    fn fillet_cube_edge() {
+        let cube = unit_cube();
+        let edge = cube
+            .edge_iter()
+            .find(|edge| {
+                let (p, q) = (edge.front().point(), edge.back().point());
+                p.x.near(&1.0) && q.x.near(&1.0) && p.y.near(&1.0) && q.y.near(&1.0)
+            })
+            .unwrap();
+        let filleted = builder::fillet_edge(&cube, &edge, 0.25).unwrap();
+        let shell = &filleted.boundaries()[0];
+        assert_eq!(shell.len(), 7);
+        assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+        assert!(filleted.is_geometric_consistent());
+        assert!(!filleted.edge_iter().any(|e| e.is_same(&edge)));
+
+        let fillet = &shell[6];
+        fillet.vertex_iter().for_each(|v| {
+            let pt = v.point();
+            assert_near!(Vector2::new(pt.x - 0.75, pt.y - 0.75).magnitude(), 0.25);
+        });
+        let surface = fillet.oriented_surface();
+        let (u, v) = surface
+            .search_parameter(Point3::new(0.75, 1.0, 0.5), None, 100)
+            .unwrap();
+        assert_near!(surface.normal(u, v), Vector3::unit_y());
+    }
+
+    #[test]
+    fn fillet_all_cube_edges() {
+        let cube = unit_cube();
+        cube.edge_iter().for_each(|edge| {
+            let filleted = builder::fillet_edge(&cube, &edge, 0.4).unwrap();
+            let shell = &filleted.boundaries()[0];
+            assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+            assert!(filleted.is_geometric_consistent());
+
+            // the normal of the fillet points outward
+            let surface = shell[6].oriented_surface();
+            let (p, q) = (edge.front().point(), edge.back().point());
+            let middle = p.midpoint(q);
+            let axial = (q - p).normalize();
+            let outward = middle - Point3::new(0.5, 0.5, 0.5);
+            let outward = (outward - axial * outward.dot(axial)).normalize();
+            let pt = middle - outward * (0.4 * f64::sqrt(2.0) - 0.4);
+            let (u, v) = surface.search_parameter(pt, None, 100).unwrap();
+            assert_near!(surface.normal(u, v), outward);
+        });
+    }
+
+    #[test]
+    fn chamfer_all_cube_edges() {
+        let cube = unit_cube();
+        cube.edge_iter().for_each(|edge| {
+            let chamfered = builder::chamfer_edge(&cube, &edge, 0.3).unwrap();
+            let shell = &chamfered.boundaries()[0];
+            assert_eq!(shell.len(), 7);
+            assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+            assert!(chamfered.is_geometric_consistent());
+            let edges: HashSet<EdgeID> = chamfered.edge_iter().map(|e| e.id()).collect();
+            let vertices: HashSet<VertexID> = chamfered.vertex_iter().map(|v| v.id()).collect();
+            assert_eq!(edges.len(), 15);
+            assert_eq!(vertices.len(), 10);
+
+            // the bevel is at the distance from the edge on both faces
+            let bevel = &shell[6];
+            let (p, q) = (edge.front().point(), edge.back().point());
+            let axial = (q - p).normalize();
+            bevel.vertex_iter().for_each(|v| {
+                let vec = v.point() - p;
+                assert_near!((vec - axial * vec.dot(axial)).magnitude(), 0.3);
+            });
+            let outward = p.midpoint(q) - Point3::new(0.5, 0.5, 0.5);
+            let outward = (outward - axial * outward.dot(axial)).normalize();
+            assert_near!(bevel.oriented_surface().normal(0.5, 0.5), outward);
+        });
+    }
+
+    #[test]
+    fn unsupported_blends() {
+        let cube = unit_cube();
+        let edge = cube.edge_iter().next().unwrap();
+        assert!(matches!(
+            builder::fillet_edge(&cube, &edge, 1.5),
+            Err(Error::UnsupportedBlend(_)),
+        ));
+        assert!(matches!(
+            builder::chamfer_edge(&cube, &edge, 1.5),
+            Err(Error::UnsupportedBlend(_)),
+        ));
+        let other = builder::vertex(Point3::new(2.0, 0.0, 0.0));
+        let other_edge = builder::line(&other, &builder::vertex(Point3::new(3.0, 0.0, 0.0)));
+        assert_eq!(
+            builder::fillet_edge(&cube, &other_edge, 0.1),
+            Err(Error::EdgeNotFound),
+        );
+
+        let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+        let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+        let disk = builder::try_attach_plane(&[circle]).unwrap();
+        let cylinder = builder::tsweep(&disk, Vector3::unit_z());
+        let edge = cylinder.edge_iter().next().unwrap();
+        assert!(matches!(
+            builder::fillet_edge(&cylinder, &edge, 0.1),
+            Err(Error::UnsupportedBlend(_)),
+        ));
+    }
+}
@@ -96,6 +96,95 @@ pub fn bezier(vertex0: &Vertex, vertex1: &Vertex, mut inter_points: Vec<Point3>)
     Edge::new(vertex0, vertex1, Curve::BSplineCurve(curve))
 }
 
+/// Returns a B-spline curve edge passing through the points of `vertices` in sequence.
+///
+/// The front and the back vertices of the edge are the first and the last ones of `vertices`,
+/// and the others are used only as the interpolated points. The degree of the curve is three,
+/// or the number of vertices minus one if it is less.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let pts = [
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(2.0, 0.0, 1.0),
+///     Point3::new(3.0, 1.0, 1.0),
+/// ];
+/// let v = Vertex::news(&pts);
+/// let edge = builder::interpolated_spline(&v).unwrap();
+/// assert_eq!(edge.front(), &v[0]);
+/// assert_eq!(edge.back(), &v[3]);
+/// // the curve passes through all points
+/// let curve = edge.oriented_curve();
+/// for pt in pts {
+///     let t = curve.search_nearest_parameter(pt, None, 100).unwrap();
+///     assert!(curve.subs(t).near(&pt));
+/// }
+/// ```
+/// # Failures
+/// - If the number of vertices is less than two, then return `Error::TooFewPoints`.
+/// - If the points of a vertex and the next one coincide, then return `Error::CoincidentPoints`.
+/// - If the first and the last vertices are the same, then return the topological error.
+pub fn interpolated_spline(vertices: &[Vertex]) -> Result<Edge> {
+    if vertices.len() < 2 {
+        return Err(Error::TooFewPoints(vertices.len()));
+    }
+    let points: Vec<Point3> = vertices.iter().map(Vertex::point).collect();
+    if let Some(i) = points.windows(2).position(|pair| pair[0].near(&pair[1])) {
+        return Err(Error::CoincidentPoints(i));
+    }
+    let degree = usize::min(3, points.len() - 1);
+    let curve =
+        BSplineCurve::interpolate(&points, degree).expect("the points have already been checked.");
+    let (front, back) = (&vertices[0], &vertices[vertices.len() - 1]);
+    Ok(Edge::try_new(front, back, Curve::BSplineCurve(curve))?)
+}
+
+/// Returns a closed wire of the lines connecting `points` in sequence and the last point to
+/// the first one.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // draw a triangle
+/// let wire = builder::polygon(&[
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ])
+/// .unwrap();
+/// assert_eq!(wire.len(), 3);
+/// assert!(wire.is_closed());
+/// assert_eq!(wire[2].back(), wire[0].front());
+/// ```
+/// # Failures
+/// - If the number of points is less than three, then return `Error::TooFewPoints`.
+/// - If a point and the next one coincide, then return `Error::CoincidentPoints`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let p = Point3::new(1.0, 0.0, 0.0);
+/// assert_eq!(
+///     builder::polygon(&[Point3::origin(), p]).unwrap_err(),
+///     Error::TooFewPoints(2),
+/// );
+/// assert_eq!(
+///     builder::polygon(&[Point3::origin(), p, p]).unwrap_err(),
+///     Error::CoincidentPoints(1),
+/// );
+/// ```
+pub fn polygon(points: &[Point3]) -> Result<Wire> {
+    if points.len() < 3 {
+        return Err(Error::TooFewPoints(points.len()));
+    }
+    let len = points.len();
+    if let Some(i) = (0..len).find(|i| points[*i].near(&points[(i + 1) % len])) {
+        return Err(Error::CoincidentPoints(i));
+    }
+    let v = Vertex::news(points);
+    Ok((0..len).map(|i| line(&v[i], &v[(i + 1) % len])).collect())
+}
+
 /// Returns a homotopic face from `edge0` to `edge1`.
 /// # Examples
 /// ```
@@ -255,6 +344,121 @@ pub fn try_wire_homotopy(wire0: &Wire, wire1: &Wire) -> Result<Shell> {
     Ok(shell)
 }
 
+/// Returns the lofted shell through `wires` in sequence, which generalizes
+/// [`try_wire_homotopy`] to more than two wires.
+///
+/// The corresponding edges of the wires are interpolated by a B-spline surface whose degree in the
+/// lofting direction is three, or the number of wires minus one if it is less. The wires are
+/// parametrized by the centripetal method with the mean distance of the vertices, and each strip
+/// between adjacent wires is the part of the surface, so the shell is smooth across the wires.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // squares with the different sizes
+/// let wires: Vec<Wire> = [(1.0, 0.0), (2.0, 1.0), (1.0, 2.0), (1.5, 3.0)]
+///     .into_iter()
+///     .map(|(size, height)| {
+///         let v = builder::vertex(Point3::new(-size, -size, height));
+///         let e = builder::tsweep(&v, Vector3::new(2.0 * size, 0.0, 0.0));
+///         let f = builder::tsweep(&e, Vector3::new(0.0, 2.0 * size, 0.0));
+///         f.boundaries()[0].clone()
+///     })
+///     .collect();
+///
+/// let shell = builder::loft(&wires).unwrap();
+/// assert_eq!(shell.len(), 12);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+/// let boundary = shell.extract_boundaries();
+/// assert_eq!(boundary.len(), 2);
+/// ```
+/// # Failures
+/// - If the wires have different numbers of edges, then return `Error::NotSameNumberOfEdges`.
+/// - If all vertices of two adjacent wires coincide, then return `Error::CoincidentWires`.
+/// # Panics
+/// Panics if the number of wires is less than two.
+pub fn loft(wires: &[Wire]) -> Result<Shell> {
+    assert!(wires.len() >= 2, "at least two wires are required.");
+    let len = wires[0].len();
+    if wires.iter().any(|wire| wire.len() != len) {
+        return Err(Error::NotSameNumberOfEdges);
+    }
+    let sections: Vec<Vec<Edge>> = wires
+        .iter()
+        .map(|wire| wire.edge_iter().cloned().collect())
+        .collect();
+    let mut params = vec![0.0];
+    for (i, pair) in sections.windows(2).enumerate() {
+        let sum = pair[0]
+            .iter()
+            .zip(&pair[1])
+            .fold(0.0, |sum, (edge0, edge1)| {
+                sum + edge0.front().point().distance(edge1.front().point())
+            });
+        let dist = sum / len as f64;
+        if dist.so_small() {
+            return Err(Error::CoincidentWires(i));
+        }
+        params.push(params[i] + f64::sqrt(dist));
+    }
+    let total = params[params.len() - 1];
+    params.iter_mut().for_each(|t| *t /= total);
+    let vdegree = usize::min(3, wires.len() - 1);
+
+    // the rails through the corresponding vertices, shared by the adjacent strips.
+    let mut rails = rustc_hash::FxHashMap::<Vec<VertexID>, Vec<Edge>>::default();
+    let mut rail = |vertices: Vec<&Vertex>, curve: BSplineCurve<Vector4>| -> Vec<Edge> {
+        let key = vertices.iter().map(|v| v.id()).collect();
+        let edges = rails.entry(key).or_insert_with(|| {
+            let mut rest = curve;
+            vertices
+                .windows(2)
+                .zip(&params[1..])
+                .map(|(ends, t)| {
+                    let next = rest.cut(*t);
+                    let piece = std::mem::replace(&mut rest, next);
+                    Edge::new(ends[0], ends[1], NurbsCurve::new(piece).into())
+                })
+                .collect()
+        });
+        edges.clone()
+    };
+
+    let mut shell = Shell::new();
+    for j in 0..len {
+        let curves = sections
+            .iter()
+            .map(|section| section[j].oriented_curve().lift_up())
+            .collect();
+        let mut surface = BSplineSurface::skin_with_parameters(curves, &params, vdegree);
+        let fronts = sections.iter().map(|section| section[j].front()).collect();
+        let backs = sections.iter().map(|section| section[j].back()).collect();
+        let front_rails = rail(fronts, surface.uiso(0.0));
+        let back_rails = rail(backs, surface.uiso(1.0));
+        for i in 0..wires.len() - 1 {
+            let strip = match i + 2 == wires.len() {
+                true => surface.clone(),
+                false => {
+                    let rest = surface.vcut(params[i + 1]);
+                    std::mem::replace(&mut surface, rest)
+                }
+            };
+            let wire: Wire = vec![
+                sections[i][j].clone(),
+                back_rails[i].clone(),
+                sections[i + 1][j].inverse(),
+                front_rails[i].inverse(),
+            ]
+            .into();
+            shell.push(Face::new(
+                vec![wire],
+                Surface::NurbsSurface(NurbsSurface::new(strip)),
+            ));
+        }
+    }
+    Ok(shell)
+}
+
 /// Creates a cone by R-sweeping.
 /// # Examples
 /// ```
@@ -350,6 +554,114 @@ pub fn cone<R: Into<Rad<f64>>>(wire: &Wire, axis: Vector3, angle: R) -> Shell {
     shell
 }
 
+/// Returns a vector perpendicular to `axis`.
+fn perpendicular(axis: Vector3) -> Vector3 {
+    let other = [Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()]
+        .into_iter()
+        .min_by(|a, b| f64::abs(a.dot(axis)).total_cmp(&f64::abs(b.dot(axis))))
+        .unwrap();
+    axis.cross(other).normalize()
+}
+
+/// Creates the parallelepiped spanned by `x`, `y`, and `z` from `origin`.
+/// # Details
+/// The result is the box if the vectors are orthogonal. The vectors need not be right-handed.
+/// # Panics
+/// Panics if the vectors are linearly dependent.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let cube = builder::cube(
+///     Point3::new(1.0, 2.0, 3.0),
+///     Vector3::unit_x(),
+///     Vector3::unit_y() * 2.0,
+///     Vector3::unit_z() * 3.0,
+/// );
+/// assert_eq!(cube.boundaries()[0].len(), 6);
+/// assert!(cube.is_geometric_consistent());
+/// ```
+pub fn cube(origin: Point3, x: Vector3, y: Vector3, z: Vector3) -> Solid {
+    let det = x.cross(y).dot(z);
+    assert!(!det.so_small(), "the vectors must be linearly independent.");
+    let (x, y) = if det > 0.0 { (x, y) } else { (y, x) };
+    let v = vertex(origin);
+    let e = tsweep(&v, x);
+    let f = tsweep(&e, y);
+    tsweep(&f, z)
+}
+
+/// Creates the sphere with `center` and `radius`.
+/// # Details
+/// The sphere is the surface of revolution of the NURBS semicircle, and so it is exact.
+/// # Panics
+/// Panics if `radius` is not positive.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let sphere = builder::sphere(Point3::new(1.0, 2.0, 3.0), 2.0);
+/// assert!(sphere.is_geometric_consistent());
+/// let surface = sphere.boundaries()[0][0].oriented_surface();
+/// assert_near!(surface.subs(0.3, 0.4).distance(Point3::new(1.0, 2.0, 3.0)), 2.0);
+/// ```
+pub fn sphere(center: Point3, radius: f64) -> Solid {
+    assert!(radius > TOLERANCE, "the radius must be positive.");
+    let v = vertex(center + Vector3::unit_y() * radius);
+    let wire = rsweep(&v, center, Vector3::unit_x(), PI);
+    let shell = cone(&wire, Vector3::unit_y(), PI * 2.0);
+    Solid::new(vec![shell])
+}
+
+/// Creates the cylinder whose bottom is the disk with `base_center` and `radius` perpendicular
+/// to `axis`, and whose top is moved by `height` along `axis`.
+/// # Remarks
+/// `axis` must be normalized. If not, panics occurs in debug mode.
+/// # Panics
+/// Panics if `radius` or `height` is not positive.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let axis = Vector3::new(1.0, 1.0, 0.0).normalize();
+/// let cylinder = builder::cylinder(Point3::new(1.0, 0.0, 0.0), 0.5, 2.0, axis);
+/// // the side, the bottom, and the top
+/// assert_eq!(cylinder.face_iter().count(), 5);
+/// assert!(cylinder.is_geometric_consistent());
+/// ```
+pub fn cylinder(base_center: Point3, radius: f64, height: f64, axis: Vector3) -> Solid {
+    debug_assert!(axis.magnitude().near(&1.0));
+    assert!(radius > TOLERANCE, "the radius must be positive.");
+    assert!(height > TOLERANCE, "the height must be positive.");
+    let v = vertex(base_center + perpendicular(axis) * radius);
+    let circle = rsweep(&v, base_center, axis, PI * 2.0);
+    let disk = try_attach_plane(&[circle]).expect("a circle is on a plane.");
+    tsweep(&disk, axis * height)
+}
+
+/// Creates the cone whose bottom is the disk with `base_center` and `radius` perpendicular
+/// to `axis`, and whose apex is moved by `height` along `axis` from `base_center`.
+/// # Remarks
+/// `axis` must be normalized. If not, panics occurs in debug mode.
+/// # Panics
+/// Panics if `radius` or `height` is not positive.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let cone = builder::cone_solid(Point3::origin(), 1.0, 2.0, Vector3::unit_z());
+/// assert!(cone.is_geometric_consistent());
+/// let apex = cone.vertex_iter().find(|v| v.point().z > 1.0).unwrap();
+/// assert_near!(apex.point(), Point3::new(0.0, 0.0, 2.0));
+/// ```
+pub fn cone_solid(base_center: Point3, radius: f64, height: f64, axis: Vector3) -> Solid {
+    debug_assert!(axis.magnitude().near(&1.0));
+    assert!(radius > TOLERANCE, "the radius must be positive.");
+    assert!(height > TOLERANCE, "the height must be positive.");
+    let v0 = vertex(base_center + axis * height);
+    let v1 = vertex(base_center + perpendicular(axis) * radius);
+    let v2 = vertex(base_center);
+    let wire: Wire = vec![line(&v0, &v1), line(&v1, &v2)].into();
+    let shell = cone(&wire, axis, PI * 2.0);
+    Solid::new(vec![shell])
+}
+
 /// Try attatiching a plane whose boundary is `wire`.
 /// # Examples
 /// ```
@@ -421,6 +733,31 @@ pub fn try_attach_plane(wires: &[Wire]) -> Result<Face> {
     Ok(Face::try_new(wires.to_owned(), plane.into())?)
 }
 
+/// Returns a planar face whose boundary is the polygon of `points`.
+///
+/// This is the shorthand of [`polygon`] and [`try_attach_plane`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // a square on the xy-plane
+/// let face = builder::planar_face(&[
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ])
+/// .unwrap();
+/// assert_eq!(face.boundaries()[0].len(), 4);
+/// let normal = face.oriented_surface().normal(0.5, 0.5);
+/// assert!(normal.near(&Vector3::unit_z()));
+/// ```
+/// # Failures
+/// - If [`polygon`] fails, then return the same error.
+/// - If the points are not in one plane, then return `Error::WireNotInOnePlane`.
+#[inline(always)]
+pub fn planar_face(points: &[Point3]) -> Result<Face> { try_attach_plane(&[polygon(points)?]) }
+
 /// Returns another topology whose points, curves, and surfaces are cloned.
 #[inline(always)]
 pub fn clone<T: Mapped<Point3, Curve, Surface>>(elem: &T) -> T { elem.topological_clone() }
@@ -464,6 +801,110 @@ pub fn scaled<T: Mapped<Point3, Curve, Surface>>(elem: &T, origin: Point3, scala
     transformed(elem, mat2 * mat1 * mat0)
 }
 
+/// Returns `count` copies of a vertex, edge, wire, face, shell or solid
+/// translated by `step` one after another.
+///
+/// The `i`-th copy is translated by `i * step`, so the first copy is at the same position as `elem`.
+/// Each copy has its own topology, independent of `elem` and the other copies.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let cubes = builder::linear_pattern(&cube, Vector3::new(2.0, 0.0, 0.0), 3);
+/// assert_eq!(cubes.len(), 3);
+/// for (i, cube) in cubes.iter().enumerate() {
+///     assert!(cube.vertex_iter().all(|v| v.point().x.near(&(2.0 * i as f64))
+///         || v.point().x.near(&(2.0 * i as f64 + 1.0))));
+/// }
+/// // the copies do not share vertices
+/// assert!(cubes[0].vertex_iter().all(|v| !cubes[1].vertex_iter().any(|w| v == w)));
+/// ```
+pub fn linear_pattern<T: Mapped<Point3, Curve, Surface>>(
+    elem: &T,
+    step: Vector3,
+    count: usize,
+) -> Vec<T> {
+    (0..count)
+        .map(|i| translated(elem, step * i as f64))
+        .collect()
+}
+
+/// Returns `count` copies of a vertex, edge, wire, face, shell or solid
+/// arranged around the axis at regular intervals.
+///
+/// The `i`-th copy is rotated by `2 * PI * i / count` around the line through `origin` with
+/// direction `axis`, so the first copy is at the same position as `elem`.
+/// Each copy has its own topology, independent of `elem` and the other copies.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let vertices = builder::circular_pattern(&v, Point3::origin(), Vector3::unit_z(), 4);
+/// assert_eq!(vertices.len(), 4);
+/// assert_near!(vertices[0].point(), Point3::new(1.0, 0.0, 0.0));
+/// assert_near!(vertices[1].point(), Point3::new(0.0, 1.0, 0.0));
+/// assert_near!(vertices[2].point(), Point3::new(-1.0, 0.0, 0.0));
+/// assert_near!(vertices[3].point(), Point3::new(0.0, -1.0, 0.0));
+/// assert_ne!(vertices[0], v);
+/// ```
+/// # Remarks
+/// `axis` must be normalized. If not, panics occurs in debug mode.
+pub fn circular_pattern<T: Mapped<Point3, Curve, Surface>>(
+    elem: &T,
+    origin: Point3,
+    axis: Vector3,
+    count: usize,
+) -> Vec<T> {
+    debug_assert!(axis.magnitude().near(&1.0), "axis must be normalized.");
+    (0..count)
+        .map(|i| rotated(elem, origin, axis, PI * 2.0 * i as f64 / count as f64))
+        .collect()
+}
+
+/// Returns a vertex, edge, wire, face, shell or solid reflected across `plane`.
+///
+/// The faces are inverted after the reflection, so the boundaries of a mirrored solid
+/// keep facing outward. Vertices, edges, and wires are only reflected.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the plane x = 1
+/// let plane = Plane::new(
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(1.0, 0.0, 1.0),
+/// );
+/// let mirrored = builder::mirrored(&cube, &plane);
+/// assert!(mirrored.vertex_iter().all(|v| 1.0 - TOLERANCE < v.point().x));
+///
+/// // the normal of the face at x = 2 points to +x.
+/// let face = mirrored
+///     .face_iter()
+///     .find(|face| face.vertex_iter().all(|v| v.point().x.near(&2.0)))
+///     .unwrap();
+/// let normal = face.oriented_surface().normal(0.5, 0.5);
+/// assert_near!(normal, Vector3::unit_x());
+/// ```
+pub fn mirrored<T: Mapped<Point3, Curve, Surface>>(elem: &T, plane: &Plane) -> T {
+    let (origin, normal) = (plane.origin().to_vec(), plane.normal());
+    let reflection = Matrix3::identity()
+        - Matrix3::from_cols(normal * normal.x, normal * normal.y, normal * normal.z) * 2.0;
+    let mat =
+        Matrix4::from_translation(normal * 2.0 * normal.dot(origin)) * Matrix4::from(reflection);
+    let mut res = transformed(elem, mat);
+    res.invert_faces();
+    res
+}
+
 /// Sweeps a vertex, an edge, a wire, a face, or a shell by a vector.
 /// # Examples
 /// ```
@@ -508,26 +949,137 @@ pub fn tsweep<T: Sweep<Point3, Curve, Surface>>(elem: &T, vector: Vector3) -> T:
         &move |curve| curve.transformed(trsl),
         &move |surface| surface.transformed(trsl),
         &move |pt0, pt1| Curve::Line(Line(*pt0, *pt1)),
-        &move |curve0, curve1| match (curve0, curve1) {
-            (Curve::Line(line), Curve::Line(_)) => {
-                Plane::new(line.0, line.1, line.0 + vector).into()
-            }
-            (Curve::BSplineCurve(curve0), Curve::BSplineCurve(curve1)) => {
-                BSplineSurface::homotopy(curve0.clone(), curve1.clone()).into()
-            }
-            (Curve::NurbsCurve(curve0), Curve::NurbsCurve(curve1)) => {
-                NurbsSurface::new(BSplineSurface::homotopy(
-                    curve0.non_rationalized().clone(),
-                    curve1.non_rationalized().clone(),
-                ))
-                .into()
-            }
-            (Curve::IntersectionCurve(_), Curve::IntersectionCurve(_)) => unimplemented!(),
-            _ => unreachable!(),
-        },
+        &move |curve0, curve1| ruled_surface(curve0, curve1),
     )
 }
 
+/// Sweeps `profile` along `vector` with scaling it linearly from `1.0` to `end_scale`
+/// about the centroid of the profile, and returns the tapered shell.
+///
+/// The side surfaces are ruled between the profile and the scaled one. The centroid is that of
+/// the curves of the profile, weighted by the length.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // the side of a truncated pyramid
+/// let square = builder::polygon(&[
+///     Point3::new(-1.0, -1.0, 0.0),
+///     Point3::new(1.0, -1.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(-1.0, 1.0, 0.0),
+/// ])
+/// .unwrap();
+/// let shell = builder::tsweep_scaled(&square, Vector3::new(0.0, 0.0, 2.0), 0.5).unwrap();
+/// assert_eq!(shell.len(), 4);
+/// assert!(shell.is_geometric_consistent());
+/// let boundaries = shell.extract_boundaries();
+/// assert_eq!(boundaries.len(), 2);
+/// // the top is the half size square
+/// let top = boundaries
+///     .iter()
+///     .find(|wire| wire.vertex_iter().all(|v| v.point().z.near(&2.0)))
+///     .unwrap();
+/// assert!(top
+///     .vertex_iter()
+///     .all(|v| v.point().x.abs().near(&0.5) && v.point().y.abs().near(&0.5)));
+///
+/// // the shell can be capped as a solid
+/// let bottom = builder::try_attach_plane(&[square.inverse()]).unwrap();
+/// let top = builder::try_attach_plane(&[top.inverse()]).unwrap();
+/// let mut shell = shell;
+/// shell.push(bottom);
+/// shell.push(top);
+/// let solid = Solid::new(vec![shell]);
+/// assert!(solid.is_geometric_consistent());
+/// ```
+/// # Failures
+/// If `end_scale` is not positive or is NaN, then return `Error::NonPositiveScale`.
+#[allow(clippy::neg_cmp_op_on_partial_ord)]
+pub fn tsweep_scaled(profile: &Wire, vector: Vector3, end_scale: f64) -> Result<Shell> {
+    // NaN is also rejected.
+    if !(end_scale > 0.0) {
+        return Err(Error::NonPositiveScale);
+    }
+    let center = wire_centroid(profile).to_vec();
+    let mat = Matrix4::from_translation(vector + center)
+        * Matrix4::from_scale(end_scale)
+        * Matrix4::from_translation(-center);
+    Ok(profile.sweep(
+        &move |pt| mat.transform_point(*pt),
+        &move |curve| curve.transformed(mat),
+        &move |surface: &Surface| surface.transformed(mat),
+        &move |pt0, pt1| Curve::Line(Line(*pt0, *pt1)),
+        &move |curve0, curve1| ruled_surface(curve0, curve1),
+    ))
+}
+
+const CENTROID_DIVISION: usize = 16;
+
+/// Returns the centroid of the curves of `wire` weighted by the length.
+fn wire_centroid(wire: &Wire) -> Point3 {
+    let (sum, length) = wire
+        .edge_iter()
+        .fold((Vector3::zero(), 0.0), |(sum, length), edge| {
+            let curve = edge.curve();
+            let (t0, t1) = curve.range_tuple();
+            let pts: Vec<Point3> = (0..=CENTROID_DIVISION)
+                .map(|i| curve.subs(t0 + (t1 - t0) * i as f64 / CENTROID_DIVISION as f64))
+                .collect();
+            pts.windows(2).fold((sum, length), |(sum, length), pair| {
+                let len = pair[0].distance(pair[1]);
+                (sum + pair[0].midpoint(pair[1]).to_vec() * len, length + len)
+            })
+        });
+    match length > 0.0 {
+        true => Point3::from_vec(sum / length),
+        false => Point3::origin(),
+    }
+}
+
+/// Returns the ruled surface between `curve0` and `curve1`, which are connected by lines.
+fn ruled_surface(curve0: &Curve, curve1: &Curve) -> Surface {
+    match (curve0, curve1) {
+        (Curve::Line(line0), Curve::Line(line1)) => Plane::new(line0.0, line0.1, line1.0).into(),
+        (Curve::BSplineCurve(curve0), Curve::BSplineCurve(curve1)) => {
+            BSplineSurface::homotopy(curve0.clone(), curve1.clone()).into()
+        }
+        (Curve::NurbsCurve(curve0), Curve::NurbsCurve(curve1)) => {
+            NurbsSurface::new(BSplineSurface::homotopy(
+                curve0.non_rationalized().clone(),
+                curve1.non_rationalized().clone(),
+            ))
+            .into()
+        }
+        (Curve::IntersectionCurve(curve0), Curve::IntersectionCurve(curve1)) => {
+            BSplineSurface::homotopy(
+                intersection_curve_approximation(curve0),
+                intersection_curve_approximation(curve1),
+            )
+            .into()
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Approximates the intersection curve by the piecewise linear B-spline curve through the division
+/// points, which is parametrized by the same parameter as the intersection curve.
+/// The chords are apart from the curve at most its tolerance, up to which `Surface::include`
+/// checks the intersection curves.
+pub(crate) fn intersection_curve_approximation(
+    curve: &IntersectionCurve<Leader, Surface>,
+) -> BSplineCurve<Point3> {
+    let (params, pts) = curve.parameter_division(curve.range_tuple(), curve.tolerance());
+    let knots: Vec<f64> = params
+        .first()
+        .into_iter()
+        .chain(&params)
+        .chain(params.last())
+        .copied()
+        .collect();
+    BSplineCurve::new(KnotVec::from(knots), pts)
+}
+
 /// Sweeps a vertex, an edge, a wire, a face, or a shell by the rotation.
 /// # Details
 /// If the absolute value of `angle` is more than 2π rad, then the result is closed shape.
@@ -639,6 +1191,371 @@ pub fn rsweep<T: ClosedSweep<Point3, Curve, Surface>, R: Into<Rad<f64>>>(
     }
 }
 
+/// Sweeps a vertex, an edge, a wire, a face, or a shell along the helix, i.e. rotates it around
+/// `axis` and translates it along `axis` simultaneously.
+/// # Details
+/// The element is rotated by `turns` times 2π rad, and is translated by `pitch` per turn.
+/// The sweep is divided into segments of at most a quarter turn. The curves traced by the
+/// vertices are cubic B-spline interpolations of the helix, and the surfaces traced by the
+/// curves are the skinned surfaces through the sections of the curves.
+/// If `turns` is negative, the element is rotated in the opposite direction and
+/// translated by `-pitch` per turn.
+/// # Remarks
+/// `axis` must be normalized. If not, panics occurs in debug mode.
+/// # Panics
+/// Panics if `turns` is zero, or if `pitch` is zero and a vertex is on the axis.
+/// # Examples
+/// ```
+/// // a coil spring
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::new(2.2, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::new(2.0, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+/// let disk = builder::try_attach_plane(&[circle]).unwrap();
+/// let spring: Solid = builder::helical_sweep(&disk, Point3::origin(), Vector3::unit_z(), 1.0, 3.0);
+/// assert!(spring.is_geometric_consistent());
+///
+/// // the section at the end is moved by three pitches.
+/// let shell = &spring.boundaries()[0];
+/// let end = shell[shell.len() - 1].boundaries()[0].vertex_iter().next().unwrap();
+/// assert_near!(end.point(), Point3::new(2.2, 0.0, 3.0));
+/// ```
+pub fn helical_sweep<T: MultiSweep<Point3, Curve, Surface>>(
+    elem: &T,
+    origin: Point3,
+    axis: Vector3,
+    pitch: f64,
+    turns: f64,
+) -> T::Swept {
+    debug_assert!(axis.magnitude().near(&1.0));
+    assert!(!turns.so_small(), "the number of turns must be non-zero.");
+    let division = usize::max(f64::ceil(turns.abs() * 4.0) as usize, 1);
+    let angle = PI * 2.0 * turns / division as f64;
+    let rise = pitch * turns / division as f64;
+    helix_multi_sweep(elem, origin, axis, angle, rise, division)
+}
+
+/// Sweeps `profile` along `vector` with rotating it around the axis through the centroid of the
+/// profile parallel to `vector`, and returns the twisted shell.
+/// # Details
+/// The profile is rotated in proportion to the distance traveled, by `total_twist` in total.
+/// The sweep is divided into segments of at most a quarter turn, and the surfaces are the
+/// B-spline surfaces skinned through the sections of the profile curves, as [`helical_sweep`].
+/// The centroid is that of the curves of the profile, weighted by the length.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// // a twisted square column
+/// let square = builder::polygon(&[
+///     Point3::new(-1.0, -1.0, 0.0),
+///     Point3::new(1.0, -1.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(-1.0, 1.0, 0.0),
+/// ])
+/// .unwrap();
+/// let shell = builder::tsweep_twisted(&square, Vector3::new(0.0, 0.0, 2.0), Rad(PI / 4.0)).unwrap();
+/// assert_eq!(shell.len(), 4);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+/// assert!(shell.is_geometric_consistent());
+///
+/// // the top is rotated by π/4.
+/// let boundaries = shell.extract_boundaries();
+/// assert_eq!(boundaries.len(), 2);
+/// let top = boundaries
+///     .iter()
+///     .find(|wire| wire.vertex_iter().all(|v| v.point().z.near(&2.0)))
+///     .unwrap();
+/// let r = f64::sqrt(2.0);
+/// assert!(top.vertex_iter().any(|v| v.point().near(&Point3::new(0.0, -r, 2.0))));
+///
+/// // the side edges are helices.
+/// let edge = shell[0].boundaries()[0][1].clone();
+/// let curve = edge.oriented_curve();
+/// let (t0, t1) = curve.range_tuple();
+/// let p0 = curve.subs(t0);
+/// for i in 0..=10 {
+///     let pt = curve.subs(t0 + (t1 - t0) * i as f64 / 10.0);
+///     let angle = f64::atan2(pt.y, pt.x) - f64::atan2(p0.y, p0.x);
+///     assert!((angle - PI / 4.0 * pt.z / 2.0).abs() < 1.0e-4);
+///     assert!((f64::hypot(pt.x, pt.y) - r).abs() < 1.0e-4);
+/// }
+///
+/// // the shell can be capped as a solid
+/// let bottom = builder::try_attach_plane(&[square.inverse()]).unwrap();
+/// let top = builder::try_attach_plane(&[top.inverse()]).unwrap();
+/// let mut shell = shell;
+/// shell.push(bottom);
+/// shell.push(top);
+/// let solid = Solid::new(vec![shell]);
+/// assert!(solid.is_geometric_consistent());
+/// ```
+/// # Failures
+/// If `vector` is zero, then return `Error::ZeroSweepVector`.
+pub fn tsweep_twisted(profile: &Wire, vector: Vector3, total_twist: Rad<f64>) -> Result<Shell> {
+    if vector.so_small() {
+        return Err(Error::ZeroSweepVector);
+    }
+    let origin = wire_centroid(profile);
+    let division = usize::max(f64::ceil(total_twist.0.abs() / (PI.0 / 2.0)) as usize, 1);
+    let angle = total_twist / division as f64;
+    let rise = vector.magnitude() / division as f64;
+    Ok(helix_multi_sweep(
+        profile,
+        origin,
+        vector.normalize(),
+        angle,
+        rise,
+        division,
+    ))
+}
+
+/// Sweeps `elem` along the helix by `division` segments, each of which rotates `elem` by `angle`
+/// and translates it by `rise` along `axis`.
+fn helix_multi_sweep<T: MultiSweep<Point3, Curve, Surface>>(
+    elem: &T,
+    origin: Point3,
+    axis: Vector3,
+    angle: Rad<f64>,
+    rise: f64,
+    division: usize,
+) -> T::Swept {
+    let trsl = helix_transform(origin, axis, angle, rise);
+    let section = move |k: usize| {
+        let ratio = k as f64 / HELIX_SECTIONS as f64;
+        helix_transform(origin, axis, angle * ratio, rise * ratio)
+    };
+    elem.multi_sweep(
+        &move |pt| trsl.transform_point(*pt),
+        &move |curve| curve.transformed(trsl),
+        &move |surface| surface.transformed(trsl),
+        &move |pt, _| {
+            let pts: Vec<Point3> = (0..=HELIX_SECTIONS)
+                .map(|k| section(k).transform_point(*pt))
+                .collect();
+            BSplineCurve::interpolate(&pts, 3)
+                .expect("a vertex on the axis cannot be swept without pitch.")
+                .into()
+        },
+        &move |curve, _| {
+            let curve = NurbsCurve::new(curve.clone().lift_up());
+            let curves = (0..=HELIX_SECTIONS)
+                .map(|k| curve.transformed(section(k)).into_non_rationalized())
+                .collect();
+            let params: Vec<f64> = (0..=HELIX_SECTIONS)
+                .map(|k| k as f64 / HELIX_SECTIONS as f64)
+                .collect();
+            let surface = BSplineSurface::skin_with_parameters(curves, &params, 3);
+            NurbsSurface::new(surface).into()
+        },
+        division,
+    )
+}
+
+/// Sweeps `profile` along `path`, moving it by the rotation-minimizing frame of `path`.
+/// # Details
+/// The profile is placed at the start point of the path, and is moved so that its relation to
+/// the path is kept without twisting. The path is divided into segments of at most a quarter turn,
+/// and each face is the skinned surface through the moved sections of a profile edge.
+/// If `path` is closed, the frames are slightly twisted so that the last section coincides with
+/// `profile`, and the result is a torus-like shell.
+/// # Failures
+/// - If the derivation of `path` vanishes at some point, returns [`Error::NonRegularPath`].
+/// - If the curvature radius of `path` is smaller than the distance from the start point of
+///   `path` to `profile` at some point, returns [`Error::TooSmallCurvatureRadius`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::new(0.2, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+/// let ctrl_pts = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(0.0, 0.0, 4.0),
+///     Point3::new(4.0, 0.0, 4.0),
+///     Point3::new(4.0, 0.0, 8.0),
+/// ];
+/// let path = BSplineCurve::new(KnotVec::bezier_knot(3), ctrl_pts);
+/// let pipe = builder::sweep_along(&circle, &Curve::BSplineCurve(path)).unwrap();
+/// assert_eq!(pipe.shell_condition(), ShellCondition::Oriented);
+///
+/// // the end of the pipe is the circle around the end point of the path
+/// let boundaries = pipe.extract_boundaries();
+/// let end = boundaries
+///     .iter()
+///     .find(|wire| wire.vertex_iter().all(|v| v.point().z > 1.0))
+///     .unwrap();
+/// end.vertex_iter().for_each(|v| {
+///     assert_near!(v.point().distance(Point3::new(4.0, 0.0, 8.0)), 0.2);
+/// });
+/// ```
+#[inline(always)]
+pub fn sweep_along(profile: &Wire, path: &Curve) -> Result<Shell> {
+    pipe::sweep_along(profile, path)
+}
+
+/// Returns the closed wires of the cross section of `solid` by `plane`.
+///
+/// The outer loops are counterclockwise and the holes are clockwise seen from the normal of
+/// `plane`, so each outer loop together with its holes can be attached by [`try_attach_plane`].
+/// The section through the planar faces is a line, and the other one is an intersection curve.
+/// The faces and the edges on which `plane` is tangent to `solid` are not in the section.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let plane = Plane::new(
+///     Point3::new(0.0, 0.0, 0.5),
+///     Point3::new(1.0, 0.0, 0.5),
+///     Point3::new(0.0, 1.0, 0.5),
+/// );
+/// let wires = builder::section(&cube, &plane);
+/// assert_eq!(wires.len(), 1);
+/// assert_eq!(wires[0].len(), 4);
+///
+/// let face = builder::try_attach_plane(&wires).unwrap();
+/// let normal = face.oriented_surface().normal(0.5, 0.5);
+/// assert_near!(normal, Vector3::unit_z());
+/// ```
+/// # Remarks
+/// The loops on the plane which do not cross any edges of `solid` are not detected.
+#[inline(always)]
+pub fn section(solid: &Solid, plane: &Plane) -> Vec<Wire> { section::section(solid, plane) }
+
+/// Rounds `edge` of `solid` by the rolling ball blend with `radius`.
+///
+/// The edge is replaced by a part of the cylinder, and the adjacent faces are retrimmed.
+/// For now, only the following configuration is supported, and the other ones return
+/// [`Error::UnsupportedBlend`] with the reason.
+/// - The edge is a line shared by exactly two planar faces, and the edge is convex.
+/// - Each end of the edge is shared by exactly three edges, i.e. the edge itself and
+///   one line on each adjacent face, and the lines are perpendicular to the edge.
+/// - The faces at the ends, which contain the two lines, are planes.
+/// - The lines at the ends are longer than the distance from the edge to the contact lines
+///   of the ball, i.e. the radius is not too large.
+///
+/// If `edge` is not contained in `solid`, returns [`Error::EdgeNotFound`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // the unit cube
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the vertical edge at (1, 1)
+/// let edge = cube
+///     .edge_iter()
+///     .find(|edge| {
+///         let (p, q) = (edge.front().point(), edge.back().point());
+///         p.x == 1.0 && p.y == 1.0 && q.x == 1.0 && q.y == 1.0
+///     })
+///     .unwrap();
+/// let filleted = builder::fillet_edge(&cube, &edge, 0.2).unwrap();
+/// assert_eq!(filleted.boundaries()[0].len(), 7);
+/// assert!(filleted.is_geometric_consistent());
+/// ```
+#[inline(always)]
+pub fn fillet_edge(solid: &Solid, edge: &Edge, radius: f64) -> Result<Solid> {
+    blend::blend_edge(solid, edge, blend::Profile::Round(radius))
+}
+
+/// Bevels `edge` of `solid` by the plane through the lines at `distance` from the edge
+/// on the adjacent faces.
+///
+/// The edge is replaced by the planar face, and the adjacent faces are retrimmed.
+/// The supported configuration is the same as the one of [`fillet_edge`], where the radius is
+/// replaced by the distance.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::collections::HashSet;
+///
+/// // the unit cube
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let edge = cube.edge_iter().next().unwrap();
+/// let chamfered = builder::chamfer_edge(&cube, &edge, 0.2).unwrap();
+/// // one face, three edges, and two vertices are added.
+/// let edges: HashSet<EdgeID> = chamfered.edge_iter().map(|e| e.id()).collect();
+/// let vertices: HashSet<VertexID> = chamfered.vertex_iter().map(|v| v.id()).collect();
+/// assert_eq!(chamfered.face_iter().count(), 7);
+/// assert_eq!(edges.len(), 15);
+/// assert_eq!(vertices.len(), 10);
+/// assert!(chamfered.is_geometric_consistent());
+/// ```
+#[inline(always)]
+pub fn chamfer_edge(solid: &Solid, edge: &Edge, distance: f64) -> Result<Solid> {
+    blend::blend_edge(solid, edge, blend::Profile::Bevel(distance))
+}
+
+/// Hollows `solid` by the wall of `thickness`, and opens `open_faces`.
+///
+/// Every face is offset inward by `thickness`. The planes are translated, the revolved surfaces
+/// are revolved from the offset profiles, and the other surfaces are approximated by B-spline
+/// surfaces. If `open_faces` is empty, the inner faces bound the cavity as the second boundary
+/// shell. Otherwise, `open_faces` and their offsets are removed, and the outer and inner faces are
+/// stitched by the side walls around the openings.
+///
+/// For now, only the following configuration is supported, and the other ones return
+/// [`Error::UnsupportedShell`] with the reason.
+/// - The solid has exactly one boundary shell.
+/// - Each vertex is shared by at most three faces up to the tangent ones, and is not the apex of
+///   a cone.
+/// - The faces tangent along an edge are offset to a line or an arc along the edge.
+/// - The open faces are not adjacent to each other.
+///
+/// If the thickness exceeds the local feature size, i.e. some offset surface folds itself or some
+/// offset edge is collapsed or reversed, returns [`Error::ThicknessTooLarge`]. If some of
+/// `open_faces` are not contained in `solid`, returns [`Error::FaceNotFound`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // the unit cube
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // opens the top face
+/// let top = cube
+///     .face_iter()
+///     .find(|face| face.vertex_iter().all(|v| v.point().z == 1.0))
+///     .unwrap()
+///     .clone();
+/// let cup = builder::shell(&cube, 0.1, &[top]).unwrap();
+/// // five outer faces, four walls, and five inner faces
+/// assert_eq!(cup.boundaries()[0].len(), 14);
+/// assert!(cup.is_geometric_consistent());
+///
+/// // too thick wall
+/// assert_eq!(builder::shell(&cube, 0.6, &[]), Err(errors::Error::ThicknessTooLarge));
+///
+/// // the cylinder is hollowed into the pipe with the inner radius 0.9
+/// let cylinder = builder::cylinder(Point3::origin(), 1.0, 2.0, Vector3::unit_z());
+/// let pipe = builder::shell(&cylinder, 0.1, &[]).unwrap();
+/// assert!(pipe.is_geometric_consistent());
+/// pipe.boundaries()[1].vertex_iter().for_each(|v| {
+///     let pt = v.point();
+///     assert_near!(Vector2::new(pt.x, pt.y).magnitude(), 0.9);
+/// });
+/// ```
+#[inline(always)]
+pub fn shell(solid: &Solid, thickness: f64, open_faces: &[Face]) -> Result<Solid> {
+    hollow::hollow(solid, thickness, open_faces)
+}
+
 fn partial_rsweep<T: MultiSweep<Point3, Curve, Surface>>(
     elem: &T,
     origin: Point3,
@@ -666,6 +1583,16 @@ fn partial_rsweep<T: MultiSweep<Point3, Curve, Surface>>(
     )
 }
 
+/// the number of the intervals between the sections interpolated in a segment of helical sweep
+const HELIX_SECTIONS: usize = 8;
+
+fn helix_transform(origin: Point3, axis: Vector3, angle: Rad<f64>, rise: f64) -> Matrix4 {
+    let mat0 = Matrix4::from_translation(-origin.to_vec());
+    let mat1 = Matrix4::from_axis_angle(axis, angle);
+    let mat2 = Matrix4::from_translation(origin.to_vec() + axis * rise);
+    mat2 * mat1 * mat0
+}
+
 fn whole_rsweep<T: ClosedSweep<Point3, Curve, Surface>>(
     elem: &T,
     origin: Point3,
@@ -757,3 +1684,315 @@ mod partial_torus {
         assert!(torus.is_geometric_consistent());
     }
 }
+
+#[cfg(test)]
+mod intersection_sweep {
+    use super::*;
+
+    #[test]
+    fn tsweep_section_of_torus() {
+        let v = vertex(Point3::new(3.0, 0.0, 0.0));
+        let circle = rsweep(&v, Point3::new(2.0, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+        let torus = Solid::new(vec![rsweep(
+            &circle,
+            Point3::origin(),
+            Vector3::unit_z(),
+            Rad(7.0),
+        )]);
+        let plane = Plane::new(
+            Point3::new(0.0, 0.0, 0.5),
+            Point3::new(1.0, 0.0, 0.5),
+            Point3::new(0.0, 1.0, 0.5),
+        );
+        let wires = section(&torus, &plane);
+        assert_eq!(wires.len(), 2);
+        let r = f64::sqrt(0.75);
+        for wire in &wires {
+            assert!(wire
+                .edge_iter()
+                .all(|edge| matches!(edge.curve(), Curve::IntersectionCurve(_))));
+            let shell: Shell = tsweep(wire, Vector3::unit_z());
+            assert_eq!(shell.len(), wire.len());
+            assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+            assert!(shell.is_geometric_consistent());
+            let shifted = Surface::Plane(Plane::new(
+                Point3::new(0.0, 0.0, 1.5),
+                Point3::new(1.0, 0.0, 1.5),
+                Point3::new(0.0, 1.0, 1.5),
+            ));
+            assert!(!shifted.include(&wire.front_edge().unwrap().curve()));
+            shell.iter().for_each(|face| {
+                let Surface::BSplineSurface(surface) = face.surface() else {
+                    panic!("the swept surface is not a B-spline surface.");
+                };
+                let ((u0, u1), (v0, v1)) = surface.range_tuple();
+                (0..=4)
+                    .flat_map(|i| (0..=4).map(move |j| (i, j)))
+                    .for_each(|(i, j)| {
+                        let u = u0 + (u1 - u0) * i as f64 / 4.0;
+                        let v = v0 + (v1 - v0) * j as f64 / 4.0;
+                        let pt = surface.subs(u, v);
+                        let radius = f64::hypot(pt.x, pt.y);
+                        let dist = f64::min(f64::abs(radius - 2.0 - r), f64::abs(radius - 2.0 + r));
+                        assert!(dist < 0.05, "{pt:?}");
+                        assert!(pt.z.near(&(0.5 + v)));
+                    });
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod loft {
+    use super::*;
+
+    #[test]
+    fn loft_circles() {
+        let wires: Vec<Wire> = [(1.0, 0.0), (2.0, 1.0), (1.0, 2.0), (1.5, 4.0)]
+            .into_iter()
+            .map(|(radius, height)| {
+                let v = vertex(Point3::new(radius, 0.0, height));
+                rsweep(
+                    &v,
+                    Point3::new(0.0, 0.0, height),
+                    Vector3::unit_z(),
+                    Rad(7.0),
+                )
+            })
+            .collect();
+        let shell = loft(&wires).unwrap();
+        assert_eq!(shell.len(), wires[0].len() * 3);
+        assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+        assert!(shell.is_geometric_consistent());
+
+        // the strips are smoothly connected across the wires
+        wires[1..3].iter().for_each(|wire| {
+            wire.edge_iter().for_each(|edge| {
+                let faces: Vec<&Face> = shell
+                    .iter()
+                    .filter(|face| face.edge_iter().any(|e| e.is_same(edge)))
+                    .collect();
+                assert_eq!(faces.len(), 2);
+                let curve = edge.oriented_curve();
+                let (t0, t1) = curve.range_tuple();
+                let pt = curve.subs((t0 + t1) / 2.0);
+                let [n0, n1] = [faces[0], faces[1]].map(|face| {
+                    let surface = face.oriented_surface();
+                    let (u, v) = surface.search_parameter(pt, None, 100).unwrap();
+                    surface.normal(u, v)
+                });
+                assert_near!(n0, n1);
+            });
+        });
+
+        // the surface is radial, not piecewise-linear between the wires
+        let pt = shell[0].oriented_surface().subs(0.5, 0.5);
+        assert!(f64::hypot(pt.x, pt.y) > 1.1);
+    }
+
+    #[test]
+    fn loft_failures() {
+        let square = |z: f64, n: usize| -> Wire {
+            let v: Vec<Vertex> = (0..n)
+                .map(|i| {
+                    let t = 2.0 * std::f64::consts::PI * i as f64 / n as f64;
+                    vertex(Point3::new(f64::cos(t), f64::sin(t), z))
+                })
+                .collect();
+            (0..n).map(|i| line(&v[i], &v[(i + 1) % n])).collect()
+        };
+        assert_eq!(
+            loft(&[square(0.0, 4), square(1.0, 3)]),
+            Err(Error::NotSameNumberOfEdges),
+        );
+        assert_eq!(
+            loft(&[square(0.0, 4), square(1.0, 4), square(1.0, 4)]),
+            Err(Error::CoincidentWires(1)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod helical {
+    use super::*;
+
+    #[test]
+    fn helix_rails() {
+        // the triangular profile of a screw thread
+        let v = [
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(1.2, 0.0, 0.1),
+            Point3::new(1.0, 0.0, 0.2),
+        ]
+        .map(vertex);
+        let wire: Wire = vec![line(&v[0], &v[1]), line(&v[1], &v[2]), line(&v[2], &v[0])].into();
+        [(0.5, 2.3), (0.5, -1.0), (-0.3, 0.7)]
+            .into_iter()
+            .for_each(|(pitch, turns)| {
+                let shell = helical_sweep(&wire, Point3::origin(), Vector3::unit_z(), pitch, turns);
+                assert_eq!(shell.len(), 3 * f64::ceil(f64::abs(turns) * 4.0) as usize);
+                assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+                assert!(shell.is_geometric_consistent());
+                let shifted = Surface::Plane(Plane::new(
+                    Point3::new(0.0, 0.0, 1.5),
+                    Point3::new(1.0, 0.0, 1.5),
+                    Point3::new(0.0, 1.0, 1.5),
+                ));
+                assert!(!shifted.include(&wire.front_edge().unwrap().curve()));
+                shell.edge_iter().for_each(|edge| {
+                    let curve = edge.curve();
+                    let Curve::BSplineCurve(_) = curve else {
+                        return;
+                    };
+                    // the points on the rails are on the helices
+                    let (t0, t1) = curve.range_tuple();
+                    let start = curve.subs(t0);
+                    let start_angle = f64::atan2(start.y, start.x);
+                    (0..=10).for_each(|i| {
+                        let pt = curve.subs(t0 + (t1 - t0) * i as f64 / 10.0);
+                        let radius = f64::hypot(start.x, start.y);
+                        assert!(f64::abs(f64::hypot(pt.x, pt.y) - radius) < 1.0e-4);
+                        let mut angle = f64::atan2(pt.y, pt.x) - start_angle;
+                        angle -= f64::round(angle / (2.0 * PI.0)) * 2.0 * PI.0;
+                        let rise = pt.z - start.z;
+                        let expected = pitch * angle / (2.0 * PI.0);
+                        assert!(f64::abs(rise - expected) < 1.0e-4, "{rise} {expected}");
+                    });
+                });
+            });
+    }
+}
+
+#[cfg(test)]
+mod primitives {
+    use super::*;
+
+    /// Checks that the normals of the faces of the convex `solid` point outward from `inner`.
+    fn test_outward(solid: &Solid, inner: Point3) {
+        assert!(solid.is_geometric_consistent());
+        solid.face_iter().for_each(|face| {
+            let surface = face.oriented_surface();
+            face.boundaries()[0].vertex_iter().for_each(|v| {
+                let (u, v) = surface.search_parameter(v.point(), None, 100).unwrap();
+                let normal = surface.normal(u, v);
+                if normal.magnitude().near(&1.0) {
+                    assert!(normal.dot(surface.subs(u, v) - inner) > -TOLERANCE);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn mirrored_cube() {
+        let (x, y, z) = (Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z());
+        let cube = cube(Point3::origin(), x, y, z);
+        // the plane of the face at x = 1, and an oblique plane
+        let planes = [
+            Plane::new(
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(1.0, 0.0, 1.0),
+            ),
+            Plane::new(
+                Point3::new(0.0, 0.0, 2.0),
+                Point3::new(1.0, 1.0, 2.0),
+                Point3::new(1.0, 0.0, 3.0),
+            ),
+        ];
+        planes.iter().for_each(|plane| {
+            let mirrored = mirrored(&cube, plane);
+            assert!(mirrored.is_geometric_consistent());
+            assert_eq!(
+                mirrored.boundaries()[0].shell_condition(),
+                ShellCondition::Closed
+            );
+            let center = Point3::new(0.5, 0.5, 0.5);
+            let (origin, normal) = (plane.origin(), plane.normal());
+            let inner = center - normal * 2.0 * normal.dot(center - origin);
+            test_outward(&mirrored, inner);
+        });
+    }
+
+    #[test]
+    fn primitive_solids() {
+        let (x, y, z) = (Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z());
+        let right = cube(Point3::origin(), x, y, z);
+        test_outward(&right, Point3::new(0.5, 0.5, 0.5));
+        let left = cube(Point3::origin(), y, x, z);
+        test_outward(&left, Point3::new(0.5, 0.5, 0.5));
+
+        let center = Point3::new(1.0, -1.0, 2.0);
+        let sphere = sphere(center, 0.5);
+        test_outward(&sphere, center);
+        assert_eq!(
+            sphere.boundaries()[0].shell_condition(),
+            ShellCondition::Closed
+        );
+
+        let axis = Vector3::new(1.0, 2.0, -2.0).normalize();
+        let cylinder = cylinder(center, 0.5, 3.0, axis);
+        test_outward(&cylinder, center + axis * 1.5);
+        assert_eq!(
+            cylinder.boundaries()[0].shell_condition(),
+            ShellCondition::Closed
+        );
+
+        let cone = cone_solid(center, 1.0, 2.0, axis);
+        test_outward(&cone, center + axis * 0.5);
+        assert_eq!(
+            cone.boundaries()[0].shell_condition(),
+            ShellCondition::Closed
+        );
+    }
+
+    #[test]
+    fn tsweep_scaled_non_positive_scale() {
+        let profile = polygon(&[
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ])
+        .unwrap();
+        [0.0, -1.0, f64::NAN].into_iter().for_each(|scale| {
+            assert!(matches!(
+                tsweep_scaled(&profile, Vector3::unit_z(), scale),
+                Err(Error::NonPositiveScale)
+            ));
+        });
+    }
+
+    #[test]
+    fn tsweep_scaled_mixed_profile() {
+        // the D-shaped profile of a line and a circle arc
+        let v = Vertex::news([Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)]);
+        let profile: Wire = vec![
+            line(&v[0], &v[1]),
+            circle_arc(&v[1], &v[0], Point3::new(0.0, 1.0, 0.0)),
+        ]
+        .into();
+        let shell = tsweep_scaled(&profile, Vector3::new(0.0, 0.0, 2.0), 0.5).unwrap();
+        assert_eq!(shell.len(), 2);
+        assert!(matches!(shell[0].surface(), Surface::Plane(_)));
+        assert!(matches!(shell[1].surface(), Surface::NurbsSurface(_)));
+        assert!(shell.is_geometric_consistent());
+
+        let boundaries = shell.extract_boundaries();
+        assert_eq!(boundaries.len(), 2);
+        let top = boundaries
+            .iter()
+            .find(|wire| wire.vertex_iter().all(|v| v.point().z.near(&2.0)))
+            .unwrap();
+        let pts: Vec<Point3> = top.vertex_iter().map(|v| v.point()).collect();
+        assert_eq!(pts.len(), 2);
+        assert_near!(pts[0].distance(pts[1]), 1.0);
+        // the middle of the arc is also scaled about the same center
+        let arc = top
+            .iter()
+            .find(|edge| matches!(edge.curve(), Curve::NurbsCurve(_)));
+        let curve = arc.unwrap().oriented_curve();
+        let (t0, t1) = curve.range_tuple();
+        let mid = curve.subs((t0 + t1) / 2.0);
+        let chord_mid = pts[0].midpoint(pts[1]);
+        assert_near!(mid.distance(chord_mid), 0.5);
+    }
+}
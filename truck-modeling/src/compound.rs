@@ -0,0 +1,337 @@
+use crate::*;
+use truck_polymesh::PolygonMesh;
+
+/// Assembly of parts placed by the transforms.
+///
+/// Each part is a pair of the transform matrix and the shape in the local coordinates, and
+/// may have a name. The shapes are not flattened into the global coordinates, so the same
+/// solid can be shared by several parts. The default part is [`Solid`], and
+/// [`Compound::map_parts`] converts the parts into other types, e.g. the meshes, keeping
+/// the transforms.
+///
+/// [`Mapped`], and hence [`builder::transformed`], maps the whole assembly in the global
+/// coordinates, keeping the transforms of the parts. [`Compound::moved`] moves the whole
+/// assembly by premultiplying the transforms without mapping the shapes.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let mut compound = Compound::new();
+/// compound.push(Matrix4::identity(), cube.clone());
+/// compound.push_named(
+///     "moved",
+///     Matrix4::from_translation(Vector3::new(2.0, 0.0, 0.0)),
+///     cube,
+/// );
+/// assert_eq!(compound.len(), 2);
+/// assert_eq!(compound.name(1), Some("moved"));
+/// assert_eq!(compound.position("moved"), Some(1));
+///
+/// // the parts are placed in the global coordinates by the transforms.
+/// let solids = compound.flatten();
+/// let x_max = solids[1]
+///     .vertex_iter()
+///     .map(|v| v.point().x)
+///     .fold(f64::NEG_INFINITY, f64::max);
+/// assert_near!(x_max, 3.0);
+///
+/// // the builder functions act on the whole assembly.
+/// let plane = Plane::new(
+///     Point3::origin(),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.0, 0.0, 1.0),
+/// );
+/// let mirrored = builder::mirrored(&compound, &plane);
+/// assert_eq!(mirrored.parts()[1].0, compound.parts()[1].0);
+/// let solids = mirrored.flatten();
+/// let x_min = solids[1]
+///     .vertex_iter()
+///     .map(|v| v.point().x)
+///     .fold(f64::INFINITY, f64::min);
+/// assert_near!(x_min, -3.0);
+/// let translated = builder::translated(&compound, Vector3::new(0.0, 0.0, 1.0));
+/// let solids = translated.flatten();
+/// let z_min = solids[1]
+///     .vertex_iter()
+///     .map(|v| v.point().z)
+///     .fold(f64::INFINITY, f64::min);
+/// assert_near!(z_min, 1.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Compound<T = Solid> {
+    parts: Vec<(Matrix4, T)>,
+    names: Vec<Option<String>>,
+}
+
+impl<T> Compound<T> {
+    /// Creates the empty compound.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            parts: Vec::new(),
+            names: Vec::new(),
+        }
+    }
+
+    /// Adds the part without name, and returns the index of the part.
+    #[inline(always)]
+    pub fn push(&mut self, matrix: Matrix4, part: T) -> usize {
+        self.parts.push((matrix, part));
+        self.names.push(None);
+        self.parts.len() - 1
+    }
+
+    /// Adds the named part, and returns the index of the part.
+    #[inline(always)]
+    pub fn push_named(&mut self, name: impl Into<String>, matrix: Matrix4, part: T) -> usize {
+        self.parts.push((matrix, part));
+        self.names.push(Some(name.into()));
+        self.parts.len() - 1
+    }
+
+    /// Returns the number of the parts.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.parts.len() }
+
+    /// Returns whether the compound has no parts.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.parts.is_empty() }
+
+    /// Returns the parts, the pairs of the transforms and the shapes.
+    #[inline(always)]
+    pub fn parts(&self) -> &[(Matrix4, T)] { &self.parts }
+
+    /// Returns the mutable parts, the pairs of the transforms and the shapes.
+    #[inline(always)]
+    pub fn parts_mut(&mut self) -> &mut [(Matrix4, T)] { &mut self.parts }
+
+    /// Returns the iterator over the parts.
+    #[inline(always)]
+    pub fn iter(&self) -> std::slice::Iter<'_, (Matrix4, T)> { self.parts.iter() }
+
+    /// Returns the mutable iterator over the parts.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, (Matrix4, T)> { self.parts.iter_mut() }
+
+    /// Returns the name of the `idx`th part.
+    /// Returns `None` if the part has no name or `idx` is out of range.
+    #[inline(always)]
+    pub fn name(&self, idx: usize) -> Option<&str> { self.names.get(idx)?.as_deref() }
+
+    /// Returns the index of the first part named `name`.
+    #[inline(always)]
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|x| x.as_deref() == Some(name))
+    }
+
+    /// Returns the compound whose transforms are premultiplied by `matrix`, i.e. the whole
+    /// assembly is moved by `matrix`. The shapes of the parts are shared with `self`.
+    #[inline(always)]
+    pub fn moved(&self, matrix: Matrix4) -> Self
+    where T: Clone {
+        Self {
+            parts: self
+                .parts
+                .iter()
+                .map(|(mat, part)| (matrix * mat, part.clone()))
+                .collect(),
+            names: self.names.clone(),
+        }
+    }
+
+    /// Returns the compound whose shapes are converted by `f`, keeping the transforms and the names.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let mut compound = Compound::new();
+    /// compound.push_named("a", Matrix4::from_scale(2.0), v.clone());
+    /// compound.push(Matrix4::identity(), v);
+    /// let points = compound.map_parts(|v| v.point());
+    /// assert_eq!(points.parts()[0], (Matrix4::from_scale(2.0), Point3::origin()));
+    /// assert_eq!(points.name(0), Some("a"));
+    /// assert_eq!(points.name(1), None);
+    /// ```
+    #[inline(always)]
+    pub fn map_parts<U>(&self, mut f: impl FnMut(&T) -> U) -> Compound<U> {
+        Compound {
+            parts: self
+                .parts
+                .iter()
+                .map(|(mat, part)| (*mat, f(part)))
+                .collect(),
+            names: self.names.clone(),
+        }
+    }
+}
+
+impl<T: Mapped<Point3, Curve, Surface>> Compound<T> {
+    /// Returns the shapes placed in the global coordinates by [`builder::transformed`].
+    #[inline(always)]
+    pub fn flatten(&self) -> Vec<T> {
+        self.parts
+            .iter()
+            .map(|(mat, part)| builder::transformed(part, *mat))
+            .collect()
+    }
+}
+
+impl Compound {
+    /// Returns the bounding box including the solids in the global coordinates.
+    ///
+    /// The box is the union of the transformed bounding boxes of the vertices, the control
+    /// points of the curves and the surfaces, and the circles drawn by the revolved curves,
+    /// so it contains the solids but may be larger than the tight one.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let cylinder = builder::cylinder(Point3::origin(), 1.0, 2.0, Vector3::unit_z());
+    /// let mut compound = Compound::new();
+    /// compound.push(Matrix4::identity(), cylinder.clone());
+    /// compound.push(Matrix4::from_translation(Vector3::new(5.0, 0.0, 0.0)), cylinder);
+    /// let bdd_box = compound.bounding_box();
+    /// // close to the tight box [-1, 6] x [-1, 1] x [0, 2]
+    /// assert!(bdd_box.min().distance(Point3::new(-1.0, -1.0, 0.0)) < 0.05);
+    /// assert!(bdd_box.max().distance(Point3::new(6.0, 1.0, 2.0)) < 0.05);
+    /// ```
+    pub fn bounding_box(&self) -> BoundingBox<Point3> {
+        self.parts
+            .iter()
+            .map(|(mat, solid)| {
+                let mut bdd_box: BoundingBox<Point3> =
+                    solid.vertex_iter().map(|v| v.point()).collect();
+                solid
+                    .edge_iter()
+                    .for_each(|edge| bdd_box += curve_bounding_box(&edge.curve()));
+                solid
+                    .face_iter()
+                    .for_each(|face| bdd_box += surface_bounding_box(&face.surface()));
+                transformed_box(bdd_box, *mat)
+            })
+            .fold(BoundingBox::new(), |sum, bdd_box| sum + bdd_box)
+    }
+}
+
+/// Returns the bounding box including the curve by the control points.
+fn curve_bounding_box(curve: &Curve) -> BoundingBox<Point3> {
+    match curve {
+        Curve::Line(line) => [line.0, line.1].into_iter().collect(),
+        Curve::BSplineCurve(curve) => curve.roughly_bounding_box(),
+        Curve::NurbsCurve(curve) => curve.roughly_bounding_box(),
+        Curve::IntersectionCurve(curve) => match curve.leader() {
+            Leader::Polyline(polyline) => polyline.0.iter().collect(),
+            Leader::BSpline(curve) => curve.roughly_bounding_box(),
+        },
+    }
+}
+
+/// Returns the bounding box including the surface. The planes are bounded by their edges.
+fn surface_bounding_box(surface: &Surface) -> BoundingBox<Point3> {
+    match surface {
+        Surface::Plane(_) => BoundingBox::new(),
+        Surface::BSplineSurface(surface) => surface.roughly_bounding_box(),
+        Surface::NurbsSurface(surface) => surface.roughly_bounding_box(),
+        Surface::RevolutedCurve(surface) => {
+            let revolved = surface.entity();
+            let (origin, axis) = (revolved.origin(), revolved.axis().normalize());
+            // the revolved box is contained in the hull of the circles drawn by the corners.
+            let bdd_box = corners(curve_bounding_box(revolved.entity_curve()))
+                .into_iter()
+                .flat_map(|pt| {
+                    let center = origin + axis * (pt - origin).dot(axis);
+                    let radius = pt.distance(center);
+                    let extent = Vector3::new(
+                        f64::sqrt(f64::max(1.0 - axis.x * axis.x, 0.0)),
+                        f64::sqrt(f64::max(1.0 - axis.y * axis.y, 0.0)),
+                        f64::sqrt(f64::max(1.0 - axis.z * axis.z, 0.0)),
+                    ) * radius;
+                    [center + extent, center - extent]
+                })
+                .collect();
+            transformed_box(bdd_box, *surface.transform())
+        }
+    }
+}
+
+fn corners(bdd_box: BoundingBox<Point3>) -> Vec<Point3> {
+    if bdd_box.is_empty() {
+        return Vec::new();
+    }
+    let (min, max) = (bdd_box.min(), bdd_box.max());
+    let coord = |i: usize, j: usize| match (i >> j) & 1 {
+        0 => min[j],
+        _ => max[j],
+    };
+    (0..8)
+        .map(|i| Point3::new(coord(i, 0), coord(i, 1), coord(i, 2)))
+        .collect()
+}
+
+fn transformed_box(bdd_box: BoundingBox<Point3>, matrix: Matrix4) -> BoundingBox<Point3> {
+    corners(bdd_box)
+        .into_iter()
+        .map(|pt| matrix.transform_point(pt))
+        .collect()
+}
+
+impl Compound<PolygonMesh> {
+    /// Returns the bounding box of the meshes in the global coordinates.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// use truck_polymesh::{Faces, PolygonMesh, StandardAttributes};
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter([[0, 1, 2]]),
+    /// );
+    /// let mut compound = Compound::new();
+    /// compound.push(Matrix4::identity(), mesh.clone());
+    /// compound.push(Matrix4::from_translation(Vector3::new(0.0, 0.0, 3.0)), mesh);
+    /// let bdd_box = compound.bounding_box();
+    /// assert_near!(bdd_box.min(), Point3::new(0.0, 0.0, 0.0));
+    /// assert_near!(bdd_box.max(), Point3::new(1.0, 1.0, 3.0));
+    /// ```
+    pub fn bounding_box(&self) -> BoundingBox<Point3> {
+        self.parts
+            .iter()
+            .flat_map(|(mat, mesh)| mesh.positions().iter().map(|p| mat.transform_point(*p)))
+            .collect()
+    }
+}
+
+impl<T> Default for Compound<T> {
+    #[inline(always)]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T> FromIterator<(Matrix4, T)> for Compound<T> {
+    #[inline(always)]
+    fn from_iter<I: IntoIterator<Item = (Matrix4, T)>>(iter: I) -> Self {
+        let parts: Vec<_> = iter.into_iter().collect();
+        let names = vec![None; parts.len()];
+        Self { parts, names }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Compound<T> {
+    type Item = &'a (Matrix4, T);
+    type IntoIter = std::slice::Iter<'a, (Matrix4, T)>;
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter { self.parts.iter() }
+}
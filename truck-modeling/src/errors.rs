@@ -14,6 +14,58 @@ pub enum Error {
     /// cf. [`builder::try_wire_homotopy`](../builder/fn.try_wire_homotopy.html)
     #[error("The wires must contain the same number of edges to create a homotopy.")]
     NotSameNumberOfEdges,
+    /// tried to loft through two adjacent wires whose vertices coincide.
+    /// cf. [`builder::loft`](../builder/fn.loft.html)
+    #[error("The {0}th wire and the next one coincide.")]
+    CoincidentWires(usize),
+    /// tried to fillet or chamfer an edge which is not contained in the solid.
+    /// cf. [`builder::fillet_edge`](../builder/fn.fillet_edge.html),
+    /// [`builder::chamfer_edge`](../builder/fn.chamfer_edge.html)
+    #[error("The edge to be blended is not contained in the solid.")]
+    EdgeNotFound,
+    /// tried to fillet or chamfer an edge in the configuration not supported yet.
+    /// cf. [`builder::fillet_edge`](../builder/fn.fillet_edge.html),
+    /// [`builder::chamfer_edge`](../builder/fn.chamfer_edge.html)
+    #[error("This fillet or chamfer is not supported: {0}")]
+    UnsupportedBlend(&'static str),
+    /// tried to open a face which is not contained in the solid.
+    /// cf. [`builder::shell`](../builder/fn.shell.html)
+    #[error("The face to be opened is not contained in the solid.")]
+    FaceNotFound,
+    /// tried to shell a solid in the configuration not supported yet.
+    /// cf. [`builder::shell`](../builder/fn.shell.html)
+    #[error("This shelling is not supported: {0}")]
+    UnsupportedShell(&'static str),
+    /// the thickness exceeds the local feature size, and the offset faces intersect themselves.
+    /// cf. [`builder::shell`](../builder/fn.shell.html)
+    #[error("The thickness is too large: the offset faces intersect themselves.")]
+    ThicknessTooLarge,
+    /// the curvature radius of the path is smaller than the extent of the profile.
+    /// cf. [`builder::sweep_along`](../builder/fn.sweep_along.html)
+    #[error("The curvature radius of the path is smaller than the extent of the profile.")]
+    TooSmallCurvatureRadius,
+    /// tried to sweep along a path whose derivation vanishes at some point.
+    /// cf. [`builder::sweep_along`](../builder/fn.sweep_along.html)
+    #[error("The path is not regular: the derivation vanishes.")]
+    NonRegularPath,
+    /// tried to create a polygon from less than three points, or a spline through less than
+    /// two vertices. cf. [`builder::polygon`](../builder/fn.polygon.html),
+    /// [`builder::interpolated_spline`](../builder/fn.interpolated_spline.html)
+    #[error("The number of the points {0} is too few.")]
+    TooFewPoints(usize),
+    /// tried to create a polygon or a spline whose adjacent points coincide.
+    /// cf. [`builder::polygon`](../builder/fn.polygon.html),
+    /// [`builder::interpolated_spline`](../builder/fn.interpolated_spline.html)
+    #[error("The {0}th point and the next one coincide.")]
+    CoincidentPoints(usize),
+    /// tried to sweep with scaling by a non-positive factor.
+    /// cf. [`builder::tsweep_scaled`](../builder/fn.tsweep_scaled.html)
+    #[error("The scale factor must be positive.")]
+    NonPositiveScale,
+    /// tried to sweep along the zero vector.
+    /// cf. [`builder::tsweep_twisted`](../builder/fn.tsweep_twisted.html)
+    #[error("The sweep vector must be non-zero.")]
+    ZeroSweepVector,
 }
 
 #[test]
@@ -31,6 +83,33 @@ fn print_messages() {
     )
     .unwrap();
     writeln!(&mut std::io::stderr(), "{}\n", Error::WireNotInOnePlane).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::CoincidentWires(1)).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::EdgeNotFound).unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "{}\n",
+        Error::UnsupportedBlend("the edge must be convex.")
+    )
+    .unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::FaceNotFound).unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "{}\n",
+        Error::UnsupportedShell("the faces must be planes.")
+    )
+    .unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::ThicknessTooLarge).unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "{}\n",
+        Error::TooSmallCurvatureRadius
+    )
+    .unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::NonRegularPath).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::TooFewPoints(2)).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::CoincidentPoints(1)).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::NonPositiveScale).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::ZeroSweepVector).unwrap();
     writeln!(
         &mut std::io::stderr(),
         "*******************************************************"
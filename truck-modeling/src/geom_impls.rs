@@ -13,7 +13,7 @@ pub(super) fn circle_arc_by_three_points(
     circle_arc(point0, origin, axis, angle * 2.0)
 }
 
-fn circum_center(pt0: Point3, pt1: Point3, pt2: Point3) -> Point3 {
+pub(super) fn circum_center(pt0: Point3, pt1: Point3, pt2: Point3) -> Point3 {
     let (vec0, vec1) = (pt1 - pt0, pt2 - pt0);
     let (a2, ab, b2) = (vec0.dot(vec0), vec0.dot(vec1), vec1.dot(vec1));
     let (det, u, v) = (a2 * b2 - ab * ab, a2 * b2 - ab * b2, a2 * b2 - ab * a2);
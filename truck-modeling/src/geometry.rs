@@ -211,30 +211,66 @@ impl Transformed<Matrix4> for Surface {
     }
 }
 
+impl Surface {
+    /// Returns whether the points of the intersection curve are on the surface up to the
+    /// tolerance with which the intersection curve was generated.
+    fn include_intersection_curve(&self, curve: &IntersectionCurve<Leader, Surface>) -> bool {
+        let tol = curve.tolerance();
+        let (t0, t1) = curve.range_tuple();
+        let mut hint = None;
+        (0..=INCLUDE_CURVE_DIVISION).all(|i| {
+            let pt = curve.subs(t0 + (t1 - t0) * i as f64 / INCLUDE_CURVE_DIVISION as f64);
+            let res = hint
+                .and_then(|(u, v)| self.search_nearest_parameter(pt, (u, v), INCLUDE_CURVE_TRIALS))
+                .or_else(|| self.search_nearest_parameter(pt, None, INCLUDE_CURVE_TRIALS));
+            hint = res;
+            res.is_some_and(|(u, v)| self.subs(u, v).distance(pt) < tol)
+        })
+    }
+}
+
+const INCLUDE_CURVE_DIVISION: usize = 16;
+const INCLUDE_CURVE_TRIALS: usize = 100;
+
 impl IncludeCurve<Curve> for Surface {
     #[inline(always)]
     fn include(&self, curve: &Curve) -> bool {
+        if let Curve::IntersectionCurve(curve) = curve {
+            return self.include_intersection_curve(curve);
+        }
         match self {
             Surface::BSplineSurface(surface) => match curve {
                 Curve::Line(curve) => surface.include(&curve.to_bspline()),
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NurbsCurve(curve) => surface.include(curve),
-                Curve::IntersectionCurve(_) => unimplemented!(),
+                Curve::IntersectionCurve(_) => unreachable!(),
             },
             Surface::NurbsSurface(surface) => match curve {
                 Curve::Line(curve) => surface.include(&curve.to_bspline()),
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NurbsCurve(curve) => surface.include(curve),
-                Curve::IntersectionCurve(_) => unimplemented!(),
+                Curve::IntersectionCurve(_) => unreachable!(),
             },
             Surface::Plane(surface) => match curve {
                 Curve::Line(curve) => surface.include(&curve.to_bspline()),
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NurbsCurve(curve) => surface.include(curve),
-                Curve::IntersectionCurve(_) => unimplemented!(),
+                Curve::IntersectionCurve(_) => unreachable!(),
             },
             Surface::RevolutedCurve(surface) => match surface.entity_curve() {
-                Curve::Line(curve) => self.include(&Curve::BSplineCurve(curve.to_bspline())),
+                Curve::Line(entity_curve) => {
+                    let surface = RevolutedCurve::by_revolution(
+                        entity_curve.to_bspline(),
+                        surface.origin(),
+                        surface.axis(),
+                    );
+                    match curve {
+                        Curve::Line(curve) => surface.include(&curve.to_bspline()),
+                        Curve::BSplineCurve(curve) => surface.include(curve),
+                        Curve::NurbsCurve(curve) => surface.include(curve),
+                        Curve::IntersectionCurve(_) => unreachable!(),
+                    }
+                }
                 Curve::BSplineCurve(entity_curve) => {
                     let surface = RevolutedCurve::by_revolution(
                         entity_curve,
@@ -245,7 +281,7 @@ impl IncludeCurve<Curve> for Surface {
                         Curve::Line(curve) => surface.include(&curve.to_bspline()),
                         Curve::BSplineCurve(curve) => surface.include(curve),
                         Curve::NurbsCurve(curve) => surface.include(curve),
-                        Curve::IntersectionCurve(_) => unimplemented!(),
+                        Curve::IntersectionCurve(_) => unreachable!(),
                     }
                 }
                 Curve::NurbsCurve(entity_curve) => {
@@ -258,7 +294,7 @@ impl IncludeCurve<Curve> for Surface {
                         Curve::Line(curve) => surface.include(&curve.to_bspline()),
                         Curve::BSplineCurve(curve) => surface.include(curve),
                         Curve::NurbsCurve(curve) => surface.include(curve),
-                        Curve::IntersectionCurve(_) => unimplemented!(),
+                        Curve::IntersectionCurve(_) => unreachable!(),
                     }
                 }
                 Curve::IntersectionCurve(_) => unimplemented!(),
@@ -0,0 +1,869 @@
+use crate::*;
+use errors::Error;
+use rustc_hash::FxHashMap as HashMap;
+
+/// the number of the divisions of the surfaces for checking the regularity of the offsets
+const REGULARITY_DIVISION: usize = 16;
+/// the initial number of the divisions for interpolating the offsets
+const INITIAL_DIVISION: usize = 8;
+/// the maximum number of the divisions for interpolating the offsets
+const MAX_DIVISION: usize = 256;
+/// the tolerance of the interpolation of the offsets
+const OFFSET_TOLERANCE: f64 = 1.0e-7;
+/// the number of the divisions of the edges for tracing the offset edges
+const EDGE_DIVISION: usize = 12;
+/// the tolerance of the intersection curves between the offsets
+const INTERSECTION_TOLERANCE: f64 = 1.0e-3;
+/// the upper bound of the sine of the angle between the normals of the tangent faces
+const TANGENCY: f64 = 1.0e-4;
+/// the number of the trials of searching parameters and Newton's method
+const SEARCH_TRIALS: usize = 100;
+
+fn unsupported<T>(reason: &'static str) -> Result<T> { Err(Error::UnsupportedShell(reason)) }
+
+/// Returns whether the unit normals `n0` and `n1` are the normals of the tangent faces.
+fn is_tangent(n0: Vector3, n1: Vector3) -> bool {
+    n0.cross(n1).magnitude() < TANGENCY && n0.dot(n1) > 0.0
+}
+
+/// Returns whether `mat` is the composition of an orthogonal map, a uniform scaling and a translation.
+fn is_similarity(mat: &Matrix4) -> bool {
+    let m = Matrix3::from_cols(mat.x.truncate(), mat.y.truncate(), mat.z.truncate());
+    let scale2 = m.x.magnitude2();
+    let projective = Vector3::new(mat.x.w, mat.y.w, mat.z.w);
+    projective.so_small()
+        && mat.w.w.near(&1.0)
+        && (m.transpose() * m).near(&(Matrix3::identity() * scale2))
+}
+
+/// Returns the point `x` with `x.dot(normal) == c` for all `(normal, c)` in `rows`.
+fn solve(rows: [(Vector3, f64); 3]) -> Option<Point3> {
+    let mat = Matrix3::from_cols(rows[0].0, rows[1].0, rows[2].0).transpose();
+    if mat.determinant().so_small() {
+        return None;
+    }
+    let rhs = Vector3::new(rows[0].1, rows[1].1, rows[2].1);
+    Some(Point3::from_vec(mat.invert()? * rhs))
+}
+
+/// Returns the common point of `surfaces` near `pt` by Newton's method. If `surfaces` are two,
+/// the point is taken on the plane through `pt` with `normal`.
+fn common_point(
+    surfaces: &[&Surface],
+    hints: &mut [Option<(f64, f64)>],
+    normal: Option<Vector3>,
+    mut pt: Point3,
+) -> Option<Point3> {
+    let plane = normal.map(|n| (n, n.dot(pt.to_vec())));
+    for _ in 0..SEARCH_TRIALS {
+        let mut rows = Vec::with_capacity(3);
+        let mut converged = true;
+        for (surface, hint) in surfaces.iter().zip(hints.iter_mut()) {
+            let (u, v) = surface.search_nearest_parameter(pt, *hint, SEARCH_TRIALS)?;
+            *hint = Some((u, v));
+            let (foot, n) = (surface.subs(u, v), surface.normal(u, v));
+            converged &= foot.near(&pt);
+            rows.push((n, n.dot(foot.to_vec())));
+        }
+        if converged {
+            return Some(pt);
+        }
+        rows.extend(plane);
+        pt = solve([rows[0], rows[1], rows[2]])?;
+    }
+    None
+}
+
+/// Returns whether the offset grid with `distance` keeps the orientations of the cells of the grid
+/// on `surface`, i.e. the offset does not fold itself.
+fn is_regular_offset(surface: &Surface, distance: f64) -> bool {
+    let (Some((u0, u1)), Some((v0, v1))) = surface.try_range_tuple() else {
+        return true;
+    };
+    let n = REGULARITY_DIVISION;
+    let grid: Vec<Vec<(Point3, Point3)>> = (0..=n)
+        .map(|i| {
+            (0..=n)
+                .map(|j| {
+                    let u = u0 + (u1 - u0) * i as f64 / n as f64;
+                    let v = v0 + (v1 - v0) * j as f64 / n as f64;
+                    let pt = surface.subs(u, v);
+                    (pt, pt + surface.normal(u, v) * distance)
+                })
+                .collect()
+        })
+        .collect();
+    (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .all(|(i, j)| {
+            let (p00, p10, p01) = (grid[i][j], grid[i + 1][j], grid[i][j + 1]);
+            let cell = (p10.0 - p00.0).cross(p01.0 - p00.0);
+            let offset_cell = (p10.1 - p00.1).cross(p01.1 - p00.1);
+            // the degenerate cells, e.g. at the poles, are skipped.
+            cell.so_small() || cell.dot(offset_cell) > 0.0
+        })
+}
+
+/// Returns the cubic B-spline curve interpolating `curve` on `[0, 1]` up to `OFFSET_TOLERANCE`.
+fn approximate_curve(curve: impl Fn(f64) -> Point3) -> Result<BSplineCurve<Point3>> {
+    let mut n = INITIAL_DIVISION;
+    while n <= MAX_DIVISION {
+        let params: Vec<f64> = (0..=n).map(|i| i as f64 / n as f64).collect();
+        let pts: Vec<Point3> = params.iter().map(|&t| curve(t)).collect();
+        let bspcurve = BSplineCurve::interpolate_with_parameters(&pts, &params, 3);
+        let fits = params.windows(2).all(|ts| {
+            let t = (ts[0] + ts[1]) / 2.0;
+            bspcurve.subs(t).distance(curve(t)) < OFFSET_TOLERANCE
+        });
+        if fits {
+            return Ok(bspcurve);
+        }
+        n *= 2;
+    }
+    unsupported("failed to approximate the offset surfaces.")
+}
+
+/// Returns the bicubic B-spline surface interpolating `surface` on `[0, 1]^2` up to
+/// `OFFSET_TOLERANCE`.
+fn approximate_surface(surface: impl Fn(f64, f64) -> Point3) -> Result<BSplineSurface<Point3>> {
+    let (mut m, mut n) = (INITIAL_DIVISION, INITIAL_DIVISION);
+    while m <= MAX_DIVISION && n <= MAX_DIVISION {
+        let uparams: Vec<f64> = (0..=m).map(|i| i as f64 / m as f64).collect();
+        let vparams: Vec<f64> = (0..=n).map(|j| j as f64 / n as f64).collect();
+        let curves = vparams
+            .iter()
+            .map(|&v| {
+                let pts: Vec<Point3> = uparams.iter().map(|&u| surface(u, v)).collect();
+                BSplineCurve::interpolate_with_parameters(&pts, &uparams, 3)
+            })
+            .collect();
+        let bspsurface = BSplineSurface::skin_with_parameters(curves, &vparams, 3);
+        let fits =
+            |u: f64, v: f64| bspsurface.subs(u, v).distance(surface(u, v)) < OFFSET_TOLERANCE;
+        let ufits = uparams.windows(2).all(|us| {
+            let u = (us[0] + us[1]) / 2.0;
+            vparams.iter().all(|&v| fits(u, v))
+        });
+        let vfits = vparams.windows(2).all(|vs| {
+            let v = (vs[0] + vs[1]) / 2.0;
+            uparams.iter().all(|&u| fits(u, v))
+        });
+        if ufits && vfits {
+            return Ok(bspsurface);
+        }
+        if !ufits {
+            m *= 2;
+        }
+        if !vfits {
+            n *= 2;
+        }
+    }
+    unsupported("failed to approximate the offset surfaces.")
+}
+
+/// Returns the line or the arc similar to `curve` from `front` to `back`, which is the candidate of
+/// the offset of `curve`. The arc is given by the similarity fixing the axis of the circle.
+fn similar_curve(curve: &Curve, front: Point3, back: Point3) -> Option<Curve> {
+    let candidate = match curve {
+        Curve::Line(_) => Curve::Line(Line(front, back)),
+        Curve::BSplineCurve(_) | Curve::NurbsCurve(_) => {
+            let (t0, t1) = curve.range_tuple();
+            let [a, b, c] = [0.0, 1.0 / 3.0, 2.0 / 3.0].map(|s| curve.subs(t0 + (t1 - t0) * s));
+            let (ab, ac) = (b - a, c - a);
+            let axis = ab.cross(ac);
+            if axis.so_small() {
+                return None;
+            }
+            let center = a
+                + (ab.magnitude2() * ac - ac.magnitude2() * ab).cross(axis)
+                    / (2.0 * axis.magnitude2());
+            let (axis, radius) = (axis.normalize(), a.distance(center));
+            let is_circle = (0..=EDGE_DIVISION).all(|i| {
+                let pt = curve.subs(t0 + (t1 - t0) * i as f64 / EDGE_DIVISION as f64);
+                pt.distance(center).near(&radius) && axis.dot(pt - center).so_small()
+            });
+            if !is_circle {
+                return None;
+            }
+            let new_center = center + axis * axis.dot(front - a);
+            let mat = Matrix4::from_translation(new_center.to_vec())
+                * Matrix4::from_scale(front.distance(new_center) / radius)
+                * Matrix4::from_translation(-center.to_vec());
+            curve.transformed(mat)
+        }
+        Curve::IntersectionCurve(_) => return None,
+    };
+    (candidate.front().near(&front) && candidate.back().near(&back)).then_some(candidate)
+}
+
+/// Returns the curve through `offset(t)` for the parameters `t` of `curve`, that is, the line or
+/// the arc similar to `curve` if it fits, and the approximating B-spline curve otherwise.
+fn offset_curve(curve: &Curve, offset: impl Fn(f64) -> Point3) -> Result<Curve> {
+    let (t0, t1) = curve.range_tuple();
+    let fits = |candidate: &Curve| {
+        (0..=EDGE_DIVISION).all(|i| {
+            let t = t0 + (t1 - t0) * i as f64 / EDGE_DIVISION as f64;
+            candidate.subs(t).near(&offset(t))
+        })
+    };
+    match similar_curve(curve, offset(t0), offset(t1)) {
+        Some(candidate) if fits(&candidate) => Ok(candidate),
+        _ => approximate_curve(|s| offset(t0 + (t1 - t0) * s)).map(Curve::BSplineCurve),
+    }
+}
+
+/// Returns the revolved surface offset from `revolved` by `distance`, which is the revolution of
+/// the offset of the profile. The transform of `revolved` must be a similarity.
+fn offset_revolved(
+    revolved: &Processor<RevolutedCurve<Curve>, Matrix4>,
+    distance: f64,
+) -> Result<Surface> {
+    let Some(inv) = revolved.transform().invert() else {
+        return unsupported("the transforms of the surfaces must be invertible.");
+    };
+    let offset = |t: f64| {
+        let (u, v) = match revolved.orientation() {
+            true => (t, 0.0),
+            false => (0.0, t),
+        };
+        let pt = revolved.subs(u, v) + revolved.normal(u, v) * distance;
+        inv.transform_point(pt)
+    };
+    let profile = offset_curve(revolved.entity().entity_curve(), offset)?;
+    Ok(Surface::RevolutedCurve(revolved.map_ref(|entity| {
+        RevolutedCurve::by_revolution(profile, entity.origin(), entity.axis())
+    })))
+}
+
+/// Returns the surface offset from `surface` by `distance` along the normal.
+///
+/// The planes are translated, the revolved surfaces are revolved from the offset profiles, and
+/// the other surfaces are approximated by the B-spline surfaces.
+fn offset_surface(surface: &Surface, distance: f64) -> Result<Surface> {
+    if let Surface::Plane(plane) = surface {
+        let trans = Matrix4::from_translation(plane.normal() * distance);
+        return Ok(Surface::Plane(plane.transformed(trans)));
+    }
+    if !is_regular_offset(surface, distance) {
+        return Err(Error::ThicknessTooLarge);
+    }
+    match surface {
+        Surface::RevolutedCurve(revolved) if is_similarity(revolved.transform()) => {
+            offset_revolved(revolved, distance)
+        }
+        _ => {
+            let (Some((u0, u1)), Some((v0, v1))) = surface.try_range_tuple() else {
+                return unsupported("the curved surfaces must be bounded.");
+            };
+            let bspsurface = approximate_surface(|s, t| {
+                let (u, v) = (u0 + (u1 - u0) * s, v0 + (v1 - v0) * t);
+                surface.subs(u, v) + surface.normal(u, v) * distance
+            })?;
+            Ok(Surface::BSplineSurface(bspsurface))
+        }
+    }
+}
+
+/// Returns the normal of `surface` at the vertex `pt`.
+fn vertex_normal(surface: &Surface, pt: Point3) -> Result<Vector3> {
+    let Some((u, v)) = surface.search_parameter(pt, None, SEARCH_TRIALS) else {
+        return unsupported("failed to search the vertices on the surfaces.");
+    };
+    if let Surface::RevolutedCurve(revolved) = surface {
+        // the revolved surface is smooth at the pole only if the profile is perpendicular to the axis.
+        let (uder, vder) = (surface.uder(u, v), surface.vder(u, v));
+        let der = match uder.magnitude2() > vder.magnitude2() {
+            true => uder,
+            false => vder,
+        };
+        let axis = revolved
+            .transform()
+            .transform_vector(revolved.entity().axis());
+        if uder.cross(vder).so_small() && !der.normalize().dot(axis.normalize()).so_small() {
+            return unsupported("the apices of the cones must not be the vertices.");
+        }
+    }
+    Ok(surface.normal(u, v))
+}
+
+/// Returns the inner vertex at `thickness` inside from `pt`, where `normals` are the pairs of the
+/// indices and the normals of the faces sharing `pt`.
+fn inner_point(
+    pt: Point3,
+    normals: &[(usize, Vector3)],
+    offsets: &[Surface],
+    thickness: f64,
+) -> Result<Point3> {
+    // the faces tangent to each other at `pt` share the offset point.
+    let mut groups = Vec::<(Vector3, &Surface)>::new();
+    for &(i, normal) in normals {
+        if !groups.iter().any(|(n, _)| is_tangent(*n, normal)) {
+            groups.push((normal, &offsets[i]));
+        }
+    }
+    let row = |n: Vector3| (n, n.dot(pt.to_vec()) - thickness);
+    let res = match groups[..] {
+        [(n, _)] => Some(pt - n * thickness),
+        [(n0, s0), (n1, s1)] => {
+            let normal = n0.cross(n1);
+            solve([row(n0), row(n1), (normal, normal.dot(pt.to_vec()))])
+                .and_then(|start| common_point(&[s0, s1], &mut [None; 2], Some(normal), start))
+        }
+        [(n0, s0), (n1, s1), (n2, s2)] => solve([row(n0), row(n1), row(n2)])
+            .and_then(|start| common_point(&[s0, s1, s2], &mut [None; 3], None, start)),
+        _ => return unsupported("each vertex must be shared by at most three transversal faces."),
+    };
+    res.ok_or(Error::UnsupportedShell(
+        "the faces at each vertex must be linearly independent.",
+    ))
+}
+
+/// Returns whether the polyline `pts` at the parameters `params` runs along `curve`. If not, the
+/// offset edge is reversed.
+fn keeps_direction(curve: &Curve, params: &[f64], pts: &[Point3]) -> bool {
+    params.windows(2).zip(pts.windows(2)).all(|(ts, ps)| {
+        let dir = curve.subs(ts[1]) - curve.subs(ts[0]);
+        (ps[1] - ps[0]).dot(dir) >= TOLERANCE
+    })
+}
+
+/// Returns the normals of `surfaces` at the points of `curve` at `params`.
+fn normals_along(
+    curve: &Curve,
+    surfaces: [&Surface; 2],
+    params: &[f64],
+) -> Result<Vec<[Vector3; 2]>> {
+    let mut hints = [None; 2];
+    params
+        .iter()
+        .map(|&t| {
+            let pt = curve.subs(t);
+            let mut normals = [Vector3::zero(); 2];
+            for k in 0..2 {
+                let Some((u, v)) =
+                    surfaces[k].search_nearest_parameter(pt, hints[k], SEARCH_TRIALS)
+                else {
+                    return unsupported("failed to search the edges on the surfaces.");
+                };
+                hints[k] = Some((u, v));
+                normals[k] = surfaces[k].normal(u, v);
+            }
+            Ok(normals)
+        })
+        .collect()
+}
+
+/// Returns `EDGE_DIVISION + 1` parameters dividing `(t0, t1)` uniformly.
+fn division((t0, t1): (f64, f64)) -> Vec<f64> {
+    (0..=EDGE_DIVISION)
+        .map(|i| t0 + (t1 - t0) * i as f64 / EDGE_DIVISION as f64)
+        .collect()
+}
+
+/// Returns the inner curve from `front` to `back` of the edge with `curve` shared by the faces
+/// with `surfaces`, which lies on `offsets`.
+fn inner_curve(
+    curve: &Curve,
+    surfaces: [&Surface; 2],
+    offsets: [&Surface; 2],
+    (front, back): (Point3, Point3),
+    thickness: f64,
+) -> Result<Curve> {
+    let (t0, t1) = curve.range_tuple();
+    let params = division((t0, t1));
+    let normals = normals_along(curve, surfaces, &params)?;
+    let fits = |candidate: &Curve| {
+        let mut hints = [None; 2];
+        params.windows(2).all(|ts| {
+            let t = (ts[0] + ts[1]) / 2.0;
+            let pt = candidate.subs(t);
+            let on_offsets = offsets.iter().zip(&mut hints).all(|(offset, hint)| {
+                *hint = offset.search_nearest_parameter(pt, *hint, SEARCH_TRIALS);
+                hint.is_some_and(|(u, v)| offset.subs(u, v).near(&pt))
+            });
+            on_offsets && candidate.der(t).dot(curve.der(t)) >= TOLERANCE
+        })
+    };
+
+    // the offset edge is reversed if the feet of the inner ends on `curve` are reversed.
+    let feet = [(front, t0), (back, t1)]
+        .map(|(pt, hint)| curve.search_nearest_parameter(pt, Some(hint), SEARCH_TRIALS));
+    let [Some(s0), Some(s1)] = feet else {
+        return unsupported("failed to search the inner vertices on the edges.");
+    };
+    if s1 - s0 < TOLERANCE {
+        return Err(Error::ThicknessTooLarge);
+    }
+
+    if normals.iter().all(|[n0, n1]| is_tangent(*n0, *n1)) {
+        // the interior points are offset along the common normals.
+        let interior = &params[1..EDGE_DIVISION];
+        let pts: Vec<Point3> = interior
+            .iter()
+            .zip(&normals[1..])
+            .map(|(&t, [n, _])| curve.subs(t) - n * thickness)
+            .collect();
+        if !keeps_direction(curve, interior, &pts) {
+            return Err(Error::ThicknessTooLarge);
+        }
+        return match similar_curve(curve, front, back) {
+            Some(candidate) if fits(&candidate) => Ok(candidate),
+            _ => unsupported("the offsets of the tangent edges must be lines or arcs."),
+        };
+    }
+    if normals.iter().any(|[n0, n1]| is_tangent(*n0, *n1)) {
+        return unsupported(
+            "the faces must meet either transversally or tangentially along each edge.",
+        );
+    }
+
+    // the points are traced on the normal planes of `curve` between the feet.
+    let stations = division((s0, s1));
+    let normals = normals_along(curve, surfaces, &stations)?;
+    let mut pts = vec![front];
+    let mut hints = [None; 2];
+    for (&t, [n0, n1]) in stations[1..EDGE_DIVISION].iter().zip(&normals[1..]) {
+        let (pt, der) = (curve.subs(t), curve.der(t));
+        let row = |n: &Vector3| (*n, n.dot(pt.to_vec()) - thickness);
+        let Some(inner) = solve([row(n0), row(n1), (der, der.dot(pt.to_vec()))])
+            .and_then(|start| common_point(&offsets, &mut hints, Some(der), start))
+        else {
+            return unsupported("failed to intersect the offset surfaces.");
+        };
+        pts.push(inner);
+    }
+    pts.push(back);
+    if !keeps_direction(curve, &stations, &pts) {
+        return Err(Error::ThicknessTooLarge);
+    }
+    match similar_curve(curve, front, back) {
+        Some(candidate) if fits(&candidate) => Ok(candidate),
+        _ => Ok(IntersectionCurve::new_unchecked(
+            Box::new(offsets[0].clone()),
+            Box::new(offsets[1].clone()),
+            PolylineCurve(pts),
+            INTERSECTION_TOLERANCE,
+        )
+        .into()),
+    }
+}
+
+/// Returns the side wall between the outer edge `edge` and the inner edge `inner` connected by
+/// `connection0` at the front and `connection1` at the back.
+fn wall(edge: &Edge, inner: &Edge, connection0: &Edge, connection1: &Edge) -> Result<Face> {
+    let wire: Wire = vec![
+        edge.clone(),
+        connection1.clone(),
+        inner.inverse(),
+        connection0.inverse(),
+    ]
+    .into();
+    let inner_curve = match inner.oriented_curve() {
+        Curve::IntersectionCurve(curve) => {
+            Curve::BSplineCurve(builder::intersection_curve_approximation(&curve))
+        }
+        curve => {
+            if let Ok(face) = builder::try_attach_plane(std::slice::from_ref(&wire)) {
+                return Ok(face);
+            }
+            curve
+        }
+    };
+    let surface = BSplineSurface::homotopy(edge.oriented_curve().lift_up(), inner_curve.lift_up());
+    Ok(Face::try_new(
+        vec![wire],
+        Surface::NurbsSurface(NurbsSurface::new(surface)),
+    )?)
+}
+
+pub(super) fn hollow(solid: &Solid, thickness: f64, open_faces: &[Face]) -> Result<Solid> {
+    if thickness < TOLERANCE {
+        return unsupported("the thickness must be positive.");
+    }
+    let [shell] = solid.boundaries().as_slice() else {
+        return unsupported("the solid must have exactly one boundary shell.");
+    };
+    if open_faces
+        .iter()
+        .any(|open| !shell.face_iter().any(|face| face.is_same(open)))
+    {
+        return Err(Error::FaceNotFound);
+    }
+    let is_open = |face: &Face| open_faces.iter().any(|open| open.is_same(face));
+    let surfaces: Vec<Surface> = shell.face_iter().map(Face::oriented_surface).collect();
+    let mut vertex_normals = HashMap::<VertexID, Vec<(usize, Vector3)>>::default();
+    for vertex in shell.vertex_iter() {
+        if vertex_normals.contains_key(&vertex.id()) {
+            continue;
+        }
+        let normals = shell
+            .face_iter()
+            .zip(&surfaces)
+            .enumerate()
+            .filter(|(_, (face, _))| face.vertex_iter().any(|v| v == vertex))
+            .map(|(i, (_, surface))| Ok((i, vertex_normal(surface, vertex.point())?)))
+            .collect::<Result<Vec<_>>>()?;
+        vertex_normals.insert(vertex.id(), normals);
+    }
+    let offsets = surfaces
+        .iter()
+        .map(|surface| offset_surface(surface, -thickness))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut inner_vertices = HashMap::<VertexID, Vertex>::default();
+    for vertex in shell.vertex_iter() {
+        if inner_vertices.contains_key(&vertex.id()) {
+            continue;
+        }
+        let normals = &vertex_normals[&vertex.id()];
+        let pt = inner_point(vertex.point(), normals, &offsets, thickness)?;
+        inner_vertices.insert(vertex.id(), Vertex::new(pt));
+    }
+
+    let mut inner_edges = HashMap::<EdgeID, Edge>::default();
+    for edge in shell.edge_iter() {
+        if inner_edges.contains_key(&edge.id()) {
+            continue;
+        }
+        let adjacents: Vec<usize> = shell
+            .face_iter()
+            .enumerate()
+            .filter(|(_, face)| face.edge_iter().any(|e| e.id() == edge.id()))
+            .map(|(i, _)| i)
+            .collect();
+        // the seam is shared by a face with itself.
+        let (i, j) = match adjacents[..] {
+            [i] => (i, i),
+            [i, j] => (i, j),
+            _ => return unsupported("each edge must be shared by at most two faces."),
+        };
+        let (front, back) = edge.absolute_ends();
+        let (v0, v1) = (&inner_vertices[&front.id()], &inner_vertices[&back.id()]);
+        let curve = inner_curve(
+            &edge.curve(),
+            [&surfaces[i], &surfaces[j]],
+            [&offsets[i], &offsets[j]],
+            (v0.point(), v1.point()),
+            thickness,
+        )?;
+        inner_edges.insert(edge.id(), Edge::new(v0, v1, curve));
+    }
+    let inner_edge = |edge: &Edge| -> Edge {
+        let inner = &inner_edges[&edge.id()];
+        match edge.orientation() {
+            true => inner.clone(),
+            false => inner.inverse(),
+        }
+    };
+
+    let mut outer_faces = Vec::new();
+    let mut inner_faces = Vec::new();
+    let mut walls = Vec::new();
+    let mut connections = HashMap::<VertexID, Edge>::default();
+    for (face, offset) in shell.face_iter().zip(offsets) {
+        if !is_open(face) {
+            let boundaries = face
+                .boundaries()
+                .iter()
+                .map(|wire| wire.edge_iter().map(inner_edge).collect())
+                .collect();
+            let inner = Face::try_new(boundaries, offset)?;
+            outer_faces.push(face.clone());
+            inner_faces.push(inner.inverse());
+            continue;
+        }
+        for edge in face.edge_iter() {
+            let adjacent_open = shell.face_iter().any(|other| {
+                !other.is_same(face)
+                    && is_open(other)
+                    && other.edge_iter().any(|e| e.is_same(&edge))
+            });
+            if adjacent_open {
+                return unsupported("the open faces must not be adjacent to each other.");
+            }
+            let [connection0, connection1] = [edge.front(), edge.back()].map(|v| {
+                connections
+                    .entry(v.id())
+                    .or_insert_with(|| {
+                        let inner = &inner_vertices[&v.id()];
+                        Edge::new(v, inner, Line(v.point(), inner.point()).into())
+                    })
+                    .clone()
+            });
+            walls.push(wall(&edge, &inner_edge(&edge), &connection0, &connection1)?);
+        }
+    }
+
+    let boundaries = match open_faces.is_empty() {
+        true => vec![outer_faces.into(), inner_faces.into()],
+        false => {
+            let mut faces = outer_faces;
+            faces.extend(walls);
+            faces.extend(inner_faces);
+            vec![faces.into()]
+        }
+    };
+    Ok(Solid::try_new(boundaries)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn unit_cube() -> Solid {
+        let v = builder::vertex(Point3::origin());
+        let e = builder::tsweep(&v, Vector3::unit_x());
+        let f = builder::tsweep(&e, Vector3::unit_y());
+        builder::tsweep(&f, Vector3::unit_z())
+    }
+
+    #[test]
+    fn hollow_closed_cube() {
+        let cube = unit_cube();
+        let hollowed = builder::shell(&cube, 0.1, &[]).unwrap();
+        let boundaries = hollowed.boundaries();
+        assert_eq!(boundaries.len(), 2);
+        assert_eq!(boundaries[1].shell_condition(), ShellCondition::Closed);
+        assert!(hollowed.is_geometric_consistent());
+        boundaries[1].face_iter().for_each(|face| {
+            // the inner faces point to the cavity
+            let surface = face.oriented_surface();
+            let (u, v) = (0.5, 0.5);
+            let pt = surface.subs(u, v);
+            let to_center = Point3::new(0.5, 0.5, 0.5) - pt;
+            assert!(surface.normal(u, v).dot(to_center) > 0.0);
+            assert!(to_center.magnitude() > 0.4 - TOLERANCE);
+        });
+        boundaries[1].vertex_iter().for_each(|v| {
+            let pt = v.point();
+            [pt.x, pt.y, pt.z].into_iter().for_each(|x| {
+                assert!(x.near(&0.1) || x.near(&0.9));
+            });
+        });
+    }
+
+    #[test]
+    fn open_cube_top() {
+        let cube = unit_cube();
+        let top = cube
+            .face_iter()
+            .find(|face| face.vertex_iter().all(|v| v.point().z.near(&1.0)))
+            .unwrap()
+            .clone();
+        let cup = builder::shell(&cube, 0.1, &[top]).unwrap();
+        let shell = &cup.boundaries()[0];
+        assert_eq!(cup.boundaries().len(), 1);
+        // five outer faces, four walls, and five inner faces
+        assert_eq!(shell.len(), 14);
+        assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+        assert!(cup.is_geometric_consistent());
+        let vertices: HashSet<VertexID> = cup.vertex_iter().map(|v| v.id()).collect();
+        assert_eq!(vertices.len(), 16);
+    }
+
+    #[test]
+    fn unsupported_shells() {
+        let cube = unit_cube();
+        assert_eq!(
+            builder::shell(&cube, 0.6, &[]).unwrap_err(),
+            Error::ThicknessTooLarge,
+        );
+        // the bottom and the side at y = 0
+        let faces: Vec<Face> = cube
+            .face_iter()
+            .filter(|face| {
+                face.vertex_iter().all(|v| v.point().z.near(&0.0))
+                    || face.vertex_iter().all(|v| v.point().y.near(&0.0))
+            })
+            .cloned()
+            .collect();
+        assert_eq!(faces.len(), 2);
+        assert!(matches!(
+            builder::shell(&cube, 0.1, &faces),
+            Err(Error::UnsupportedShell(_)),
+        ));
+        let other = unit_cube();
+        assert_eq!(
+            builder::shell(&cube, 0.1, &[other.face_iter().next().unwrap().clone()]),
+            Err(Error::FaceNotFound),
+        );
+
+        // the apex of the cone is not smooth.
+        let cone = builder::cone_solid(Point3::origin(), 1.0, 1.0, Vector3::unit_z());
+        assert!(matches!(
+            builder::shell(&cone, 0.1, &[]),
+            Err(Error::UnsupportedShell(_)),
+        ));
+    }
+
+    /// Returns the inner faces of `hollowed`, which share no vertices with `outer`.
+    fn inner_faces(hollowed: &Solid, outer: &Solid) -> Vec<Face> {
+        let outer_vertices: HashSet<VertexID> = outer.vertex_iter().map(|v| v.id()).collect();
+        hollowed
+            .face_iter()
+            .filter(|face| {
+                face.vertex_iter()
+                    .all(|v| !outer_vertices.contains(&v.id()))
+            })
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn hollow_cylinder() {
+        let cylinder = builder::cylinder(Point3::origin(), 1.0, 2.0, Vector3::unit_z());
+        let hollowed = builder::shell(&cylinder, 0.1, &[]).unwrap();
+        let boundaries = hollowed.boundaries();
+        assert_eq!(boundaries.len(), 2);
+        assert_eq!(boundaries[1].shell_condition(), ShellCondition::Closed);
+        assert!(hollowed.is_geometric_consistent());
+        boundaries[1].vertex_iter().for_each(|v| {
+            let pt = v.point();
+            assert_near!(Vector2::new(pt.x, pt.y).magnitude(), 0.9);
+            assert!(pt.z.near(&0.1) || pt.z.near(&1.9));
+        });
+        boundaries[1].face_iter().for_each(|face| {
+            let surface = face.oriented_surface();
+            let (u, v) = match surface.try_range_tuple() {
+                (Some((u0, u1)), Some((v0, v1))) => ((u0 + u1) / 2.0, (v0 + v1) / 2.0),
+                _ => surface
+                    .search_parameter(
+                        face.boundaries()[0].front_vertex().unwrap().point(),
+                        None,
+                        100,
+                    )
+                    .unwrap(),
+            };
+            let pt = surface.subs(u, v);
+            let to_axis = Point3::new(0.0, 0.0, pt.z) - pt;
+            if let Surface::RevolutedCurve(_) = surface {
+                // the inner side is the cylinder of radius 0.9 facing the axis.
+                assert_near!(to_axis.magnitude(), 0.9);
+                assert!(surface.normal(u, v).dot(to_axis) > 0.0);
+            }
+        });
+
+        let top = cylinder
+            .face_iter()
+            .find(|face| face.vertex_iter().all(|v| v.point().z.near(&2.0)))
+            .unwrap()
+            .clone();
+        let cup = builder::shell(&cylinder, 0.1, &[top]).unwrap();
+        let shell = &cup.boundaries()[0];
+        assert_eq!(cup.boundaries().len(), 1);
+        assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+        assert!(cup.is_geometric_consistent());
+        let inner = inner_faces(&cup, &cylinder);
+        assert!(!inner.is_empty());
+        inner
+            .iter()
+            .flat_map(|face| face.vertex_iter())
+            .for_each(|v| {
+                let pt = v.point();
+                assert_near!(Vector2::new(pt.x, pt.y).magnitude(), 0.9);
+            });
+    }
+
+    #[test]
+    fn hollow_tube() {
+        // the tube with the inner radius 1 and the outer radius 2
+        let rect = builder::polygon(&[
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 1.0),
+            Point3::new(2.0, 0.0, 1.0),
+            Point3::new(2.0, 0.0, 0.0),
+        ])
+        .unwrap();
+        let face = builder::try_attach_plane(&[rect]).unwrap();
+        let tube = builder::rsweep(&face, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+        let hollowed = builder::shell(&tube, 0.2, &[]).unwrap();
+        assert!(hollowed.is_geometric_consistent());
+        hollowed.boundaries()[1].vertex_iter().for_each(|v| {
+            let pt = v.point();
+            let radius = Vector2::new(pt.x, pt.y).magnitude();
+            assert!(radius.near(&1.2) || radius.near(&1.8));
+            assert!(pt.z.near(&0.2) || pt.z.near(&0.8));
+        });
+        assert_eq!(
+            builder::shell(&tube, 0.6, &[]).unwrap_err(),
+            Error::ThicknessTooLarge,
+        );
+    }
+
+    #[test]
+    fn hollow_freeform_box() {
+        // the box whose side at y = 0 is bulged by the B-spline curve
+        let v = builder::vertex(Point3::origin());
+        let bottom = builder::tsweep(&v, Vector3::unit_y());
+        let v0 = bottom.front();
+        let v1 = builder::vertex(Point3::new(2.0, 0.0, 0.0));
+        let bulge = builder::bezier(
+            v0,
+            &v1,
+            vec![Point3::new(0.5, -0.3, 0.0), Point3::new(1.5, -0.3, 0.0)],
+        );
+        let v2 = builder::vertex(Point3::new(2.0, 1.0, 0.0));
+        let wire: Wire = vec![
+            bulge,
+            builder::line(&v1, &v2),
+            builder::line(&v2, bottom.back()),
+            bottom.inverse(),
+        ]
+        .into();
+        let profile = builder::try_attach_plane(&[wire]).unwrap();
+        let solid = builder::tsweep(&profile, Vector3::unit_z());
+        let top = solid
+            .face_iter()
+            .find(|face| face.vertex_iter().all(|v| v.point().z.near(&1.0)))
+            .unwrap()
+            .clone();
+
+        let cup = builder::shell(&solid, 0.1, &[top]).unwrap();
+        assert_eq!(
+            cup.boundaries()[0].shell_condition(),
+            ShellCondition::Closed
+        );
+        assert!(cup.is_geometric_consistent());
+        // the bulged side and the offset of the bottom meet along the intersection curve.
+        assert!(cup
+            .edge_iter()
+            .any(|edge| matches!(edge.curve(), Curve::IntersectionCurve(_))));
+        // the inner side is apart from the bulged side by the thickness.
+        let side = solid
+            .face_iter()
+            .find(|face| matches!(face.surface(), Surface::BSplineSurface(_)))
+            .unwrap()
+            .oriented_surface();
+        inner_faces(&cup, &solid)
+            .iter()
+            .flat_map(|face| face.vertex_iter())
+            .for_each(|v| {
+                let pt = v.point();
+                assert!(pt.z.near(&0.1) || pt.z.near(&0.9));
+                let (u, w) = side.search_nearest_parameter(pt, None, 100).unwrap();
+                let dist = side.subs(u, w).distance(pt);
+                assert!(dist > 0.1 - TOLERANCE);
+            });
+    }
+
+    #[test]
+    fn hollow_torus() {
+        let v = builder::vertex(Point3::new(1.5, 0.0, 0.0));
+        let circle = builder::rsweep(&v, Point3::new(1.0, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+        let disk = builder::try_attach_plane(&[circle]).unwrap();
+        let torus = builder::rsweep(&disk, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+        let hollowed = builder::shell(&torus, 0.1, &[]).unwrap();
+        assert!(hollowed.is_geometric_consistent());
+        hollowed.boundaries()[1].vertex_iter().for_each(|v| {
+            let pt = v.point();
+            let radius = Vector2::new(pt.x, pt.y).magnitude();
+            assert_near!(Vector2::new(radius - 1.0, pt.z).magnitude(), 0.4);
+        });
+        // the inner side folds beyond the minor radius.
+        assert_eq!(
+            builder::shell(&torus, 0.6, &[]).unwrap_err(),
+            Error::ThicknessTooLarge,
+        );
+    }
+}
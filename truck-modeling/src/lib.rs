@@ -82,6 +82,10 @@ pub mod topo_traits {
             S: Clone, {
             self.mapped(&Clone::clone, &Clone::clone, &Clone::clone)
         }
+
+        /// Inverts the orientations of all faces, and does nothing for vertices, edges, and wires.
+        #[doc(hidden)]
+        fn invert_faces(&mut self) {}
     }
 
     /// Abstract sweeping, builds a circle-arc, a prism, a half torus, and so on.
@@ -146,19 +150,35 @@ pub mod topo_traits {
             division: usize,
         ) -> Self::Swept;
     }
+
+    /// Tessellation of edges and wires into polylines.
+    pub trait PolylineTessellation<P> {
+        /// Returns the points of the polyline approximating `self` within `tol`.
+        ///
+        /// Lines are not divided, circle arcs are divided uniformly by the angle,
+        /// and the other curves are divided adaptively.
+        fn tessellate(&self, tol: f64) -> Vec<P>;
+    }
 }
 pub use topo_traits::*;
 
 /// `Result` with crate's errors.
 pub type Result<T> = std::result::Result<T, errors::Error>;
 
+mod blend;
 /// the building model utility API
 pub mod builder;
 mod closed_sweep;
+mod compound;
+pub use compound::Compound;
 /// declare errors
 pub mod errors;
 mod geom_impls;
+mod hollow;
 mod mapped;
 mod multi_sweep;
+mod pipe;
+mod section;
 mod sweep;
+mod tessellation;
 mod topo_impls;
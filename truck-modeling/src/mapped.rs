@@ -1,4 +1,5 @@
 use crate::topo_traits::*;
+use crate::{base::*, Compound};
 use truck_topology::*;
 
 impl<P: Clone, C: Clone, S: Clone> Mapped<P, C, S> for Vertex<P> {
@@ -47,6 +48,8 @@ impl<P: Clone, C: Clone, S: Clone> Mapped<P, C, S> for Face<P, C, S> {
     ) -> Self {
         self.mapped(point_mapping, curve_mapping, surface_mapping)
     }
+    #[inline(always)]
+    fn invert_faces(&mut self) { self.invert(); }
 }
 
 impl<P: Clone, C: Clone, S: Clone> Mapped<P, C, S> for Shell<P, C, S> {
@@ -59,6 +62,12 @@ impl<P: Clone, C: Clone, S: Clone> Mapped<P, C, S> for Shell<P, C, S> {
     ) -> Self {
         self.mapped(point_mapping, curve_mapping, surface_mapping)
     }
+    #[inline(always)]
+    fn invert_faces(&mut self) {
+        self.face_iter_mut().for_each(|face| {
+            face.invert();
+        });
+    }
 }
 
 impl<P: Clone, C: Clone, S: Clone> Mapped<P, C, S> for Solid<P, C, S> {
@@ -78,4 +87,61 @@ impl<P: Clone, C: Clone, S: Clone> Mapped<P, C, S> for Solid<P, C, S> {
                 .collect(),
         )
     }
+    #[inline(always)]
+    fn invert_faces(&mut self) { self.not(); }
+}
+
+impl<C, S, T> Mapped<Point3, C, S> for Compound<T>
+where
+    C: Transformed<Matrix4>,
+    S: Transformed<Matrix4>,
+    T: Mapped<Point3, C, S>,
+{
+    /// Returns a new compound whose parts are mapped in the global coordinates.
+    ///
+    /// Each part is moved to the global coordinates by its transform, mapped, and moved back,
+    /// so the transforms and the names are kept. The part whose transform is singular is
+    /// mapped in the global coordinates and placed by the identity.
+    fn mapped<FP: Fn(&Point3) -> Point3, FC: Fn(&C) -> C, FS: Fn(&S) -> S>(
+        &self,
+        point_mapping: &FP,
+        curve_mapping: &FC,
+        surface_mapping: &FS,
+    ) -> Self {
+        let mapped_part = |mat: Matrix4, part: &T| match mat.invert() {
+            Some(inv) => (
+                mat,
+                part.mapped(
+                    &move |pt: &Point3| {
+                        inv.transform_point(point_mapping(&mat.transform_point(*pt)))
+                    },
+                    &move |curve: &C| curve_mapping(&curve.transformed(mat)).transformed(inv),
+                    &move |surface: &S| surface_mapping(&surface.transformed(mat)).transformed(inv),
+                ),
+            ),
+            None => (
+                Matrix4::identity(),
+                part.mapped(
+                    &move |pt: &Point3| point_mapping(&mat.transform_point(*pt)),
+                    &move |curve: &C| curve_mapping(&curve.transformed(mat)),
+                    &move |surface: &S| surface_mapping(&surface.transformed(mat)),
+                ),
+            ),
+        };
+        self.iter()
+            .enumerate()
+            .fold(Compound::new(), |mut res, (i, (mat, part))| {
+                let (mat, part) = mapped_part(*mat, part);
+                match self.name(i) {
+                    Some(name) => res.push_named(name, mat, part),
+                    None => res.push(mat, part),
+                };
+                res
+            })
+    }
+    /// Returns another compound whose parts are cloned in their local coordinates.
+    #[inline(always)]
+    fn topological_clone(&self) -> Self { self.map_parts(Mapped::topological_clone) }
+    #[inline(always)]
+    fn invert_faces(&mut self) { self.iter_mut().for_each(|(_, part)| part.invert_faces()); }
 }
@@ -0,0 +1,262 @@
+use crate::*;
+use errors::Error;
+use rustc_hash::FxHashMap as HashMap;
+use std::f64::consts::PI;
+
+/// the number of the intervals between the sections interpolated in a segment
+const SWEEP_SECTIONS: usize = 8;
+/// the number of the steps of the rotation-minimizing frame between the sections
+const FRAME_STEPS: usize = 4;
+/// the number of the divisions of the path for estimating the turning angle
+const TURNING_DIVISION: usize = 64;
+
+/// Returns the rotation-minimizing frames at `pts` with `tangents` by the double reflection method.
+fn rotation_minimizing_frames(pts: &[Point3], tangents: &[Vector3]) -> Vec<Vector3> {
+    let t0 = tangents[0];
+    let axis = [Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()]
+        .into_iter()
+        .min_by(|a, b| f64::abs(a.dot(t0)).total_cmp(&f64::abs(b.dot(t0))))
+        .unwrap();
+    let mut frames = vec![t0.cross(axis).normalize()];
+    for i in 0..pts.len() - 1 {
+        let r = frames[i];
+        let v1 = pts[i + 1] - pts[i];
+        let c1 = v1.dot(v1);
+        if c1.so_small2() {
+            frames.push(r);
+            continue;
+        }
+        let r_l = r - v1 * (2.0 / c1 * v1.dot(r));
+        let t_l = tangents[i] - v1 * (2.0 / c1 * v1.dot(tangents[i]));
+        let v2 = tangents[i + 1] - t_l;
+        let c2 = v2.dot(v2);
+        let next = match c2.so_small2() {
+            true => r_l,
+            false => r_l - v2 * (2.0 / c2 * v2.dot(r_l)),
+        };
+        frames.push(next);
+    }
+    frames
+}
+
+#[inline(always)]
+fn frame_matrix(origin: Point3, r: Vector3, tangent: Vector3) -> Matrix4 {
+    let rot = Matrix3::from_cols(r, tangent.cross(r), tangent);
+    Matrix4::from_translation(origin.to_vec()) * Matrix4::from(rot)
+}
+
+pub(super) fn sweep_along(profile: &Wire, path: &Curve) -> Result<Shell> {
+    let (t0, t1) = path.range_tuple();
+    let closed = path.front().near(&path.back());
+    let param = |i: usize, n: usize| t0 + (t1 - t0) * i as f64 / n as f64;
+
+    // the path must be regular, and the profile must be within its curvature radius.
+    let profile_extent = profile
+        .edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.curve();
+            let (s0, s1) = curve.range_tuple();
+            (0..=SWEEP_SECTIONS)
+                .map(move |i| curve.subs(s0 + (s1 - s0) * i as f64 / SWEEP_SECTIONS as f64))
+        })
+        .fold(0.0, |extent, pt| {
+            f64::max(extent, pt.distance(path.front()))
+        });
+    let steps = TURNING_DIVISION * SWEEP_SECTIONS;
+    let min_radius = (0..=steps).try_fold(f64::INFINITY, |radius, i| {
+        let t = param(i, steps);
+        let (der, der2) = (path.der(t), path.der2(t));
+        if der.so_small() {
+            return Err(Error::NonRegularPath);
+        }
+        let curvature = der.cross(der2).magnitude() / der.magnitude().powi(3);
+        Ok(f64::min(radius, 1.0 / curvature))
+    })?;
+    if min_radius < profile_extent {
+        return Err(Error::TooSmallCurvatureRadius);
+    }
+
+    // the path is divided so that each segment turns at most a quarter turn.
+    let turning = (0..TURNING_DIVISION).fold(0.0, |sum, i| {
+        let (t, s) = (param(i, TURNING_DIVISION), param(i + 1, TURNING_DIVISION));
+        sum + path.der(t).angle(path.der(s)).0
+    });
+    let min_segments = if closed { 3 } else { 1 };
+    let segments = usize::max(f64::ceil(turning / (PI / 2.0)) as usize, min_segments);
+    let sections = segments * SWEEP_SECTIONS;
+
+    let steps = sections * FRAME_STEPS;
+    let pts: Vec<Point3> = (0..=steps).map(|i| path.subs(param(i, steps))).collect();
+    let tangents: Vec<Vector3> = (0..=steps)
+        .map(|i| path.der(param(i, steps)).normalize())
+        .collect();
+    let mut frames = rotation_minimizing_frames(&pts, &tangents);
+    if closed {
+        // distributes the twist of the frame at the end so that the frames are closed.
+        let (r0, r1) = (frames[0], frames[steps]);
+        let twist = f64::atan2(tangents[0].dot(r1.cross(r0)), r1.dot(r0));
+        frames.iter_mut().enumerate().for_each(|(i, r)| {
+            let angle = Rad(twist * i as f64 / steps as f64);
+            *r = Matrix3::from_axis_angle(tangents[i], angle) * *r;
+        });
+    }
+    let start_inverse = frame_matrix(pts[0], frames[0], tangents[0])
+        .invert()
+        .unwrap();
+    let transforms: Vec<Matrix4> = (0..=sections)
+        .map(|k| match k == 0 || (closed && k == sections) {
+            true => Matrix4::identity(),
+            false => {
+                let i = k * FRAME_STEPS;
+                frame_matrix(pts[i], frames[i], tangents[i]) * start_inverse
+            }
+        })
+        .collect();
+
+    let boundaries: Vec<Wire> = (0..=segments)
+        .map(|s| match s == 0 || (closed && s == segments) {
+            true => profile.clone(),
+            false => builder::transformed(profile, transforms[s * SWEEP_SECTIONS]),
+        })
+        .collect();
+    let params: Vec<f64> = (0..=SWEEP_SECTIONS)
+        .map(|k| k as f64 / SWEEP_SECTIONS as f64)
+        .collect();
+    let mut shell = Shell::new();
+    for s in 0..segments {
+        // the rails traced by the vertices, shared by the adjacent faces.
+        let mut rails = HashMap::<VertexID, Edge>::default();
+        let (wire0, wire1) = (&boundaries[s], &boundaries[s + 1]);
+        for ((edge, edge0), edge1) in profile
+            .edge_iter()
+            .zip(wire0.edge_iter())
+            .zip(wire1.edge_iter())
+        {
+            let curve = NurbsCurve::new(edge.oriented_curve().lift_up());
+            let curves = transforms[s * SWEEP_SECTIONS..=(s + 1) * SWEEP_SECTIONS]
+                .iter()
+                .map(|mat| curve.transformed(*mat).into_non_rationalized())
+                .collect();
+            let surface = BSplineSurface::skin_with_parameters(curves, &params, 3);
+            let mut rail = |vertex: &Vertex, v0: &Vertex, v1: &Vertex, u: f64| -> Edge {
+                rails
+                    .entry(vertex.id())
+                    .or_insert_with(|| Edge::new(v0, v1, NurbsCurve::new(surface.uiso(u)).into()))
+                    .clone()
+            };
+            let front_rail = rail(edge.front(), edge0.front(), edge1.front(), 0.0);
+            let back_rail = rail(edge.back(), edge0.back(), edge1.back(), 1.0);
+            let wire: Wire = vec![
+                edge0.clone(),
+                back_rail,
+                edge1.inverse(),
+                front_rail.inverse(),
+            ]
+            .into();
+            shell.push(Face::new(
+                vec![wire],
+                Surface::NurbsSurface(NurbsSurface::new(surface)),
+            ));
+        }
+    }
+    Ok(shell)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circle_profile(center: Point3, normal: Vector3, radius: f64) -> Wire {
+        let axis = match normal.cross(Vector3::unit_z()).so_small() {
+            true => Vector3::unit_x(),
+            false => normal.cross(Vector3::unit_z()).normalize(),
+        };
+        let v = builder::vertex(center + axis * radius);
+        builder::rsweep(&v, center, normal, Rad(7.0))
+    }
+
+    #[test]
+    fn pipe_along_bezier() {
+        let ctrl_pts = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(0.0, 0.0, 4.0),
+            Point3::new(4.0, 0.0, 4.0),
+            Point3::new(4.0, 4.0, 8.0),
+        ];
+        let path = BSplineCurve::new(KnotVec::bezier_knot(3), ctrl_pts);
+        let profile = circle_profile(Point3::origin(), Vector3::unit_z(), 0.2);
+        let path = Curve::BSplineCurve(path);
+        let shell = builder::sweep_along(&profile, &path).unwrap();
+        assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+        assert!(shell.is_geometric_consistent());
+        assert_eq!(shell.extract_boundaries().len(), 2);
+
+        // the surfaces keep the distance from the path
+        shell.face_iter().for_each(|face| {
+            let surface = face.oriented_surface();
+            (0..=4).for_each(|i| {
+                (0..=4).for_each(|j| {
+                    let pt = surface.subs(i as f64 / 4.0, j as f64 / 4.0);
+                    let t = path.search_nearest_parameter(pt, None, 100).unwrap();
+                    assert!(f64::abs(path.subs(t).distance(pt) - 0.2) < 1.0e-3);
+                });
+            });
+        });
+    }
+
+    #[test]
+    fn closed_path() {
+        // a periodic B-spline curve near the circle with the radius 2
+        let ctrl_pts: Vec<Point3> = (0..8)
+            .map(|i| {
+                let t = 2.0 * PI * i as f64 / 8.0;
+                Point3::new(2.0 * f64::cos(t), 2.0 * f64::sin(t), 0.0)
+            })
+            .collect();
+        let path = BSplineCurve::make_periodic(ctrl_pts, 3);
+        let start = path.front();
+        let tangent = path.der(0.0).normalize();
+        let profile = circle_profile(start, tangent, 0.5);
+        let path = Curve::BSplineCurve(path);
+        let shell = builder::sweep_along(&profile, &path).unwrap();
+        assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+        assert!(shell.is_geometric_consistent());
+        shell.face_iter().for_each(|face| {
+            let pt = face.oriented_surface().subs(0.5, 0.5);
+            let t = path.search_nearest_parameter(pt, None, 100).unwrap();
+            assert!(f64::abs(path.subs(t).distance(pt) - 0.5) < 1.0e-3);
+        });
+    }
+
+    #[test]
+    fn too_sharp_path() {
+        let ctrl_pts = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(0.0, 0.0, 1.0),
+            Point3::new(1.0, 0.0, 1.0),
+        ];
+        let path = Curve::BSplineCurve(BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts));
+        let profile = circle_profile(Point3::origin(), Vector3::unit_z(), 0.8);
+        assert!(matches!(
+            builder::sweep_along(&profile, &path),
+            Err(Error::TooSmallCurvatureRadius),
+        ));
+    }
+
+    #[test]
+    fn degenerate_path() {
+        // the derivative vanishes at the start point.
+        let ctrl_pts = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(0.0, 0.0, 2.0),
+            Point3::new(0.0, 0.0, 3.0),
+        ];
+        let path = Curve::BSplineCurve(BSplineCurve::new(KnotVec::bezier_knot(3), ctrl_pts));
+        let profile = circle_profile(Point3::origin(), Vector3::unit_z(), 0.2);
+        assert!(matches!(
+            builder::sweep_along(&profile, &path),
+            Err(Error::NonRegularPath),
+        ));
+    }
+}
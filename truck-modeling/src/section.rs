@@ -0,0 +1,539 @@
+use crate::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+/// the number of the steps for tracing the section across a face
+const TRACE_DIVISION: f64 = 64.0;
+/// the number of the iterations of Newton's method projecting the trace onto the plane
+const PROJECTION_TRIALS: usize = 4;
+/// the number of the iterations of bisection searching the crossing of an edge
+const BISECTION_TRIALS: usize = 64;
+
+/// A point on the plane on the boundary of a face with its parameter on the surface.
+#[derive(Clone, Debug)]
+struct Crossing {
+    vertex: Vertex,
+    uv: Point2,
+}
+
+struct Sectioner {
+    origin: Point3,
+    normal: Vector3,
+    /// the tolerance for dividing curves
+    tol: f64,
+}
+
+impl Sectioner {
+    #[inline(always)]
+    fn distance(&self, pt: Point3) -> f64 { self.normal.dot(pt - self.origin) }
+
+    #[inline(always)]
+    fn on_plane(&self, pt: Point3) -> bool { self.distance(pt).so_small() }
+
+    /// Divides the curve. The intersection curves are approximated by their leaders for speed.
+    fn division(&self, curve: &Curve) -> (Vec<f64>, Vec<Point3>) {
+        match curve {
+            Curve::IntersectionCurve(curve) => {
+                let leader = curve.leader();
+                leader.parameter_division(leader.range_tuple(), self.tol)
+            }
+            _ => curve.parameter_division(curve.range_tuple(), self.tol),
+        }
+    }
+
+    /// Returns the closed polyline approximating `wire`.
+    fn polyline(&self, wire: &Wire) -> Vec<Point3> {
+        wire.edge_iter()
+            .flat_map(|edge| {
+                let (_, pts) = self.division(&edge.oriented_curve());
+                let len = pts.len();
+                pts.into_iter().take(len - 1)
+            })
+            .chain(wire.front_vertex().map(|v| v.point()))
+            .collect()
+    }
+
+    fn is_on_plane(&self, curve: &Curve) -> bool {
+        self.division(curve)
+            .1
+            .into_iter()
+            .all(|pt| self.on_plane(pt))
+    }
+
+    /// Returns the points where the curve crosses the plane, except for the end points.
+    fn crossings(&self, curve: &Curve) -> Vec<Point3> {
+        let (params, pts) = self.division(curve);
+        let mut last: Option<(f64, f64)> = None;
+        let mut res = Vec::new();
+        for (t, pt) in params.into_iter().zip(pts) {
+            let dist = self.distance(pt);
+            if dist.so_small() {
+                continue;
+            }
+            if let Some((s, prev)) = last {
+                if prev * dist < 0.0 {
+                    res.push(self.bisection(curve, (s, prev), t));
+                }
+            }
+            last = Some((t, dist));
+        }
+        res
+    }
+
+    fn bisection(&self, curve: &Curve, (mut s, prev): (f64, f64), mut t: f64) -> Point3 {
+        for _ in 0..BISECTION_TRIALS {
+            let mid = (s + t) / 2.0;
+            match self.distance(curve.subs(mid)) * prev > 0.0 {
+                true => s = mid,
+                false => t = mid,
+            }
+        }
+        curve.subs((s + t) / 2.0)
+    }
+
+    /// Returns the signed distance of the boundary point of `face` farthest from the plane.
+    fn face_side(&self, face: &Face) -> f64 {
+        face.edge_iter()
+            .flat_map(|edge| self.division(&edge.curve()).1)
+            .map(|pt| self.distance(pt))
+            .fold(0.0, |side, dist| match dist.abs() > side.abs() {
+                true => dist,
+                false => side,
+            })
+    }
+
+    fn is_coplanar(&self, face: &Face) -> bool {
+        match face.surface() {
+            Surface::Plane(plane) => {
+                plane.normal().cross(self.normal).so_small()
+                    && face.vertex_iter().all(|v| self.on_plane(v.point()))
+            }
+            _ => false,
+        }
+    }
+
+    #[inline(always)]
+    fn gradient(&self, surface: &Surface, uv: Point2) -> Vector2 {
+        Vector2::new(
+            self.normal.dot(surface.uder(uv.x, uv.y)),
+            self.normal.dot(surface.vder(uv.x, uv.y)),
+        )
+    }
+
+    /// Traces the section on `surface` from `crossings[start]` in the direction with `sign`
+    /// until it reaches another crossing, and returns the index of the crossing and the trace.
+    fn trace(
+        &self,
+        surface: &Surface,
+        domain: &[Vec<Point2>],
+        step: f64,
+        crossings: &[Crossing],
+        start: usize,
+        sign: f64,
+    ) -> Option<(usize, Vec<Point3>)> {
+        let mut uv = crossings[start].uv;
+        let mut pts = vec![crossings[start].vertex.point()];
+        for _ in 0..4 * TRACE_DIVISION as usize {
+            let grad = self.gradient(surface, uv);
+            if grad.magnitude().so_small() {
+                return None;
+            }
+            let dir = Vector2::new(-grad.y, grad.x) * (sign / grad.magnitude());
+            let der = surface.uder(uv.x, uv.y) * dir.x + surface.vder(uv.x, uv.y) * dir.y;
+            let mut next = uv + dir * (step / der.magnitude());
+            for _ in 0..PROJECTION_TRIALS {
+                let grad = self.gradient(surface, next);
+                let dist = self.distance(surface.subs(next.x, next.y));
+                next -= grad * (dist / grad.magnitude2());
+            }
+            let (pt, next_pt) = (surface.subs(uv.x, uv.y), surface.subs(next.x, next.y));
+            let arrival = crossings
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != start)
+                .map(|(i, crossing)| (i, segment_distance(crossing.vertex.point(), pt, next_pt)))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b));
+            if let Some((i, dist)) = arrival {
+                if dist < step / 2.0 {
+                    pts.push(crossings[i].vertex.point());
+                    return Some((i, pts));
+                }
+            }
+            if !inside(domain, next) {
+                return None;
+            }
+            pts.push(next_pt);
+            uv = next;
+        }
+        None
+    }
+}
+
+fn segment_distance(pt: Point3, p: Point3, q: Point3) -> f64 {
+    let dir = q - p;
+    let t = match dir.magnitude2().so_small2() {
+        true => 0.0,
+        false => f64::clamp((pt - p).dot(dir) / dir.magnitude2(), 0.0, 1.0),
+    };
+    pt.distance(p + dir * t)
+}
+
+/// Returns whether `pt` is inside the polygons by the even-odd rule.
+fn inside(polygons: &[Vec<Point2>], pt: Point2) -> bool {
+    polygons
+        .iter()
+        .flat_map(|polygon| polygon.windows(2))
+        .filter(|seg| {
+            let (p, q) = (seg[0], seg[1]);
+            (p.y > pt.y) != (q.y > pt.y) && {
+                let x = p.x + (pt.y - p.y) / (q.y - p.y) * (q.x - p.x);
+                pt.x < x
+            }
+        })
+        .count()
+        % 2
+        == 1
+}
+
+fn signed_area(polygon: &[Point2]) -> f64 {
+    polygon
+        .windows(2)
+        .map(|seg| seg[0].x * seg[1].y - seg[1].x * seg[0].y)
+        .sum::<f64>()
+        / 2.0
+}
+
+/// Chains the edges into closed wires. The edges out of any closed wires are dropped.
+fn chain(edges: Vec<Edge>) -> Vec<Wire> {
+    let mut adjacency = HashMap::<VertexID, Vec<usize>>::default();
+    edges.iter().enumerate().for_each(|(i, edge)| {
+        adjacency.entry(edge.front().id()).or_default().push(i);
+        adjacency.entry(edge.back().id()).or_default().push(i);
+    });
+    let mut used = vec![false; edges.len()];
+    let mut wires = Vec::new();
+    for i in 0..edges.len() {
+        if used[i] {
+            continue;
+        }
+        used[i] = true;
+        let mut wire = Wire::from(vec![edges[i].clone()]);
+        let start = edges[i].front().id();
+        let mut current = edges[i].back().id();
+        while current != start {
+            let Some(&j) = adjacency[&current].iter().find(|j| !used[**j]) else {
+                break;
+            };
+            used[j] = true;
+            let edge = match edges[j].front().id() == current {
+                true => edges[j].clone(),
+                false => edges[j].inverse(),
+            };
+            current = edge.back().id();
+            wire.push_back(edge);
+        }
+        if current == start {
+            wires.push(wire);
+        }
+    }
+    wires
+}
+
+pub(super) fn section(solid: &Solid, plane: &Plane) -> Vec<Wire> {
+    let bdb: BoundingBox<Point3> = solid.vertex_iter().map(|v| v.point()).collect();
+    let sectioner = Sectioner {
+        origin: plane.origin(),
+        normal: plane.normal(),
+        tol: bdb.diameter() * 1.0e-3,
+    };
+    let faces: Vec<&Face> = solid.face_iter().collect();
+    let coplanar: Vec<bool> = faces
+        .iter()
+        .map(|face| sectioner.is_coplanar(face))
+        .collect();
+    let mut adjacency = HashMap::<EdgeID, Vec<usize>>::default();
+    faces.iter().enumerate().for_each(|(i, face)| {
+        face.edge_iter()
+            .for_each(|edge| adjacency.entry(edge.id()).or_default().push(i))
+    });
+
+    // the new vertices on the plane, corresponding to the vertices of the solid
+    let mut vertices = HashMap::<VertexID, Vertex>::default();
+    let mut new_vertex = |v: &Vertex| -> Vertex {
+        vertices
+            .entry(v.id())
+            .or_insert_with(|| Vertex::new(v.point()))
+            .clone()
+    };
+    let mut section_edges = Vec::new();
+    let mut coplanar_edges = HashMap::<EdgeID, Edge>::default();
+    let mut crossings = HashMap::<EdgeID, Vec<Vertex>>::default();
+    let mut on_plane_edges = HashSet::<EdgeID>::default();
+    for edge in solid.edge_iter() {
+        if crossings.contains_key(&edge.id()) {
+            continue;
+        }
+        let curve = edge.curve();
+        if !sectioner.is_on_plane(&curve) {
+            let pts = sectioner.crossings(&curve);
+            crossings.insert(edge.id(), pts.into_iter().map(Vertex::new).collect());
+            continue;
+        }
+        crossings.insert(edge.id(), Vec::new());
+        on_plane_edges.insert(edge.id());
+        let (front, back) = edge.absolute_ends();
+        let new_edge = Edge::new(&new_vertex(front), &new_vertex(back), curve);
+        let adjacents = &adjacency[&edge.id()];
+        // the boundary of the coplanar faces, where the shared edges between them are canceled
+        let count = adjacents.iter().filter(|i| coplanar[**i]).count();
+        if count == 1 {
+            coplanar_edges.insert(edge.id(), new_edge);
+        } else if count == 0 {
+            // the edge is in the section unless the plane is tangent to the solid there.
+            let sides: Vec<f64> = adjacents
+                .iter()
+                .map(|i| sectioner.face_side(faces[*i]))
+                .collect();
+            if sides.iter().any(|side| *side > 0.0) && sides.iter().any(|side| *side < 0.0) {
+                section_edges.push(new_edge);
+            }
+        }
+    }
+    section_edges.extend(coplanar_edges.into_values());
+
+    for (face, _) in faces
+        .iter()
+        .zip(&coplanar)
+        .filter(|(_, coplanar)| !**coplanar)
+    {
+        let surface = face.oriented_surface();
+        // the parameter of `pt` on the same period as `hint`
+        let search = |pt: Point3, hint: Option<Point2>| -> Option<Point2> {
+            let hint_tuple = hint.map(|uv| (uv.x, uv.y));
+            let uv = surface
+                .search_parameter(pt, hint_tuple, 100)
+                .or_else(|| surface.search_nearest_parameter(pt, hint_tuple, 100))?;
+            let mut uv = Point2::from(uv);
+            if let Some(hint) = hint {
+                let periods = [surface.u_period(), surface.v_period()];
+                periods.into_iter().enumerate().for_each(|(i, period)| {
+                    if let Some(period) = period {
+                        uv[i] += period * f64::round((hint[i] - uv[i]) / period);
+                    }
+                });
+            }
+            Some(uv)
+        };
+        let boundary: Vec<Vec<Point3>> = face
+            .boundaries()
+            .iter()
+            .map(|wire| sectioner.polyline(wire))
+            .collect();
+        let Some(domain) = boundary
+            .iter()
+            .map(|pts| {
+                pts.iter().try_fold(Vec::new(), |mut uvs, pt| {
+                    uvs.push(search(*pt, uvs.last().copied())?);
+                    Some(uvs)
+                })
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        let mut face_crossings = Vec::<Crossing>::new();
+        let mut added = HashSet::<VertexID>::default();
+        for edge in face.edge_iter() {
+            let front = edge.front();
+            let on_plane = sectioner.on_plane(front.point());
+            let vertex = on_plane.then(|| new_vertex(front));
+            let interior = crossings[&edge.id()].iter().cloned();
+            for vertex in vertex.into_iter().chain(interior) {
+                if !added.insert(vertex.id()) {
+                    continue;
+                }
+                let pt = vertex.point();
+                let hint = boundary
+                    .iter()
+                    .flatten()
+                    .zip(domain.iter().flatten())
+                    .min_by(|(p, _), (q, _)| p.distance2(pt).total_cmp(&q.distance2(pt)))
+                    .map(|(_, uv)| *uv);
+                if let Some(uv) = search(pt, hint) {
+                    face_crossings.push(Crossing { vertex, uv });
+                }
+            }
+        }
+
+        let size = face
+            .vertex_iter()
+            .map(|v| v.point())
+            .collect::<BoundingBox<Point3>>()
+            .diameter();
+        let step = size / TRACE_DIVISION;
+        // the sections along the edges on the plane have already been found.
+        let mut traced: HashSet<(VertexID, VertexID)> = face
+            .edge_iter()
+            .filter(|edge| on_plane_edges.contains(&edge.id()))
+            .map(|edge| (new_vertex(edge.front()).id(), new_vertex(edge.back()).id()))
+            .collect();
+        for i in 0..face_crossings.len() {
+            for sign in [1.0, -1.0] {
+                let Some((j, pts)) =
+                    sectioner.trace(&surface, &domain, step, &face_crossings, i, sign)
+                else {
+                    continue;
+                };
+                let (v0, v1) = (&face_crossings[i].vertex, &face_crossings[j].vertex);
+                if traced.contains(&(v1.id(), v0.id())) || !traced.insert((v0.id(), v1.id())) {
+                    continue;
+                }
+                let curve = match surface {
+                    Surface::Plane(_) => Curve::Line(Line(v0.point(), v1.point())),
+                    _ => IntersectionCurve::new_unchecked(
+                        Box::new(surface.clone()),
+                        Box::new(Surface::Plane(*plane)),
+                        PolylineCurve(pts),
+                        sectioner.tol,
+                    )
+                    .into(),
+                };
+                section_edges.push(Edge::new(v0, v1, curve));
+            }
+        }
+    }
+
+    // orients the outer loops counterclockwise and the holes clockwise.
+    let u_axis = plane.u_axis().normalize();
+    let v_axis = sectioner.normal.cross(u_axis);
+
+    let mut wires = chain(section_edges);
+    let polygons: Vec<Vec<Point2>> = wires
+        .iter()
+        .map(|wire| {
+            sectioner
+                .polyline(wire)
+                .into_iter()
+                .map(|pt| {
+                    let vec = pt - sectioner.origin;
+                    Point2::new(vec.dot(u_axis), vec.dot(v_axis))
+                })
+                .collect()
+        })
+        .collect();
+    wires.iter_mut().enumerate().for_each(|(i, wire)| {
+        let depth = (0..polygons.len())
+            .filter(|j| *j != i && inside(&polygons[*j..=*j], polygons[i][0]))
+            .count();
+        let is_hole = depth % 2 == 1;
+        if (signed_area(&polygons[i]) > 0.0) == is_hole {
+            wire.invert();
+        }
+    });
+    wires
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn unit_cube() -> Solid {
+        let v = builder::vertex(Point3::origin());
+        let e = builder::tsweep(&v, Vector3::unit_x());
+        let f = builder::tsweep(&e, Vector3::unit_y());
+        builder::tsweep(&f, Vector3::unit_z())
+    }
+
+    fn horizontal_plane(z: f64) -> Plane {
+        Plane::new(
+            Point3::new(0.0, 0.0, z),
+            Point3::new(1.0, 0.0, z),
+            Point3::new(0.0, 1.0, z),
+        )
+    }
+
+    /// Returns the signed area of the wire projected to the xy-plane.
+    fn area(wire: &Wire) -> f64 {
+        let polygon: Vec<Point2> = wire
+            .edge_iter()
+            .flat_map(|edge| {
+                let curve = edge.oriented_curve();
+                let (t0, t1) = curve.range_tuple();
+                (0..8).map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / 8.0))
+            })
+            .chain(wire.front_vertex().map(|v| v.point()))
+            .map(|pt| Point2::new(pt.x, pt.y))
+            .collect();
+        signed_area(&polygon)
+    }
+
+    #[test]
+    fn section_cube() {
+        let cube = unit_cube();
+        // crossing the side faces and lying on the top face
+        for z in [0.5, 1.0] {
+            let wires = builder::section(&cube, &horizontal_plane(z));
+            assert_eq!(wires.len(), 1);
+            assert_eq!(wires[0].len(), 4);
+            assert!(wires[0].is_closed());
+            assert!(wires[0].vertex_iter().all(|v| v.point().z.near(&z)));
+            assert!(area(&wires[0]).near(&1.0));
+        }
+        // the plane through two opposite vertical edges
+        let plane = Plane::new(
+            Point3::origin(),
+            Point3::new(0.0, 0.0, 1.0),
+            Point3::new(1.0, 1.0, 0.0),
+        );
+        let wires = builder::section(&cube, &plane);
+        assert_eq!(wires.len(), 1);
+        assert_eq!(wires[0].len(), 4);
+        // tangent to the cube
+        assert!(builder::section(&cube, &horizontal_plane(2.0)).is_empty());
+        let plane = Plane::new(
+            Point3::new(1.0, 1.0, 0.0),
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(2.0, 0.0, 0.0),
+        );
+        assert!(builder::section(&cube, &plane).is_empty());
+    }
+
+    #[test]
+    fn section_torus() {
+        let v = builder::vertex(Point3::new(3.0, 0.0, 0.0));
+        let circle = builder::rsweep(&v, Point3::new(2.0, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+        let torus = Solid::new(vec![builder::rsweep(
+            &circle,
+            Point3::origin(),
+            Vector3::unit_z(),
+            Rad(7.0),
+        )]);
+        // the outer contour is counterclockwise, the inner one is clockwise.
+        for z in [0.0, 0.5] {
+            let wires = builder::section(&torus, &horizontal_plane(z));
+            assert_eq!(wires.len(), 2);
+            let r = f64::sqrt(1.0 - z * z);
+            let mut areas: Vec<f64> = wires.iter().map(area).collect();
+            areas.sort_by(f64::total_cmp);
+            // the areas of the polygons inscribed in the circles
+            let inner = -PI * (2.0 - r) * (2.0 - r);
+            let outer = PI * (2.0 + r) * (2.0 + r);
+            assert!(f64::abs(areas[0] / inner - 1.0) < 0.02);
+            assert!(f64::abs(areas[1] / outer - 1.0) < 0.02);
+            wires.iter().flat_map(Wire::edge_iter).for_each(|edge| {
+                let curve = edge.curve();
+                let (t0, t1) = curve.range_tuple();
+                let pt = curve.subs((t0 + t1) / 2.0);
+                assert!(pt.z.near(&z));
+            });
+        }
+        // two disjoint circles through the axis
+        let plane = Plane::new(
+            Point3::origin(),
+            Point3::new(1.0, 1.0, 0.0),
+            Point3::new(0.0, 0.0, 1.0),
+        );
+        let wires = builder::section(&torus, &plane);
+        assert_eq!(wires.len(), 2);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::*;
+use std::f64::consts::PI;
+
+const ARC_CHECK_DIVISION: usize = 16;
+
+/// Circle arc `center + cos(theta) * x_axis + sin(theta) * y_axis` with `theta` in `[0, angle]`.
+#[derive(Clone, Copy, Debug)]
+struct CircleArc {
+    center: Point3,
+    x_axis: Vector3,
+    y_axis: Vector3,
+    angle: f64,
+}
+
+impl CircleArc {
+    /// Returns the arc if `curve` is a circle arc parametrized monotonically with respect to the angle.
+    fn try_from_curve<C: BoundedCurve<Point = Point3>>(curve: &C) -> Option<Self> {
+        let (t0, t1) = curve.range_tuple();
+        let pt0 = curve.subs(t0);
+        let pt1 = curve.subs(t0 + (t1 - t0) / 3.0);
+        let pt2 = curve.subs(t0 + (t1 - t0) * 2.0 / 3.0);
+        let normal = (pt1 - pt0).cross(pt2 - pt1);
+        if normal.so_small() {
+            return None;
+        }
+        let normal = normal.normalize();
+        let center = geom_impls::circum_center(pt0, pt1, pt2);
+        let x_axis = pt0 - center;
+        let radius = x_axis.magnitude();
+        let y_axis = normal.cross(x_axis);
+        let arg = |pt: Point3| {
+            let vec = pt - center;
+            let angle = f64::atan2(vec.dot(y_axis), vec.dot(x_axis));
+            match angle < 0.0 {
+                true => angle + 2.0 * PI,
+                false => angle,
+            }
+        };
+        let mut angle = 0.0;
+        for i in 1..=ARC_CHECK_DIVISION {
+            let t = t0 + (t1 - t0) * i as f64 / ARC_CHECK_DIVISION as f64;
+            let pt = curve.subs(t);
+            let on_circle = pt.distance(center).near(&radius) && normal.dot(pt - center).so_small();
+            let mut next = arg(pt);
+            if i == ARC_CHECK_DIVISION && next.so_small() {
+                next = 2.0 * PI;
+            }
+            if !on_circle || next <= angle {
+                return None;
+            }
+            angle = next;
+        }
+        Some(Self {
+            center,
+            x_axis,
+            y_axis,
+            angle,
+        })
+    }
+
+    fn tessellate(&self, tol: f64) -> Vec<Point3> {
+        let radius = self.x_axis.magnitude();
+        let step = 2.0 * f64::acos(1.0 - f64::min(tol / radius, 1.0));
+        let division = usize::max(f64::ceil(self.angle / step) as usize, 1);
+        (0..=division)
+            .map(|i| {
+                let theta = self.angle * i as f64 / division as f64;
+                self.center + f64::cos(theta) * self.x_axis + f64::sin(theta) * self.y_axis
+            })
+            .collect()
+    }
+}
+
+fn curve_tessellation(curve: &Curve, tol: f64) -> Vec<Point3> {
+    match curve {
+        Curve::Line(line) => vec![line.0, line.1],
+        Curve::NurbsCurve(nurbs) => match CircleArc::try_from_curve(nurbs) {
+            Some(arc) => arc.tessellate(tol),
+            None => nurbs.parameter_division(nurbs.range_tuple(), tol).1,
+        },
+        _ => curve.parameter_division(curve.range_tuple(), tol).1,
+    }
+}
+
+impl PolylineTessellation<Point3> for Edge {
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    ///
+    /// let v = [Point3::new(1.0, 0.0, 0.0), Point3::new(-1.0, 0.0, 0.0)].map(builder::vertex);
+    /// // a line is not divided
+    /// let line = builder::line(&v[0], &v[1]);
+    /// assert_eq!(line.tessellate(0.01).len(), 2);
+    ///
+    /// // a circle arc is divided uniformly by the angle
+    /// let arc = builder::circle_arc(&v[0], &v[1], Point3::new(0.0, 1.0, 0.0));
+    /// let pts = arc.tessellate(0.01);
+    /// assert_eq!(pts[0], v[0].point());
+    /// assert_eq!(pts[pts.len() - 1], v[1].point());
+    /// pts.windows(2).for_each(|p| {
+    ///     assert_near!(p[0].to_vec().magnitude(), 1.0);
+    ///     assert!(p[0].midpoint(p[1]).to_vec().magnitude() > 1.0 - 0.01);
+    /// });
+    /// ```
+    fn tessellate(&self, tol: f64) -> Vec<Point3> {
+        let mut pts = curve_tessellation(&self.oriented_curve(), tol);
+        let len = pts.len();
+        pts[0] = self.front().point();
+        pts[len - 1] = self.back().point();
+        pts
+    }
+}
+
+impl PolylineTessellation<Point3> for Wire {
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    ///
+    /// let v = [
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    /// ]
+    /// .map(builder::vertex);
+    /// let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+    /// let pts = wire.tessellate(0.01);
+    /// assert_eq!(pts, vec![v[0].point(), v[1].point(), v[2].point()]);
+    /// ```
+    fn tessellate(&self, tol: f64) -> Vec<Point3> {
+        let mut res = Vec::new();
+        self.edge_iter().for_each(|edge| {
+            let pts = edge.tessellate(tol);
+            let skip = usize::from(!res.is_empty());
+            res.extend(pts.into_iter().skip(skip));
+        });
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arc_detection() {
+        let v = [Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 1.0)].map(builder::vertex);
+        let transit = Point3::new(0.5, 0.5, 0.0);
+        let edge = builder::circle_arc(&v[0], &v[1], transit);
+        let Curve::NurbsCurve(nurbs) = edge.oriented_curve() else {
+            panic!("a circle arc must be a NURBS curve.");
+        };
+        let arc = CircleArc::try_from_curve(&nurbs).unwrap();
+        let radius = arc.x_axis.magnitude();
+        arc.tessellate(0.001).iter().for_each(|pt| {
+            assert_near!(pt.distance(arc.center), radius);
+            let t = nurbs.search_nearest_parameter(*pt, None, 100).unwrap();
+            assert_near!(nurbs.subs(t), *pt);
+        });
+
+        // a whole circle
+        let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+        let wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+        let pts = wire.tessellate(0.001);
+        assert_near!(pts[0], pts[pts.len() - 1]);
+        pts.iter()
+            .for_each(|pt| assert_near!(pt.to_vec().magnitude(), 1.0));
+
+        // a Bezier curve is not a circle arc
+        let v = [Point3::new(0.0, 0.0, 0.0), Point3::new(3.0, 0.0, 0.0)].map(builder::vertex);
+        let inter_points = vec![Point3::new(1.0, 1.0, 0.0), Point3::new(2.0, 1.0, 0.0)];
+        let edge = builder::bezier(&v[0], &v[1], inter_points);
+        let Curve::BSplineCurve(bspcurve) = edge.oriented_curve() else {
+            panic!("a Bezier curve must be a B-spline curve.");
+        };
+        let nurbs = NurbsCurve::<Vector4>::from(bspcurve);
+        assert!(CircleArc::try_from_curve(&nurbs).is_none());
+    }
+}
@@ -0,0 +1,33 @@
+use truck_modeling::*;
+
+#[test]
+fn search_parameter_on_thin_torus() {
+    // the thin ring `rsweep`ed from the circle of radius 0.01 centered at (10, 0, 0)
+    let v = builder::vertex(Point3::new(9.99, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::new(10.0, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+    let torus = builder::rsweep(&circle, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    assert_eq!(torus.shell_condition(), ShellCondition::Closed);
+
+    // the parameters are apart from the grid of the presearch.
+    const N: usize = 20;
+    torus.face_iter().for_each(|face| {
+        let surface = face.oriented_surface();
+        assert!(matches!(surface, Surface::RevolutedCurve(_)));
+        let (Some((u0, u1)), Some((v0, v1))) = surface.try_range_tuple() else {
+            panic!("the revolved surface is unbounded.");
+        };
+        for i in 0..N {
+            for j in 0..N {
+                let u = u0 + (u1 - u0) * (i as f64 + 0.377) / N as f64;
+                let v = v0 + (v1 - v0) * (j as f64 + 0.613) / N as f64;
+                let pt = surface.subs(u, v);
+                let (s, t) = surface
+                    .search_parameter(pt, None, 100)
+                    .unwrap_or_else(|| panic!("failed to search the parameter of ({u}, {v})."));
+                assert_near!(surface.subs(s, t), pt);
+                let (s, t) = surface.search_parameter(pt, (u, v), 10).unwrap();
+                assert_near!(surface.subs(s, t), pt);
+            }
+        }
+    });
+}
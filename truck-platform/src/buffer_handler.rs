@@ -21,6 +21,19 @@ impl BufferHandler {
             stride,
         }
     }
+    /// Overwrites the head of the buffer by a slice. The buffer must be created with
+    /// `BufferUsages::COPY_DST`.
+    /// # Panic
+    /// Panic occurs if the size of `vec` is larger than the one of `self`.
+    #[inline(always)]
+    pub fn write_slice<T: Sized + Pod + Zeroable, A: AsRef<[T]>>(&self, vec: &A, queue: &Queue) {
+        let contents: &[u8] = bytemuck::cast_slice(vec.as_ref());
+        assert!(
+            contents.len() as u64 <= self.size,
+            "The slice is larger than the buffer."
+        );
+        queue.write_buffer(&self.buffer, 0, contents);
+    }
     /// Returns the reference of the buffer.
     #[inline(always)]
     pub const fn buffer(&self) -> &Buffer { &self.buffer }
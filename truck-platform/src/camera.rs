@@ -68,6 +68,78 @@ impl Camera {
     #[inline(always)]
     pub const fn projection_type(&self) -> ProjectionType { self.projection_type }
 
+    /// Moves the camera so that the whole of `bounding_box` is on the screen.
+    ///
+    /// The direction of the camera is kept, and the camera looks at the center of the box.
+    /// The clipping planes are reset so that the view volume contains the box.
+    /// The field of view is kept for perspective cameras, and the screen size is changed for
+    /// parallel cameras. Does nothing if the box is empty.
+    /// # Arguments
+    /// - `bounding_box`: the box to be framed
+    /// - `as_rat`: the aspect ratio, x-resolution / y-resolution.
+    /// # Examples
+    /// ```
+    /// use truck_base::{bounding_box::BoundingBox, cgmath64::*, tolerance::Tolerance};
+    /// use truck_platform::*;
+    /// let bounding_box: BoundingBox<Point3> =
+    ///     [Point3::new(-1.0, 0.0, 2.0), Point3::new(3.0, 1.0, 5.0)].into_iter().collect();
+    /// for mut camera in [
+    ///     Camera::default(),
+    ///     Camera::parallel_camera(Matrix4::from_angle_x(Deg(30.0)), 1.0, 0.1, 1.0),
+    /// ] {
+    ///     let eye_direction = camera.eye_direction();
+    ///     camera.fit_to_box(&bounding_box, 1.5);
+    ///     assert!(camera.eye_direction().near(&eye_direction));
+    ///     // all corners of the box are in the normalized view volume.
+    ///     let projection = camera.projection(1.5);
+    ///     for x in [-1.0, 3.0] {
+    ///         for y in [0.0, 1.0] {
+    ///             for z in [2.0, 5.0] {
+    ///                 let p = projection.transform_point(Point3::new(x, y, z));
+    ///                 assert!(-1.0 < p.x && p.x < 1.0 && -1.0 < p.y && p.y < 1.0);
+    ///                 assert!(0.0 < p.z && p.z < 1.0);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn fit_to_box(&mut self, bounding_box: &BoundingBox<Point3>, as_rat: f64) {
+        if bounding_box.is_empty() {
+            return;
+        }
+        let center = bounding_box.center();
+        // a box of one point is framed as the unit sphere.
+        let radius = match bounding_box.diameter() > 0.0 {
+            true => bounding_box.diameter() / 2.0,
+            false => 1.0,
+        };
+        let mut matrix = self.matrix;
+        let (distance, camera) = match self.projection_type {
+            ProjectionType::Perspective => {
+                // the cotangents of the half angles of the view
+                let cot_x = self.projection[0][0] / as_rat;
+                let cot_y = self.projection[1][1];
+                let distance = radius * f64::sqrt(1.0 + f64::max(cot_x, cot_y).powi(2));
+                let field_of_view = Rad(2.0 * f64::atan(1.0 / cot_y));
+                // The depth of the perspective projection is in [-1, 1], and the negative part
+                // is clipped, so the actual near plane is about twice as far as `near`.
+                let (near, far) = (0.45 * (distance - radius), 1.1 * (distance + radius));
+                let camera = Camera::perspective_camera(matrix, field_of_view, near, far);
+                (distance, camera)
+            }
+            ProjectionType::Parallel => {
+                let screen_size = 2.0 * radius * f64::max(1.0, 1.0 / as_rat);
+                let distance = 2.0 * radius;
+                let camera =
+                    Camera::parallel_camera(matrix, screen_size, 0.5 * radius, 3.5 * radius);
+                (distance, camera)
+            }
+        };
+        let position = center - distance * self.eye_direction();
+        matrix[3] = position.to_homogeneous();
+        *self = Camera { matrix, ..camera };
+    }
+
     /// Creates a perspective camera.
     /// # Arguments
     /// * `matrix`:  camera matrix
@@ -251,7 +323,7 @@ impl Camera {
             * self.matrix.invert().unwrap()
     }
 
-    fn camera_info(&self, as_rat: f64) -> CameraInfo {
+    pub(crate) fn camera_info(&self, as_rat: f64) -> CameraInfo {
         CameraInfo {
             camera_matrix: self.matrix.cast().unwrap().into(),
             camera_projection: self.projection(as_rat).cast().unwrap().into(),
@@ -368,6 +440,132 @@ impl Camera {
             }
         }
     }
+
+    /// Returns the field of view or the screen size, and the distances to the near and far
+    /// clipping planes, recovered from the projection matrix.
+    fn projection_parameters(&self) -> (f64, f64, f64) {
+        let m = &self.projection;
+        match self.projection_type {
+            ProjectionType::Perspective => {
+                let field_of_view = 2.0 * f64::atan(1.0 / m[1][1]);
+                let (a, b) = (m[2][2], m[3][2]);
+                (field_of_view, b / (a - 1.0), b / (a + 1.0))
+            }
+            ProjectionType::Parallel => {
+                let near_clip = m[3][2] / m[2][2];
+                (2.0 / m[0][0], near_clip, near_clip - 1.0 / m[2][2])
+            }
+        }
+    }
+
+    /// Interpolates two cameras at the parameter `t`.
+    ///
+    /// The rotation parts of the camera matrices are interpolated by the spherical linear
+    /// interpolation along the shorter arc, and the positions and the projection parameters,
+    /// the field of view or the screen size and the clipping distances, are interpolated
+    /// linearly. Returns `None` if the projection types of the cameras are different.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_base::{assert_near, cgmath64::*, tolerance::*};
+    /// use truck_platform::*;
+    /// let a = Camera::perspective_camera(Matrix4::identity(), Rad(PI / 4.0), 0.1, 10.0);
+    /// let matrix = Matrix4::from_translation(Vector3::new(2.0, 0.0, 0.0))
+    ///     * Matrix4::from_angle_y(Rad(PI / 2.0));
+    /// let b = Camera::perspective_camera(matrix, Rad(PI / 2.0), 0.1, 20.0);
+    ///
+    /// let camera = Camera::interpolate(&a, &b, 0.0).unwrap();
+    /// assert_near!(camera.matrix, a.matrix);
+    /// assert_near!(camera.projection(1.0), a.projection(1.0));
+    ///
+    /// let camera = Camera::interpolate(&a, &b, 0.5).unwrap();
+    /// assert_near!(camera.position(), Point3::new(1.0, 0.0, 0.0));
+    /// let dir = Vector3::new(-1.0, 0.0, -1.0).normalize();
+    /// assert_near!(camera.eye_direction(), dir);
+    /// let expected = Camera::perspective_camera(camera.matrix, Rad(3.0 * PI / 8.0), 0.1, 15.0);
+    /// assert_near!(camera.projection(1.0), expected.projection(1.0));
+    ///
+    /// // a perspective camera and a parallel camera are not interpolated.
+    /// let c = Camera::parallel_camera(Matrix4::identity(), 1.0, 0.1, 10.0);
+    /// assert!(Camera::interpolate(&a, &c, 0.5).is_none());
+    /// ```
+    pub fn interpolate(a: &Camera, b: &Camera, t: f64) -> Option<Camera> {
+        if a.projection_type != b.projection_type {
+            return None;
+        }
+        let rotation = |camera: &Camera| {
+            let m = &camera.matrix;
+            Quaternion::from(Matrix3::from_cols(
+                m[0].truncate(),
+                m[1].truncate(),
+                m[2].truncate(),
+            ))
+        };
+        let rotation = rotation(a).slerp(rotation(b), t);
+        let position = a.position() + (b.position() - a.position()) * t;
+        let matrix = Matrix4::from_translation(position.to_vec()) * Matrix4::from(rotation);
+        let (a_params, b_params) = (a.projection_parameters(), b.projection_parameters());
+        let lerp = |x: f64, y: f64| x + (y - x) * t;
+        let (size, near_clip, far_clip) = (
+            lerp(a_params.0, b_params.0),
+            lerp(a_params.1, b_params.1),
+            lerp(a_params.2, b_params.2),
+        );
+        Some(match a.projection_type {
+            ProjectionType::Perspective => {
+                Camera::perspective_camera(matrix, Rad(size), near_clip, far_clip)
+            }
+            ProjectionType::Parallel => Camera::parallel_camera(matrix, size, near_clip, far_clip),
+        })
+    }
+}
+
+impl CameraPath {
+    /// Creates the camera path from keyframes, pairs of the time and the camera.
+    ///
+    /// The keyframes are sorted by the time. Returns `None` if there are no keyframes, the times
+    /// are not finite, or the projection types of the cameras are different.
+    /// # Examples
+    /// ```
+    /// use truck_base::cgmath64::*;
+    /// use truck_platform::*;
+    /// let a = Camera::default();
+    /// let b = Camera::parallel_camera(Matrix4::identity(), 1.0, 0.1, 10.0);
+    /// assert!(CameraPath::try_new([(0.0, a), (1.0, a)]).is_some());
+    /// assert!(CameraPath::try_new([(0.0, a), (1.0, b)]).is_none());
+    /// assert!(CameraPath::try_new([]).is_none());
+    /// ```
+    pub fn try_new(keyframes: impl IntoIterator<Item = (f64, Camera)>) -> Option<CameraPath> {
+        let mut keyframes: Vec<(f64, Camera)> = keyframes.into_iter().collect();
+        let projection_type = keyframes.first()?.1.projection_type;
+        let valid = keyframes
+            .iter()
+            .all(|(t, camera)| t.is_finite() && camera.projection_type == projection_type);
+        if !valid {
+            return None;
+        }
+        keyframes.sort_by(|(t0, _), (t1, _)| f64::total_cmp(t0, t1));
+        Some(CameraPath { keyframes })
+    }
+
+    /// Returns the keyframes sorted by the time.
+    #[inline(always)]
+    pub fn keyframes(&self) -> &[(f64, Camera)] { &self.keyframes }
+
+    /// Returns the camera at the time `t`, interpolating the keyframes before and after `t`.
+    ///
+    /// The camera at the first or last keyframe is returned if `t` is out of the range.
+    pub fn sample(&self, t: f64) -> Camera {
+        let idx = self.keyframes.partition_point(|(t0, _)| *t0 <= t);
+        if idx == 0 {
+            return self.keyframes[0].1;
+        } else if idx == self.keyframes.len() {
+            return self.keyframes[idx - 1].1;
+        }
+        let ((t0, a), (t1, b)) = (&self.keyframes[idx - 1], &self.keyframes[idx]);
+        Camera::interpolate(a, b, (t - t0) / (t1 - t0))
+            .expect("the projection types of keyframes are the same")
+    }
 }
 
 impl Default for Camera {
@@ -0,0 +1,178 @@
+use crate::*;
+use image::RgbaImage;
+use std::sync::mpsc::{channel, Receiver};
+
+#[inline(always)]
+fn padded_bytes_per_row(width: u32) -> u32 {
+    (width * 4).div_ceil(COPY_BYTES_PER_ROW_ALIGNMENT) * COPY_BYTES_PER_ROW_ALIGNMENT
+}
+
+impl FrameCapturer {
+    /// Creates a new frame capturer compatible with `scene`, and the receiver of the frames.
+    /// # Arguments
+    /// - `scene`: the scene to be captured. The canvas size and the format must not be changed.
+    /// - `ring_size`: the number of the mappable buffers, i.e. the number of the frames in flight.
+    ///   At least one buffer is created.
+    /// # Panics
+    /// Panics if the format of the render texture is not one of the 8-bit RGBA or BGRA formats.
+    pub fn new(scene: &Scene, ring_size: usize) -> (Self, Receiver<RgbaImage>) {
+        let RenderTextureConfig {
+            canvas_size,
+            format,
+        } = scene.descriptor().render_texture;
+        let bgra = match format {
+            TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => false,
+            TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb => true,
+            _ => panic!("the texture format {format:?} is not supported."),
+        };
+        let size = (padded_bytes_per_row(canvas_size.0) * canvas_size.1) as u64;
+        let buffers = (0..usize::max(ring_size, 1))
+            .map(|_| {
+                scene.device().create_buffer(&BufferDescriptor {
+                    label: None,
+                    mapped_at_creation: false,
+                    usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+                    size,
+                })
+            })
+            .collect();
+        let (sender, receiver) = channel();
+        let capturer = Self {
+            device_handler: scene.device_handler().clone(),
+            texture: scene.compatible_texture(),
+            canvas_size,
+            bgra,
+            buffers,
+            pending: Default::default(),
+            next: 0,
+            sender,
+        };
+        (capturer, receiver)
+    }
+
+    /// Renders `scene` and starts the readback of the frame.
+    ///
+    /// Blocks only if all buffers in the ring are in flight, waiting for the oldest frame.
+    /// # Panics
+    /// Panics if the canvas size of `scene` is changed after the creation of `self`,
+    /// or if the readback of a frame fails.
+    pub fn capture(&mut self, scene: &Scene) {
+        let canvas_size = scene.descriptor().render_texture.canvas_size;
+        assert_eq!(
+            self.canvas_size, canvas_size,
+            "the canvas size of the scene has been changed."
+        );
+        if self.pending.len() == self.buffers.len() {
+            Self::expect_received(self.receive_front(true));
+        }
+
+        let view = self.texture.create_view(&Default::default());
+        scene.render(&view);
+
+        let slot = self.next;
+        self.next = (slot + 1) % self.buffers.len();
+        let (width, height) = canvas_size;
+        let mut encoder = scene
+            .device()
+            .create_command_encoder(&CommandEncoderDescriptor { label: None });
+        encoder.copy_texture_to_buffer(
+            ImageCopyTexture {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: Origin3d::ZERO,
+                aspect: TextureAspect::All,
+            },
+            ImageCopyBuffer {
+                buffer: &self.buffers[slot],
+                layout: ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row(width)),
+                    rows_per_image: Some(height),
+                },
+            },
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+        scene.queue().submit(Some(encoder.finish()));
+
+        let result = MapResult::default();
+        let closure_result = Arc::clone(&result);
+        self.buffers[slot]
+            .slice(..)
+            .map_async(MapMode::Read, move |res| {
+                *closure_result.lock().unwrap() = Some(res)
+            });
+        self.pending.push_back((slot, result));
+
+        self.device_handler.device().poll(Maintain::Poll);
+        while Self::expect_received(self.receive_front(false)) {}
+    }
+
+    /// Waits for all frames in flight and sends them to the receiver.
+    /// # Panics
+    /// Panics if the readback of a frame fails.
+    pub fn flush(&mut self) { while Self::expect_received(self.receive_front(true)) {} }
+
+    /// Returns the number of frames whose readback has not finished yet.
+    #[inline(always)]
+    pub fn frames_in_flight(&self) -> usize { self.pending.len() }
+
+    /// Sends the oldest frame in flight if its readback has finished.
+    /// If `wait` is `true`, waits for the readback. Returns `Ok(false)` if no frame is sent.
+    /// The frame whose readback has failed is removed from the ring and the error is returned.
+    fn receive_front(&mut self, wait: bool) -> Result<bool, BufferAsyncError> {
+        let Some((slot, result)) = self.pending.front() else {
+            return Ok(false);
+        };
+        if wait {
+            while result.lock().unwrap().is_none() {
+                self.device_handler.device().poll(Maintain::Wait);
+            }
+        }
+        let received = result.lock().unwrap().take();
+        match received {
+            Some(Ok(())) => {}
+            Some(Err(e)) => {
+                self.pending.pop_front();
+                return Err(e);
+            }
+            None => return Ok(false),
+        }
+
+        let buffer = &self.buffers[*slot];
+        let (width, height) = self.canvas_size;
+        let padded = padded_bytes_per_row(width) as usize;
+        let mut frame = Vec::with_capacity((width * height * 4) as usize);
+        {
+            let view = buffer.slice(..).get_mapped_range();
+            view.chunks(padded)
+                .for_each(|row| frame.extend_from_slice(&row[..width as usize * 4]));
+        }
+        buffer.unmap();
+        self.pending.pop_front();
+        if self.bgra {
+            frame.chunks_mut(4).for_each(|pixel| pixel.swap(0, 2));
+        }
+        let frame = RgbaImage::from_raw(width, height, frame).unwrap();
+        // the frame is discarded if the receiver has been dropped.
+        let _ = self.sender.send(frame);
+        Ok(true)
+    }
+
+    #[inline(always)]
+    fn expect_received(received: Result<bool, BufferAsyncError>) -> bool {
+        received.unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl Drop for FrameCapturer {
+    fn drop(&mut self) {
+        // the frames whose readback has failed are discarded, not to panic in dropping.
+        while !self.pending.is_empty() {
+            let _ = self.receive_front(true);
+        }
+    }
+}
@@ -0,0 +1,434 @@
+use crate::*;
+use std::mem::size_of;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Zeroable, Pod)]
+struct GizmoVertex {
+    position: [f32; 3],
+    color: [f32; 3],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Zeroable, Pod)]
+struct GizmoInfo {
+    position: [f32; 2],
+    size: f32,
+    _padding: f32,
+}
+
+const TICK_HEIGHT: f32 = 0.05;
+
+impl GizmoVertex {
+    #[inline(always)]
+    fn line(p: [f32; 3], q: [f32; 3], color: [f32; 3]) -> [GizmoVertex; 2] {
+        [
+            GizmoVertex { position: p, color },
+            GizmoVertex { position: q, color },
+        ]
+    }
+}
+
+fn gizmo_bind_group_layout(handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+    Arc::new(bind_group_util::create_bind_group_layout(
+        handler.device(),
+        &[PreBindGroupLayoutEntry {
+            visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }],
+    ))
+}
+
+fn gizmo_bind_group(
+    handler: &DeviceHandler,
+    layout: &BindGroupLayout,
+    position: Point2,
+    size: f64,
+) -> Arc<BindGroup> {
+    let info = GizmoInfo {
+        position: position.cast().unwrap().into(),
+        size: size as f32,
+        _padding: 0.0,
+    };
+    let buffer = BufferHandler::from_slice(&[info], handler.device(), BufferUsages::UNIFORM);
+    Arc::new(bind_group_util::create_bind_group(
+        handler.device(),
+        layout,
+        [buffer.binding_resource()],
+    ))
+}
+
+fn gizmo_pipeline(
+    handler: &DeviceHandler,
+    layout: &PipelineLayout,
+    scene_desc: &SceneDescriptor,
+    (vertex_entry, fragment_entry): (&str, &str),
+    depth_compare: CompareFunction,
+) -> Arc<RenderPipeline> {
+    let device = handler.device();
+    let module = device.create_shader_module(ShaderModuleDescriptor {
+        source: ShaderSource::Wgsl(include_str!("shaders/gizmo.wgsl").into()),
+        label: None,
+    });
+    let sample_count = scene_desc.backend_buffer.sample_count;
+    let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+        layout: Some(layout),
+        vertex: VertexState {
+            module: &module,
+            entry_point: vertex_entry,
+            buffers: &[VertexBufferLayout {
+                array_stride: size_of::<GizmoVertex>() as BufferAddress,
+                step_mode: VertexStepMode::Vertex,
+                attributes: &[
+                    VertexAttribute {
+                        format: VertexFormat::Float32x3,
+                        offset: 0,
+                        shader_location: 0,
+                    },
+                    VertexAttribute {
+                        format: VertexFormat::Float32x3,
+                        offset: 3 * 4,
+                        shader_location: 1,
+                    },
+                ],
+            }],
+        },
+        fragment: Some(FragmentState {
+            module: &module,
+            entry_point: fragment_entry,
+            targets: &[Some(ColorTargetState {
+                format: scene_desc.render_texture.format,
+                blend: Some(BlendState::REPLACE),
+                write_mask: ColorWrites::ALL,
+            })],
+        }),
+        primitive: PrimitiveState {
+            topology: PrimitiveTopology::LineList,
+            ..Default::default()
+        },
+        // lines do not hide the other objects.
+        depth_stencil: match scene_desc.backend_buffer.depth_test {
+            true => Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: false,
+                depth_compare,
+                stencil: Default::default(),
+                bias: Default::default(),
+            }),
+            false => None,
+        },
+        multisample: MultisampleState {
+            count: sample_count,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        },
+        label: None,
+        multiview: None,
+    });
+    Arc::new(pipeline)
+}
+
+impl AxisTriad {
+    /// Creates a new axis triad.
+    /// # Arguments
+    /// - `position`: the position of the origin of the triad in the normalized device coordinate
+    /// - `size`: the length of each axis in the normalized device coordinate
+    #[inline(always)]
+    pub fn new(position: Point2, size: f64) -> Self {
+        Self {
+            position,
+            size,
+            id: RenderID::gen(),
+        }
+    }
+}
+
+impl Default for AxisTriad {
+    #[inline(always)]
+    fn default() -> Self { Self::new(Point2::new(-0.85, -0.85), 0.1) }
+}
+
+impl Rendered for AxisTriad {
+    impl_render_id!(id);
+    fn vertex_buffer(
+        &self,
+        handler: &DeviceHandler,
+    ) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        let o = [0.0, 0.0, 0.0];
+        let vertices = [
+            GizmoVertex::line(o, [1.0, 0.0, 0.0], [1.0, 0.0, 0.0]),
+            GizmoVertex::line(o, [0.0, 1.0, 0.0], [0.0, 1.0, 0.0]),
+            GizmoVertex::line(o, [0.0, 0.0, 1.0], [0.0, 0.0, 1.0]),
+        ]
+        .concat();
+        let buffer = BufferHandler::from_slice(&vertices, handler.device(), BufferUsages::VERTEX);
+        (Arc::new(buffer), None)
+    }
+    fn bind_group_layout(&self, handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        gizmo_bind_group_layout(handler)
+    }
+    fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
+        gizmo_bind_group(handler, layout, self.position, self.size)
+    }
+    fn pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        // gizmos are always drawn in front of the other objects.
+        gizmo_pipeline(
+            handler,
+            layout,
+            scene_desc,
+            ("vs_triad", "fs_main"),
+            CompareFunction::Always,
+        )
+    }
+}
+
+impl ScaleReference {
+    /// Creates a new scale bar.
+    /// # Arguments
+    /// - `position`: the position of the left end of the bar in the normalized device coordinate
+    /// - `length`: the length of the bar in the normalized device coordinate
+    /// - `divisions`: the number of divisions
+    #[inline(always)]
+    pub fn new(position: Point2, length: f64, divisions: usize) -> Self {
+        Self {
+            position,
+            length,
+            divisions,
+            color: Vector3::new(1.0, 1.0, 1.0),
+            id: RenderID::gen(),
+        }
+    }
+
+    /// Returns the length in the world coordinate of one division.
+    /// # Arguments
+    /// - `camera`: the camera of the scene
+    /// - `as_rat`: the aspect ratio, x-resolution / y-resolution.
+    /// - `depth`: the distance from the camera along the eye direction.
+    ///   Ignored if the camera is parallel.
+    /// # Examples
+    /// ```
+    /// use truck_base::{assert_near, cgmath64::*, tolerance::*};
+    /// use truck_platform::*;
+    ///
+    /// // the screen height is 3.0 in the world coordinate.
+    /// let camera = Camera::parallel_camera(Matrix4::identity(), 3.0, 0.1, 10.0);
+    /// // the half of the screen height is divided into 3 parts.
+    /// let scale = ScaleReference::new(Point2::new(0.0, 0.0), 1.0, 3);
+    /// assert_near!(scale.world_units_per_division(&camera, 1.0, 5.0), 0.5);
+    /// ```
+    pub fn world_units_per_division(&self, camera: &Camera, as_rat: f64, depth: f64) -> f64 {
+        let ndc_per_unit = camera.projection[0][0] / as_rat;
+        let ndc_per_division = self.length / usize::max(self.divisions, 1) as f64;
+        match camera.projection_type {
+            ProjectionType::Perspective => ndc_per_division * depth / ndc_per_unit,
+            ProjectionType::Parallel => ndc_per_division / ndc_per_unit,
+        }
+    }
+}
+
+impl Default for ScaleReference {
+    #[inline(always)]
+    fn default() -> Self { Self::new(Point2::new(0.5, -0.9), 0.4, 4) }
+}
+
+impl Rendered for ScaleReference {
+    impl_render_id!(id);
+    fn vertex_buffer(
+        &self,
+        handler: &DeviceHandler,
+    ) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        let color: [f32; 3] = self.color.cast().unwrap().into();
+        let n = usize::max(self.divisions, 1);
+        let ticks = (0..=n).flat_map(|i| {
+            let x = i as f32 / n as f32;
+            let h = if i == 0 || i == n {
+                2.0 * TICK_HEIGHT
+            } else {
+                TICK_HEIGHT
+            };
+            GizmoVertex::line([x, 0.0, 0.0], [x, h, 0.0], color)
+        });
+        let vertices: Vec<GizmoVertex> = GizmoVertex::line([0.0, 0.0, 0.0], [1.0, 0.0, 0.0], color)
+            .into_iter()
+            .chain(ticks)
+            .collect();
+        let buffer = BufferHandler::from_slice(&vertices, handler.device(), BufferUsages::VERTEX);
+        (Arc::new(buffer), None)
+    }
+    fn bind_group_layout(&self, handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        gizmo_bind_group_layout(handler)
+    }
+    fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
+        gizmo_bind_group(handler, layout, self.position, self.length)
+    }
+    fn pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        // gizmos are always drawn in front of the other objects.
+        gizmo_pipeline(
+            handler,
+            layout,
+            scene_desc,
+            ("vs_screen", "fs_main"),
+            CompareFunction::Always,
+        )
+    }
+}
+
+impl BoundingBoxInstance {
+    /// Creates the wireframe of `bounding_box` drawn in white.
+    #[inline(always)]
+    pub fn new(bounding_box: BoundingBox<Point3>) -> Self {
+        Self {
+            bounding_box,
+            color: Vector3::new(1.0, 1.0, 1.0),
+            id: RenderID::gen(),
+        }
+    }
+}
+
+impl Rendered for BoundingBoxInstance {
+    impl_render_id!(id);
+    fn vertex_buffer(
+        &self,
+        handler: &DeviceHandler,
+    ) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        let color: [f32; 3] = self.color.cast().unwrap().into();
+        let (min, max): ([f32; 3], [f32; 3]) = match self.bounding_box.is_empty() {
+            true => ([0.0; 3], [0.0; 3]),
+            false => (
+                self.bounding_box.min().cast().unwrap().into(),
+                self.bounding_box.max().cast().unwrap().into(),
+            ),
+        };
+        // the corner of the box whose coordinates are chosen from `min` or `max` by the bits of `i`.
+        let corner = |i: usize| [0, 1, 2].map(|k| if i >> k & 1 == 0 { min[k] } else { max[k] });
+        // the 12 edges connect the corners whose indices differ only in one bit.
+        let vertices: Vec<GizmoVertex> = (0..8)
+            .flat_map(|i| [1, 2, 4].map(move |bit| (i, i | bit)))
+            .filter(|(i, j)| i != j)
+            .flat_map(|(i, j)| GizmoVertex::line(corner(i), corner(j), color))
+            .collect();
+        let buffer = BufferHandler::from_slice(&vertices, handler.device(), BufferUsages::VERTEX);
+        (Arc::new(buffer), None)
+    }
+    fn bind_group_layout(&self, handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        Arc::new(bind_group_util::create_bind_group_layout(
+            handler.device(),
+            &[],
+        ))
+    }
+    fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
+        Arc::new(bind_group_util::create_bind_group(
+            handler.device(),
+            layout,
+            [],
+        ))
+    }
+    fn pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        gizmo_pipeline(
+            handler,
+            layout,
+            scene_desc,
+            ("vs_world", "fs_main"),
+            CompareFunction::Less,
+        )
+    }
+}
+
+impl GridInstance {
+    /// Creates a new grid on the XZ plane.
+    /// # Arguments
+    /// - `extent`: the half of the length of the sides of the square grid
+    /// - `spacing`: the spacing of the minor lines
+    #[inline(always)]
+    pub fn new(extent: f64, spacing: f64) -> Self {
+        Self {
+            extent,
+            spacing,
+            major_interval: 10,
+            minor_color: Vector3::new(0.3, 0.3, 0.3),
+            major_color: Vector3::new(0.6, 0.6, 0.6),
+            fade_distance: extent,
+            matrix: Matrix4::identity(),
+            id: RenderID::gen(),
+        }
+    }
+}
+
+impl Rendered for GridInstance {
+    impl_render_id!(id);
+    fn vertex_buffer(
+        &self,
+        handler: &DeviceHandler,
+    ) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        let (minor, major): ([f32; 3], [f32; 3]) = (
+            self.minor_color.cast().unwrap().into(),
+            self.major_color.cast().unwrap().into(),
+        );
+        let n = match self.spacing > 0.0 {
+            true => (self.extent / self.spacing).floor() as isize,
+            false => 0,
+        };
+        let major_interval = usize::max(self.major_interval, 1) as isize;
+        let transform = |x: f64, z: f64| -> [f32; 3] {
+            let p = self.matrix.transform_point(Point3::new(x, 0.0, z));
+            p.cast().unwrap().into()
+        };
+        let e = self.extent;
+        let vertices: Vec<GizmoVertex> = (-n..=n)
+            .flat_map(|i| {
+                let t = i as f64 * self.spacing;
+                let color = if i % major_interval == 0 {
+                    major
+                } else {
+                    minor
+                };
+                let x_line = GizmoVertex::line(transform(t, -e), transform(t, e), color);
+                let z_line = GizmoVertex::line(transform(-e, t), transform(e, t), color);
+                [x_line, z_line].concat()
+            })
+            .collect();
+        let buffer = BufferHandler::from_slice(&vertices, handler.device(), BufferUsages::VERTEX);
+        (Arc::new(buffer), None)
+    }
+    fn bind_group_layout(&self, handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        gizmo_bind_group_layout(handler)
+    }
+    fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
+        // the size of the gizmo is used as the fade distance.
+        let fade_distance = f64::max(self.fade_distance, f64::EPSILON);
+        gizmo_bind_group(handler, layout, Point2::origin(), fade_distance)
+    }
+    fn pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        gizmo_pipeline(
+            handler,
+            layout,
+            scene_desc,
+            ("vs_grid", "fs_grid"),
+            CompareFunction::Less,
+        )
+    }
+}
@@ -44,8 +44,8 @@
 
 use bytemuck::{Pod, Zeroable};
 use derive_more::*;
-use std::sync::Arc;
-use truck_base::cgmath64::*;
+use std::sync::{Arc, Mutex};
+use truck_base::{bounding_box::BoundingBox, cgmath64::*};
 pub use wgpu;
 use wgpu::util::{BufferInitDescriptor, DeviceExt};
 use wgpu::*;
@@ -80,6 +80,12 @@ struct SceneInfo {
     resolution: [u32; 2],
     time: f32,
     num_of_lights: u32,
+    ambient_color: [f32; 4],
+    fog_color: [f32; 4],
+    fog_density: f32,
+    fog_near: f32,
+    fog_far: f32,
+    fog_mode: u32,
 }
 
 /// safe handler of GPU buffer
@@ -143,6 +149,7 @@ pub struct RenderObject {
     pipeline: Arc<RenderPipeline>,
     bind_group_layout: Arc<BindGroupLayout>,
     bind_group: Arc<BindGroup>,
+    transparent_center: Option<Point3>,
     visible: bool,
 }
 
@@ -158,7 +165,7 @@ pub enum ProjectionType {
 /// Camera
 ///
 /// A [`Scene`](./struct.Scene.html) holds only one `Camera`.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Camera {
     /// camera matrix
     ///
@@ -168,6 +175,78 @@ pub struct Camera {
     projection_type: ProjectionType,
 }
 
+/// Path of the camera interpolating keyframes, for turntable renders and fly-throughs.
+///
+/// All keyframes have the same projection type and are sorted by the time.
+/// The camera between two keyframes is given by [`Camera::interpolate`].
+///
+/// # Examples
+/// ```
+/// use truck_base::{cgmath64::*, tolerance::Tolerance};
+/// use truck_platform::*;
+/// let camera = |x: f64| {
+///     let matrix = Matrix4::from_translation(Vector3::new(x, 0.0, 0.0));
+///     Camera::perspective_camera(matrix, Rad(1.0), 0.1, 10.0)
+/// };
+/// let path = CameraPath::try_new([(2.0, camera(4.0)), (0.0, camera(0.0)), (1.0, camera(1.0))])
+///     .unwrap();
+/// assert!(path.sample(0.5).position().near(&Point3::new(0.5, 0.0, 0.0)));
+/// assert!(path.sample(1.5).position().near(&Point3::new(2.5, 0.0, 0.0)));
+/// // clamped outside the range
+/// assert!(path.sample(3.0).position().near(&Point3::new(4.0, 0.0, 0.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraPath {
+    keyframes: Vec<(f64, Camera)>,
+}
+
+/// Orbit controls of the camera, rotating around, panning and zooming to the target point.
+///
+/// The camera is placed by the spherical coordinates around `target` whose up direction is the
+/// y-axis: the camera with `azimuth == 0` and `elevation == 0` is on the z-axis from `target`
+/// and looks toward the negative z direction. The methods update the coordinates and return the
+/// updated [`Camera`].
+///
+/// # Examples
+/// ```
+/// use truck_base::{cgmath64::*, tolerance::Tolerance};
+/// use truck_platform::*;
+/// let mut controls = OrbitControls {
+///     target: Point3::new(1.0, 2.0, 3.0),
+///     distance: 5.0,
+///     ..Default::default()
+/// };
+/// let camera = controls.camera();
+/// assert!(camera.position().near(&Point3::new(1.0, 2.0, 8.0)));
+/// assert!(camera.eye_direction().near(&-Vector3::unit_z()));
+///
+/// // a quarter turn to the right around the target
+/// let camera = controls.rotate(std::f64::consts::PI / 2.0, 0.0);
+/// assert!(camera.position().near(&Point3::new(6.0, 2.0, 3.0)));
+/// assert!(camera.eye_direction().near(&-Vector3::unit_x()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitControls {
+    /// the point which the camera looks at
+    pub target: Point3,
+    /// the distance from the target to the camera
+    pub distance: f64,
+    /// the angle of the camera position around the y-axis from the z-axis
+    pub azimuth: Rad<f64>,
+    /// the angle of the camera position above the xz-plane, in `[-π/2, π/2]`
+    pub elevation: Rad<f64>,
+    /// the projection type of the returned cameras
+    pub projection_type: ProjectionType,
+    /// the field of view of the perspective cameras
+    pub field_of_view: Rad<f64>,
+    /// the height of the screen of the parallel cameras
+    pub screen_size: f64,
+    /// the distance to the near clipping plane
+    pub near_clip: f64,
+    /// the distance to the far clipping plane
+    pub far_clip: f64,
+}
+
 /// Rays corresponding to a point on the screen, defined by the camera.
 #[derive(Clone, Copy, Debug)]
 pub struct Ray {
@@ -184,6 +263,17 @@ pub enum LightType {
     Uniform,
 }
 
+/// the kinds of the distance fog: linear or exponential
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FogMode {
+    /// The ratio of the fog is `fog_density` times the linear interpolation from zero at
+    /// `fog_near` to one at `fog_far`.
+    Linear,
+    /// The ratio of the fog is `1 - exp(-fog_density * (depth - fog_near))`.
+    /// `fog_far` is not used.
+    Exponential,
+}
+
 /// Light
 ///
 /// There is no limit to the number of lights that can be added to a [`Scene`](./struct.Scene.html).
@@ -220,6 +310,7 @@ pub struct DeviceHandler {
 struct WindowHandler {
     window: Arc<winit::window::Window>,
     surface: Arc<Surface<'static>>,
+    present_mode: PresentMode,
 }
 
 /// The unique ID for `Rendered` struct.
@@ -246,6 +337,29 @@ pub struct StudioConfig {
     pub lights: Vec<Light>,
     /// background color. Default is `Color::BLACK`.
     pub background: Color,
+    /// ambient light color, linear rgb. Default is `Vector3::zero()`.
+    ///
+    /// The fill light lighting all surfaces uniformly from all directions, without placing a
+    /// [`Light`]. The shaders of `truck-rendimpl` add the albedo multiplied by this color to
+    /// the contribution of the lights, before blending with the albedo by
+    /// `Material::ambient_ratio`, so the ambient ratio still works as the self-illumination.
+    pub ambient: Vector3,
+    /// fog color, linear rgb. Default is `Vector3::zero()`.
+    ///
+    /// The shaded colors are blended with the fog color by the ratio determined by the depth in
+    /// the view space, the distance from the camera along the eye direction. See [`FogMode`] for
+    /// the ratios. The fog is applied by the shaders of `truck-rendimpl` for polygons.
+    pub fog_color: Vector3,
+    /// density of the fog. Default is `0.0`, i.e. no fog.
+    ///
+    /// For [`FogMode::Linear`], this is the ratio of the fog beyond `fog_far`, in `[0, 1]`.
+    pub fog_density: f64,
+    /// the depth from which the fog starts. Default is `0.0`.
+    pub fog_near: f64,
+    /// the depth at which the linear fog is the densest. Default is `10.0`.
+    pub fog_far: f64,
+    /// the kind of the fog. Default is `FogMode::Linear`.
+    pub fog_mode: FogMode,
 }
 
 /// Configuration for buffer preparation
@@ -255,6 +369,27 @@ pub struct BackendBufferConfig {
     pub depth_test: bool,
     /// sample count for anti-aliasing by MSAA. 1, 2, 4, 8, or 16. Default is `1`.
     pub sample_count: u32,
+    /// outlines of objects drawn over the rendered image. Default is `None`.
+    ///
+    /// The outlines are detected from the depth texture, so they are drawn only if
+    /// `depth_test` is `true`. No additional render target is allocated, but the depth
+    /// texture is created with `TextureUsages::TEXTURE_BINDING`, which may disable the
+    /// depth compression of some GPUs, and one more render pass per frame is needed.
+    pub outline: Option<OutlineConfig>,
+}
+
+/// Configuration of the outlines drawn by [`Scene`].
+///
+/// The edges are detected from the gaps of the depth: silhouettes of objects and the
+/// convex creases of surfaces are outlined. The lines are drawn on the front side of the edges.
+#[derive(Clone, Debug, Copy)]
+pub struct OutlineConfig {
+    /// the color of lines. Default is `Color::BLACK`.
+    pub color: Color,
+    /// the width of lines in pixels. Default is `2.0`.
+    pub width: f64,
+    /// the gap of depth detected as an edge, relative to the distance from the camera. Default is `0.05`.
+    pub threshold: f64,
 }
 
 /// Configuration for rendering texture
@@ -285,6 +420,103 @@ pub struct WindowSceneDescriptor {
     pub studio: StudioConfig,
     /// Configures buffer preparation, depth and MSAA.
     pub backend_buffer: BackendBufferConfig,
+    /// The present mode of the surface, e.g. `Fifo` for vsync, `Immediate` or `Mailbox` for low latency.
+    /// If the surface does not support the mode, `Fifo` is used. Default is `Fifo`.
+    pub present_mode: PresentMode,
+}
+
+/// Axis triad drawn at a fixed position on the screen, which shows the orientation of the camera.
+///
+/// Only the rotation of the camera is reflected, so the triad keeps its position and size on the screen.
+/// The x, y, and z axes are drawn in red, green, and blue, respectively.
+///
+/// Gizmos ignore the depth test, so add them to the scene after the other objects.
+#[derive(Debug)]
+pub struct AxisTriad {
+    /// the position of the origin of the triad in the normalized device coordinate.
+    /// Default is `Point2::new(-0.85, -0.85)`.
+    pub position: Point2,
+    /// the length of each axis in the normalized device coordinate based on the vertical direction.
+    /// Default is `0.1`.
+    pub size: f64,
+    id: RenderID,
+}
+
+/// Scale bar drawn at a fixed position on the screen.
+///
+/// The world length of one division is obtained by
+/// [`ScaleReference::world_units_per_division`](./struct.ScaleReference.html#method.world_units_per_division).
+#[derive(Debug)]
+pub struct ScaleReference {
+    /// the position of the left end of the bar in the normalized device coordinate.
+    /// Default is `Point2::new(0.5, -0.9)`.
+    pub position: Point2,
+    /// the length of the bar in the normalized device coordinate. Default is `0.4`.
+    pub length: f64,
+    /// the number of divisions. Default is `4`.
+    pub divisions: usize,
+    /// [0, 1] range RGB color of the bar. Default is `Vector3::new(1.0, 1.0, 1.0)`.
+    pub color: Vector3,
+    id: RenderID,
+}
+
+/// Wireframe of an axis-aligned box drawn in the world coordinate.
+///
+/// The 12 edges are drawn as a line list without index buffer. Unlike the other gizmos,
+/// the edges are hidden by the objects in front of them if the depth test is enabled.
+/// Useful for debugging and framing the camera by
+/// [`Scene::fit_camera_to`](./struct.Scene.html#method.fit_camera_to).
+#[derive(Debug)]
+pub struct BoundingBoxInstance {
+    /// the drawn box. Nothing is drawn if the box is empty.
+    pub bounding_box: BoundingBox<Point3>,
+    /// [0, 1] range RGB color of the edges. Default is `Vector3::new(1.0, 1.0, 1.0)`.
+    pub color: Vector3,
+    id: RenderID,
+}
+
+/// Reference grid drawn in the world coordinate.
+///
+/// The grid is a square on the XZ plane of the local coordinate, whose sides are parallel to
+/// the axes. The lines are hidden by the objects in front of them if the depth test is enabled,
+/// and fade into the background color with the distance from the camera.
+#[derive(Debug)]
+pub struct GridInstance {
+    /// the half of the length of the sides of the grid.
+    pub extent: f64,
+    /// the spacing of the minor lines.
+    pub spacing: f64,
+    /// the number of the minor spacings between the major lines. Default is `10`.
+    pub major_interval: usize,
+    /// [0, 1] range RGB color of the minor lines. Default is `Vector3::new(0.3, 0.3, 0.3)`.
+    pub minor_color: Vector3,
+    /// [0, 1] range RGB color of the major lines, including the axes.
+    /// Default is `Vector3::new(0.6, 0.6, 0.6)`.
+    pub major_color: Vector3,
+    /// the distance from the camera at which the lines are completely faded.
+    /// Default is the same as `extent`.
+    pub fade_distance: f64,
+    /// the matrix mapping the XZ plane to the plane of the grid. Default is the identity.
+    pub matrix: Matrix4,
+    id: RenderID,
+}
+
+/// Statistics of the last rendering of [`Scene`](./struct.Scene.html).
+///
+/// This is only the bookkeeping of the draw calls issued in [`Scene::render`](./struct.Scene.html#method.render),
+/// and is obtained by [`Scene::last_render_stats`](./struct.Scene.html#method.last_render_stats).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RenderStats {
+    /// the number of objects registered in the scene
+    pub object_count: usize,
+    /// the number of visible objects
+    pub visible_count: usize,
+    /// the total number of vertices in the vertex buffers of the visible objects
+    pub total_vertices: u64,
+    /// the total number of indices in the index buffers of the visible objects
+    pub total_indices: u64,
+    /// the number of draw calls
+    pub draw_calls: usize,
 }
 
 /// Wraps `wgpu` and provides an intuitive graphics API.
@@ -299,8 +531,35 @@ pub struct Scene {
     bind_group_layout: BindGroupLayout,
     foward_depth: Option<Texture>,
     sampling_buffer: Option<Texture>,
+    outline: Option<OutlinePass>,
     scene_desc: SceneDescriptor,
     clock: TimeInstant,
+    render_stats: Mutex<RenderStats>,
+    pipeline_cache: Mutex<PipelineCache>,
+    camera_uniform: Mutex<CameraUniform>,
+}
+
+/// The camera buffer bound to the scene, rewritten only if the camera or the aspect ratio is changed.
+#[derive(Debug)]
+struct CameraUniform {
+    buffer: BufferHandler,
+    uploaded: (Camera, f64),
+    dirty: bool,
+}
+
+/// (pipeline key, sample count, depth test, texture format)
+type PipelineCacheKey = (u64, u32, bool, TextureFormat);
+
+#[derive(Debug, Default)]
+struct PipelineCache {
+    pipelines: rustc_hash::FxHashMap<PipelineCacheKey, (Arc<BindGroupLayout>, Arc<RenderPipeline>)>,
+    hits: usize,
+}
+
+#[derive(Debug)]
+struct OutlinePass {
+    bind_group_layout: BindGroupLayout,
+    pipeline: RenderPipeline,
 }
 
 /// Utility for wrapper
@@ -312,6 +571,28 @@ pub struct WindowScene {
     window_handler: WindowHandler,
 }
 
+type MapResult = Arc<Mutex<Option<Result<(), BufferAsyncError>>>>;
+
+/// Pipelined readback of the rendered frames, e.g. for exporting an animation to video.
+///
+/// `FrameCapturer` keeps a ring of mappable buffers, so that the readback of a frame
+/// overlaps with the rendering of the next frames. The captured frames are sent to the receiver
+/// returned by [`FrameCapturer::new`] in the captured order as [`image::RgbaImage`]. The pixels
+/// of the BGRA textures are swizzled into RGBA, and the values are stored as encoded in the
+/// texture, i.e. the `*Srgb` formats give the sRGB values and the others the raw values.
+/// The remaining frames are sent by [`FrameCapturer::flush`] or when the capturer is dropped.
+#[derive(Debug)]
+pub struct FrameCapturer {
+    device_handler: DeviceHandler,
+    texture: Texture,
+    canvas_size: (u32, u32),
+    bgra: bool,
+    buffers: Vec<Buffer>,
+    pending: std::collections::VecDeque<(usize, MapResult)>,
+    next: usize,
+    sender: std::sync::mpsc::Sender<image::RgbaImage>,
+}
+
 /// Rendered objects in the scene.
 pub trait Rendered {
     /// Returns the render id.
@@ -339,25 +620,35 @@ pub trait Rendered {
         layout: &PipelineLayout,
         scene_descriptor: &SceneDescriptor,
     ) -> Arc<RenderPipeline>;
+    /// Returns the center of the object in the world coordinates if the object is rendered
+    /// with alpha blending.
+    ///
+    /// The objects with the centers are drawn after the other objects, from back to front
+    /// seen from the camera. Default returns `None`.
+    #[inline(always)]
+    fn transparent_center(&self) -> Option<Point3> { None }
+    /// Returns the key for sharing the bind group layout and the render pipeline.
+    ///
+    /// [`Scene`] reuses the bind group layout and the pipeline of the objects with the same key
+    /// instead of creating new ones, so the key must cover all the inputs of
+    /// [`bind_group_layout`](./trait.Rendered.html#tymethod.bind_group_layout) and
+    /// [`pipeline`](./trait.Rendered.html#tymethod.pipeline) except the scene descriptor,
+    /// e.g. the shader modules and the blend state. Default returns `None`, which means
+    /// the pipeline of `self` is not shared.
+    #[inline(always)]
+    fn pipeline_key(&self) -> Option<u64> { None }
     #[doc(hidden)]
     fn render_object(&self, scene: &Scene) -> RenderObject {
         let (vertex_buffer, index_buffer) = self.vertex_buffer(scene.device_handler());
-        let bind_group_layout = self.bind_group_layout(scene.device_handler());
+        let (bind_group_layout, pipeline) = scene.object_pipeline(self, None);
         let bind_group = self.bind_group(scene.device_handler(), &bind_group_layout);
-        let pipeline_layout = scene
-            .device()
-            .create_pipeline_layout(&PipelineLayoutDescriptor {
-                bind_group_layouts: &[&scene.bind_group_layout, &bind_group_layout],
-                push_constant_ranges: &[],
-                label: None,
-            });
-        let pipeline = self.pipeline(scene.device_handler(), &pipeline_layout, &scene.scene_desc);
         RenderObject {
             vertex_buffer,
             index_buffer,
             bind_group_layout,
             bind_group,
             pipeline,
+            transparent_center: self.transparent_center(),
             visible: true,
         }
     }
@@ -365,7 +656,11 @@ pub trait Rendered {
 
 mod buffer_handler;
 mod camera;
+mod frame_capturer;
+mod gizmo;
 mod light;
+mod orbit_controls;
+mod outline;
 #[doc(hidden)]
 pub mod rendered_macros;
 mod scene;
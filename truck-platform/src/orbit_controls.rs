@@ -0,0 +1,152 @@
+use crate::*;
+use std::f64::consts::PI;
+
+impl OrbitControls {
+    /// Returns the camera matrix, the position and the axes of the camera.
+    fn matrix(&self) -> Matrix4 {
+        let (sin_az, cos_az) = self.azimuth.0.sin_cos();
+        let (sin_el, cos_el) = self.elevation.0.sin_cos();
+        let z = Vector3::new(cos_el * sin_az, sin_el, cos_el * cos_az);
+        let x = Vector3::new(cos_az, 0.0, -sin_az);
+        let y = z.cross(x);
+        let position = self.target + z * self.distance;
+        Matrix4::from_cols(
+            x.extend(0.0),
+            y.extend(0.0),
+            z.extend(0.0),
+            position.to_homogeneous(),
+        )
+    }
+
+    /// Returns the half of the height of the visible area on the plane through the target.
+    fn half_height(&self) -> f64 {
+        match self.projection_type {
+            ProjectionType::Perspective => self.distance * f64::tan(self.field_of_view.0 / 2.0),
+            ProjectionType::Parallel => self.screen_size / 2.0,
+        }
+    }
+
+    /// Returns the camera at the current state.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_base::{cgmath64::*, tolerance::Tolerance};
+    /// use truck_platform::*;
+    /// let controls = OrbitControls {
+    ///     distance: 2.0,
+    ///     elevation: Rad(std::f64::consts::PI / 2.0),
+    ///     projection_type: ProjectionType::Parallel,
+    ///     ..Default::default()
+    /// };
+    /// let camera = controls.camera();
+    /// assert_eq!(camera.projection_type(), ProjectionType::Parallel);
+    /// // looking down from above
+    /// assert!(camera.position().near(&Point3::new(0.0, 2.0, 0.0)));
+    /// assert!(camera.eye_direction().near(&-Vector3::unit_y()));
+    /// assert!(camera.head_direction().near(&-Vector3::unit_z()));
+    /// ```
+    pub fn camera(&self) -> Camera {
+        let matrix = self.matrix();
+        match self.projection_type {
+            ProjectionType::Perspective => Camera::perspective_camera(
+                matrix,
+                self.field_of_view,
+                self.near_clip,
+                self.far_clip,
+            ),
+            ProjectionType::Parallel => {
+                Camera::parallel_camera(matrix, self.screen_size, self.near_clip, self.far_clip)
+            }
+        }
+    }
+
+    /// Rotates the camera around the target, and returns the updated camera.
+    ///
+    /// `dx` and `dy` are added to the azimuth and the elevation in radians, respectively.
+    /// The elevation is clamped to `[-π/2, π/2]` so that the camera does not turn over.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_base::{cgmath64::*, tolerance::Tolerance};
+    /// use truck_platform::*;
+    /// let mut controls = OrbitControls::default();
+    /// let camera = controls.rotate(0.0, 10.0);
+    /// assert_eq!(controls.elevation, Rad(std::f64::consts::PI / 2.0));
+    /// assert!(camera.eye_direction().near(&-Vector3::unit_y()));
+    /// ```
+    pub fn rotate(&mut self, dx: f64, dy: f64) -> Camera {
+        self.azimuth = Rad((self.azimuth.0 + dx) % (2.0 * PI));
+        self.elevation = Rad(f64::clamp(self.elevation.0 + dy, -PI / 2.0, PI / 2.0));
+        self.camera()
+    }
+
+    /// Translates the camera and the target parallel to the screen, and returns the updated camera.
+    ///
+    /// `dx` and `dy` are the displacement of the cursor on the screen whose height is 2, i.e. the
+    /// unit is the half of the screen height. The target moves in the opposite direction so that
+    /// the point on the target plane follows the cursor.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_base::{cgmath64::*, tolerance::Tolerance};
+    /// use truck_platform::*;
+    /// let mut controls = OrbitControls {
+    ///     projection_type: ProjectionType::Parallel,
+    ///     screen_size: 4.0,
+    ///     ..Default::default()
+    /// };
+    /// let camera = controls.pan(0.5, 0.0);
+    /// assert!(controls.target.near(&Point3::new(-1.0, 0.0, 0.0)));
+    /// assert!(camera.eye_direction().near(&-Vector3::unit_z()));
+    /// ```
+    pub fn pan(&mut self, dx: f64, dy: f64) -> Camera {
+        let matrix = self.matrix();
+        let h = self.half_height();
+        self.target -= (matrix[0].truncate() * dx + matrix[1].truncate() * dy) * h;
+        self.camera()
+    }
+
+    /// Zooms in the target by `delta` and returns the updated camera. Negative `delta` zooms out.
+    ///
+    /// The distance to the target for the perspective camera or the screen size for the parallel
+    /// camera is multiplied by `exp(-delta)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_base::{cgmath64::*, tolerance::Tolerance};
+    /// use truck_platform::*;
+    /// let mut controls = OrbitControls::default();
+    /// let camera = controls.zoom(f64::ln(2.0));
+    /// assert!(camera.position().near(&Point3::new(0.0, 0.0, 1.5)));
+    ///
+    /// controls.projection_type = ProjectionType::Parallel;
+    /// controls.zoom(-f64::ln(2.0));
+    /// assert!(controls.screen_size.near(&2.0));
+    /// assert!(controls.distance.near(&1.5));
+    /// ```
+    pub fn zoom(&mut self, delta: f64) -> Camera {
+        let scale = f64::exp(-delta);
+        match self.projection_type {
+            ProjectionType::Perspective => self.distance *= scale,
+            ProjectionType::Parallel => self.screen_size *= scale,
+        }
+        self.camera()
+    }
+}
+
+impl Default for OrbitControls {
+    #[inline(always)]
+    fn default() -> OrbitControls {
+        OrbitControls {
+            target: Point3::origin(),
+            distance: 3.0,
+            azimuth: Rad(0.0),
+            elevation: Rad(0.0),
+            projection_type: ProjectionType::Perspective,
+            field_of_view: Rad(PI / 4.0),
+            screen_size: 1.0,
+            near_clip: 0.1,
+            far_clip: 10.0,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::*;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Zeroable, Pod)]
+struct OutlineInfo {
+    inverse_projection: [[f32; 4]; 4],
+    color: [f32; 4],
+    width: f32,
+    threshold: f32,
+    _padding: [f32; 2],
+}
+
+impl Default for OutlineConfig {
+    #[inline(always)]
+    fn default() -> OutlineConfig {
+        OutlineConfig {
+            color: Color::BLACK,
+            width: 2.0,
+            threshold: 0.05,
+        }
+    }
+}
+
+impl OutlinePass {
+    /// Returns `None` if the outlines are disabled or there is no depth texture.
+    pub(super) fn new(device: &Device, scene_desc: &SceneDescriptor) -> Option<OutlinePass> {
+        let backend_buffer = scene_desc.backend_buffer;
+        if backend_buffer.outline.is_none() || !backend_buffer.depth_test {
+            return None;
+        }
+        let multisampled = backend_buffer.sample_count > 1;
+        let bind_group_layout = bind_group_util::create_bind_group_layout(
+            device,
+            &[
+                PreBindGroupLayoutEntry {
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                PreBindGroupLayoutEntry {
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        sample_type: TextureSampleType::Depth,
+                        view_dimension: TextureViewDimension::D2,
+                        multisampled,
+                    },
+                    count: None,
+                },
+            ],
+        );
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+            label: None,
+        });
+        let module = device.create_shader_module(ShaderModuleDescriptor {
+            source: ShaderSource::Wgsl(include_str!("shaders/outline.wgsl").into()),
+            label: None,
+        });
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(&pipeline_layout),
+            vertex: VertexState {
+                module: &module,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(FragmentState {
+                module: &module,
+                entry_point: match multisampled {
+                    true => "fs_main_ms",
+                    false => "fs_main",
+                },
+                targets: &[Some(ColorTargetState {
+                    format: scene_desc.render_texture.format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: Default::default(),
+            depth_stencil: None,
+            multisample: Default::default(),
+            label: None,
+            multiview: None,
+        });
+        Some(OutlinePass {
+            bind_group_layout,
+            pipeline,
+        })
+    }
+
+    /// Draws the outlines over `view` by the depth texture of the last rendering.
+    pub(super) fn draw(
+        &self,
+        device: &Device,
+        encoder: &mut CommandEncoder,
+        view: &TextureView,
+        depth_view: &TextureView,
+        scene_desc: &SceneDescriptor,
+    ) {
+        let Some(config) = scene_desc.backend_buffer.outline else {
+            return;
+        };
+        let (width, height) = scene_desc.render_texture.canvas_size;
+        let as_rat = width as f64 / height as f64;
+        let projection = Matrix4::from_nonuniform_scale(1.0 / as_rat, 1.0, 1.0)
+            * scene_desc.studio.camera.projection;
+        let color = config.color;
+        let info = OutlineInfo {
+            inverse_projection: projection.invert().unwrap().cast().unwrap().into(),
+            color: [
+                color.r as f32,
+                color.g as f32,
+                color.b as f32,
+                color.a as f32,
+            ],
+            width: config.width as f32,
+            threshold: config.threshold as f32,
+            _padding: [0.0; 2],
+        };
+        let buffer = BufferHandler::from_slice(&[info], device, BufferUsages::UNIFORM);
+        let bind_group = bind_group_util::create_bind_group(
+            device,
+            &self.bind_group_layout,
+            [
+                buffer.binding_resource(),
+                BindingResource::TextureView(depth_view),
+            ],
+        );
+        let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Load,
+                    store: StoreOp::Store,
+                },
+            })],
+            ..Default::default()
+        });
+        rpass.set_pipeline(&self.pipeline);
+        rpass.set_bind_group(0, &bind_group, &[]);
+        rpass.draw(0..3, 0..1);
+    }
+}
@@ -84,3 +84,16 @@ macro_rules! derive_pipeline {
         }
     };
 }
+
+/// Derives [`Rendred::transparent_center()`](./trait.Rendered.html#method.transparent_center)
+/// # Arguments
+/// `id_member`: the member variant of the super `Rendered` struct.
+#[macro_export]
+macro_rules! derive_transparent_center {
+    ($($id_member: tt).*) => {
+        #[inline(always)]
+        fn transparent_center(&self) -> Option<Point3> {
+            self.$($id_member)*.transparent_center()
+        }
+    };
+}
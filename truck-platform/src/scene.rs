@@ -10,6 +10,22 @@ impl RenderID {
     pub fn gen() -> Self { RenderID(MAXID.fetch_add(1, Ordering::SeqCst)) }
 }
 
+async fn request_device(adapter: &Adapter) -> Result<(Device, Queue), RequestDeviceError> {
+    adapter
+        .request_device(
+            &DeviceDescriptor {
+                required_features: Default::default(),
+                #[cfg(not(feature = "webgl"))]
+                required_limits: Limits::downlevel_defaults().using_resolution(adapter.limits()),
+                #[cfg(feature = "webgl")]
+                required_limits: Limits::downlevel_webgl2_defaults(),
+                label: None,
+            },
+            None,
+        )
+        .await
+}
+
 async fn init_default_device(
     window: Option<Arc<Window>>,
 ) -> (DeviceHandler, Option<WindowHandler>) {
@@ -40,18 +56,7 @@ async fn init_default_device(
         .await
         .expect("Failed to find an appropriate adapter");
 
-    let (device, queue) = adapter
-        .request_device(
-            &DeviceDescriptor {
-                required_features: Default::default(),
-                #[cfg(not(feature = "webgl"))]
-                required_limits: Limits::downlevel_defaults().using_resolution(adapter.limits()),
-                #[cfg(feature = "webgl")]
-                required_limits: Limits::downlevel_webgl2_defaults(),
-                label: None,
-            },
-            None,
-        )
+    let (device, queue) = request_device(&adapter)
         .await
         .expect("Failed to create device");
     let device_handler = DeviceHandler {
@@ -62,6 +67,7 @@ async fn init_default_device(
     let window_handler = window.map(|window| WindowHandler {
         window,
         surface: Arc::new(surface.unwrap()),
+        present_mode: PresentMode::Fifo,
     });
     (device_handler, window_handler)
 }
@@ -92,6 +98,64 @@ impl DeviceHandler {
 
     /// Creates default device handler.
     pub async fn default_device() -> Self { init_default_device(None).await.0 }
+
+    /// Creates the device handler without any windows, for offscreen rendering on CI runners.
+    ///
+    /// The software adapter, found by `force_fallback_adapter`, is preferred so that the
+    /// rendered images do not depend on the GPU. If there is no software adapter, the other
+    /// adapters are tried. Returns `None` if no adapter or device is available.
+    ///
+    /// The rendered images are read back by [`Scene::render_to_buffer`] or [`FrameCapturer`].
+    ///
+    /// # Backends
+    /// - Vulkan: a CPU implementation, e.g. lavapipe or SwiftShader, is used if installed.
+    /// - DX12: WARP is used.
+    /// - OpenGL: Mesa llvmpipe is used on Linux through EGL, e.g. with `LIBGL_ALWAYS_SOFTWARE=1`.
+    /// - Metal: there is no software adapter, so the hardware adapter is used.
+    /// - WebGPU and WebGL: not supported since the adapter requires the browser.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_platform::*;
+    /// match pollster::block_on(DeviceHandler::headless()) {
+    ///     Some(handler) => println!("{:?}", handler.adapter().get_info()),
+    ///     None => println!("no adapter is available"),
+    /// }
+    /// ```
+    pub async fn headless() -> Option<Self> {
+        let instance = Instance::new(InstanceDescriptor {
+            backends: Backends::all(),
+            ..Default::default()
+        });
+        let mut options = RequestAdapterOptions {
+            power_preference: PowerPreference::LowPower,
+            compatible_surface: None,
+            force_fallback_adapter: true,
+        };
+        let adapter = match instance.request_adapter(&options).await {
+            Some(adapter) => adapter,
+            None => {
+                options.force_fallback_adapter = false;
+                instance.request_adapter(&options).await?
+            }
+        };
+        let (device, queue) = request_device(&adapter).await.ok()?;
+        Some(DeviceHandler::new(
+            Arc::new(adapter),
+            Arc::new(device),
+            Arc::new(queue),
+        ))
+    }
+}
+
+impl From<FogMode> for u32 {
+    #[inline(always)]
+    fn from(fog_mode: FogMode) -> u32 {
+        match fog_mode {
+            FogMode::Linear => 0,
+            FogMode::Exponential => 1,
+        }
+    }
 }
 
 impl Default for StudioConfig {
@@ -101,6 +165,12 @@ impl Default for StudioConfig {
             background: Color::BLACK,
             camera: Camera::default(),
             lights: vec![Light::default()],
+            ambient: Vector3::zero(),
+            fog_color: Vector3::zero(),
+            fog_density: 0.0,
+            fog_near: 0.0,
+            fog_far: 10.0,
+            fog_mode: FogMode::Linear,
         }
     }
 }
@@ -111,6 +181,7 @@ impl Default for BackendBufferConfig {
         BackendBufferConfig {
             depth_test: true,
             sample_count: 1,
+            outline: None,
         }
     }
 }
@@ -156,9 +227,13 @@ impl SceneDescriptor {
     /// ```
     #[inline(always)]
     pub fn camera_buffer(&self, device: &Device) -> BufferHandler {
+        self.studio.camera.buffer(self.aspect_ratio(), device)
+    }
+
+    #[inline(always)]
+    fn aspect_ratio(&self) -> f64 {
         let (width, height) = self.render_texture.canvas_size;
-        let as_rat = width as f64 / height as f64;
-        self.studio.camera.buffer(as_rat, device)
+        width as f64 / height as f64
     }
 
     /// Creates a `STORAGE` buffer of all lights.
@@ -207,7 +282,12 @@ impl SceneDescriptor {
     }
 
     #[inline(always)]
-    fn depth_texture(device: &Device, size: (u32, u32), sample_count: u32) -> Texture {
+    fn depth_texture(
+        device: &Device,
+        size: (u32, u32),
+        sample_count: u32,
+        usage: TextureUsages,
+    ) -> Texture {
         device.create_texture(&TextureDescriptor {
             size: Extent3d {
                 width: size.0,
@@ -218,7 +298,7 @@ impl SceneDescriptor {
             sample_count,
             dimension: TextureDimension::D2,
             format: TextureFormat::Depth32Float,
-            usage: TextureUsages::RENDER_ATTACHMENT,
+            usage,
             view_formats: &[],
             label: None,
         })
@@ -226,10 +306,16 @@ impl SceneDescriptor {
 
     fn backend_buffers(&self, device: &Device) -> (Option<Texture>, Option<Texture>) {
         let foward_depth = if self.backend_buffer.depth_test {
+            // the outline pass reads the depth texture.
+            let usage = match self.backend_buffer.outline {
+                Some(_) => TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+                None => TextureUsages::RENDER_ATTACHMENT,
+            };
             Some(Self::depth_texture(
                 device,
                 self.render_texture.canvas_size,
                 self.backend_buffer.sample_count,
+                usage,
             ))
         } else {
             None
@@ -253,6 +339,34 @@ impl SceneDescriptor {
 #[derive(Debug)]
 pub struct SceneDescriptorMut<'a>(&'a mut Scene);
 
+impl CameraUniform {
+    fn new(scene_desc: &SceneDescriptor, device: &Device) -> Self {
+        let (camera, as_rat) = (scene_desc.studio.camera, scene_desc.aspect_ratio());
+        let buffer = BufferHandler::from_slice(
+            &[camera.camera_info(as_rat)],
+            device,
+            BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        );
+        Self {
+            buffer,
+            uploaded: (camera, as_rat),
+            dirty: false,
+        }
+    }
+
+    /// Writes the camera to the buffer if it is changed since the last upload.
+    fn update(&mut self, scene_desc: &SceneDescriptor, queue: &Queue) {
+        let current = (scene_desc.studio.camera, scene_desc.aspect_ratio());
+        if self.dirty || self.uploaded != current {
+            let (camera, as_rat) = current;
+            self.buffer
+                .write_slice(&[camera.camera_info(as_rat)], queue);
+            self.uploaded = current;
+            self.dirty = false;
+        }
+    }
+}
+
 impl<'a> std::ops::Deref for SceneDescriptorMut<'a> {
     type Target = SceneDescriptor;
     #[inline(always)]
@@ -269,6 +383,7 @@ impl<'a> Drop for SceneDescriptorMut<'a> {
         let (forward_depth, sampling_buffer) = self.backend_buffers(self.0.device());
         self.0.foward_depth = forward_depth;
         self.0.sampling_buffer = sampling_buffer;
+        self.0.outline = OutlinePass::new(self.0.device(), &self.0.scene_desc);
     }
 }
 
@@ -332,12 +447,17 @@ impl Scene {
         let device = device_handler.device();
         let (foward_depth, sampling_buffer) = scene_desc.backend_buffers(device);
         let bind_group_layout = Self::init_scene_bind_group_layout(device);
+        let outline = OutlinePass::new(device, scene_desc);
         Scene {
             objects: Default::default(),
             bind_group_layout,
             foward_depth,
             sampling_buffer,
+            outline,
             clock: TimeInstant::now(),
+            render_stats: Default::default(),
+            pipeline_cache: Default::default(),
+            camera_uniform: Mutex::new(CameraUniform::new(scene_desc, device)),
             scene_desc: scene_desc.clone(),
             device_handler,
         }
@@ -389,6 +509,76 @@ impl Scene {
     #[inline(always)]
     pub fn elapsed(&self) -> std::time::Duration { self.clock.elapsed() }
 
+    /// Returns the statistics of the last rendering.
+    ///
+    /// If the scene has not been rendered yet, returns `RenderStats::default()`.
+    #[inline(always)]
+    pub fn last_render_stats(&self) -> RenderStats { *self.render_stats.lock().unwrap() }
+
+    /// Returns the number of the objects whose bind group layout and pipeline were taken
+    /// from the pipeline cache, since the scene was created.
+    ///
+    /// Only the objects with [`Rendered::pipeline_key`](./trait.Rendered.html#method.pipeline_key)
+    /// are cached.
+    #[inline(always)]
+    pub fn pipeline_cache_hits(&self) -> usize { self.pipeline_cache.lock().unwrap().hits }
+
+    /// Drops all the cached pipelines.
+    ///
+    /// The cache is keyed by the sample count, the depth test and the texture format, so
+    /// the pipelines for the old descriptor are not reused after the descriptor is changed.
+    /// This function releases them. The pipelines used by the registered objects are kept
+    /// until the objects are removed or updated.
+    #[inline(always)]
+    pub fn clear_pipeline_cache(&mut self) {
+        self.pipeline_cache.get_mut().unwrap().pipelines.clear()
+    }
+
+    /// Returns the bind group layout and the pipeline of `object`, shared with the other
+    /// objects with the same pipeline key. If `layout` is given, it is used instead of
+    /// creating a new bind group layout when the pipeline is not cached.
+    pub(crate) fn object_pipeline<R: Rendered + ?Sized>(
+        &self,
+        object: &R,
+        layout: Option<&Arc<BindGroupLayout>>,
+    ) -> (Arc<BindGroupLayout>, Arc<RenderPipeline>) {
+        let handler = &self.device_handler;
+        let create = || {
+            let bind_group_layout = match layout {
+                Some(layout) => Arc::clone(layout),
+                None => object.bind_group_layout(handler),
+            };
+            let pipeline_layout =
+                handler
+                    .device()
+                    .create_pipeline_layout(&PipelineLayoutDescriptor {
+                        bind_group_layouts: &[&self.bind_group_layout, &bind_group_layout],
+                        push_constant_ranges: &[],
+                        label: None,
+                    });
+            let pipeline = object.pipeline(handler, &pipeline_layout, &self.scene_desc);
+            (bind_group_layout, pipeline)
+        };
+        let Some(key) = object.pipeline_key() else {
+            return create();
+        };
+        let backend_buffer = &self.scene_desc.backend_buffer;
+        let key = (
+            key,
+            backend_buffer.sample_count,
+            backend_buffer.depth_test,
+            self.scene_desc.render_texture.format,
+        );
+        let mut cache = self.pipeline_cache.lock().unwrap();
+        if let Some(cached) = cache.pipelines.get(&key).cloned() {
+            cache.hits += 1;
+            return cached;
+        }
+        let created = create();
+        cache.pipelines.insert(key, created.clone());
+        created
+    }
+
     /// Returns the reference of the descriptor.
     #[inline(always)]
     pub const fn descriptor(&self) -> &SceneDescriptor { &self.scene_desc }
@@ -399,8 +589,12 @@ impl Scene {
     ///
     /// When the return value is dropped, the depth buffer and sampling buffer are automatically updated.
     /// Use `studio_config_mut` if you only want to update the colors of the camera, lights, and background.
+    /// The camera buffer is rewritten at the next rendering in both cases.
     #[inline(always)]
-    pub fn descriptor_mut(&mut self) -> SceneDescriptorMut<'_> { SceneDescriptorMut(self) }
+    pub fn descriptor_mut(&mut self) -> SceneDescriptorMut<'_> {
+        self.camera_uniform.get_mut().unwrap().dirty = true;
+        SceneDescriptorMut(self)
+    }
 
     /// Returns the reference of the studio configuration.
     #[inline(always)]
@@ -410,6 +604,43 @@ impl Scene {
     #[inline(always)]
     pub fn studio_config_mut(&mut self) -> &mut StudioConfig { &mut self.scene_desc.studio }
 
+    /// Moves the camera of the scene so that the whole of `bounding_box` is on the screen.
+    ///
+    /// See [`Camera::fit_to_box`](./struct.Camera.html#method.fit_to_box) for the details.
+    /// The aspect ratio is the one of the canvas.
+    #[inline(always)]
+    pub fn fit_camera_to(&mut self, bounding_box: &BoundingBox<Point3>) {
+        let (width, height) = self.scene_desc.render_texture.canvas_size;
+        let as_rat = width as f64 / height as f64;
+        self.scene_desc
+            .studio
+            .camera
+            .fit_to_box(bounding_box, as_rat);
+    }
+
+    /// Returns the lights in the scene.
+    #[inline(always)]
+    pub fn lights(&self) -> &[Light] { &self.scene_desc.studio.lights }
+
+    /// Returns the mutable reference of the lights in the scene.
+    ///
+    /// # Remarks
+    ///
+    /// The light buffer is created from the lights at each rendering,
+    /// so the changes are reflected in the next frame.
+    #[inline(always)]
+    pub fn lights_mut(&mut self) -> &mut Vec<Light> { &mut self.scene_desc.studio.lights }
+
+    /// Adds a light to the scene and returns its index.
+    ///
+    /// The index is stable unless lights are removed via [`Scene::lights_mut`].
+    #[inline(always)]
+    pub fn add_light(&mut self, light: Light) -> usize {
+        let lights = &mut self.scene_desc.studio.lights;
+        lights.push(light);
+        lights.len() - 1
+    }
+
     /// Returns the bind group layout in the scene.
     #[inline(always)]
     pub const fn bind_group_layout(&self) -> &BindGroupLayout { &self.bind_group_layout }
@@ -454,20 +685,34 @@ impl Scene {
     /// # Shader Example
     /// ```glsl
     /// layout(set = 0, binding = 2) uniform Scene {
-    ///     vec4 bk_color;  // color of back ground
-    ///     float time;     // elapsed time since the scene was created.
-    ///     uint nlights;   // the number of lights
+    ///     vec4 bk_color;      // color of back ground
+    ///     uvec2 resolution;   // the size of the render texture
+    ///     float time;         // elapsed time since the scene was created.
+    ///     uint nlights;       // the number of lights
+    ///     vec4 ambient;       // the ambient light color, ambient.w == 1.0
+    ///     vec4 fog_color;     // the fog color, fog_color.w == 1.0
+    ///     float fog_density;  // the density of the fog, zero if no fog
+    ///     float fog_near;     // the depth from which the fog starts
+    ///     float fog_far;      // the depth at which the linear fog is the densest
+    ///     uint fog_mode;      // Linear => 0, Exponential => 1
     /// };
     /// ```
     #[inline(always)]
     pub fn scene_status_buffer(&self) -> BufferHandler {
-        let bk = self.scene_desc.studio.background;
+        let studio = &self.scene_desc.studio;
+        let bk = studio.background;
         let size = self.scene_desc.render_texture.canvas_size;
         let scene_info = SceneInfo {
             background_color: [bk.r as f32, bk.g as f32, bk.b as f32, bk.a as f32],
             resolution: [size.0, size.1],
             time: self.elapsed().as_secs_f32(),
-            num_of_lights: self.scene_desc.studio.lights.len() as u32,
+            num_of_lights: studio.lights.len() as u32,
+            ambient_color: studio.ambient.extend(1.0).cast().unwrap().into(),
+            fog_color: studio.fog_color.extend(1.0).cast().unwrap().into(),
+            fog_density: studio.fog_density as f32,
+            fog_near: studio.fog_near as f32,
+            fog_far: studio.fog_far as f32,
+            fog_mode: studio.fog_mode.into(),
         };
         BufferHandler::from_slice(&[scene_info], self.device(), BufferUsages::UNIFORM)
     }
@@ -492,17 +737,27 @@ impl Scene {
     /// };
     ///
     /// layout(set = 0, binding = 2) uniform Scene {
-    ///     float time;     // elapsed time since the scene was created.
-    ///     uint nlights;   // the number of lights
+    ///     vec4 bk_color;      // color of back ground
+    ///     uvec2 resolution;   // the size of the render texture
+    ///     float time;         // elapsed time since the scene was created.
+    ///     uint nlights;       // the number of lights
+    ///     vec4 ambient;       // the ambient light color, ambient.w == 1.0
+    ///     vec4 fog_color;     // the fog color, fog_color.w == 1.0
+    ///     float fog_density;  // the density of the fog, zero if no fog
+    ///     float fog_near;     // the depth from which the fog starts
+    ///     float fog_far;      // the depth at which the linear fog is the densest
+    ///     uint fog_mode;      // Linear => 0, Exponential => 1
     /// };
     /// ```
     #[inline(always)]
     pub fn scene_bind_group(&self) -> BindGroup {
+        let mut camera_uniform = self.camera_uniform.lock().unwrap();
+        camera_uniform.update(&self.scene_desc, self.queue());
         bind_group_util::create_bind_group(
             self.device(),
             &self.bind_group_layout,
             vec![
-                self.camera_buffer().binding_resource(),
+                camera_uniform.buffer.binding_resource(),
                 self.lights_buffer().binding_resource(),
                 self.scene_status_buffer().binding_resource(),
             ],
@@ -525,11 +780,25 @@ impl Scene {
     /// If there does not exist the render object in the scene, does nothing and returns `false`.
     #[inline(always)]
     pub fn set_visibility<R: Rendered>(&mut self, object: &R, visible: bool) -> bool {
+        self.set_visible(object.render_id(), visible)
+    }
+    /// Sets the visibility of the render object with the ID `id`.
+    ///
+    /// The invisible objects keep their GPU buffers and are skipped in rendering.
+    /// If there does not exist the render object in the scene, does nothing and returns `false`.
+    #[inline(always)]
+    pub fn set_visible(&mut self, id: RenderID, visible: bool) -> bool {
         self.objects
-            .get_mut(&object.render_id())
+            .get_mut(&id)
             .map(|obj| obj.visible = visible)
             .is_some()
     }
+    /// Returns the visibility of the render object with the ID `id`,
+    /// or `None` if there does not exist the render object in the scene.
+    #[inline(always)]
+    pub fn is_visible(&self, id: RenderID) -> Option<bool> {
+        self.objects.get(&id).map(|obj| obj.visible)
+    }
     /// Adds render objects to the scene.
     ///
     /// If there already exists a render object with the same ID,
@@ -569,6 +838,24 @@ impl Scene {
     #[inline(always)]
     pub fn number_of_objects(&self) -> usize { self.objects.len() }
 
+    /// Returns the number of the render objects in the scene, the same as [`Scene::number_of_objects`].
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.objects.len() }
+
+    /// Returns `true` if there are no render objects in the scene.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.objects.len() == 0 }
+
+    /// Returns the iterator over the IDs of the render objects in the scene, in no particular order.
+    #[inline(always)]
+    pub fn object_ids(&self) -> impl Iterator<Item = RenderID> + '_ {
+        self.objects.as_slice().iter().map(|(id, _)| *id)
+    }
+
+    /// Returns `true` if the render object with the ID `id` is in the scene.
+    #[inline(always)]
+    pub fn contains(&self, id: RenderID) -> bool { self.objects.get(&id).is_some() }
+
     /// Synchronizes the information of vertices of `object` in the CPU memory
     /// and that in the GPU memory.
     ///
@@ -582,6 +869,7 @@ impl Scene {
                 let (vb, ib) = object.vertex_buffer(handler);
                 render_object.vertex_buffer = vb;
                 render_object.index_buffer = ib;
+                render_object.transparent_center = object.transparent_center();
                 true
             }
         }
@@ -611,6 +899,7 @@ impl Scene {
             Some(render_object) => {
                 let bind_group = object.bind_group(handler, &render_object.bind_group_layout);
                 render_object.bind_group = bind_group;
+                render_object.transparent_center = object.transparent_center();
                 true
             }
             _ => false,
@@ -634,24 +923,24 @@ impl Scene {
     /// If there does not exist the render object in the scene, does nothing and returns false.
     #[inline(always)]
     pub fn update_pipeline<R: Rendered>(&mut self, object: &R) -> bool {
-        let (handler, objects) = (&self.device_handler, &mut self.objects);
-        match objects.get_mut(&object.render_id()) {
-            Some(render_object) => {
-                let device = handler.device();
-                let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
-                    bind_group_layouts: &[
-                        &self.bind_group_layout,
-                        &render_object.bind_group_layout,
-                    ],
-                    push_constant_ranges: &[],
-                    label: None,
-                });
-                render_object.pipeline =
-                    object.pipeline(handler, &pipeline_layout, &self.scene_desc);
-                true
-            }
-            _ => false,
+        let Some(render_object) = self.objects.get(&object.render_id()) else {
+            return false;
+        };
+        let old_layout = Arc::clone(&render_object.bind_group_layout);
+        let (layout, pipeline) = self.object_pipeline(object, Some(&old_layout));
+        // the bind group must be recreated if the layout is replaced by the cached one.
+        let bind_group = match Arc::ptr_eq(&layout, &old_layout) {
+            true => None,
+            false => Some(object.bind_group(&self.device_handler, &layout)),
+        };
+        let render_object = self.objects.get_mut(&object.render_id()).unwrap();
+        if let Some(bind_group) = bind_group {
+            render_object.bind_group = bind_group;
         }
+        render_object.bind_group_layout = layout;
+        render_object.pipeline = pipeline;
+        render_object.transparent_center = object.transparent_center();
+        true
     }
     /// Synchronizes the information of pipeline of `object` in the CPU memory
     /// and that in the GPU memory.
@@ -679,6 +968,29 @@ impl Scene {
         }
     }
 
+    fn draw_object<'a>(
+        rpass: &mut RenderPass<'a>,
+        object: &'a RenderObject,
+        stats: &mut RenderStats,
+    ) {
+        let vertex_size = object.vertex_buffer.size / object.vertex_buffer.stride;
+        stats.visible_count += 1;
+        stats.total_vertices += vertex_size;
+        stats.draw_calls += 1;
+        rpass.set_pipeline(&object.pipeline);
+        rpass.set_bind_group(1, &object.bind_group, &[]);
+        rpass.set_vertex_buffer(0, object.vertex_buffer.buffer.slice(..));
+        match object.index_buffer {
+            Some(ref index_buffer) => {
+                rpass.set_index_buffer(index_buffer.buffer.slice(..), IndexFormat::Uint32);
+                let index_size = index_buffer.size as u32 / std::mem::size_of::<u32>() as u32;
+                stats.total_indices += index_size as u64;
+                rpass.draw_indexed(0..index_size, 0, 0..1);
+            }
+            None => rpass.draw(0..vertex_size as u32, 0..1),
+        }
+    }
+
     /// Renders the scene to `view`.
     pub fn render(&self, view: &TextureView) {
         let bind_group = self.scene_bind_group();
@@ -693,6 +1005,10 @@ impl Scene {
         let mut encoder = self
             .device()
             .create_command_encoder(&CommandEncoderDescriptor { label: None });
+        let mut stats = RenderStats {
+            object_count: self.objects.len(),
+            ..Default::default()
+        };
         {
             let (attachment, resolve_target) = match sampled_view.as_ref() {
                 Some(sampled_view) => (sampled_view, Some(view)),
@@ -713,28 +1029,36 @@ impl Scene {
                 ..Default::default()
             });
             rpass.set_bind_group(0, &bind_group, &[]);
+            // the transparent objects are drawn after the opaque ones, from back to front.
+            let camera_position = self.scene_desc.studio.camera.position();
+            let mut transparents = Vec::new();
             for (_, object) in &self.objects {
                 if !object.visible {
                     continue;
                 }
-                rpass.set_pipeline(&object.pipeline);
-                rpass.set_bind_group(1, &object.bind_group, &[]);
-                rpass.set_vertex_buffer(0, object.vertex_buffer.buffer.slice(..));
-                match object.index_buffer {
-                    Some(ref index_buffer) => {
-                        rpass.set_index_buffer(index_buffer.buffer.slice(..), IndexFormat::Uint32);
-                        let index_size =
-                            index_buffer.size as u32 / std::mem::size_of::<u32>() as u32;
-                        rpass.draw_indexed(0..index_size, 0, 0..1);
+                match object.transparent_center {
+                    Some(center) => {
+                        transparents.push((center.distance2(camera_position), object));
                     }
-                    None => rpass.draw(
-                        0..(object.vertex_buffer.size / object.vertex_buffer.stride) as u32,
-                        0..1,
-                    ),
+                    None => Self::draw_object(&mut rpass, object, &mut stats),
                 }
             }
+            transparents.sort_by(|(d0, _), (d1, _)| d1.total_cmp(d0));
+            for (_, object) in transparents {
+                Self::draw_object(&mut rpass, object, &mut stats);
+            }
+        }
+        if let (Some(outline), Some(depth_view)) = (&self.outline, &depth_view) {
+            outline.draw(
+                self.device(),
+                &mut encoder,
+                view,
+                depth_view,
+                &self.scene_desc,
+            );
         }
         self.queue().submit(vec![encoder.finish()]);
+        *self.render_stats.lock().unwrap() = stats;
     }
 
     /// Render image to buffer.
@@ -792,13 +1116,23 @@ impl WindowScene {
     pub async fn from_window(window: Arc<Window>, scene_desc: &WindowSceneDescriptor) -> Self {
         let size = window.inner_size();
         let got = init_default_device(Some(window)).await;
-        let (device_handler, window_handler) = (got.0, got.1.unwrap());
+        let (device_handler, mut window_handler) = (got.0, got.1.unwrap());
         let (device, surface) = (&device_handler.device, &window_handler.surface);
+        let capabilities = surface.get_capabilities(&device_handler.adapter);
+        if capabilities
+            .present_modes
+            .contains(&scene_desc.present_mode)
+        {
+            window_handler.present_mode = scene_desc.present_mode;
+        }
         let render_texture = RenderTextureConfig {
             canvas_size: size.into(),
             format: TextureFormat::Bgra8Unorm,
         };
-        let config = render_texture.compatible_surface_config();
+        let config = SurfaceConfiguration {
+            present_mode: window_handler.present_mode,
+            ..render_texture.compatible_surface_config()
+        };
         surface.configure(device, &config);
 
         Self {
@@ -819,16 +1153,29 @@ impl WindowScene {
     /// Get the reference of surface.
     #[inline(always)]
     pub const fn surface(&self) -> &Arc<Surface<'_>> { &self.window_handler.surface }
+    /// Returns the present mode of the surface.
+    ///
+    /// This is the mode in [`WindowSceneDescriptor`] if it is supported by the surface, otherwise `Fifo`.
+    #[inline(always)]
+    pub const fn present_mode(&self) -> PresentMode { self.window_handler.present_mode }
+    fn surface_config(&self) -> SurfaceConfiguration {
+        SurfaceConfiguration {
+            present_mode: self.present_mode(),
+            ..self
+                .scene
+                .scene_desc
+                .render_texture
+                .compatible_surface_config()
+        }
+    }
     /// Adjusts the size of the backend buffers (depth or sampling buffer) to the size of the window.
     pub fn size_alignment(&mut self) {
         let size = self.window().inner_size();
         let canvas_size = self.scene.scene_desc.render_texture.canvas_size;
         if canvas_size != (size.width, size.height) {
-            let mut desc = self.scene.descriptor_mut();
-            desc.render_texture.canvas_size = size.into();
-            let config = desc.render_texture.compatible_surface_config();
-            drop(desc);
-            self.surface().configure(self.device(), &config);
+            self.scene.descriptor_mut().render_texture.canvas_size = size.into();
+            self.surface()
+                .configure(self.device(), &self.surface_config());
         }
     }
     /// Render scene to initializing window.
@@ -838,12 +1185,7 @@ impl WindowScene {
         let surface_texture = match surface.get_current_texture() {
             Ok(got) => got,
             Err(_) => {
-                let config = self
-                    .scene
-                    .scene_desc
-                    .render_texture
-                    .compatible_surface_config();
-                surface.configure(self.device(), &config);
+                surface.configure(self.device(), &self.surface_config());
                 surface
                     .get_current_texture()
                     .expect("Failed to acquire next surface texture!")
@@ -0,0 +1,91 @@
+struct Camera {
+    matrix: mat4x4<f32>,
+    projection: mat4x4<f32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> camera: Camera;
+
+struct Scene {
+    background_color: vec4<f32>,
+    resolution: vec2<u32>,
+    _time: f32,
+    _nlights: u32,
+}
+
+@group(0)
+@binding(2)
+var<uniform> scene: Scene;
+
+struct Gizmo {
+    position: vec2<f32>,
+    size: f32,
+    _padding: f32,
+}
+
+@group(1)
+@binding(0)
+var<uniform> gizmo: Gizmo;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+}
+
+@vertex
+fn vs_triad(@location(0) position: vec3<f32>, @location(1) color: vec3<f32>) -> VertexOutput {
+    let rotation = mat3x3<f32>(camera.matrix[0].xyz, camera.matrix[1].xyz, camera.matrix[2].xyz);
+    let dir = transpose(rotation) * position;
+    let as_rat = f32(scene.resolution.x) / f32(scene.resolution.y);
+    let xy = gizmo.position + gizmo.size * vec2<f32>(dir.x / as_rat, dir.y);
+    var out: VertexOutput;
+    out.position = vec4<f32>(xy, 0.0, 1.0);
+    out.color = color;
+    return out;
+}
+
+@vertex
+fn vs_screen(@location(0) position: vec3<f32>, @location(1) color: vec3<f32>) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(gizmo.position + gizmo.size * position.xy, 0.0, 1.0);
+    out.color = color;
+    return out;
+}
+
+@vertex
+fn vs_world(@location(0) position: vec3<f32>, @location(1) color: vec3<f32>) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = camera.projection * vec4<f32>(position, 1.0);
+    out.color = color;
+    return out;
+}
+
+struct GridOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+    @location(1) world_position: vec3<f32>,
+}
+
+@vertex
+fn vs_grid(@location(0) position: vec3<f32>, @location(1) color: vec3<f32>) -> GridOutput {
+    var out: GridOutput;
+    out.position = camera.projection * vec4<f32>(position, 1.0);
+    out.color = color;
+    out.world_position = position;
+    return out;
+}
+
+// `gizmo.size` is the distance where the grid fades into the background.
+@fragment
+fn fs_grid(in: GridOutput) -> @location(0) vec4<f32> {
+    let dist = distance(in.world_position, camera.matrix[3].xyz);
+    let ratio = clamp(dist / gizmo.size, 0.0, 1.0);
+    let color = mix(pow(in.color, vec3<f32>(0.4545)), scene.background_color.rgb, ratio);
+    return vec4<f32>(color, 1.0);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(pow(in.color, vec3<f32>(0.4545)), 1.0);
+}
@@ -0,0 +1,85 @@
+struct OutlineInfo {
+    inverse_projection: mat4x4<f32>,
+    color: vec4<f32>,
+    width: f32,
+    threshold: f32,
+}
+
+@group(0)
+@binding(0)
+var<uniform> info: OutlineInfo;
+
+@group(0)
+@binding(1)
+var r_depth: texture_depth_2d;
+
+@group(0)
+@binding(1)
+var r_depth_ms: texture_depth_multisampled_2d;
+
+// a triangle covering the whole screen
+@vertex
+fn vs_main(@builtin(vertex_index) idx: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((idx << 1u) & 2u), f32(idx & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+// the distance from the camera plane
+fn view_depth(depth: f32) -> f32 {
+    let p = info.inverse_projection * vec4<f32>(0.0, 0.0, depth, 1.0);
+    return -p.z / p.w;
+}
+
+// The depth is affine on planes in the screen space, so the midpoint of the neighbors
+// predicts the depth of the center. Only the front side of the gap is the edge.
+fn is_edge(center: f32, neighbor0: f32, neighbor1: f32) -> bool {
+    let z = view_depth(center);
+    let predicted = view_depth((neighbor0 + neighbor1) * 0.5);
+    return predicted - z > info.threshold * abs(z);
+}
+
+fn offsets() -> array<vec2<i32>, 4> {
+    let r = max(i32(round(info.width)), 1);
+    return array<vec2<i32>, 4>(
+        vec2<i32>(-r, 0),
+        vec2<i32>(r, 0),
+        vec2<i32>(0, -r),
+        vec2<i32>(0, r),
+    );
+}
+
+fn load_depth(coord: vec2<i32>) -> f32 {
+    let size = vec2<i32>(textureDimensions(r_depth)) - 1;
+    return textureLoad(r_depth, clamp(coord, vec2<i32>(0), size), 0);
+}
+
+fn load_depth_ms(coord: vec2<i32>) -> f32 {
+    let size = vec2<i32>(textureDimensions(r_depth_ms)) - 1;
+    return textureLoad(r_depth_ms, clamp(coord, vec2<i32>(0), size), 0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let coord = vec2<i32>(position.xy);
+    let d = offsets();
+    let center = load_depth(coord);
+    let horizontal = is_edge(center, load_depth(coord + d[0]), load_depth(coord + d[1]));
+    let vertical = is_edge(center, load_depth(coord + d[2]), load_depth(coord + d[3]));
+    if (!horizontal && !vertical) {
+        discard;
+    }
+    return info.color;
+}
+
+@fragment
+fn fs_main_ms(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let coord = vec2<i32>(position.xy);
+    let d = offsets();
+    let center = load_depth_ms(coord);
+    let horizontal = is_edge(center, load_depth_ms(coord + d[0]), load_depth_ms(coord + d[1]));
+    let vertical = is_edge(center, load_depth_ms(coord + d[2]), load_depth_ms(coord + d[3]));
+    if (!horizontal && !vertical) {
+        discard;
+    }
+    return info.color;
+}
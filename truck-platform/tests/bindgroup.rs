@@ -57,6 +57,7 @@ fn exec_bind_group_test(backend: Backends, out_dir: &str) {
                 b: 0.3,
                 a: 0.4,
             },
+            ..Default::default()
         },
         render_texture: RenderTextureConfig {
             canvas_size: (PICTURE_WIDTH, PICTURE_HEIGHT),
@@ -0,0 +1,41 @@
+mod common;
+use truck_base::cgmath64::*;
+use truck_platform::*;
+use wgpu::*;
+
+const PICTURE_WIDTH: u32 = 256;
+const PICTURE_HEIGHT: u32 = 256;
+
+fn exec_camera_buffer_test(backend: Backends, _out_dir: &str) {
+    let handler = common::init_device(backend);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: (PICTURE_WIDTH, PICTURE_HEIGHT),
+                format: TextureFormat::Rgba8Unorm,
+            },
+            ..Default::default()
+        },
+    );
+    let triad = AxisTriad::new(Point2::new(0.0, 0.0), 0.5);
+    scene.add_object(&triad);
+    let buffer0 = pollster::block_on(scene.render_to_buffer());
+    // the camera buffer is kept while the camera is not moved.
+    let buffer1 = pollster::block_on(scene.render_to_buffer());
+    assert!(common::same_buffer(&buffer0, &buffer1));
+
+    scene.studio_config_mut().camera.matrix = Matrix4::from_angle_y(Rad(1.0));
+    let buffer2 = pollster::block_on(scene.render_to_buffer());
+    assert!(!common::same_buffer(&buffer0, &buffer2));
+    scene.studio_config_mut().camera.matrix = Matrix4::identity();
+    let buffer3 = pollster::block_on(scene.render_to_buffer());
+    assert!(common::same_buffer(&buffer0, &buffer3));
+
+    scene.descriptor_mut().studio.camera.matrix = Matrix4::from_angle_x(Rad(1.0));
+    let buffer4 = pollster::block_on(scene.render_to_buffer());
+    assert!(!common::same_buffer(&buffer0, &buffer4));
+}
+
+#[test]
+fn camera_buffer_test() { common::os_alt_exec_test(exec_camera_buffer_test); }
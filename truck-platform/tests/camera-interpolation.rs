@@ -0,0 +1,64 @@
+use std::f64::consts::PI;
+use truck_base::{assert_near, cgmath64::*, tolerance::*};
+use truck_platform::*;
+
+fn cameras(projection_type: ProjectionType) -> (Camera, Camera) {
+    let matrix0 = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+        * Matrix4::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Rad(0.4));
+    let matrix1 = Matrix4::from_translation(Vector3::new(-1.0, 0.0, 5.0))
+        * Matrix4::from_axis_angle(Vector3::new(0.0, 1.0, 2.0).normalize(), Rad(-1.2));
+    match projection_type {
+        ProjectionType::Perspective => (
+            Camera::perspective_camera(matrix0, Rad(PI / 4.0), 0.1, 10.0),
+            Camera::perspective_camera(matrix1, Rad(PI / 3.0), 0.5, 40.0),
+        ),
+        ProjectionType::Parallel => (
+            Camera::parallel_camera(matrix0, 1.0, 0.1, 10.0),
+            Camera::parallel_camera(matrix1, 3.0, 0.5, 40.0),
+        ),
+    }
+}
+
+#[test]
+fn interpolate_end_points() {
+    for projection_type in [ProjectionType::Perspective, ProjectionType::Parallel] {
+        let (a, b) = cameras(projection_type);
+        let camera = Camera::interpolate(&a, &b, 0.0).unwrap();
+        assert_eq!(camera.projection_type(), projection_type);
+        assert_near!(camera.matrix, a.matrix);
+        assert_near!(camera.projection(1.5), a.projection(1.5));
+        let camera = Camera::interpolate(&a, &b, 1.0).unwrap();
+        assert_near!(camera.matrix, b.matrix);
+        assert_near!(camera.projection(1.5), b.projection(1.5));
+    }
+}
+
+#[test]
+fn interpolate_short_way() {
+    // rotations by 170 degrees and -170 degrees are 20 degrees apart.
+    let a = Camera::perspective_camera(Matrix4::from_angle_z(Deg(170.0)), Rad(1.0), 0.1, 10.0);
+    let b = Camera::perspective_camera(Matrix4::from_angle_z(Deg(-170.0)), Rad(1.0), 0.1, 10.0);
+    let camera = Camera::interpolate(&a, &b, 0.5).unwrap();
+    assert_near!(camera.head_direction(), -Vector3::unit_y());
+    let camera = Camera::interpolate(&a, &b, 0.25).unwrap();
+    let angle = Deg(175.0);
+    assert_near!(camera.matrix, Matrix4::from_angle_z(angle));
+}
+
+#[test]
+fn camera_path() {
+    let (a, b) = cameras(ProjectionType::Perspective);
+    let c = Camera::perspective_camera(Matrix4::identity(), Rad(1.0), 0.2, 20.0);
+    let path = CameraPath::try_new([(1.0, b), (-1.0, a), (3.0, c)]).unwrap();
+    let times: Vec<f64> = path.keyframes().iter().map(|(t, _)| *t).collect();
+    assert_eq!(times, [-1.0, 1.0, 3.0]);
+    for (t, camera) in [(-2.0, a), (-1.0, a), (1.0, b), (3.0, c), (4.0, c)] {
+        let sample = path.sample(t);
+        assert_near!(sample.matrix, camera.matrix);
+        assert_near!(sample.projection(1.0), camera.projection(1.0));
+    }
+    let sample = path.sample(2.5);
+    let expected = Camera::interpolate(&b, &c, 0.75).unwrap();
+    assert_near!(sample.matrix, expected.matrix);
+    assert_near!(sample.projection(1.0), expected.projection(1.0));
+}
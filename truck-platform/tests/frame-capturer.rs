@@ -0,0 +1,57 @@
+mod common;
+use common::Plane;
+use truck_platform::*;
+use wgpu::*;
+
+fn exec_frame_capturer_test(backend: Backends, _out_dir: &str) {
+    let handler = common::init_device(backend);
+    // the width is not a multiple of 64, so that the rows of the readback buffers are padded.
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: (100, 60),
+                format: TextureFormat::Rgba8Unorm,
+            },
+            ..Default::default()
+        },
+    );
+    let plane = new_plane!("shaders/unicolor.wgsl", "vs_main", "fs_main");
+    scene.add_object(&plane);
+    let answer = pollster::block_on(scene.render_to_buffer());
+
+    let (mut capturer, receiver) = FrameCapturer::new(&scene, 3);
+    (0..8).for_each(|_| capturer.capture(&scene));
+    assert!(capturer.frames_in_flight() <= 3);
+    capturer.flush();
+    assert_eq!(capturer.frames_in_flight(), 0);
+    let frames = receiver.try_iter().collect::<Vec<_>>();
+    assert_eq!(frames.len(), 8);
+    frames
+        .iter()
+        .for_each(|frame| assert!(frame.as_raw() == &answer));
+
+    capturer.capture(&scene);
+    drop(capturer);
+    assert!(receiver
+        .try_iter()
+        .map(|frame| frame.into_raw())
+        .eq([answer]));
+
+    // the pixels of the BGRA textures are swizzled into RGBA.
+    let mut desc = scene.descriptor_mut();
+    desc.render_texture.format = TextureFormat::Bgra8Unorm;
+    drop(desc);
+    let mut answer = pollster::block_on(scene.render_to_buffer());
+    answer.chunks_mut(4).for_each(|pixel| pixel.swap(0, 2));
+    let (mut capturer, receiver) = FrameCapturer::new(&scene, 1);
+    capturer.capture(&scene);
+    capturer.flush();
+    assert!(receiver
+        .try_iter()
+        .map(|frame| frame.into_raw())
+        .eq([answer]));
+}
+
+#[test]
+fn frame_capturer_test() { common::os_alt_exec_test(exec_frame_capturer_test); }
@@ -0,0 +1,100 @@
+mod common;
+use truck_base::{bounding_box::BoundingBox, cgmath64::*};
+use truck_platform::*;
+use wgpu::*;
+
+const PICTURE_WIDTH: u32 = 256;
+const PICTURE_HEIGHT: u32 = 256;
+
+fn exec_gizmo_test(backend: Backends, _out_dir: &str) {
+    let handler = common::init_device(backend);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: (PICTURE_WIDTH, PICTURE_HEIGHT),
+                format: TextureFormat::Rgba8Unorm,
+            },
+            ..Default::default()
+        },
+    );
+    let empty = pollster::block_on(scene.render_to_buffer());
+    let triad = AxisTriad::new(Point2::new(0.0, 0.0), 0.5);
+    let buffer0 = common::render_one(&mut scene, &triad);
+    assert!(!common::same_buffer(&empty, &buffer0));
+
+    // rotating the camera changes the triad, but translating does not.
+    scene.studio_config_mut().camera.matrix =
+        Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    let buffer1 = common::render_one(&mut scene, &triad);
+    assert!(common::same_buffer(&buffer0, &buffer1));
+    scene.studio_config_mut().camera.matrix = Matrix4::from_angle_y(Rad(1.0));
+    let buffer2 = common::render_one(&mut scene, &triad);
+    assert!(!common::same_buffer(&buffer0, &buffer2));
+
+    let scale = ScaleReference::default();
+    let buffer3 = common::render_one(&mut scene, &scale);
+    assert!(!common::same_buffer(&empty, &buffer3));
+}
+
+#[test]
+fn gizmo_test() { common::os_alt_exec_test(exec_gizmo_test); }
+
+fn exec_bounding_box_test(backend: Backends, _out_dir: &str) {
+    let handler = common::init_device(backend);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: (PICTURE_WIDTH, PICTURE_HEIGHT),
+                format: TextureFormat::Rgba8Unorm,
+            },
+            ..Default::default()
+        },
+    );
+    let empty = pollster::block_on(scene.render_to_buffer());
+    let bounding_box: BoundingBox<Point3> =
+        [Point3::new(10.0, 10.0, 10.0), Point3::new(12.0, 13.0, 14.0)]
+            .into_iter()
+            .collect();
+    let instance = BoundingBoxInstance::new(bounding_box);
+    // the box is behind the default camera.
+    let buffer0 = common::render_one(&mut scene, &instance);
+    assert!(common::same_buffer(&empty, &buffer0));
+    scene.fit_camera_to(&bounding_box);
+    let buffer1 = common::render_one(&mut scene, &instance);
+    assert!(!common::same_buffer(&empty, &buffer1));
+}
+
+#[test]
+fn bounding_box_test() { common::os_alt_exec_test(exec_bounding_box_test); }
+
+fn exec_grid_test(backend: Backends, _out_dir: &str) {
+    let handler = common::init_device(backend);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: (PICTURE_WIDTH, PICTURE_HEIGHT),
+                format: TextureFormat::Rgba8Unorm,
+            },
+            ..Default::default()
+        },
+    );
+    let empty = pollster::block_on(scene.render_to_buffer());
+    // looks down at the grid from above.
+    scene.studio_config_mut().camera.matrix =
+        Matrix4::from_translation(Vector3::new(0.0, 2.0, 0.0)) * Matrix4::from_angle_x(Deg(-30.0));
+    let grid = GridInstance::new(5.0, 0.5);
+    let buffer0 = common::render_one(&mut scene, &grid);
+    assert!(!common::same_buffer(&empty, &buffer0));
+
+    // the grid is completely faded out.
+    let mut grid = GridInstance::new(5.0, 0.5);
+    grid.fade_distance = 1.0;
+    let buffer1 = common::render_one(&mut scene, &grid);
+    assert!(common::same_buffer(&empty, &buffer1));
+}
+
+#[test]
+fn grid_test() { common::os_alt_exec_test(exec_grid_test); }
@@ -0,0 +1,103 @@
+mod common;
+use common::Plane;
+use truck_platform::*;
+use wgpu::*;
+
+#[test]
+fn headless_render() {
+    let _ = env_logger::try_init();
+    let Some(handler) = pollster::block_on(DeviceHandler::headless()) else {
+        eprintln!("no adapter is available, skipped.");
+        return;
+    };
+    eprintln!("{:?}", handler.adapter().get_info());
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            studio: StudioConfig {
+                background: Color::GREEN,
+                ..Default::default()
+            },
+            render_texture: RenderTextureConfig {
+                canvas_size: (64, 48),
+                format: TextureFormat::Rgba8Unorm,
+            },
+            ..Default::default()
+        },
+    );
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    assert_eq!(buffer.len(), 64 * 48 * 4);
+    assert!(buffer.chunks(4).all(|pixel| pixel == [0, 255, 0, 255]));
+
+    let plane = new_plane!("shaders/unicolor.wgsl", "vs_main", "fs_main");
+    scene.add_object(&plane);
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    assert!(buffer.chunks(4).any(|pixel| pixel != [0, 255, 0, 255]));
+}
+
+#[test]
+fn visibility_by_id() {
+    let Some(handler) = pollster::block_on(DeviceHandler::headless()) else {
+        eprintln!("no adapter is available, skipped.");
+        return;
+    };
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: (64, 48),
+                format: TextureFormat::Rgba8Unorm,
+            },
+            ..Default::default()
+        },
+    );
+    let plane = new_plane!("shaders/unicolor.wgsl", "vs_main", "fs_main");
+    let id = plane.render_id();
+    assert_eq!(scene.is_visible(id), None);
+    assert!(!scene.set_visible(id, false));
+    scene.add_object(&plane);
+    assert_eq!(scene.is_visible(id), Some(true));
+    let shown = pollster::block_on(scene.render_to_buffer());
+
+    assert!(scene.set_visible(id, false));
+    assert_eq!(scene.is_visible(id), Some(false));
+    let hidden = pollster::block_on(scene.render_to_buffer());
+    assert!(hidden.chunks(4).all(|pixel| pixel == [0, 0, 0, 255]));
+    assert_eq!(scene.last_render_stats().visible_count, 0);
+
+    assert!(scene.set_visible(id, true));
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    assert!(buffer == shown && buffer != hidden);
+}
+
+#[test]
+fn object_queries() {
+    let Some(handler) = pollster::block_on(DeviceHandler::headless()) else {
+        eprintln!("no adapter is available, skipped.");
+        return;
+    };
+    let mut scene = Scene::new(handler, &Default::default());
+    assert!(scene.is_empty());
+    let planes: Vec<Plane> = (0..3)
+        .map(|_| new_plane!("shaders/unicolor.wgsl", "vs_main", "fs_main"))
+        .collect();
+    scene.add_objects(&planes);
+    assert_eq!(scene.len(), 3);
+    assert!(!scene.is_empty());
+    assert!(planes.iter().all(|plane| scene.contains(plane.render_id())));
+
+    scene.remove_object(&planes[0]);
+    assert!(!scene.contains(planes[0].render_id()));
+    let mut ids: Vec<RenderID> = scene.object_ids().collect();
+    ids.sort_by_key(|id| planes.iter().position(|plane| plane.render_id() == *id));
+    assert_eq!(ids, [planes[1].render_id(), planes[2].render_id()]);
+
+    // hide all
+    let ids: Vec<RenderID> = scene.object_ids().collect();
+    ids.into_iter().for_each(|id| {
+        scene.set_visible(id, false);
+    });
+    assert!(scene
+        .object_ids()
+        .all(|id| scene.is_visible(id) == Some(false)));
+}
@@ -0,0 +1,40 @@
+mod common;
+use common::Plane;
+use truck_base::cgmath64::*;
+use truck_platform::*;
+use wgpu::*;
+
+fn exec_lights_test(backend: Backends, _out_dir: &str) {
+    let handler = common::init_device(backend);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: (256, 256),
+                format: TextureFormat::Rgba8Unorm,
+            },
+            ..Default::default()
+        },
+    );
+    assert_eq!(scene.lights(), &[Light::default()]);
+
+    let light = Light {
+        position: Point3::new(1.0, 2.0, 3.0),
+        color: Vector3::new(0.5, 0.5, 0.5),
+        light_type: LightType::Uniform,
+    };
+    assert_eq!(scene.add_light(light.clone()), 1);
+    assert_eq!(scene.add_light(Light::default()), 2);
+    assert_eq!(scene.lights()[1], light);
+
+    scene.lights_mut()[2].color = Vector3::new(1.0, 0.0, 0.0);
+    assert_eq!(scene.lights()[2].color, Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(scene.studio_config().lights.len(), 3);
+
+    let plane = new_plane!("shaders/unicolor.wgsl", "vs_main", "fs_main");
+    scene.add_object(&plane);
+    let _ = pollster::block_on(scene.render_to_buffer());
+}
+
+#[test]
+fn lights_test() { common::os_alt_exec_test(exec_lights_test); }
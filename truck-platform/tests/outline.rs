@@ -0,0 +1,53 @@
+mod common;
+use common::Plane;
+use truck_platform::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+fn save_buffer<P: AsRef<std::path::Path>>(path: P, vec: &[u8]) {
+    let (width, height) = PICTURE_SIZE;
+    image::save_buffer(path, vec, width, height, image::ColorType::Rgba8).unwrap();
+}
+
+fn exec_outline_test(backend: Backends, out_dir: &str) {
+    let out_dir = String::from(out_dir);
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let handler = common::init_device(backend);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: PICTURE_SIZE,
+                format: TextureFormat::Rgba8Unorm,
+            },
+            ..Default::default()
+        },
+    );
+    let plane = new_plane!("shaders/trapezoid.wgsl", "vs_main", "fs_main");
+    let buffer0 = common::render_one(&mut scene, &plane);
+
+    let outline = OutlineConfig {
+        color: Color::RED,
+        ..Default::default()
+    };
+    scene.descriptor_mut().backend_buffer.outline = Some(outline);
+    // no edges in the empty scene
+    let empty0 = pollster::block_on(scene.render_to_buffer());
+    scene.descriptor_mut().backend_buffer.outline = None;
+    let empty1 = pollster::block_on(scene.render_to_buffer());
+    assert!(common::same_buffer(&empty0, &empty1));
+
+    scene.descriptor_mut().backend_buffer.outline = Some(outline);
+    let buffer1 = common::render_one(&mut scene, &plane);
+    save_buffer(out_dir.clone() + "outline.png", &buffer1);
+    assert!(!common::same_buffer(&buffer0, &buffer1));
+
+    scene.descriptor_mut().backend_buffer.sample_count = 4;
+    let buffer2 = common::render_one(&mut scene, &plane);
+    save_buffer(out_dir + "outline-msaa.png", &buffer2);
+    assert!(!common::same_buffer(&buffer0, &buffer2));
+}
+
+#[test]
+fn outline_test() { common::os_alt_exec_test(exec_outline_test); }
@@ -0,0 +1,46 @@
+mod common;
+use common::Plane;
+use truck_platform::*;
+use wgpu::*;
+
+fn exec_render_stats_test(backend: Backends, _out_dir: &str) {
+    let handler = common::init_device(backend);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: (256, 256),
+                format: TextureFormat::Rgba8Unorm,
+            },
+            ..Default::default()
+        },
+    );
+    assert_eq!(scene.last_render_stats(), RenderStats::default());
+
+    let plane0 = new_plane!("shaders/unicolor.wgsl", "vs_main", "fs_main");
+    let plane1 = new_plane!("shaders/unicolor.wgsl", "vs_main", "fs_main");
+    scene.add_objects([&plane0, &plane1]);
+    scene.set_visibility(&plane1, false);
+    let _ = pollster::block_on(scene.render_to_buffer());
+    let stats = scene.last_render_stats();
+    assert_eq!(
+        stats,
+        RenderStats {
+            object_count: 2,
+            visible_count: 1,
+            total_vertices: 6,
+            total_indices: 0,
+            draw_calls: 1,
+        }
+    );
+
+    scene.set_visibility(&plane1, true);
+    let _ = pollster::block_on(scene.render_to_buffer());
+    let stats = scene.last_render_stats();
+    assert_eq!(stats.visible_count, 2);
+    assert_eq!(stats.total_vertices, 12);
+    assert_eq!(stats.draw_calls, 2);
+}
+
+#[test]
+fn render_stats_test() { common::os_alt_exec_test(exec_render_stats_test); }
@@ -0,0 +1,269 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+use serde_json::{json, Value};
+use std::io::Write;
+
+type Vertex = StandardVertex;
+type Result<T> = std::result::Result<T, errors::Error>;
+
+const GLB_MAGIC: u32 = 0x4654_6C67;
+const GLB_VERSION: u32 = 2;
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const TRIANGLES: u32 = 4;
+
+/// Metallic-roughness material of glTF 2.0
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Material {
+    /// base color, RGBA in the linear space
+    pub base_color_factor: [f64; 4],
+    /// metalness, in the range `[0, 1]`
+    pub metallic_factor: f64,
+    /// roughness, in the range `[0, 1]`
+    pub roughness_factor: f64,
+}
+
+impl Default for Material {
+    /// The default values of glTF 2.0.
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            base_color_factor: [1.0; 4],
+            metallic_factor: 1.0,
+            roughness_factor: 1.0,
+        }
+    }
+}
+
+/// The vertex attributes expanded so that each vertex has its own position, normal and uv.
+#[derive(Debug, Default)]
+struct ExpandedMesh {
+    positions: Vec<[f32; 3]>,
+    normals: Option<Vec<[f32; 3]>>,
+    uv_coords: Option<Vec<[f32; 2]>>,
+    indices: Vec<u32>,
+}
+
+fn expand(mesh: &PolygonMesh) -> ExpandedMesh {
+    let mut vertex_map = HashMap::<Vertex, u32>::default();
+    let mut vertices = Vec::<Vertex>::new();
+    let indices = mesh
+        .faces()
+        .triangle_iter()
+        .flatten()
+        .map(|v| {
+            *vertex_map.entry(v).or_insert_with(|| {
+                vertices.push(v);
+                vertices.len() as u32 - 1
+            })
+        })
+        .collect();
+    let positions = vertices
+        .iter()
+        .map(|v| mesh.positions()[v.pos].cast::<f32>().unwrap().into())
+        .collect();
+    // glTF requires that all vertices have the attribute.
+    let normals = vertices
+        .iter()
+        .map(|v| {
+            let n = mesh.normals()[v.nor?];
+            match n.so_small() {
+                true => Some([0.0, 0.0, 1.0]),
+                false => Some(n.normalize().cast::<f32>().unwrap().into()),
+            }
+        })
+        .collect::<Option<Vec<_>>>();
+    let uv_coords = vertices
+        .iter()
+        .map(|v| {
+            let uv = mesh.uv_coords()[v.uv?];
+            // glTF takes the origin of the texture at the upper left corner.
+            Some([uv.x as f32, 1.0 - uv.y as f32])
+        })
+        .collect::<Option<Vec<_>>>();
+    ExpandedMesh {
+        positions,
+        normals,
+        uv_coords,
+        indices,
+    }
+}
+
+#[derive(Debug, Default)]
+struct Buffers {
+    bin: Vec<u8>,
+    buffer_views: Vec<Value>,
+    accessors: Vec<Value>,
+}
+
+impl Buffers {
+    /// Appends `data` as a new buffer view and an accessor, and returns the index of the accessor.
+    fn push<T: bytemuck::Pod>(&mut self, data: &[T], accessor: Value, target: u32) -> usize {
+        let byte_offset = self.bin.len();
+        self.bin.extend_from_slice(bytemuck::cast_slice(data));
+        // every accessor in this module has the components with 4 bytes.
+        self.bin.resize(self.bin.len().div_ceil(4) * 4, 0);
+        self.buffer_views.push(json!({
+            "buffer": 0,
+            "byteOffset": byte_offset,
+            "byteLength": size_of_val(data),
+            "target": target,
+        }));
+        let mut accessor = accessor;
+        accessor["bufferView"] = json!(self.buffer_views.len() - 1);
+        self.accessors.push(accessor);
+        self.accessors.len() - 1
+    }
+}
+
+fn write_chunk<W: Write>(writer: &mut W, chunk_type: u32, data: &[u8], padding: u8) -> Result<()> {
+    let padded_len = data.len().div_ceil(4) * 4;
+    writer.write_all(&(padded_len as u32).to_le_bytes())?;
+    writer.write_all(&chunk_type.to_le_bytes())?;
+    writer.write_all(data)?;
+    writer.write_all(&vec![padding; padded_len - data.len()])?;
+    Ok(())
+}
+
+/// Writes `mesh` as a binary glTF 2.0 (`.glb`) with the default material.
+///
+/// The positions, normals and uv coordinates are expanded to the per-vertex attributes,
+/// and the faces are triangulated. The normals and the uv coordinates are exported only
+/// if all face vertices have them.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ];
+/// let faces = Faces::from_iter(&[[0, 1, 2, 3]]);
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+///
+/// let mut glb = Vec::new();
+/// gltf::export(&mesh, &mut glb).unwrap();
+/// assert_eq!(&glb[0..4], b"glTF");
+/// assert_eq!(glb.len() % 4, 0);
+/// ```
+#[inline(always)]
+pub fn export<W: Write>(mesh: &PolygonMesh, writer: W) -> Result<()> {
+    export_with_material(mesh, &Material::default(), writer)
+}
+
+/// Writes `mesh` as a binary glTF 2.0 (`.glb`) with `material`.
+///
+/// See [`export`] for the details of the output.
+pub fn export_with_material<W: Write>(
+    mesh: &PolygonMesh,
+    material: &Material,
+    mut writer: W,
+) -> Result<()> {
+    let expanded = expand(mesh);
+    let mut buffers = Buffers::default();
+
+    // the accessor of positions requires the bounding box.
+    let (min, max) = match expanded.positions.is_empty() {
+        true => ([0.0; 3], [0.0; 3]),
+        false => expanded.positions.iter().fold(
+            ([f32::INFINITY; 3], [f32::NEG_INFINITY; 3]),
+            |(min, max), p| {
+                (
+                    [0, 1, 2].map(|i| f32::min(min[i], p[i])),
+                    [0, 1, 2].map(|i| f32::max(max[i], p[i])),
+                )
+            },
+        ),
+    };
+    let position_accessor = json!({
+        "componentType": FLOAT,
+        "count": expanded.positions.len(),
+        "type": "VEC3",
+        "min": min,
+        "max": max,
+    });
+    let mut attributes = serde_json::Map::new();
+    let idx = buffers.push(&expanded.positions, position_accessor, ARRAY_BUFFER);
+    attributes.insert("POSITION".into(), json!(idx));
+    if let Some(normals) = &expanded.normals {
+        let accessor = json!({
+            "componentType": FLOAT,
+            "count": normals.len(),
+            "type": "VEC3",
+        });
+        let idx = buffers.push(normals, accessor, ARRAY_BUFFER);
+        attributes.insert("NORMAL".into(), json!(idx));
+    }
+    if let Some(uv_coords) = &expanded.uv_coords {
+        let accessor = json!({
+            "componentType": FLOAT,
+            "count": uv_coords.len(),
+            "type": "VEC2",
+        });
+        let idx = buffers.push(uv_coords, accessor, ARRAY_BUFFER);
+        attributes.insert("TEXCOORD_0".into(), json!(idx));
+    }
+    let index_accessor = json!({
+        "componentType": UNSIGNED_INT,
+        "count": expanded.indices.len(),
+        "type": "SCALAR",
+    });
+    let indices = buffers.push(&expanded.indices, index_accessor, ELEMENT_ARRAY_BUFFER);
+
+    let Buffers {
+        bin,
+        buffer_views,
+        accessors,
+    } = buffers;
+    let document = json!({
+        "asset": {
+            "version": "2.0",
+            "generator": "truck-polymesh",
+        },
+        "scene": 0,
+        "scenes": [{ "nodes": [0] }],
+        "nodes": [{ "mesh": 0 }],
+        "meshes": [{
+            "primitives": [{
+                "attributes": attributes,
+                "indices": indices,
+                "material": 0,
+                "mode": TRIANGLES,
+            }],
+        }],
+        "materials": [{
+            "pbrMetallicRoughness": {
+                "baseColorFactor": material.base_color_factor,
+                "metallicFactor": material.metallic_factor,
+                "roughnessFactor": material.roughness_factor,
+            },
+        }],
+        "buffers": [{ "byteLength": bin.len() }],
+        "bufferViews": buffer_views,
+        "accessors": accessors,
+    });
+    let json = serde_json::to_vec(&document).map_err(std::io::Error::from)?;
+
+    let json_len = json.len().div_ceil(4) * 4;
+    let total_len = 12 + 8 + json_len + 8 + bin.len();
+    let total_len = u32::try_from(total_len)
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "too large mesh"))?;
+    writer.write_all(&GLB_MAGIC.to_le_bytes())?;
+    writer.write_all(&GLB_VERSION.to_le_bytes())?;
+    writer.write_all(&total_len.to_le_bytes())?;
+    write_chunk(&mut writer, CHUNK_JSON, &json, b' ')?;
+    write_chunk(&mut writer, CHUNK_BIN, &bin, 0)?;
+    Ok(())
+}
@@ -89,7 +89,7 @@ pub struct PolygonMesh<V = StandardVertex, A = StandardAttributes> {
 }
 
 /// structured quadrangle mesh
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct StructuredMesh {
     positions: Vec<Vec<Point3>>,
     uv_division: Option<(Vec<f64>, Vec<f64>)>,
@@ -109,6 +109,10 @@ pub mod faces;
 mod meshing_shape;
 /// wavefront obj I/O
 pub mod obj;
+/// glTF 2.0 export
+pub mod gltf;
+/// PLY I/O
+pub mod ply;
 /// Defines [`PolygonMeshEditor`](./polygon_mesh/struct.PolygonMeshEditor.html).
 pub mod polygon_mesh;
 /// Defines generalized polyline curve.
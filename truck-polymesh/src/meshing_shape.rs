@@ -1,4 +1,6 @@
 use crate::*;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 impl<P> PolylineCurve<P> {
     /// meshing the curve
@@ -24,20 +26,47 @@ impl StructuredMesh {
         let (div0, div1) = surface.parameter_division(range, tol);
         create_mesh(surface, div0, div1)
     }
+
+    /// meshing the surface, evaluating the rows of the grid in parallel by `rayon`.
+    ///
+    /// The division of the parameter is the same as [`StructuredMesh::from_surface`], and
+    /// the rows are gathered in order, so the result is identical to the one of `from_surface`
+    /// regardless of the number of threads.
+    /// # Arguments
+    /// * `bspsurface` - bspline surface to meshed
+    /// * `tol` - standard tolerance for meshing
+    #[cfg(feature = "rayon")]
+    pub fn par_from_surface<S>(
+        surface: &S,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> StructuredMesh
+    where
+        S: ParametricSurface3D + ParameterDivision2D + Sync,
+    {
+        let (div0, div1) = surface.parameter_division(range, tol);
+        let (positions, normals) = div0
+            .par_iter()
+            .map(|u| create_row(surface, *u, &div1))
+            .unzip();
+        StructuredMesh {
+            positions,
+            uv_division: Some((div0, div1)),
+            normals: Some(normals),
+        }
+    }
+}
+
+fn create_row<S>(surface: &S, u: f64, div1: &[f64]) -> (Vec<Point3>, Vec<Vector3>)
+where S: ParametricSurface3D {
+    div1.iter()
+        .map(|v| (surface.subs(u, *v), surface.normal(u, *v)))
+        .unzip()
 }
 
 fn create_mesh<S>(surface: &S, div0: Vec<f64>, div1: Vec<f64>) -> StructuredMesh
 where S: ParametricSurface3D {
-    let mut positions = vec![Vec::with_capacity(div1.len()); div0.len()];
-    let mut normals = vec![Vec::with_capacity(div1.len()); div0.len()];
-    div0.iter()
-        .zip(positions.iter_mut().zip(normals.iter_mut()))
-        .for_each(|(u, (prow, nrow))| {
-            div1.iter().for_each(move |v| {
-                prow.push(surface.subs(*u, *v));
-                nrow.push(surface.normal(*u, *v));
-            })
-        });
+    let (positions, normals) = div0.iter().map(|u| create_row(surface, *u, &div1)).unzip();
     StructuredMesh {
         positions,
         uv_division: Some((div0, div1)),
@@ -1,5 +1,8 @@
 use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::fs::File;
 use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
 type Vertex = StandardVertex;
 type Result<T> = std::result::Result<T, errors::Error>;
 
@@ -47,7 +50,9 @@ type Result<T> = std::result::Result<T, errors::Error>;
 ///     },
 ///     faces,
 /// );
-/// obj::write(&mesh, std::fs::File::create("meshdata.obj").unwrap());
+/// let mut buffer = Vec::new();
+/// obj::write(&mesh, &mut buffer).unwrap();
+/// assert_eq!(obj::read(buffer.as_slice()).unwrap(), mesh);
 /// ```
 pub fn write<W: Write>(mesh: &PolygonMesh, writer: W) -> Result<()> {
     sub_write(mesh, &mut BufWriter::new(writer))
@@ -89,25 +94,27 @@ fn write3vec<V: std::ops::Index<usize, Output = f64>, W: Write>(
 }
 
 impl Vertex {
-    fn write<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+    /// Writes the vertex whose indices are shifted by `offsets`.
+    fn write<W: Write>(&self, [p, t, n]: [usize; 3], writer: &mut W) -> std::io::Result<()> {
+        let pos = self.pos + p + 1;
         match (self.uv, self.nor) {
-            (None, None) => writer.write_fmt(format_args!("{}", self.pos + 1)),
-            (Some(uv), None) => writer.write_fmt(format_args!("{}/{}", self.pos + 1, uv + 1)),
-            (None, Some(nor)) => writer.write_fmt(format_args!("{}//{}", self.pos + 1, nor + 1)),
+            (None, None) => writer.write_fmt(format_args!("{pos}")),
+            (Some(uv), None) => writer.write_fmt(format_args!("{pos}/{}", uv + t + 1)),
+            (None, Some(nor)) => writer.write_fmt(format_args!("{pos}//{}", nor + n + 1)),
             (Some(uv), Some(nor)) => {
-                writer.write_fmt(format_args!("{}/{}/{}", self.pos + 1, uv + 1, nor + 1))
+                writer.write_fmt(format_args!("{pos}/{}/{}", uv + t + 1, nor + n + 1))
             }
         }
     }
 }
 
 impl Faces {
-    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+    fn write<W: Write>(&self, offsets: [usize; 3], writer: &mut W) -> Result<()> {
         for face in self.face_iter() {
             writer.write_all(b"f")?;
             for v in face {
                 writer.write_all(b" ")?;
-                v.write(writer)?;
+                v.write(offsets, writer)?;
             }
             writer.write_all(b"\n")?;
         }
@@ -119,15 +126,26 @@ fn sub_write<W: Write>(mesh: &PolygonMesh, writer: &mut BufWriter<W>) -> Result<
     write3vec(writer, mesh.positions(), "v")?;
     write2vec(writer, mesh.uv_coords(), "vt")?;
     write3vec(writer, mesh.normals(), "vn")?;
-    mesh.faces.write(writer)
+    mesh.faces.write([0; 3], writer)
 }
 
-/// Reads mesh data from wavefront obj file.
-pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
-    let mut positions = Vec::new();
-    let mut uv_coords = Vec::new();
-    let mut normals = Vec::new();
-    let mut faces = Faces::default();
+/// The data parsed from obj file.
+#[derive(Debug, Default)]
+struct ObjData {
+    attributes: StandardAttributes,
+    faces: Faces,
+    /// the material libraries declared by `mtllib`
+    mtllibs: Vec<String>,
+    /// the faces grouped by `usemtl`, the faces before the first `usemtl` have no material.
+    groups: Vec<(Option<String>, Faces)>,
+}
+
+fn parse<R: Read>(reader: R) -> Result<ObjData> {
+    let mut data = ObjData {
+        groups: vec![(None, Faces::default())],
+        ..Default::default()
+    };
+    let mut current = 0;
     let reader = BufReader::new(reader);
     for line in reader.lines().map(|s| s.unwrap()) {
         let mut args = line.split_whitespace();
@@ -136,16 +154,16 @@ pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
                 let x = args.next().unwrap().parse::<f64>()?;
                 let y = args.next().unwrap().parse::<f64>()?;
                 let z = args.next().unwrap().parse::<f64>()?;
-                positions.push(Point3::new(x, y, z));
+                data.attributes.positions.push(Point3::new(x, y, z));
             } else if first_str == "vt" {
                 let u = args.next().unwrap().parse::<f64>()?;
                 let v = args.next().unwrap().parse::<f64>()?;
-                uv_coords.push(Vector2::new(u, v));
+                data.attributes.uv_coords.push(Vector2::new(u, v));
             } else if first_str == "vn" {
                 let x = args.next().unwrap().parse::<f64>()?;
                 let y = args.next().unwrap().parse::<f64>()?;
                 let z = args.next().unwrap().parse::<f64>()?;
-                normals.push(Vector3::new(x, y, z));
+                data.attributes.normals.push(Vector3::new(x, y, z));
             } else if first_str == "f" {
                 let mut face = Vec::new();
                 for vert_str in args {
@@ -171,16 +189,311 @@ pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
                     };
                     face.push(vert);
                 }
-                faces.push(face);
+                data.groups[current].1.push(&face);
+                data.faces.push(face);
+            } else if first_str == "mtllib" {
+                data.mtllibs.extend(args.map(String::from));
+            } else if first_str == "usemtl" {
+                let name = args.next().map(String::from);
+                current = match data.groups.iter().position(|(n, _)| *n == name) {
+                    Some(idx) => idx,
+                    None => {
+                        data.groups.push((name, Faces::default()));
+                        data.groups.len() - 1
+                    }
+                };
             }
         }
     }
-    PolygonMesh::try_new(
-        StandardAttributes {
-            positions,
-            uv_coords,
-            normals,
-        },
-        faces,
-    )
+    Ok(data)
+}
+
+/// Reads mesh data from wavefront obj file.
+pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
+    let data = parse(reader)?;
+    PolygonMesh::try_new(data.attributes, data.faces)
+}
+
+/// Material of wavefront obj, described in `.mtl` file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ObjMaterial {
+    /// the name declared by `newmtl`
+    pub name: String,
+    /// ambient color `Ka`
+    pub ambient: [f64; 3],
+    /// diffuse color `Kd`
+    pub diffuse: [f64; 3],
+    /// specular color `Ks`
+    pub specular: [f64; 3],
+    /// specular exponent `Ns`
+    pub shininess: f64,
+    /// opacity `d`, or one minus the transparency `Tr`
+    pub alpha: f64,
+    /// the file name of the diffuse texture `map_Kd`
+    pub diffuse_map: Option<String>,
+}
+
+impl Default for ObjMaterial {
+    /// Non-specular gray material named `default`.
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            name: "default".to_string(),
+            ambient: [0.0; 3],
+            diffuse: [0.8; 3],
+            specular: [0.0; 3],
+            shininess: 0.0,
+            alpha: 1.0,
+            diffuse_map: None,
+        }
+    }
+}
+
+fn parse_array<'a, const N: usize>(mut args: impl Iterator<Item = &'a str>) -> Result<[f64; N]> {
+    let mut res = [0.0; N];
+    for x in &mut res {
+        *x = args.next().unwrap_or_default().parse::<f64>()?;
+    }
+    Ok(res)
+}
+
+/// Reads the materials from `.mtl` file.
+///
+/// Only the colors, the specular exponent, the opacity and the diffuse texture are read,
+/// the other statements are skipped.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mtl = b"newmtl red
+/// Kd 1.0 0.0 0.0
+/// Ns 10.0
+/// newmtl glass
+/// Kd 0.9 0.9 1.0
+/// Tr 0.75
+/// map_Kd glass.png
+/// ";
+/// let materials = obj::read_mtl(mtl.as_slice()).unwrap();
+/// assert_eq!(materials.len(), 2);
+/// assert_eq!(materials[0].name, "red");
+/// assert_eq!(materials[0].diffuse, [1.0, 0.0, 0.0]);
+/// assert_eq!(materials[0].shininess, 10.0);
+/// assert_eq!(materials[1].alpha, 0.25);
+/// assert_eq!(materials[1].diffuse_map.as_deref(), Some("glass.png"));
+/// ```
+pub fn read_mtl<R: Read>(reader: R) -> Result<Vec<ObjMaterial>> {
+    let mut materials = Vec::<ObjMaterial>::new();
+    for line in BufReader::new(reader).lines() {
+        let line = line?;
+        let mut args = line.split_whitespace();
+        let Some(first_str) = args.next() else {
+            continue;
+        };
+        if first_str == "newmtl" {
+            materials.push(ObjMaterial {
+                name: args.next().unwrap_or_default().to_string(),
+                ..Default::default()
+            });
+            continue;
+        }
+        let Some(material) = materials.last_mut() else {
+            continue;
+        };
+        match first_str {
+            "Ka" => material.ambient = parse_array(args)?,
+            "Kd" => material.diffuse = parse_array(args)?,
+            "Ks" => material.specular = parse_array(args)?,
+            "Ns" => material.shininess = parse_array::<1>(args)?[0],
+            "d" => material.alpha = parse_array::<1>(args)?[0],
+            "Tr" => material.alpha = 1.0 - parse_array::<1>(args)?[0],
+            // the options precede the file name.
+            "map_Kd" => material.diffuse_map = args.last().map(String::from),
+            _ => {}
+        }
+    }
+    Ok(materials)
+}
+
+/// Writes the materials to `.mtl` file.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let materials = vec![obj::ObjMaterial {
+///     name: "blue".to_string(),
+///     diffuse: [0.0, 0.0, 1.0],
+///     alpha: 0.5,
+///     ..Default::default()
+/// }];
+/// let mut mtl = Vec::new();
+/// obj::write_mtl(&materials, &mut mtl).unwrap();
+/// assert_eq!(obj::read_mtl(mtl.as_slice()).unwrap(), materials);
+/// ```
+pub fn write_mtl<W: Write>(materials: &[ObjMaterial], writer: W) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    for material in materials {
+        let [r, g, b] = material.ambient;
+        writer.write_fmt(format_args!("newmtl {}\nKa {r} {g} {b}\n", material.name))?;
+        let [r, g, b] = material.diffuse;
+        writer.write_fmt(format_args!("Kd {r} {g} {b}\n"))?;
+        let [r, g, b] = material.specular;
+        writer.write_fmt(format_args!("Ks {r} {g} {b}\n"))?;
+        writer.write_fmt(format_args!(
+            "Ns {}\nd {}\n",
+            material.shininess, material.alpha
+        ))?;
+        if let Some(map) = &material.diffuse_map {
+            writer.write_fmt(format_args!("map_Kd {map}\n"))?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the submesh consisting of `faces`, with only the attributes referred by `faces`.
+fn submesh(attributes: &StandardAttributes, faces: &Faces) -> PolygonMesh {
+    fn reindex<T: Copy>(
+        map: &mut HashMap<usize, usize>,
+        src: &[T],
+        dst: &mut Vec<T>,
+        idx: usize,
+    ) -> usize {
+        *map.entry(idx).or_insert_with(|| {
+            dst.push(src[idx]);
+            dst.len() - 1
+        })
+    }
+    let mut res = StandardAttributes::default();
+    let mut maps = <[HashMap<usize, usize>; 3]>::default();
+    let faces = faces
+        .face_iter()
+        .map(|face| {
+            face.iter()
+                .map(|v| Vertex {
+                    pos: reindex(
+                        &mut maps[0],
+                        &attributes.positions,
+                        &mut res.positions,
+                        v.pos,
+                    ),
+                    uv: v.uv.map(|i| {
+                        reindex(&mut maps[1], &attributes.uv_coords, &mut res.uv_coords, i)
+                    }),
+                    nor: v
+                        .nor
+                        .map(|i| reindex(&mut maps[2], &attributes.normals, &mut res.normals, i)),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Faces>();
+    PolygonMesh::new(res, faces)
+}
+
+/// Reads the meshes and their materials from wavefront obj file at `path`.
+///
+/// The faces are grouped by `usemtl` statements, and the `i`-th mesh consists of the faces with
+/// the `i`-th material. The material libraries are searched in the directory of the obj file.
+/// If a library or a material is not found, the warning is printed to stderr and the default
+/// material with the same name is used. The faces before the first `usemtl` have the default
+/// material named `default`.
+pub fn read_with_materials<P: AsRef<Path>>(
+    path: P,
+) -> Result<(Vec<PolygonMesh>, Vec<ObjMaterial>)> {
+    let path = path.as_ref();
+    let data = parse(File::open(path)?)?;
+    // checks the indices of all the faces.
+    PolygonMesh::try_new(data.attributes.clone(), data.faces)?;
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let library: Vec<ObjMaterial> = data
+        .mtllibs
+        .iter()
+        .filter_map(|lib| {
+            let lib = dir.join(lib);
+            let res = File::open(&lib)
+                .map_err(errors::Error::from)
+                .and_then(read_mtl);
+            res.map_err(|e| eprintln!("Warning: failed to read {}: {e}", lib.display()))
+                .ok()
+        })
+        .flatten()
+        .collect();
+    let (meshes, materials) = data
+        .groups
+        .iter()
+        .filter(|(_, faces)| !faces.is_empty())
+        .map(|(name, faces)| {
+            let material = match name {
+                None => ObjMaterial::default(),
+                Some(name) => match library.iter().find(|mat| mat.name == *name) {
+                    Some(material) => material.clone(),
+                    None => {
+                        eprintln!("Warning: the material {name} is not found.");
+                        ObjMaterial {
+                            name: name.clone(),
+                            ..Default::default()
+                        }
+                    }
+                },
+            };
+            (submesh(&data.attributes, faces), material)
+        })
+        .unzip();
+    Ok((meshes, materials))
+}
+
+/// Writes the meshes to wavefront obj file at `path` and their materials to `.mtl` file with the
+/// same stem as `path`. The `i`-th mesh is written with the `i`-th material.
+///
+/// # Failures
+/// Returns [`Error::DifferentLengthArrays`](errors::Error::DifferentLengthArrays)
+/// if the lengths of `meshes` and `materials` are not the same.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ];
+/// let attrs = StandardAttributes {
+///     positions,
+///     ..Default::default()
+/// };
+/// let mesh = PolygonMesh::new(attrs, Faces::from_iter(&[[0, 1, 2]]));
+/// let material = obj::ObjMaterial {
+///     name: "red".to_string(),
+///     diffuse: [1.0, 0.0, 0.0],
+///     ..Default::default()
+/// };
+///
+/// let path = std::env::temp_dir().join("truck-polymesh-triangle.obj");
+/// obj::write_with_materials(&[mesh.clone()], &[material.clone()], &path).unwrap();
+/// let (meshes, materials) = obj::read_with_materials(&path).unwrap();
+/// assert_eq!(meshes, vec![mesh]);
+/// assert_eq!(materials, vec![material]);
+/// ```
+pub fn write_with_materials<P: AsRef<Path>>(
+    meshes: &[PolygonMesh],
+    materials: &[ObjMaterial],
+    path: P,
+) -> Result<()> {
+    if meshes.len() != materials.len() {
+        return Err(errors::Error::DifferentLengthArrays);
+    }
+    let path = path.as_ref();
+    let mtl_path = path.with_extension("mtl");
+    write_mtl(materials, File::create(&mtl_path)?)?;
+    let mut writer = BufWriter::new(File::create(path)?);
+    if let Some(mtllib) = mtl_path.file_name() {
+        writer.write_fmt(format_args!("mtllib {}\n", mtllib.to_string_lossy()))?;
+    }
+    let mut offsets = [0; 3];
+    for (mesh, material) in meshes.iter().zip(materials) {
+        write3vec(&mut writer, mesh.positions(), "v")?;
+        write2vec(&mut writer, mesh.uv_coords(), "vt")?;
+        write3vec(&mut writer, mesh.normals(), "vn")?;
+        writer.write_fmt(format_args!("usemtl {}\n", material.name))?;
+        mesh.faces.write(offsets, &mut writer)?;
+        offsets[0] += mesh.positions().len();
+        offsets[1] += mesh.uv_coords().len();
+        offsets[2] += mesh.normals().len();
+    }
+    Ok(())
 }
@@ -0,0 +1,478 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+
+type Vertex = StandardVertex;
+type Result<T> = std::result::Result<T, errors::Error>;
+
+fn syntax_error(message: &str) -> errors::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string()).into()
+}
+
+/// PLY format
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlyFormat {
+    /// ASCII format
+    #[default]
+    Ascii,
+    /// binary format in little endian
+    BinaryLittleEndian,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Scalar {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl Scalar {
+    fn parse(name: &str) -> Result<Self> {
+        Ok(match name {
+            "char" | "int8" => Scalar::I8,
+            "uchar" | "uint8" => Scalar::U8,
+            "short" | "int16" => Scalar::I16,
+            "ushort" | "uint16" => Scalar::U16,
+            "int" | "int32" => Scalar::I32,
+            "uint" | "uint32" => Scalar::U32,
+            "float" | "float32" => Scalar::F32,
+            "double" | "float64" => Scalar::F64,
+            _ => return Err(syntax_error("unknown scalar type")),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Property {
+    Scalar(String, Scalar),
+    List(String, Scalar, Scalar),
+}
+
+#[derive(Clone, Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+/// Reads the values in the body of PLY.
+trait ValueReader {
+    fn read_value(&mut self, scalar: Scalar) -> Result<f64>;
+}
+
+struct AsciiValueReader<I>(I);
+
+impl<'a, I: Iterator<Item = &'a str>> ValueReader for AsciiValueReader<I> {
+    fn read_value(&mut self, _: Scalar) -> Result<f64> {
+        let token = self
+            .0
+            .next()
+            .ok_or_else(|| syntax_error("unexpected EOF"))?;
+        Ok(token.parse::<f64>()?)
+    }
+}
+
+struct BinaryValueReader<R>(R);
+
+impl<R: Read> ValueReader for BinaryValueReader<R> {
+    fn read_value(&mut self, scalar: Scalar) -> Result<f64> {
+        macro_rules! read_le {
+            ($ty: ty) => {{
+                let mut bytes = [0u8; std::mem::size_of::<$ty>()];
+                self.0.read_exact(&mut bytes)?;
+                f64::from(<$ty>::from_le_bytes(bytes))
+            }};
+        }
+        Ok(match scalar {
+            Scalar::I8 => read_le!(i8),
+            Scalar::U8 => read_le!(u8),
+            Scalar::I16 => read_le!(i16),
+            Scalar::U16 => read_le!(u16),
+            Scalar::I32 => read_le!(i32),
+            Scalar::U32 => read_le!(u32),
+            Scalar::F32 => read_le!(f32),
+            Scalar::F64 => read_le!(f64),
+        })
+    }
+}
+
+fn read_header<R: BufRead>(reader: &mut R) -> Result<(PlyFormat, Vec<Element>)> {
+    let mut line = String::new();
+    let mut next_line = |line: &mut String| -> Result<()> {
+        line.clear();
+        match reader.read_line(line)? {
+            0 => Err(syntax_error("unexpected EOF in the header")),
+            _ => Ok(()),
+        }
+    };
+    next_line(&mut line)?;
+    if line.trim() != "ply" {
+        return Err(syntax_error("the magic number \"ply\" is not found"));
+    }
+    let mut format = None;
+    let mut elements = Vec::<Element>::new();
+    loop {
+        next_line(&mut line)?;
+        let args: Vec<&str> = line.split_whitespace().collect();
+        match args.as_slice() {
+            ["end_header"] => break,
+            ["format", "ascii", _] => format = Some(PlyFormat::Ascii),
+            ["format", "binary_little_endian", _] => format = Some(PlyFormat::BinaryLittleEndian),
+            ["format", ..] => return Err(syntax_error("unsupported format")),
+            ["element", name, count] => elements.push(Element {
+                name: name.to_string(),
+                count: count.parse::<usize>()?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count, item, name] => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| syntax_error("property before element"))?;
+                let (count, item) = (Scalar::parse(count)?, Scalar::parse(item)?);
+                element
+                    .properties
+                    .push(Property::List(name.to_string(), count, item));
+            }
+            ["property", scalar, name] => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| syntax_error("property before element"))?;
+                let scalar = Scalar::parse(scalar)?;
+                element
+                    .properties
+                    .push(Property::Scalar(name.to_string(), scalar));
+            }
+            ["comment", ..] | ["obj_info", ..] | [] => {}
+            _ => return Err(syntax_error("unknown header line")),
+        }
+    }
+    let format = format.ok_or_else(|| syntax_error("the format is not declared"))?;
+    Ok((format, elements))
+}
+
+#[derive(Clone, Debug, Default)]
+struct PlyData {
+    positions: Vec<Point3>,
+    normals: Vec<Vector3>,
+    colors: Vec<[u8; 3]>,
+    faces: Faces,
+}
+
+fn read_body(elements: &[Element], reader: &mut impl ValueReader) -> Result<PlyData> {
+    let mut data = PlyData::default();
+    for element in elements {
+        let has_normals = element
+            .properties
+            .iter()
+            .any(|property| matches!(property, Property::Scalar(name, _) if name == "nx"));
+        let has_colors = element
+            .properties
+            .iter()
+            .any(|property| matches!(property, Property::Scalar(name, _) if name == "red"));
+        for _ in 0..element.count {
+            let mut values = HashMap::<&str, f64>::default();
+            let mut indices = None;
+            for property in &element.properties {
+                match property {
+                    Property::Scalar(name, scalar) => {
+                        values.insert(name, reader.read_value(*scalar)?);
+                    }
+                    Property::List(name, count, item) => {
+                        let count = reader.read_value(*count)? as usize;
+                        let list = (0..count)
+                            .map(|_| reader.read_value(*item))
+                            .collect::<Result<Vec<_>>>()?;
+                        if name == "vertex_indices" || name == "vertex_index" {
+                            indices = Some(list);
+                        }
+                    }
+                }
+            }
+            let value = |name: &str| values.get(name).copied().unwrap_or(0.0);
+            match element.name.as_str() {
+                "vertex" => {
+                    data.positions
+                        .push(Point3::new(value("x"), value("y"), value("z")));
+                    if has_normals {
+                        data.normals
+                            .push(Vector3::new(value("nx"), value("ny"), value("nz")));
+                    }
+                    if has_colors {
+                        data.colors.push([
+                            value("red") as u8,
+                            value("green") as u8,
+                            value("blue") as u8,
+                        ]);
+                    }
+                }
+                "face" => {
+                    let indices = indices.ok_or_else(|| syntax_error("no vertex indices"))?;
+                    let face: Vec<Vertex> = indices
+                        .into_iter()
+                        .map(|idx| Vertex {
+                            pos: idx as usize,
+                            uv: None,
+                            nor: (!data.normals.is_empty()).then_some(idx as usize),
+                        })
+                        .collect();
+                    data.faces.push(face);
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(data)
+}
+
+/// Reads mesh data and the colors of the vertices from PLY file.
+///
+/// The colors are empty if the vertices have no colors.
+/// The properties except the positions, the normals, the colors, and the vertex indices are skipped.
+pub fn read_with_colors<R: Read>(reader: R) -> Result<(PolygonMesh, Vec<[u8; 3]>)> {
+    let mut reader = BufReader::new(reader);
+    let (format, elements) = read_header(&mut reader)?;
+    let data = match format {
+        PlyFormat::Ascii => {
+            let mut body = String::new();
+            reader.read_to_string(&mut body)?;
+            read_body(&elements, &mut AsciiValueReader(body.split_whitespace()))?
+        }
+        PlyFormat::BinaryLittleEndian => read_body(&elements, &mut BinaryValueReader(reader))?,
+    };
+    let mesh = PolygonMesh::try_new(
+        StandardAttributes {
+            positions: data.positions,
+            normals: data.normals,
+            ..Default::default()
+        },
+        data.faces,
+    )?;
+    Ok((mesh, data.colors))
+}
+
+/// Reads mesh data from PLY file.
+///
+/// Both ASCII and binary little endian formats are supported.
+/// The vertex colors and the unknown properties are skipped.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let ply = b"ply
+/// format ascii 1.0
+/// element vertex 5
+/// property float x
+/// property float y
+/// property float z
+/// property float confidence
+/// element face 2
+/// property list uchar int vertex_indices
+/// end_header
+/// 0 0 0 1
+/// 1 0 0 1
+/// 1 1 0 1
+/// 0 1 0 1
+/// 2 2 0 1
+/// 4 0 1 2 3
+/// 3 1 4 2
+/// ";
+/// let mesh = ply::read(ply.as_slice()).unwrap();
+/// assert_eq!(mesh.positions().len(), 5);
+/// assert_eq!(mesh.faces().quad_faces().len(), 1);
+/// assert_eq!(mesh.faces().tri_faces().len(), 1);
+/// ```
+#[inline(always)]
+pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> { Ok(read_with_colors(reader)?.0) }
+
+/// Writes the values in the body of PLY.
+trait ValueWriter {
+    fn write_f64(&mut self, value: f64) -> Result<()>;
+    fn write_u8(&mut self, value: u8) -> Result<()>;
+    fn write_u32(&mut self, value: u32) -> Result<()>;
+    fn end_line(&mut self) -> Result<()>;
+}
+
+struct AsciiValueWriter<W> {
+    writer: W,
+    head: bool,
+}
+
+impl<W: Write> AsciiValueWriter<W> {
+    fn write_token(&mut self, args: std::fmt::Arguments<'_>) -> Result<()> {
+        if !self.head {
+            self.writer.write_all(b" ")?;
+        }
+        self.head = false;
+        Ok(self.writer.write_fmt(args)?)
+    }
+}
+
+impl<W: Write> ValueWriter for AsciiValueWriter<W> {
+    fn write_f64(&mut self, value: f64) -> Result<()> {
+        self.write_token(format_args!("{value:.10e}"))
+    }
+    fn write_u8(&mut self, value: u8) -> Result<()> { self.write_token(format_args!("{value}")) }
+    fn write_u32(&mut self, value: u32) -> Result<()> { self.write_token(format_args!("{value}")) }
+    fn end_line(&mut self) -> Result<()> {
+        self.head = true;
+        Ok(self.writer.write_all(b"\n")?)
+    }
+}
+
+struct BinaryValueWriter<W>(W);
+
+impl<W: Write> ValueWriter for BinaryValueWriter<W> {
+    fn write_f64(&mut self, value: f64) -> Result<()> {
+        Ok(self.0.write_all(&value.to_le_bytes())?)
+    }
+    fn write_u8(&mut self, value: u8) -> Result<()> { Ok(self.0.write_all(&[value])?) }
+    fn write_u32(&mut self, value: u32) -> Result<()> {
+        Ok(self.0.write_all(&value.to_le_bytes())?)
+    }
+    fn end_line(&mut self) -> Result<()> { Ok(()) }
+}
+
+fn write_body(
+    mesh: &PolygonMesh,
+    colors: Option<&[[u8; 3]]>,
+    vertices: &[Vertex],
+    faces: &[Vec<u32>],
+    writer: &mut impl ValueWriter,
+) -> Result<()> {
+    for vertex in vertices {
+        let p = mesh.positions()[vertex.pos];
+        [p.x, p.y, p.z]
+            .into_iter()
+            .try_for_each(|x| writer.write_f64(x))?;
+        if let Some(nor) = vertex.nor {
+            let n = mesh.normals()[nor];
+            [n.x, n.y, n.z]
+                .into_iter()
+                .try_for_each(|x| writer.write_f64(x))?;
+        }
+        if let Some(colors) = colors {
+            colors[vertex.pos]
+                .into_iter()
+                .try_for_each(|x| writer.write_u8(x))?;
+        }
+        writer.end_line()?;
+    }
+    for face in faces {
+        writer.write_u8(face.len() as u8)?;
+        face.iter().try_for_each(|idx| writer.write_u32(*idx))?;
+        writer.end_line()?;
+    }
+    Ok(())
+}
+
+fn sub_write<W: Write>(
+    mesh: &PolygonMesh,
+    colors: Option<&[[u8; 3]]>,
+    writer: W,
+    format: PlyFormat,
+) -> Result<()> {
+    if colors.is_some_and(|colors| colors.len() != mesh.positions().len()) {
+        return Err(errors::Error::DifferentLengthArrays);
+    }
+    // PLY has only one index for each vertex, so the pairs of the position and the normal are registered.
+    let with_normals = mesh.face_iter().flatten().all(|v| v.nor.is_some());
+    let mut vertex_map = HashMap::<Vertex, u32>::default();
+    let mut vertices = Vec::new();
+    let faces = mesh
+        .face_iter()
+        .map(|face| {
+            if face.len() > u8::MAX as usize {
+                return Err(syntax_error("a face has too many vertices"));
+            }
+            let indices = face.iter().map(|v| {
+                let v = Vertex {
+                    pos: v.pos,
+                    uv: None,
+                    nor: v.nor.filter(|_| with_normals),
+                };
+                *vertex_map.entry(v).or_insert_with(|| {
+                    vertices.push(v);
+                    vertices.len() as u32 - 1
+                })
+            });
+            Ok(indices.collect())
+        })
+        .collect::<Result<Vec<Vec<u32>>>>()?;
+
+    let mut writer = BufWriter::new(writer);
+    let format_name = match format {
+        PlyFormat::Ascii => "ascii",
+        PlyFormat::BinaryLittleEndian => "binary_little_endian",
+    };
+    writer.write_fmt(format_args!(
+        "ply\nformat {format_name} 1.0\ncomment truck\nelement vertex {}\n",
+        vertices.len()
+    ))?;
+    writer.write_all(b"property double x\nproperty double y\nproperty double z\n")?;
+    if with_normals {
+        writer.write_all(b"property double nx\nproperty double ny\nproperty double nz\n")?;
+    }
+    if colors.is_some() {
+        writer.write_all(b"property uchar red\nproperty uchar green\nproperty uchar blue\n")?;
+    }
+    writer.write_fmt(format_args!(
+        "element face {}\nproperty list uchar uint vertex_indices\nend_header\n",
+        faces.len()
+    ))?;
+    match format {
+        PlyFormat::Ascii => {
+            let mut writer = AsciiValueWriter { writer, head: true };
+            write_body(mesh, colors, &vertices, &faces, &mut writer)
+        }
+        PlyFormat::BinaryLittleEndian => {
+            let mut writer = BinaryValueWriter(writer);
+            write_body(mesh, colors, &vertices, &faces, &mut writer)
+        }
+    }
+}
+
+/// Writes mesh data to PLY file in `format`.
+///
+/// The vertices of PLY are the pairs of the positions and the normals in the mesh.
+/// The normals are written only if all vertices of the mesh have normals.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter([[0, 1, 2]]),
+/// );
+/// let mut bytes = Vec::new();
+/// ply::write(&mesh, &mut bytes, ply::PlyFormat::BinaryLittleEndian).unwrap();
+/// assert_eq!(ply::read(bytes.as_slice()).unwrap(), mesh);
+/// ```
+#[inline(always)]
+pub fn write<W: Write>(mesh: &PolygonMesh, writer: W, format: PlyFormat) -> Result<()> {
+    sub_write(mesh, None, writer, format)
+}
+
+/// Writes mesh data with the colors of the positions to PLY file in `format`.
+///
+/// # Failures
+/// Returns [`Error::DifferentLengthArrays`](errors::Error::DifferentLengthArrays)
+/// if the length of `colors` is not the same as the one of the positions.
+#[inline(always)]
+pub fn write_with_colors<W: Write>(
+    mesh: &PolygonMesh,
+    colors: &[[u8; 3]],
+    writer: W,
+    format: PlyFormat,
+) -> Result<()> {
+    sub_write(mesh, Some(colors), writer, format)
+}
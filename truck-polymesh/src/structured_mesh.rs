@@ -228,6 +228,121 @@ impl StructuredMesh {
             faces,
         }
     }
+
+    /// Recovers the structured mesh from the polygon mesh of a regular grid, e.g. the one
+    /// created by [`StructuredMesh::destruct`].
+    ///
+    /// The positions of `mesh` must be the `nu x nv` grid in the row-major order, and the faces
+    /// must be the quadrangles of the grid with the same orientation as `destruct`. The uv
+    /// divisions and the normals are recovered if all the vertices of the faces have them.
+    /// # Failures
+    /// Returns [`Error::IrregularArray`] if the faces are not the quadrangles of the grid,
+    /// one grid point has different attributes in different faces, or the uv coordinates are
+    /// not the product of the divisions.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::{errors::Error, *};
+    /// let positions = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 2.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.5), Point3::new(1.0, 2.0, 0.0)],
+    /// ];
+    /// let uv_division = (vec![0.0, 1.0], vec![0.0, 0.5, 1.0]);
+    /// let normals = vec![vec![Vector3::unit_z(); 3]; 2];
+    /// let mesh = StructuredMesh::new(positions, uv_division, normals);
+    ///
+    /// let polygon = mesh.clone().destruct();
+    /// assert_eq!(StructuredMesh::try_from_polygon(&polygon, (2, 3)).unwrap(), mesh);
+    ///
+    /// // the sizes do not fit the faces.
+    /// match StructuredMesh::try_from_polygon(&polygon, (3, 2)) {
+    ///     Err(Error::IrregularArray) => {}
+    ///     _ => panic!("wrong result!"),
+    /// }
+    /// ```
+    pub fn try_from_polygon(
+        mesh: &PolygonMesh,
+        (nu, nv): (usize, usize),
+    ) -> Result<StructuredMesh> {
+        let positions = mesh.positions();
+        if nu == 0 || nv == 0 || positions.len() != nu * nv {
+            return Err(Error::IrregularArray);
+        }
+        let faces = mesh.faces();
+        if !faces.tri_faces().is_empty()
+            || !faces.other_faces().is_empty()
+            || faces.quad_faces().len() != (nu - 1) * (nv - 1)
+        {
+            return Err(Error::IrregularArray);
+        }
+
+        let mut covered = vec![false; (nu - 1) * (nv - 1)];
+        let mut vertices: Vec<Option<StandardVertex>> = vec![None; nu * nv];
+        let attributes = |v: StandardVertex| {
+            (
+                v.uv.map(|i| mesh.uv_coords()[i]),
+                v.nor.map(|i| mesh.normals()[i]),
+            )
+        };
+        for face in faces.quad_faces() {
+            // rotate the face so that the first vertex is at the minimum grid point.
+            let k = (0..4).min_by_key(|k| face[*k].pos).unwrap();
+            let face = [
+                face[k],
+                face[(k + 1) % 4],
+                face[(k + 2) % 4],
+                face[(k + 3) % 4],
+            ];
+            let a = face[0].pos;
+            let (i, j) = (a / nv, a % nv);
+            let grid = [a, a + nv, a + nv + 1, a + 1];
+            if i + 1 >= nu || j + 1 >= nv || face.iter().map(|v| v.pos).ne(grid) {
+                return Err(Error::IrregularArray);
+            }
+            if std::mem::replace(&mut covered[i * (nv - 1) + j], true) {
+                return Err(Error::IrregularArray);
+            }
+            for v in face {
+                match vertices[v.pos] {
+                    None => vertices[v.pos] = Some(v),
+                    Some(w) if attributes(w) == attributes(v) => {}
+                    Some(_) => return Err(Error::IrregularArray),
+                }
+            }
+        }
+
+        let uv_coords: Option<Vec<Vector2>> = vertices
+            .iter()
+            .map(|v| v.and_then(|v| attributes(v).0))
+            .collect();
+        let uv_division = match uv_coords {
+            Some(uv_coords) => {
+                let u_div: Vec<f64> = (0..nu).map(|i| uv_coords[i * nv][0]).collect();
+                let v_div: Vec<f64> = (0..nv).map(|j| uv_coords[j][1]).collect();
+                let separated = itertools::iproduct!(0..nu, 0..nv)
+                    .all(|(i, j)| uv_coords[i * nv + j].near(&Vector2::new(u_div[i], v_div[j])));
+                if !separated {
+                    return Err(Error::IrregularArray);
+                }
+                Some((u_div, v_div))
+            }
+            None => None,
+        };
+        let normals: Option<Vec<Vector3>> = vertices
+            .iter()
+            .map(|v| v.and_then(|v| attributes(v).1))
+            .collect();
+        let positions = to_matrix(positions, nv);
+        match (uv_division, normals) {
+            (Some(uv_division), Some(normals)) => {
+                Self::try_new(positions, uv_division, to_matrix(&normals, nv))
+            }
+            (Some(uv_division), None) => Self::try_from_positions_and_uvs(positions, uv_division),
+            (None, Some(normals)) => {
+                Self::try_from_positions_and_normals(positions, to_matrix(&normals, nv))
+            }
+            (None, None) => Self::try_from_positions(positions),
+        }
+    }
 }
 
 impl<'de> Deserialize<'de> for StructuredMesh {
@@ -308,3 +423,8 @@ fn check_matrix_vectors_compatibility<T>(
     }
     Ok(())
 }
+
+#[inline(always)]
+fn to_matrix<T: Clone>(vec: &[T], row_len: usize) -> Vec<Vec<T>> {
+    vec.chunks(row_len).map(<[T]>::to_vec).collect()
+}
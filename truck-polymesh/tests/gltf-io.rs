@@ -0,0 +1,135 @@
+use truck_polymesh::*;
+
+fn cube() -> PolygonMesh {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    ];
+    let uv_coords = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(0.0, 1.0),
+    ];
+    let normals = vec![
+        Vector3::new(0.0, 0.0, -1.0),
+        Vector3::new(0.0, -1.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(-1.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+    let faces: Faces = Faces::from_iter(&[
+        [0, 3, 2, 1],
+        [0, 1, 5, 4],
+        [1, 2, 6, 5],
+        [2, 3, 7, 6],
+        [3, 0, 4, 7],
+        [4, 5, 6, 7],
+    ]);
+    let faces = faces
+        .face_iter()
+        .enumerate()
+        .map(|(i, face)| {
+            face.iter()
+                .enumerate()
+                .map(|(j, v)| (v.pos, Some(j), Some(i)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Faces>();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals,
+        },
+        faces,
+    )
+}
+
+#[test]
+fn export_cube() {
+    let mesh = cube();
+    let material = truck_polymesh::gltf::Material {
+        base_color_factor: [0.8, 0.2, 0.2, 1.0],
+        metallic_factor: 0.0,
+        roughness_factor: 0.5,
+    };
+    let mut glb = Vec::new();
+    truck_polymesh::gltf::export_with_material(&mesh, &material, &mut glb).unwrap();
+
+    // `from_slice` validates the document.
+    let gltf = ::gltf::Gltf::from_slice(&glb).unwrap();
+    let blob = gltf.blob.as_deref().unwrap();
+    let primitives: Vec<_> = gltf.meshes().flat_map(|mesh| mesh.primitives()).collect();
+    assert_eq!(primitives.len(), 1);
+    let primitive = &primitives[0];
+    assert_eq!(primitive.mode(), ::gltf::mesh::Mode::Triangles);
+    let pbr = primitive.material().pbr_metallic_roughness();
+    assert_eq!(pbr.base_color_factor(), [0.8, 0.2, 0.2, 1.0]);
+    assert_eq!(pbr.metallic_factor(), 0.0);
+    assert_eq!(pbr.roughness_factor(), 0.5);
+    let bounds = primitive.bounding_box();
+    assert_eq!((bounds.min, bounds.max), ([0.0; 3], [1.0; 3]));
+
+    let reader = primitive.reader(|_| Some(blob));
+    let positions: Vec<[f32; 3]> = reader.read_positions().unwrap().collect();
+    let normals: Vec<[f32; 3]> = reader.read_normals().unwrap().collect();
+    let uv_coords: Vec<[f32; 2]> = reader.read_tex_coords(0).unwrap().into_f32().collect();
+    let indices: Vec<u32> = reader.read_indices().unwrap().into_u32().collect();
+    // each corner of the cube has three pairs of the normal and the uv coordinate.
+    assert_eq!(positions.len(), 24);
+    assert_eq!(normals.len(), 24);
+    assert_eq!(uv_coords.len(), 24);
+    assert_eq!(indices.len(), 36);
+
+    mesh.faces()
+        .triangle_iter()
+        .flatten()
+        .zip(indices)
+        .for_each(|(v, idx)| {
+            let idx = idx as usize;
+            let p = mesh.positions()[v.pos];
+            let n = mesh.normals()[v.nor.unwrap()];
+            let uv = mesh.uv_coords()[v.uv.unwrap()];
+            assert_near!(p, Point3::from(positions[idx].map(f64::from)));
+            assert_near!(n, Vector3::from(normals[idx].map(f64::from)));
+            let [u, w] = uv_coords[idx].map(f64::from);
+            assert_near!(uv, Vector2::new(u, 1.0 - w));
+        });
+}
+
+#[test]
+fn export_without_attributes() {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(-1.0, 0.5, 0.0),
+    ];
+    let faces = Faces::from_iter(&[[0, 1, 2, 3, 4]]);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    let mut glb = Vec::new();
+    truck_polymesh::gltf::export(&mesh, &mut glb).unwrap();
+    let gltf = ::gltf::Gltf::from_slice(&glb).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    assert!(primitive.get(&::gltf::Semantic::Positions).is_some());
+    assert!(primitive.get(&::gltf::Semantic::Normals).is_none());
+    assert!(primitive.get(&::gltf::Semantic::TexCoords(0)).is_none());
+    let reader = primitive.reader(|_| gltf.blob.as_deref());
+    assert_eq!(reader.read_positions().unwrap().len(), 5);
+    assert_eq!(reader.read_indices().unwrap().into_u32().len(), 9);
+}
@@ -0,0 +1,110 @@
+use obj::ObjMaterial;
+use truck_polymesh::*;
+
+fn square(x: f64) -> PolygonMesh {
+    let positions = vec![
+        Point3::new(x, 0.0, 0.0),
+        Point3::new(x + 1.0, 0.0, 0.0),
+        Point3::new(x + 1.0, 1.0, 0.0),
+        Point3::new(x, 1.0, 0.0),
+    ];
+    let uv_coords = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(0.0, 1.0),
+    ];
+    let normals = vec![Vector3::unit_z()];
+    let faces = Faces::from_iter(&[
+        [
+            (0, Some(0), Some(0)),
+            (1, Some(1), Some(0)),
+            (2, Some(2), Some(0)),
+        ],
+        [
+            (0, Some(0), Some(0)),
+            (2, Some(2), Some(0)),
+            (3, Some(3), Some(0)),
+        ],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals,
+        },
+        faces,
+    )
+}
+
+#[test]
+fn materials_io() {
+    let dir = std::env::temp_dir().join("truck-polymesh-obj-materials");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("squares.obj");
+
+    let meshes = vec![square(0.0), square(2.0)];
+    let materials = vec![
+        ObjMaterial {
+            name: "red".to_string(),
+            ambient: [0.1, 0.0, 0.0],
+            diffuse: [1.0, 0.0, 0.0],
+            specular: [0.5; 3],
+            shininess: 20.0,
+            ..Default::default()
+        },
+        ObjMaterial {
+            name: "textured".to_string(),
+            alpha: 0.5,
+            diffuse_map: Some("texture.png".to_string()),
+            ..Default::default()
+        },
+    ];
+    obj::write_with_materials(&meshes, &materials, &path).unwrap();
+    let (read_meshes, read_materials) = obj::read_with_materials(&path).unwrap();
+    assert_eq!(read_meshes, meshes);
+    assert_eq!(read_materials, materials);
+
+    // the geometry without materials
+    let mesh = obj::read(std::fs::File::open(&path).unwrap()).unwrap();
+    assert_eq!(mesh.positions().len(), 8);
+    assert_eq!(mesh.tri_faces().len(), 4);
+
+    // falls back to the default materials if the library is missing.
+    std::fs::remove_file(path.with_extension("mtl")).unwrap();
+    let (read_meshes, read_materials) = obj::read_with_materials(&path).unwrap();
+    assert_eq!(read_meshes, meshes);
+    let names: Vec<&str> = read_materials.iter().map(|mat| mat.name.as_str()).collect();
+    assert_eq!(names, ["red", "textured"]);
+    assert!(read_materials.iter().all(|mat| mat.diffuse == [0.8; 3]));
+
+    // mismatched lengths
+    assert!(obj::write_with_materials(&meshes, &materials[..1], &path).is_err());
+}
+
+#[test]
+fn faces_without_usemtl() {
+    let dir = std::env::temp_dir().join("truck-polymesh-obj-materials");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("grouped.obj");
+    let obj = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0
+f 1 2 3
+usemtl blue
+f 1 3 4
+usemtl default
+f 3 2 1
+usemtl blue
+f 4 3 1
+";
+    std::fs::write(&path, obj).unwrap();
+    let (meshes, materials) = obj::read_with_materials(&path).unwrap();
+    assert_eq!(materials.len(), 3);
+    assert_eq!(materials[0], ObjMaterial::default());
+    assert_eq!(materials[1].name, "blue");
+    assert_eq!(materials[2].name, "default");
+    let face_counts: Vec<usize> = meshes.iter().map(|mesh| mesh.tri_faces().len()).collect();
+    assert_eq!(face_counts, [1, 2, 1]);
+    // the attributes not referred by the faces are removed.
+    assert_eq!(meshes[0].positions().len(), 3);
+    assert_eq!(meshes[1].positions().len(), 3);
+}
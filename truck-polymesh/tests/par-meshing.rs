@@ -0,0 +1,109 @@
+#![cfg(feature = "rayon")]
+
+use truck_geometry::prelude::*;
+use truck_polymesh::StructuredMesh;
+
+/// the unit sphere by a NURBS surface
+fn nurbs_sphere() -> NurbsSurface<Vector4> {
+    let knot_vecs = (
+        KnotVec::bezier_knot(3),
+        KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0]),
+    );
+    let row = |z: f64, r: f64, w: f64| {
+        vec![
+            Vector4::new(r, 0.0, z, 1.0) * w,
+            Vector4::new(r, 2.0 * r, z, 1.0) * w / 3.0,
+            Vector4::new(-r, 2.0 * r, z, 1.0) * w / 3.0,
+            Vector4::new(-r, 0.0, z, 1.0) * w,
+            Vector4::new(-r, -2.0 * r, z, 1.0) * w / 3.0,
+            Vector4::new(r, -2.0 * r, z, 1.0) * w / 3.0,
+            Vector4::new(r, 0.0, z, 1.0) * w,
+        ]
+    };
+    let control_points = vec![
+        row(1.0, 0.0, 1.0),
+        row(1.0, 2.0, 1.0 / 3.0),
+        row(-1.0, 2.0, 1.0 / 3.0),
+        row(-1.0, 0.0, 1.0),
+    ];
+    NurbsSurface::new(BSplineSurface::new(knot_vecs, control_points))
+}
+
+const RANGE: ((f64, f64), (f64, f64)) = ((0.0, 1.0), (0.0, 1.0));
+
+#[test]
+fn par_from_surface() {
+    let sphere = nurbs_sphere();
+    let mesh0 = StructuredMesh::from_surface(&sphere, RANGE, 0.001);
+    let mesh1 = StructuredMesh::par_from_surface(&sphere, RANGE, 0.001);
+    // bit-for-bit comparison, including the NaN normals at the poles
+    let bits = |mesh: &StructuredMesh| -> Vec<u64> {
+        let (udiv, vdiv) = mesh.uv_division().unwrap();
+        let positions = mesh
+            .positions()
+            .iter()
+            .flatten()
+            .flat_map(|p| [p.x, p.y, p.z]);
+        let normals = mesh.normals().unwrap().iter().flatten();
+        let normals = normals.flat_map(|n| [n.x, n.y, n.z]);
+        udiv.iter()
+            .chain(vdiv)
+            .copied()
+            .chain(positions)
+            .chain(normals)
+            .map(f64::to_bits)
+            .collect()
+    };
+    assert_eq!(bits(&mesh0), bits(&mesh1));
+}
+
+// The parameter division is sequential, so the speedup is limited to the sampling.
+#[test]
+#[ignore]
+fn par_bench() {
+    use std::time::Instant;
+    const TOL: f64 = 1.0e-4;
+    const N: u32 = 3;
+    let sphere = nurbs_sphere();
+
+    let instant = Instant::now();
+    (0..N).for_each(|_| {
+        let (udiv, vdiv) = sphere.parameter_division(RANGE, TOL);
+        assert!(udiv.len() * vdiv.len() > 0);
+    });
+    let division = instant.elapsed().as_millis() / N as u128;
+    let (udiv, vdiv) = sphere.parameter_division(RANGE, TOL);
+    println!(
+        "{} x {} points, division: {division}ms",
+        udiv.len(),
+        vdiv.len()
+    );
+
+    let instant = Instant::now();
+    (0..N).for_each(|_| {
+        let _mesh = StructuredMesh::from_surface(&sphere, RANGE, TOL);
+    });
+    let total = instant.elapsed().as_millis() / N as u128;
+    println!(
+        "single thread: sampling {}ms",
+        total.saturating_sub(division)
+    );
+
+    for threads in [1, 2, 4, 8] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        let instant = Instant::now();
+        pool.install(|| {
+            (0..N).for_each(|_| {
+                let _mesh = StructuredMesh::par_from_surface(&sphere, RANGE, TOL);
+            })
+        });
+        let total = instant.elapsed().as_millis() / N as u128;
+        println!(
+            "{threads} threads: sampling {}ms",
+            total.saturating_sub(division)
+        );
+    }
+}
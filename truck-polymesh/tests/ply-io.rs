@@ -0,0 +1,114 @@
+use ply::PlyFormat;
+use truck_polymesh::*;
+
+const MIXED_FACES: &[u8] = b"ply
+format ascii 1.0
+comment a square pyramid with the quadrangle bottom
+element vertex 5
+property float x
+property float y
+property float z
+property float nx
+property float ny
+property float nz
+property uchar red
+property uchar green
+property uchar blue
+property float confidence
+element face 5
+property list uchar int vertex_indices
+property uchar flags
+element edge 1
+property int vertex1
+property int vertex2
+end_header
+0 0 0 0 0 -1 255 0 0 0.5
+1 0 0 0 0 -1 0 255 0 0.5
+1 1 0 0 0 -1 0 0 255 0.5
+0 1 0 0 0 -1 255 255 0 0.5
+0.5 0.5 1 0 0 1 255 255 255 0.5
+4 0 3 2 1 0
+3 0 1 4 0
+3 1 2 4 0
+3 2 3 4 0
+3 3 0 4 0
+0 4
+";
+
+#[test]
+fn read_mixed_faces() {
+    let (mesh, colors) = ply::read_with_colors(MIXED_FACES).unwrap();
+    assert_eq!(mesh.positions().len(), 5);
+    assert_eq!(mesh.normals().len(), 5);
+    assert_eq!(mesh.faces().quad_faces().len(), 1);
+    assert_eq!(mesh.faces().tri_faces().len(), 4);
+    assert_eq!(mesh.positions()[4], Point3::new(0.5, 0.5, 1.0));
+    assert_eq!(mesh.normals()[4], Vector3::new(0.0, 0.0, 1.0));
+    assert_eq!(colors[1], [0, 255, 0]);
+    let quad = mesh.faces().quad_faces()[0];
+    assert_eq!(quad.map(|v| v.pos), [0, 3, 2, 1]);
+    assert_eq!(quad.map(|v| v.nor), [Some(0), Some(3), Some(2), Some(1)]);
+}
+
+#[test]
+fn ply_round_trip() {
+    let (mesh, colors) = ply::read_with_colors(MIXED_FACES).unwrap();
+    [PlyFormat::Ascii, PlyFormat::BinaryLittleEndian]
+        .into_iter()
+        .for_each(|format| {
+            let mut bytes = Vec::new();
+            ply::write_with_colors(&mesh, &colors, &mut bytes, format).unwrap();
+            let (read_mesh, read_colors) = ply::read_with_colors(bytes.as_slice()).unwrap();
+            assert_eq!(read_mesh.faces().quad_faces().len(), 1);
+            assert_eq!(read_mesh.faces().tri_faces().len(), 4);
+            // the vertices are renumbered in the order of the faces.
+            read_mesh
+                .face_iter()
+                .flatten()
+                .zip(mesh.face_iter().flatten())
+                .for_each(|(v, w)| {
+                    let (p, q) = (read_mesh.positions()[v.pos], mesh.positions()[w.pos]);
+                    assert!(p.near(&q));
+                    let (n, m) = (
+                        read_mesh.normals()[v.nor.unwrap()],
+                        mesh.normals()[w.nor.unwrap()],
+                    );
+                    assert!(n.near(&m));
+                    assert_eq!(read_colors[v.pos], colors[w.pos]);
+                });
+        });
+
+    // the vertices are split by the normals
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            normals: vec![Vector3::unit_z(), -Vector3::unit_z()],
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))],
+            [(0, None, Some(1)), (2, None, Some(1)), (1, None, Some(1))],
+        ]),
+    );
+    let mut bytes = Vec::new();
+    ply::write(&mesh, &mut bytes, PlyFormat::BinaryLittleEndian).unwrap();
+    let read_mesh = ply::read(bytes.as_slice()).unwrap();
+    assert_eq!(read_mesh.positions().len(), 6);
+    assert_eq!(read_mesh.faces().len(), 2);
+    read_mesh
+        .face_iter()
+        .zip(mesh.face_iter())
+        .for_each(|(f, g)| {
+            f.iter().zip(g).for_each(|(v, w)| {
+                assert_eq!(read_mesh.positions()[v.pos], mesh.positions()[w.pos]);
+                assert_eq!(
+                    read_mesh.normals()[v.nor.unwrap()],
+                    mesh.normals()[w.nor.unwrap()]
+                );
+            });
+        });
+}
@@ -85,3 +85,52 @@ fn through_polymesh() {
         assert!(f32::abs(face0.normal[2] - face1.normal[2]) < 5.0e-4);
     }
 }
+
+#[test]
+fn polymesh_round_trip() {
+    // a unit cube with quadrangle faces, which are triangulated in writing.
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    ];
+    let faces = Faces::from_iter([
+        [0, 3, 2, 1],
+        [0, 1, 5, 4],
+        [1, 2, 6, 5],
+        [2, 3, 7, 6],
+        [3, 0, 4, 7],
+        [4, 5, 6, 7],
+    ]);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    [StlType::Binary, StlType::Ascii]
+        .into_iter()
+        .for_each(|stl_type| {
+            let mut bytes = Vec::new();
+            stl::write(&mesh, &mut bytes, stl_type).unwrap();
+            let read_mesh = stl::read(bytes.as_slice(), StlType::Automatic).unwrap();
+            assert_eq!(read_mesh.faces().len(), 12);
+            assert_eq!(read_mesh.positions().len(), 8);
+            read_mesh.positions().iter().for_each(|p| {
+                assert!(mesh.positions().iter().any(|q| p.near(q)));
+            });
+            // the normals are outward
+            read_mesh.faces().triangle_iter().for_each(|tri| {
+                let n = read_mesh.normals()[tri[0].nor.unwrap()];
+                let p = read_mesh.positions()[tri[0].pos];
+                let center = Point3::new(0.5, 0.5, 0.5);
+                assert!(n.dot(p - center) > 0.0);
+            });
+        });
+}
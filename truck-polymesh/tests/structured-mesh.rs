@@ -0,0 +1,102 @@
+use truck_polymesh::{errors::Error, *};
+
+fn grid_mesh(nu: usize, nv: usize) -> StructuredMesh {
+    let udiv: Vec<f64> = (0..nu).map(|i| i as f64 / (nu - 1) as f64).collect();
+    let vdiv: Vec<f64> = (0..nv).map(|j| j as f64 / (nv - 1) as f64).collect();
+    let positions = udiv
+        .iter()
+        .map(|u| {
+            let f = |v: &f64| Point3::new(*u, *v, f64::sin(u * 3.0) * f64::cos(v * 2.0));
+            vdiv.iter().map(f).collect()
+        })
+        .collect();
+    let normals = udiv
+        .iter()
+        .map(|u| {
+            let f = |v: &f64| Vector3::new(u - 0.5, v - 0.5, 1.0).normalize();
+            vdiv.iter().map(f).collect()
+        })
+        .collect();
+    StructuredMesh::new(positions, (udiv, vdiv), normals)
+}
+
+#[test]
+fn structured_polygon_round_trip() {
+    let mesh = grid_mesh(5, 7);
+    let polygon = mesh.clone().destruct();
+    let recovered = StructuredMesh::try_from_polygon(&polygon, (5, 7)).unwrap();
+    assert_eq!(mesh, recovered);
+
+    // through obj
+    let mut obj = Vec::new();
+    obj::write(&polygon, &mut obj).unwrap();
+    let polygon = obj::read(obj.as_slice()).unwrap();
+    let recovered = StructuredMesh::try_from_polygon(&polygon, (5, 7)).unwrap();
+    let flatten = |mesh: &StructuredMesh| {
+        let positions = mesh.positions().concat();
+        let normals = mesh.normals().unwrap().concat();
+        (positions, normals)
+    };
+    let (positions0, normals0) = flatten(&mesh);
+    let (positions1, normals1) = flatten(&recovered);
+    let near = |(p0, p1): (&Point3, &Point3)| p0.near(p1);
+    assert!(positions0.iter().zip(&positions1).all(near));
+    let near = |(n0, n1): (&Vector3, &Vector3)| n0.near(n1);
+    assert!(normals0.iter().zip(&normals1).all(near));
+    let (udiv, vdiv) = recovered.uv_division().unwrap();
+    assert_eq!((udiv.len(), vdiv.len()), (5, 7));
+
+    // the order of faces and the first vertices of faces do not matter.
+    let mut polygon = mesh.clone().destruct();
+    {
+        let editor = polygon.editor();
+        editor.faces.quad_faces_mut().reverse();
+        editor
+            .faces
+            .quad_faces_mut()
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, face)| face.rotate_left(i % 4));
+    }
+    let recovered = StructuredMesh::try_from_polygon(&polygon, (5, 7)).unwrap();
+    assert_eq!(mesh, recovered);
+
+    // without attributes
+    let positions_only = StructuredMesh::from_positions(mesh.positions().clone());
+    let polygon = positions_only.clone().destruct();
+    let recovered = StructuredMesh::try_from_polygon(&polygon, (5, 7)).unwrap();
+    assert_eq!(positions_only, recovered);
+}
+
+#[test]
+fn irregular_polygon() {
+    let mesh = grid_mesh(4, 3);
+    let is_irregular = |polygon: &PolygonMesh| {
+        matches!(
+            StructuredMesh::try_from_polygon(polygon, (4, 3)),
+            Err(Error::IrregularArray)
+        )
+    };
+
+    // inverted face
+    let mut polygon = mesh.clone().destruct();
+    polygon.editor().faces.quad_faces_mut()[2].reverse();
+    assert!(is_irregular(&polygon));
+
+    // one cell is covered twice
+    let mut polygon = mesh.clone().destruct();
+    let face = polygon.quad_faces()[0];
+    polygon.editor().faces.quad_faces_mut()[1] = face;
+    assert!(is_irregular(&polygon));
+
+    // triangulated
+    let polygon = mesh.clone().destruct();
+    let triangles: Faces = polygon.faces().triangle_iter().collect();
+    let polygon = PolygonMesh::new(polygon.attributes().clone(), triangles);
+    assert!(is_irregular(&polygon));
+
+    // the same position with different normals
+    let mut polygon = mesh.destruct();
+    polygon.editor().faces.quad_faces_mut()[0][2].nor = Some(0);
+    assert!(is_irregular(&polygon));
+}
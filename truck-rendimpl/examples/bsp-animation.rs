@@ -110,6 +110,7 @@ impl App for MyApp {
                 sample_count: 4,
                 ..Default::default()
             },
+            ..Default::default()
         };
         let mut scene = WindowScene::from_window(window, &desc).await;
         let creator = scene.instance_creator();
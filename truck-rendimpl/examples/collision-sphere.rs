@@ -56,6 +56,7 @@ impl App for MyApp {
                 sample_count,
                 ..Default::default()
             },
+            ..Default::default()
         };
         let mut scene = WindowScene::from_window(window, &scene_desc).await;
         let sphere0 = sphere(Point3::new(0.0, 0.0, 0.7), 1.0, 50, 50);
@@ -67,7 +68,7 @@ impl App for MyApp {
             &PolygonState {
                 material: Material {
                     albedo: Vector4::new(1.0, 1.0, 1.0, 0.8),
-                    alpha_blend: true,
+                    alpha_mode: AlphaMode::Blend,
                     ..Default::default()
                 },
                 ..Default::default()
@@ -78,7 +79,7 @@ impl App for MyApp {
             &PolygonState {
                 material: Material {
                     albedo: Vector4::new(1.0, 1.0, 1.0, 0.8),
-                    alpha_blend: true,
+                    alpha_mode: AlphaMode::Blend,
                     ..Default::default()
                 },
                 ..Default::default()
@@ -69,11 +69,13 @@ impl App for MyApp {
                     b: BACKGROUND[2],
                     a: BACKGROUND[3],
                 },
+                ..Default::default()
             },
             backend_buffer: BackendBufferConfig {
                 sample_count,
                 ..Default::default()
             },
+            ..Default::default()
         };
         let mut scene = WindowScene::from_window(window, &scene_desc).await;
         let v = builder::vertex(Point3::new(-0.5, -0.5, -0.5));
@@ -104,7 +106,8 @@ impl App for MyApp {
                         roughness: t,
                         ambient_ratio: 0.02,
                         background_ratio: 0.0,
-                        alpha_blend: false,
+                        alpha_mode: AlphaMode::Opaque,
+                        emissive: Vector3::zero(),
                     },
                     ..Default::default()
                 };
@@ -101,7 +101,8 @@ impl MyRender {
                 reflectance: 0.04 + 0.96 * (0.5 + (time / 2.0).sin() / 2.0),
                 ambient_ratio: 0.02,
                 background_ratio: 0.0,
-                alpha_blend: false,
+                alpha_mode: AlphaMode::Opaque,
+                emissive: Vector3::zero(),
             };
             self.scene.update_bind_group(&*instance);
         }
@@ -135,6 +136,7 @@ impl App for MyRender {
                 sample_count,
                 ..Default::default()
             },
+            ..Default::default()
         };
         let mut app = MyRender {
             scene: WindowScene::from_window(window, &scene_desc).await,
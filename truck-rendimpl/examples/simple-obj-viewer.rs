@@ -63,7 +63,8 @@ impl MyApp {
                     roughness: 0.1,
                     ambient_ratio: 0.02,
                     background_ratio: 0.0,
-                    alpha_blend: false,
+                    alpha_mode: AlphaMode::Opaque,
+                    emissive: Vector3::zero(),
                 };
                 self.scene.update_bind_group(&self.instance);
                 self.scene.set_visibility(&self.instance, true);
@@ -82,7 +83,8 @@ impl MyApp {
                     roughness: 0.0,
                     ambient_ratio: 1.0,
                     background_ratio: 0.0,
-                    alpha_blend: false,
+                    alpha_mode: AlphaMode::Opaque,
+                    emissive: Vector3::zero(),
                 };
                 self.wireframe.instance_state_mut().color = Vector4::new(1.0, 1.0, 1.0, 1.0);
                 self.scene.update_bind_group(&self.instance);
@@ -97,7 +99,8 @@ impl MyApp {
                     roughness: 0.1,
                     ambient_ratio: 0.02,
                     background_ratio: 0.0,
-                    alpha_blend: false,
+                    alpha_mode: AlphaMode::Opaque,
+                    emissive: Vector3::zero(),
                 };
                 self.wireframe.instance_state_mut().color = Vector4::new(0.0, 0.0, 0.0, 1.0);
                 self.scene.update_bind_group(&self.instance);
@@ -146,11 +149,13 @@ impl App for MyApp {
                     color: Vector3::new(1.0, 1.0, 1.0),
                     light_type: LightType::Point,
                 }],
+                ..Default::default()
             },
             backend_buffer: BackendBufferConfig {
                 sample_count,
                 ..Default::default()
             },
+            ..Default::default()
         };
         let mut scene = WindowScene::from_window(window, &scene_desc).await;
         let creator = scene.instance_creator();
@@ -190,10 +195,8 @@ impl App for MyApp {
                 self.rotate_flag = state == ElementState::Pressed;
             }
             MouseButton::Right => {
-                let (light, camera) = {
-                    let desc = self.scene.studio_config_mut();
-                    (&mut desc.lights[0], &desc.camera)
-                };
+                let camera = self.scene.studio_config().camera;
+                let light = &mut self.scene.lights_mut()[0];
                 match light.light_type {
                     LightType::Point => {
                         light.position = camera.position();
@@ -264,10 +267,8 @@ impl App for MyApp {
                 };
             }
             KeyCode::KeyL => {
-                let (light, camera) = {
-                    let desc = self.scene.studio_config_mut();
-                    (&mut desc.lights[0], &desc.camera)
-                };
+                let camera = self.scene.studio_config().camera;
+                let light = &mut self.scene.lights_mut()[0];
                 *light = match light.light_type {
                     LightType::Point => {
                         let mut vec = camera.position();
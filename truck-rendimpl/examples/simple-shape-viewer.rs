@@ -64,7 +64,8 @@ impl MyApp {
                     roughness: 0.1,
                     ambient_ratio: 0.02,
                     background_ratio: 0.0,
-                    alpha_blend: false,
+                    alpha_mode: AlphaMode::Opaque,
+                    emissive: Vector3::zero(),
                 };
                 self.scene.update_bind_group(&self.instance);
                 self.scene.set_visibility(&self.instance, true);
@@ -83,7 +84,8 @@ impl MyApp {
                     roughness: 0.0,
                     ambient_ratio: 1.0,
                     background_ratio: 0.0,
-                    alpha_blend: false,
+                    alpha_mode: AlphaMode::Opaque,
+                    emissive: Vector3::zero(),
                 };
                 self.wireframe.instance_state_mut().color = Vector4::new(1.0, 1.0, 1.0, 1.0);
                 self.scene.update_bind_group(&self.instance);
@@ -98,7 +100,8 @@ impl MyApp {
                     roughness: 0.1,
                     ambient_ratio: 0.02,
                     background_ratio: 0.0,
-                    alpha_blend: false,
+                    alpha_mode: AlphaMode::Opaque,
+                    emissive: Vector3::zero(),
                 };
                 self.wireframe.instance_state_mut().color = Vector4::new(0.0, 0.0, 0.0, 1.0);
                 self.scene.update_bind_group(&self.instance);
@@ -168,11 +171,13 @@ impl App for MyApp {
                     color: Vector3::new(1.0, 1.0, 1.0),
                     light_type: LightType::Point,
                 }],
+                ..Default::default()
             },
             backend_buffer: BackendBufferConfig {
                 sample_count,
                 ..Default::default()
             },
+            ..Default::default()
         };
         let mut scene = WindowScene::from_window(window, &scene_desc).await;
         let creator = scene.instance_creator();
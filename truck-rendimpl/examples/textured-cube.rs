@@ -66,6 +66,7 @@ impl App for MyApp {
                 sample_count,
                 ..Default::default()
             },
+            ..Default::default()
         };
         let mut scene = WindowScene::from_window(window, &desc).await;
         let texture = image::load_from_memory(TEXTURE_BYTES).unwrap();
@@ -78,9 +79,11 @@ impl App for MyApp {
                 reflectance: 0.04,
                 ambient_ratio: 0.05,
                 background_ratio: 0.0,
-                alpha_blend: false,
+                alpha_mode: AlphaMode::Opaque,
+                emissive: Vector3::zero(),
             },
             texture: Some(std::sync::Arc::new(texture)),
+            normal_texture: None,
             backface_culling: true,
         };
         let mesh = Self::create_cube().triangulation(0.05).to_polygon();
@@ -5,13 +5,136 @@ use image::*;
 #[inline(always)]
 pub fn image2texture(device_handler: &DeviceHandler, image: &DynamicImage) -> Texture {
     let buffer = image.to_rgba8();
-    imagebuffer2texture(device_handler, &buffer, TextureFormat::Rgba8Unorm)
+    imagebuffer2texture(device_handler, &buffer, TextureFormat::Rgba8Unorm, 1)
+}
+
+/// Returns the number of the levels of the full mip chain of the texture with `width` and `height`.
+/// # Examples
+/// ```
+/// use truck_rendimpl::image2texture::max_mip_level_count;
+/// assert_eq!(max_mip_level_count(1, 1), 1);
+/// assert_eq!(max_mip_level_count(3, 3), 2);
+/// assert_eq!(max_mip_level_count(256, 100), 9);
+/// ```
+#[inline(always)]
+pub fn max_mip_level_count(width: u32, height: u32) -> u32 {
+    u32::max(width, height).max(1).ilog2() + 1
+}
+
+/// Utility for creating `Texture` with the full mip chain from `DynamicImage`
+///
+/// The levels except the first one are generated by the box filter on GPU.
+#[inline(always)]
+pub fn image2texture_with_mips(device_handler: &DeviceHandler, image: &DynamicImage) -> Texture {
+    let count = max_mip_level_count(image.width(), image.height());
+    image2texture_with_mip_level_count(device_handler, image, count)
+}
+
+/// Utility for creating `Texture` with `mip_level_count` levels from `DynamicImage`
+///
+/// `mip_level_count` is clamped to `1..=max_mip_level_count(width, height)`.
+pub fn image2texture_with_mip_level_count(
+    device_handler: &DeviceHandler,
+    image: &DynamicImage,
+    mip_level_count: u32,
+) -> Texture {
+    let buffer = image.to_rgba8();
+    let count = mip_level_count.clamp(1, max_mip_level_count(buffer.width(), buffer.height()));
+    let texture = imagebuffer2texture(device_handler, &buffer, TextureFormat::Rgba8Unorm, count);
+    generate_mipmaps(device_handler, &texture);
+    texture
+}
+
+fn generate_mipmaps(device_handler: &DeviceHandler, texture: &Texture) {
+    if texture.mip_level_count() < 2 {
+        return;
+    }
+    let (device, queue) = (device_handler.device(), device_handler.queue());
+    let module = device.create_shader_module(ShaderModuleDescriptor {
+        source: ShaderSource::Wgsl(include_str!("shaders/mipmap.wgsl").into()),
+        label: None,
+    });
+    let bind_group_layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+        label: None,
+        entries: &[BindGroupLayoutEntry {
+            binding: 0,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                sample_type: TextureSampleType::Float { filterable: false },
+                view_dimension: TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        }],
+    });
+    let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+        label: None,
+        bind_group_layouts: &[&bind_group_layout],
+        push_constant_ranges: &[],
+    });
+    let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+        label: None,
+        layout: Some(&pipeline_layout),
+        vertex: VertexState {
+            module: &module,
+            entry_point: "vs_main",
+            buffers: &[],
+        },
+        fragment: Some(FragmentState {
+            module: &module,
+            entry_point: "fs_main",
+            targets: &[Some(texture.format().into())],
+        }),
+        primitive: PrimitiveState::default(),
+        depth_stencil: None,
+        multisample: MultisampleState::default(),
+        multiview: None,
+    });
+    let level_view = |level: u32| {
+        texture.create_view(&TextureViewDescriptor {
+            base_mip_level: level,
+            mip_level_count: Some(1),
+            ..Default::default()
+        })
+    };
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+    (1..texture.mip_level_count()).for_each(|level| {
+        let source = level_view(level - 1);
+        let target = level_view(level);
+        let bind_group = device.create_bind_group(&BindGroupDescriptor {
+            label: None,
+            layout: &bind_group_layout,
+            entries: &[BindGroupEntry {
+                binding: 0,
+                resource: BindingResource::TextureView(&source),
+            }],
+        });
+        let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+            label: None,
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: &target,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Clear(Color::TRANSPARENT),
+                    store: StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        rpass.set_pipeline(&pipeline);
+        rpass.set_bind_group(0, &bind_group, &[]);
+        rpass.draw(0..3, 0..1);
+    });
+    queue.submit(Some(encoder.finish()));
 }
 
 fn imagebuffer2texture<P, Container>(
     device_handler: &DeviceHandler,
     image_buffer: &ImageBuffer<P, Container>,
     format: TextureFormat,
+    mip_level_count: u32,
 ) -> Texture
 where
     P: Pixel + 'static,
@@ -27,12 +150,19 @@ where
     let texture = device.create_texture(&TextureDescriptor {
         label: None,
         size,
-        mip_level_count: 1,
+        mip_level_count,
         sample_count: 1,
         dimension: TextureDimension::D2,
         format,
         view_formats: &[],
-        usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+        usage: match mip_level_count {
+            1 => TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+            _ => {
+                TextureUsages::TEXTURE_BINDING
+                    | TextureUsages::COPY_DST
+                    | TextureUsages::RENDER_ATTACHMENT
+            }
+        },
     });
     queue.write_texture(
         ImageCopyTexture {
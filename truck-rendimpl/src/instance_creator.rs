@@ -9,6 +9,9 @@ impl PolygonShaders {
     /// - `fragment_entry`: entry point of fragment shader module without texture
     /// - `tex_fragment_module`: fragment shader module with texture
     /// - `tex_fragment_entry`: entry point of fragment shader module with texture
+    ///
+    /// The shaders for normal maps are not set. Without them, the normal maps of the instances
+    /// are ignored. Use [`PolygonShaders::with_normal_map_shaders`] to set them.
     #[inline(always)]
     pub const fn new(
         vertex_module: Arc<ShaderModule>,
@@ -25,9 +28,36 @@ impl PolygonShaders {
             fragment_entry,
             tex_fragment_module,
             tex_fragment_entry,
+            normal_fragment_shaders: None,
         }
     }
 
+    /// Sets the fragment shaders for the instances with normal maps.
+    /// # Parameters
+    /// - `fragment_module`: fragment shader module with normal map and without texture
+    /// - `fragment_entry`: entry point of fragment shader module with normal map and without texture
+    /// - `tex_fragment_module`: fragment shader module with normal map and texture
+    /// - `tex_fragment_entry`: entry point of fragment shader module with normal map and texture
+    ///
+    /// The vertex buffer has the tangents at `location(3)` as `vec4<f32>`, whose last component
+    /// is the sign of the bitangent. See [`PolygonState::normal_texture`] for the encoding.
+    #[inline(always)]
+    pub fn with_normal_map_shaders(
+        mut self,
+        fragment_module: Arc<ShaderModule>,
+        fragment_entry: &'static str,
+        tex_fragment_module: Arc<ShaderModule>,
+        tex_fragment_entry: &'static str,
+    ) -> Self {
+        self.normal_fragment_shaders = Some(NormalFragmentShaders {
+            fragment_module,
+            fragment_entry,
+            tex_fragment_module,
+            tex_fragment_entry,
+        });
+        self
+    }
+
     /// Creates default polygon shaders.
     #[inline(always)]
     pub fn default(device: &Device) -> Self {
@@ -45,6 +75,12 @@ impl PolygonShaders {
             Arc::clone(&shader_module),
             "tex_main",
         )
+        .with_normal_map_shaders(
+            Arc::clone(&shader_module),
+            "nontex_normal_main",
+            Arc::clone(&shader_module),
+            "tex_normal_main",
+        )
     }
 }
 
@@ -86,6 +122,44 @@ impl WireShaders {
     }
 }
 
+impl PointShaders {
+    /// Constructor
+    /// # Parameters
+    /// - `vertex_module`: vertex shader module
+    /// - `vertex_entry`: entry point of vertex shader module
+    /// - `fragment_module`: fragment shader module
+    /// - `fragment_entry`: entry point of fragment shader module
+    #[inline(always)]
+    pub const fn new(
+        vertex_module: Arc<ShaderModule>,
+        vertex_entry: &'static str,
+        fragment_module: Arc<ShaderModule>,
+        fragment_entry: &'static str,
+    ) -> Self {
+        Self {
+            vertex_module,
+            vertex_entry,
+            fragment_module,
+            fragment_entry,
+        }
+    }
+
+    /// Creates default point cloud shaders
+    #[inline(always)]
+    fn default(device: &Device) -> Self {
+        let shader_module = Arc::new(device.create_shader_module(ShaderModuleDescriptor {
+            source: ShaderSource::Wgsl(include_str!("shaders/point.wgsl").into()),
+            label: None,
+        }));
+        Self::new(
+            Arc::clone(&shader_module),
+            "vs_main",
+            shader_module,
+            "fs_main",
+        )
+    }
+}
+
 impl CreatorCreator for DeviceHandler {
     #[inline(always)]
     fn instance_creator(&self) -> InstanceCreator {
@@ -93,6 +167,7 @@ impl CreatorCreator for DeviceHandler {
             handler: self.clone(),
             polygon_shaders: PolygonShaders::default(self.device()),
             wire_shaders: WireShaders::default(self.device()),
+            point_shaders: PointShaders::default(self.device()),
         }
     }
 }
@@ -10,11 +10,17 @@ impl Default for Material {
             reflectance: 0.25,
             ambient_ratio: 0.02,
             background_ratio: 0.0,
-            alpha_blend: false,
+            alpha_mode: AlphaMode::Opaque,
+            emissive: Vector3::zero(),
         }
     }
 }
 
+impl Default for AlphaMode {
+    #[inline(always)]
+    fn default() -> AlphaMode { AlphaMode::Opaque }
+}
+
 impl Material {
     /// Creates a `UNIFORM` buffer of material.
     ///
@@ -26,11 +32,19 @@ impl Material {
     ///     float roughness;
     ///     float reflectance;
     ///     float ambient_ratio;
+    ///     float background_ratio;
+    ///     float alpha_cutoff;
+    ///     vec3 emissive;
     /// };
     /// ```
+    /// `alpha_cutoff` is the cutoff of `AlphaMode::Mask`, and is 0.0 for the other modes.
     #[inline(always)]
     pub fn buffer(&self, device: &Device) -> BufferHandler {
-        let material_data: [f32; 8] = [
+        let alpha_cutoff = match self.alpha_mode {
+            AlphaMode::Mask { cutoff } => cutoff as f32,
+            _ => 0.0,
+        };
+        let material_data: [f32; 16] = [
             self.albedo[0] as f32,
             self.albedo[1] as f32,
             self.albedo[2] as f32,
@@ -39,6 +53,14 @@ impl Material {
             self.reflectance as f32,
             self.ambient_ratio as f32,
             self.background_ratio as f32,
+            alpha_cutoff,
+            0.0,
+            0.0,
+            0.0,
+            self.emissive[0] as f32,
+            self.emissive[1] as f32,
+            self.emissive[2] as f32,
+            0.0,
         ];
         BufferHandler::from_slice(&material_data, device, BufferUsages::UNIFORM)
     }
@@ -65,6 +87,7 @@ impl Default for PolygonState {
             matrix: Matrix4::identity(),
             material: Default::default(),
             texture: None,
+            normal_texture: None,
             backface_culling: true,
         }
     }
@@ -110,6 +133,9 @@ impl PolygonState {
     ///     float roughness;
     ///     float reflectance;
     ///     float ambient_ratio;
+    ///     float background_ratio;
+    ///     float alpha_cutoff;
+    ///     vec3 emissive;
     /// };
     /// ```
     #[inline(always)]
@@ -128,17 +154,20 @@ impl PolygonState {
     /// layout(set = 1, binding = 3) uniform sampler texture_sampler;
     /// ```
     pub fn textureview_and_sampler(&self, device: &Device) -> (TextureView, Sampler) {
-        let texture = self.texture.as_ref().unwrap();
-        let view = texture.create_view(&Default::default());
-        let sampler = device.create_sampler(&SamplerDescriptor {
-            mag_filter: FilterMode::Linear,
-            min_filter: FilterMode::Nearest,
-            mipmap_filter: FilterMode::Nearest,
-            lod_min_clamp: 0.0,
-            lod_max_clamp: 100.0,
-            ..Default::default()
-        });
-        (view, sampler)
+        view_and_sampler(self.texture.as_ref().unwrap(), device)
+    }
+
+    /// Creates texture view and sampler of the instance's normal map.
+    ///
+    /// The bind group provided by the instances holds them next to the texture image if the
+    /// instance has the texture, or next to the material otherwise.
+    /// # Shader Examples
+    /// ```glsl
+    /// layout(set = 1, binding = 4) uniform texture2D normal_view;
+    /// layout(set = 1, binding = 5) uniform sampler normal_sampler;
+    /// ```
+    pub fn normal_textureview_and_sampler(&self, device: &Device) -> (TextureView, Sampler) {
+        view_and_sampler(self.normal_texture.as_ref().unwrap(), device)
     }
 
     #[doc(hidden)]
@@ -165,3 +194,16 @@ impl PolygonState {
         }
     }
 }
+
+fn view_and_sampler(texture: &Texture, device: &Device) -> (TextureView, Sampler) {
+    let view = texture.create_view(&Default::default());
+    let sampler = device.create_sampler(&SamplerDescriptor {
+        mag_filter: FilterMode::Linear,
+        min_filter: FilterMode::Nearest,
+        mipmap_filter: FilterMode::Linear,
+        lod_min_clamp: 0.0,
+        lod_max_clamp: 100.0,
+        ..Default::default()
+    });
+    (view, sampler)
+}
@@ -37,11 +37,35 @@ pub struct Material {
     /// ratio of specular: [0, 1]. Default is 0.25.
     pub reflectance: f64,
     /// ratio of ambient: [0, 1]. Default is 0.02.
+    ///
+    /// The shaded color is blended with the albedo by this ratio, after the scene-wide ambient
+    /// light [`StudioConfig::ambient`] is added to the contribution of the lights.
     pub ambient_ratio: f64,
     /// ratio of blending background color: [0, 1]. Default is 0.0.
     pub background_ratio: f64,
-    /// alpha blend flag
-    pub alpha_blend: bool,
+    /// how to treat the alpha of the albedo. Default is `AlphaMode::Opaque`.
+    pub alpha_mode: AlphaMode,
+    /// emitted light, linear rgb. Added after lighting, so may be over 1. Default is zero.
+    pub emissive: Vector3,
+}
+
+/// The way to render the alpha of materials.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlphaMode {
+    /// The alpha is ignored, and the colors are written as they are.
+    Opaque,
+    /// The colors are blended with the ones behind by the alpha.
+    ///
+    /// The blended instances do not write the depth, and are drawn after the opaque ones,
+    /// sorted from back to front by the distance from the camera to the centers of their
+    /// bounding boxes. The order of faces within one instance is not sorted.
+    Blend,
+    /// The fragments whose alpha are less than `cutoff` are discarded, and the others are
+    /// rendered as opaque. Suitable for foliage and cutouts.
+    Mask {
+        /// the threshold of the alpha: [0, 1]
+        cutoff: f64,
+    },
 }
 
 /// Configures of instances.
@@ -53,6 +77,17 @@ pub struct PolygonState {
     pub material: Material,
     /// texture of instance
     pub texture: Option<Arc<Texture>>,
+    /// normal map of instance
+    ///
+    /// The normals are in the tangent space, encoded to RGB by `(n + 1) / 2`.
+    /// The red channel is the direction of increasing u, and the green channel is
+    /// the upward direction of the image (Y-up, the OpenGL convention), i.e.
+    /// the direction of decreasing v, since the texture is sampled with the origin
+    /// at the upper-left corner. The blue channel is the direction of the normal.
+    /// The tangents are computed from the uv coordinates of the mesh, so the mesh
+    /// should have the uv coordinates and the normals. The vertices without uv coordinates
+    /// have fixed tangents orthogonal to the normals.
+    pub normal_texture: Option<Arc<Texture>>,
     /// If this parameter is true, the backface culling will be activated.
     pub backface_culling: bool,
 }
@@ -66,6 +101,17 @@ pub struct WireFrameState {
     pub color: Vector4,
 }
 
+/// Configures of `PointCloudInstance`.
+#[derive(Clone, Debug)]
+pub struct PointCloudState {
+    /// instance matrix
+    pub matrix: Matrix4,
+    /// color of instance, multiplied by the colors of the points
+    pub color: Vector4,
+    /// the length of the sides of the points in pixels. Default is 3.0.
+    pub point_size: f64,
+}
+
 /// shaders for rendering polygons
 #[derive(Debug, Clone)]
 pub struct PolygonShaders {
@@ -75,6 +121,16 @@ pub struct PolygonShaders {
     fragment_entry: &'static str,
     tex_fragment_module: Arc<ShaderModule>,
     tex_fragment_entry: &'static str,
+    normal_fragment_shaders: Option<NormalFragmentShaders>,
+}
+
+/// fragment shaders for rendering polygons with normal maps
+#[derive(Debug, Clone)]
+struct NormalFragmentShaders {
+    fragment_module: Arc<ShaderModule>,
+    fragment_entry: &'static str,
+    tex_fragment_module: Arc<ShaderModule>,
+    tex_fragment_entry: &'static str,
 }
 
 /// shaders for rendering wireframes
@@ -86,6 +142,15 @@ pub struct WireShaders {
     fragment_entry: &'static str,
 }
 
+/// shaders for rendering point clouds
+#[derive(Debug, Clone)]
+pub struct PointShaders {
+    vertex_module: Arc<ShaderModule>,
+    vertex_entry: &'static str,
+    fragment_module: Arc<ShaderModule>,
+    fragment_entry: &'static str,
+}
+
 /// Instance of polygon
 ///
 /// One can duplicate polygons with different postures and materials
@@ -97,12 +162,18 @@ pub struct WireShaders {
 #[derive(Debug)]
 pub struct PolygonInstance {
     polygon: (Arc<BufferHandler>, Arc<BufferHandler>),
+    center: Point3,
     state: PolygonState,
     shaders: PolygonShaders,
     id: RenderID,
 }
 
 /// Wire frame rendering
+///
+/// Created from `PolygonMesh`, `StructuredMesh` and polylines by [`InstanceCreator::create_instance`].
+/// The lines are drawn as a line list with the color of [`WireFrameState`]. For polygon meshes,
+/// the edges shared by the faces are drawn only once, so one can overlay the edges of a mesh
+/// on its polygon instance with the same matrix.
 #[derive(Debug)]
 pub struct WireFrameInstance {
     vertices: Arc<BufferHandler>,
@@ -112,12 +183,25 @@ pub struct WireFrameInstance {
     id: RenderID,
 }
 
+/// Point cloud rendering
+///
+/// Each point is drawn as a square facing the screen, whose sides are `point_size` pixels,
+/// so the points keep their sizes on the screen regardless of the distance from the camera.
+#[derive(Debug)]
+pub struct PointCloudInstance {
+    vertices: Arc<BufferHandler>,
+    state: PointCloudState,
+    shaders: PointShaders,
+    id: RenderID,
+}
+
 /// Constroctor for instances
 #[derive(Debug, Clone)]
 pub struct InstanceCreator {
     handler: DeviceHandler,
     polygon_shaders: PolygonShaders,
     wire_shaders: WireShaders,
+    point_shaders: PointShaders,
 }
 
 /// for creating `InstanceCreator`
@@ -160,12 +244,22 @@ struct AttrVertex {
     pub position: [f32; 3],
     pub uv_coord: [f32; 2],
     pub normal: [f32; 3],
+    pub tangent: [f32; 4],
 }
 
 /// utility for creating `Texture`
 pub mod image2texture;
 mod instance_creator;
 mod instance_descriptor;
+mod point_cloud_instance;
 mod polygon_instance;
 mod polyrend;
 mod wireframe_instance;
+
+/// Hashes the inputs of a pipeline into the key of [`Rendered::pipeline_key`].
+fn hash_pipeline_key<T: std::hash::Hash>(inputs: T) -> u64 {
+    use std::hash::Hasher;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    inputs.hash(&mut hasher);
+    hasher.finish()
+}
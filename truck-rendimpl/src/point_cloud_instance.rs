@@ -0,0 +1,254 @@
+use crate::*;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct PointVertex {
+    position: [f32; 3],
+    color: [f32; 4],
+    corner: [f32; 2],
+}
+
+/// the corners of the two triangles of the square
+const CORNERS: [[f32; 2]; 6] = [
+    [-1.0, -1.0],
+    [1.0, -1.0],
+    [1.0, 1.0],
+    [-1.0, -1.0],
+    [1.0, 1.0],
+    [-1.0, 1.0],
+];
+
+impl Default for PointCloudState {
+    #[inline(always)]
+    fn default() -> PointCloudState {
+        PointCloudState {
+            matrix: Matrix4::identity(),
+            color: Vector4::new(1.0, 1.0, 1.0, 1.0),
+            point_size: 3.0,
+        }
+    }
+}
+
+impl PointCloudInstance {
+    /// Clone the instance as another drawn element.
+    #[inline(always)]
+    pub fn clone_instance(&self) -> Self {
+        Self {
+            vertices: Arc::clone(&self.vertices),
+            state: self.state.clone(),
+            shaders: self.shaders.clone(),
+            id: RenderID::gen(),
+        }
+    }
+    /// Returns the point cloud state
+    #[inline(always)]
+    pub const fn instance_state(&self) -> &PointCloudState { &self.state }
+    /// Returns the mutable reference to point cloud state
+    #[inline(always)]
+    pub fn instance_state_mut(&mut self) -> &mut PointCloudState { &mut self.state }
+    /// swap vertex buffers
+    #[inline(always)]
+    pub fn swap_vertex(&mut self, other: &mut PointCloudInstance) {
+        std::mem::swap(&mut self.vertices, &mut other.vertices);
+    }
+
+    fn from_points<I: IntoIterator<Item = (Point3, [f32; 4])>>(
+        points: I,
+        handler: &DeviceHandler,
+        shaders: &PointShaders,
+        state: &PointCloudState,
+    ) -> Self {
+        let vertices: Vec<PointVertex> = points
+            .into_iter()
+            .flat_map(|(p, color)| {
+                let position = p.cast().unwrap().into();
+                CORNERS.map(|corner| PointVertex {
+                    position,
+                    color,
+                    corner,
+                })
+            })
+            .collect();
+        let vb = BufferHandler::from_slice(&vertices, handler.device(), BufferUsages::VERTEX);
+        PointCloudInstance {
+            vertices: Arc::new(vb),
+            state: state.clone(),
+            shaders: shaders.clone(),
+            id: RenderID::gen(),
+        }
+    }
+}
+
+impl Instance for PointCloudInstance {
+    type Shaders = PointShaders;
+    fn standard_shaders(creator: &InstanceCreator) -> PointShaders { creator.point_shaders.clone() }
+}
+
+impl Rendered for PointCloudInstance {
+    impl_render_id!(id);
+    fn vertex_buffer(&self, _: &DeviceHandler) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        (self.vertices.clone(), None)
+    }
+    fn bind_group_layout(&self, handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        Arc::new(bind_group_util::create_bind_group_layout(
+            handler.device(),
+            &[
+                // matrix
+                PreBindGroupLayoutEntry {
+                    visibility: ShaderStages::VERTEX,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // color and point size
+                PreBindGroupLayoutEntry {
+                    visibility: ShaderStages::VERTEX,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        ))
+    }
+    fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
+        let device = handler.device();
+        let matrix_data: [[f32; 4]; 4] = self.state.matrix.cast::<f32>().unwrap().into();
+        let matrix_buffer = BufferHandler::from_slice(&matrix_data, device, BufferUsages::UNIFORM);
+        let color = self.state.color.cast::<f32>().unwrap();
+        let info_data: [f32; 8] = [
+            color[0],
+            color[1],
+            color[2],
+            color[3],
+            self.state.point_size as f32,
+            0.0,
+            0.0,
+            0.0,
+        ];
+        let info_buffer = BufferHandler::from_slice(&info_data, device, BufferUsages::UNIFORM);
+        Arc::new(bind_group_util::create_bind_group(
+            device,
+            layout,
+            vec![
+                matrix_buffer.binding_resource(),
+                info_buffer.binding_resource(),
+            ],
+        ))
+    }
+    fn pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        let device = handler.device();
+        let sample_count = scene_desc.backend_buffer.sample_count;
+        let depth_stencil = match scene_desc.backend_buffer.depth_test {
+            true => Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: CompareFunction::Less,
+                stencil: Default::default(),
+                bias: Default::default(),
+            }),
+            false => None,
+        };
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(layout),
+            vertex: VertexState {
+                module: &self.shaders.vertex_module,
+                entry_point: self.shaders.vertex_entry,
+                buffers: &[VertexBufferLayout {
+                    array_stride: size_of::<PointVertex>() as BufferAddress,
+                    step_mode: VertexStepMode::Vertex,
+                    attributes: &[
+                        VertexAttribute {
+                            format: VertexFormat::Float32x3,
+                            offset: 0,
+                            shader_location: 0,
+                        },
+                        VertexAttribute {
+                            format: VertexFormat::Float32x4,
+                            offset: 3 * 4,
+                            shader_location: 1,
+                        },
+                        VertexAttribute {
+                            format: VertexFormat::Float32x2,
+                            offset: 3 * 4 + 4 * 4,
+                            shader_location: 2,
+                        },
+                    ],
+                }],
+            },
+            fragment: Some(FragmentState {
+                module: &self.shaders.fragment_module,
+                entry_point: self.shaders.fragment_entry,
+                targets: &[Some(ColorTargetState {
+                    format: scene_desc.render_texture.format,
+                    blend: Some(BlendState::REPLACE),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState {
+                topology: PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil,
+            multisample: MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: None,
+            multiview: None,
+        });
+        Arc::new(pipeline)
+    }
+    #[inline(always)]
+    fn pipeline_key(&self) -> Option<u64> {
+        Some(hash_pipeline_key((
+            self.shaders.vertex_module.global_id(),
+            self.shaders.vertex_entry,
+            self.shaders.fragment_module.global_id(),
+            self.shaders.fragment_entry,
+        )))
+    }
+}
+
+impl ToInstance<PointCloudInstance> for Vec<Point3> {
+    type State = PointCloudState;
+    #[inline(always)]
+    fn to_instance(
+        &self,
+        handler: &DeviceHandler,
+        shaders: &PointShaders,
+        state: &PointCloudState,
+    ) -> PointCloudInstance {
+        let points = self.iter().map(|p| (*p, [1.0; 4]));
+        PointCloudInstance::from_points(points, handler, shaders, state)
+    }
+}
+
+/// The points with the [0, 1] range RGB colors.
+impl ToInstance<PointCloudInstance> for Vec<(Point3, Vector3)> {
+    type State = PointCloudState;
+    #[inline(always)]
+    fn to_instance(
+        &self,
+        handler: &DeviceHandler,
+        shaders: &PointShaders,
+        state: &PointCloudState,
+    ) -> PointCloudInstance {
+        let points = self.iter().map(|(p, c)| {
+            let c = c.cast::<f32>().unwrap();
+            (*p, [c[0], c[1], c[2], 1.0])
+        });
+        PointCloudInstance::from_points(points, handler, shaders, state)
+    }
+}
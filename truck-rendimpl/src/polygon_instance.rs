@@ -6,6 +6,7 @@ impl PolygonInstance {
     pub fn clone_instance(&self) -> PolygonInstance {
         PolygonInstance {
             polygon: self.polygon.clone(),
+            center: self.center,
             state: self.state.clone(),
             shaders: self.shaders.clone(),
             id: RenderID::gen(),
@@ -22,55 +23,73 @@ impl PolygonInstance {
     #[inline(always)]
     pub fn swap_vertex(&mut self, other: &mut PolygonInstance) {
         std::mem::swap(&mut self.polygon, &mut other.polygon);
+        std::mem::swap(&mut self.center, &mut other.center);
     }
 
+    /// Returns the fragment shaders for the normal map if the normal map is used.
     #[inline(always)]
-    fn non_textured_bdl(&self, device: &Device) -> BindGroupLayout {
-        bind_group_util::create_bind_group_layout(device, {
-            &[
-                PolygonState::matrix_bgl_entry(),
-                PolygonState::material_bgl_entry(),
-            ]
-        })
+    fn normal_fragment_shaders(&self) -> Option<&NormalFragmentShaders> {
+        match self.state.normal_texture.is_some() {
+            true => self.shaders.normal_fragment_shaders.as_ref(),
+            false => None,
+        }
     }
 
+    /// Returns the fragment shader module and its entry point chosen by the textures.
     #[inline(always)]
-    fn textured_bdl(&self, device: &Device) -> BindGroupLayout {
-        bind_group_util::create_bind_group_layout(
-            device,
-            &[
-                PolygonState::matrix_bgl_entry(),
-                PolygonState::material_bgl_entry(),
-                PolygonState::textureview_bgl_entry(),
-                PolygonState::sampler_bgl_entry(),
-            ],
-        )
+    fn fragment_shader(&self) -> (&Arc<ShaderModule>, &'static str) {
+        match (self.state.texture.is_some(), self.normal_fragment_shaders()) {
+            (true, Some(shaders)) => (&shaders.tex_fragment_module, shaders.tex_fragment_entry),
+            (false, Some(shaders)) => (&shaders.fragment_module, shaders.fragment_entry),
+            (true, None) => (
+                &self.shaders.tex_fragment_module,
+                self.shaders.tex_fragment_entry,
+            ),
+            (false, None) => (&self.shaders.fragment_module, self.shaders.fragment_entry),
+        }
     }
 
     #[inline(always)]
-    fn non_textured_bg(&self, device: &Device, layout: &BindGroupLayout) -> BindGroup {
-        bind_group_util::create_bind_group(
-            device,
-            layout,
-            vec![
-                self.state.matrix_buffer(device).binding_resource(),
-                self.state.material.buffer(device).binding_resource(),
-            ],
-        )
+    fn bdl(&self, device: &Device) -> BindGroupLayout {
+        let mut entries = vec![
+            PolygonState::matrix_bgl_entry(),
+            PolygonState::material_bgl_entry(),
+        ];
+        if self.state.texture.is_some() {
+            entries.push(PolygonState::textureview_bgl_entry());
+            entries.push(PolygonState::sampler_bgl_entry());
+        }
+        if self.normal_fragment_shaders().is_some() {
+            entries.push(PolygonState::textureview_bgl_entry());
+            entries.push(PolygonState::sampler_bgl_entry());
+        }
+        bind_group_util::create_bind_group_layout(device, &entries)
     }
+
     #[inline(always)]
-    fn textured_bg(&self, device: &Device, layout: &BindGroupLayout) -> BindGroup {
-        let (view, sampler) = self.state.textureview_and_sampler(device);
-        bind_group_util::create_bind_group(
-            device,
-            layout,
-            vec![
-                self.state.matrix_buffer(device).binding_resource(),
-                self.state.material.buffer(device).binding_resource(),
-                BindingResource::TextureView(&view),
-                BindingResource::Sampler(&sampler),
-            ],
-        )
+    fn bg(&self, device: &Device, layout: &BindGroupLayout) -> BindGroup {
+        let matrix_buffer = self.state.matrix_buffer(device);
+        let material_buffer = self.state.material.buffer(device);
+        let texture = self
+            .state
+            .texture
+            .as_ref()
+            .map(|_| self.state.textureview_and_sampler(device));
+        let normal_texture = self
+            .normal_fragment_shaders()
+            .map(|_| self.state.normal_textureview_and_sampler(device));
+        let mut resources = vec![
+            matrix_buffer.binding_resource(),
+            material_buffer.binding_resource(),
+        ];
+        texture
+            .iter()
+            .chain(&normal_texture)
+            .for_each(|(view, sampler)| {
+                resources.push(BindingResource::TextureView(view));
+                resources.push(BindingResource::Sampler(sampler));
+            });
+        bind_group_util::create_bind_group(device, layout, resources)
     }
 }
 
@@ -84,10 +103,7 @@ impl Rendered for PolygonInstance {
     }
     #[inline(always)]
     fn bind_group_layout(&self, device_handler: &DeviceHandler) -> Arc<BindGroupLayout> {
-        Arc::new(match self.state.texture.is_some() {
-            true => self.textured_bdl(device_handler.device()),
-            false => self.non_textured_bdl(device_handler.device()),
-        })
+        Arc::new(self.bdl(device_handler.device()))
     }
     #[inline(always)]
     fn bind_group(
@@ -95,10 +111,7 @@ impl Rendered for PolygonInstance {
         device_handler: &DeviceHandler,
         layout: &BindGroupLayout,
     ) -> Arc<BindGroup> {
-        Arc::new(match self.state.texture.is_some() {
-            true => self.textured_bg(device_handler.device(), layout),
-            false => self.non_textured_bg(device_handler.device(), layout),
-        })
+        Arc::new(self.bg(device_handler.device(), layout))
     }
     #[inline(always)]
     fn pipeline(
@@ -108,25 +121,20 @@ impl Rendered for PolygonInstance {
         scene_desc: &SceneDescriptor,
     ) -> Arc<RenderPipeline> {
         let device = device_handler.device();
-        let (fragment_module, fragment_entry) = match self.state.texture.is_some() {
-            true => (
-                &self.shaders.tex_fragment_module,
-                self.shaders.tex_fragment_entry,
-            ),
-            false => (&self.shaders.fragment_module, self.shaders.fragment_entry),
-        };
+        let (fragment_module, fragment_entry) = self.fragment_shader();
         let cull_mode = match self.state.backface_culling {
             true => Some(Face::Back),
             false => None,
         };
-        let blend = match self.state.material.alpha_blend {
+        let alpha_blend = self.state.material.alpha_mode == AlphaMode::Blend;
+        let blend = match alpha_blend {
             true => Some(BlendState::ALPHA_BLENDING),
             false => Some(BlendState::REPLACE),
         };
         let depth_stencil = match scene_desc.backend_buffer.depth_test {
             true => Some(DepthStencilState {
                 format: TextureFormat::Depth32Float,
-                depth_write_enabled: true,
+                depth_write_enabled: !alpha_blend,
                 depth_compare: CompareFunction::Less,
                 stencil: Default::default(),
                 bias: Default::default(),
@@ -158,6 +166,11 @@ impl Rendered for PolygonInstance {
                             offset: 2 * 4 + 3 * 4,
                             shader_location: 2,
                         },
+                        VertexAttribute {
+                            format: VertexFormat::Float32x4,
+                            offset: 2 * 4 + 3 * 4 + 3 * 4,
+                            shader_location: 3,
+                        },
                     ],
                 }],
             },
@@ -188,4 +201,25 @@ impl Rendered for PolygonInstance {
         });
         Arc::new(pipeline)
     }
+    #[inline(always)]
+    fn pipeline_key(&self) -> Option<u64> {
+        let (fragment_module, fragment_entry) = self.fragment_shader();
+        Some(hash_pipeline_key((
+            self.shaders.vertex_module.global_id(),
+            self.shaders.vertex_entry,
+            fragment_module.global_id(),
+            fragment_entry,
+            self.state.texture.is_some(),
+            self.normal_fragment_shaders().is_some(),
+            self.state.backface_culling,
+            self.state.material.alpha_mode == AlphaMode::Blend,
+        )))
+    }
+    #[inline(always)]
+    fn transparent_center(&self) -> Option<Point3> {
+        match self.state.material.alpha_mode {
+            AlphaMode::Blend => Some(self.state.matrix.transform_point(self.center)),
+            _ => None,
+        }
+    }
 }
@@ -19,6 +19,7 @@ impl CreateBuffers for PolygonMesh {
                 .normal
                 .and_then(|v| Some(v.cast()?.into()))
                 .unwrap_or([0.0, 0.0, 0.0]),
+            tangent: [0.0; 4],
         });
         let indices = expanded
             .faces()
@@ -26,13 +27,100 @@ impl CreateBuffers for PolygonMesh {
             .flatten()
             .map(|x| x as u32)
             .collect::<Vec<_>>();
+        let mut vertices = expanded.attributes().clone();
+        set_tangents(&mut vertices, &indices);
         (
-            BufferHandler::from_slice(expanded.attributes(), device, vertex_usage),
+            BufferHandler::from_slice(&vertices, device, vertex_usage),
             BufferHandler::from_slice(&indices, device, index_usage),
         )
     }
 }
 
+/// Returns the center of the bounding box, or the origin if there are no points.
+fn bounding_center<'a>(points: impl IntoIterator<Item = &'a Point3>) -> Point3 {
+    let bdb: BoundingBox<Point3> = points.into_iter().collect();
+    match bdb.is_empty() {
+        true => Point3::origin(),
+        false => bdb.center(),
+    }
+}
+
+/// Sets the tangents of the vertices for normal maps.
+///
+/// The tangents are the derivations by u, orthogonalized to the normals. The last components
+/// are the signs such that the cross products of the normals and the tangents multiplied by
+/// them point to the upward direction of the texture image, i.e. the decreasing direction of v.
+/// The vertices without valid uv coordinates have the fallback tangents, the orthogonalized
+/// coordinate axes which are the least parallel to the normals with the signs `1`, so that the
+/// tangents do not depend on the order of the faces. The vertices without normals have `+x`.
+fn set_tangents(vertices: &mut [AttrVertex], indices: &[u32]) {
+    let vec3 = |v: [f32; 3]| Vector3::new(v[0] as f64, v[1] as f64, v[2] as f64);
+    let mut derivations = vec![(Vector3::zero(), Vector3::zero()); vertices.len()];
+    indices.chunks_exact(3).for_each(|tri| {
+        let [a, b, c] = [0, 1, 2].map(|i| &vertices[tri[i] as usize]);
+        let (dp1, dp2) = (
+            vec3(b.position) - vec3(a.position),
+            vec3(c.position) - vec3(a.position),
+        );
+        let (du1, dv1) = (
+            (b.uv_coord[0] - a.uv_coord[0]) as f64,
+            (b.uv_coord[1] - a.uv_coord[1]) as f64,
+        );
+        let (du2, dv2) = (
+            (c.uv_coord[0] - a.uv_coord[0]) as f64,
+            (c.uv_coord[1] - a.uv_coord[1]) as f64,
+        );
+        let det = du1 * dv2 - du2 * dv1;
+        if det.so_small() {
+            return;
+        }
+        let der_u = (dp1 * dv2 - dp2 * dv1) / det;
+        let der_v = (dp2 * du1 - dp1 * du2) / det;
+        tri.iter().for_each(|i| {
+            let (u, v) = &mut derivations[*i as usize];
+            *u += der_u;
+            *v += der_v;
+        });
+    });
+    vertices
+        .iter_mut()
+        .zip(derivations)
+        .for_each(|(vertex, (der_u, der_v))| {
+            let normal = vec3(vertex.normal);
+            if normal.so_small() {
+                vertex.tangent = [1.0, 0.0, 0.0, 1.0];
+                return;
+            }
+            let normal = normal.normalize();
+            let tangent = der_u - normal * normal.dot(der_u);
+            let (tangent, sign) = match tangent.so_small() {
+                true => (fallback_tangent(normal), 1.0),
+                false => {
+                    let tangent = tangent.normalize();
+                    match normal.cross(tangent).dot(der_v) > 0.0 {
+                        true => (tangent, -1.0),
+                        false => (tangent, 1.0),
+                    }
+                }
+            };
+            vertex.tangent = tangent.extend(sign).cast().unwrap().into();
+        });
+}
+
+/// Returns the unit vector orthogonal to the unit vector `normal`, obtained from the coordinate
+/// axis which is the least parallel to `normal`.
+fn fallback_tangent(normal: Vector3) -> Vector3 {
+    let abs = normal.map(f64::abs);
+    let axis = if abs.x <= abs.y && abs.x <= abs.z {
+        Vector3::unit_x()
+    } else if abs.y <= abs.z {
+        Vector3::unit_y()
+    } else {
+        Vector3::unit_z()
+    };
+    (axis - normal * normal.dot(axis)).normalize()
+}
+
 impl Instance for PolygonInstance {
     type Shaders = PolygonShaders;
     fn standard_shaders(creator: &InstanceCreator) -> PolygonShaders {
@@ -52,6 +140,7 @@ impl ToInstance<PolygonInstance> for PolygonMesh {
         let (vb, ib) = self.buffers(BufferUsages::VERTEX, BufferUsages::INDEX, handler.device());
         PolygonInstance {
             polygon: (Arc::new(vb), Arc::new(ib)),
+            center: bounding_center(self.positions()),
             state: state.clone(),
             shaders: shaders.clone(),
             id: RenderID::gen(),
@@ -73,12 +162,18 @@ impl ToInstance<WireFrameInstance> for PolygonMesh {
             .iter()
             .map(|p| p.cast().unwrap().into())
             .collect();
-        let mut strips = Vec::<u32>::new();
-        self.faces().face_iter().for_each(|face| {
-            face.iter().circular_tuple_windows().for_each(|(a, b)| {
-                strips.extend([a.pos as u32, b.pos as u32]);
-            });
-        });
+        // the edges shared by the adjacent faces are drawn only once.
+        let strips: Vec<u32> = self
+            .faces()
+            .face_iter()
+            .flat_map(|face| {
+                face.iter()
+                    .circular_tuple_windows()
+                    .map(|(a, b)| (usize::min(a.pos, b.pos), usize::max(a.pos, b.pos)))
+            })
+            .unique()
+            .flat_map(|(a, b)| [a as u32, b as u32])
+            .collect();
         let vb = BufferHandler::from_slice(&positions, device, BufferUsages::VERTEX);
         let ib = BufferHandler::from_slice(&strips, device, BufferUsages::INDEX);
         WireFrameInstance {
@@ -112,6 +207,7 @@ impl CreateBuffers for StructuredMesh {
                     Some(normals) => normals[i][j].cast().unwrap().into(),
                     None => [0.0, 0.0, 0.0],
                 },
+                tangent: [0.0; 4],
             });
         });
         let mut indices = Vec::<u32>::new();
@@ -125,6 +221,7 @@ impl CreateBuffers for StructuredMesh {
                 (i * n + j) as u32,
             ]);
         });
+        set_tangents(&mut vertices, &indices);
         (
             BufferHandler::from_slice(&vertices, device, vertex_usage),
             BufferHandler::from_slice(&indices, device, index_usage),
@@ -144,6 +241,7 @@ impl ToInstance<PolygonInstance> for StructuredMesh {
         let (vb, ib) = self.buffers(BufferUsages::VERTEX, BufferUsages::INDEX, handler.device());
         PolygonInstance {
             polygon: (Arc::new(vb), Arc::new(ib)),
+            center: bounding_center(self.positions().iter().flatten()),
             state: state.clone(),
             shaders: shaders.clone(),
             id: RenderID::gen(),
@@ -255,3 +353,74 @@ impl ToInstance<WireFrameInstance> for Vec<PolylineCurve<Point3>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vertex(position: [f32; 3], uv_coord: [f32; 2], normal: [f32; 3]) -> AttrVertex {
+        AttrVertex {
+            position,
+            uv_coord,
+            normal,
+            tangent: [0.0; 4],
+        }
+    }
+
+    #[test]
+    fn tangents_from_uv() {
+        let normal = [0.0, 0.0, 1.0];
+        let mut vertices = [
+            vertex([0.0, 0.0, 0.0], [0.0, 1.0], normal),
+            vertex([1.0, 0.0, 0.0], [1.0, 1.0], normal),
+            vertex([0.0, 1.0, 0.0], [0.0, 0.0], normal),
+        ];
+        set_tangents(&mut vertices, &[0, 1, 2]);
+        // v decreases upward, so the bitangent `normal.cross(tangent)` is +y.
+        vertices
+            .iter()
+            .for_each(|v| assert_eq!(v.tangent, [1.0, 0.0, 0.0, 1.0]));
+
+        // mirrored texture
+        vertices[0].uv_coord = [0.0, 0.0];
+        vertices[1].uv_coord = [1.0, 0.0];
+        vertices[2].uv_coord = [0.0, 1.0];
+        set_tangents(&mut vertices, &[0, 1, 2]);
+        vertices
+            .iter()
+            .for_each(|v| assert_eq!(v.tangent, [1.0, 0.0, 0.0, -1.0]));
+    }
+
+    #[test]
+    fn fallback_tangents() {
+        let normals = [
+            [0.0, 0.0, 1.0],
+            [0.0, 0.6, -0.8],
+            [1.0, 1.0, 1.0],
+            [0.0, 0.0, 0.0],
+        ];
+        // without uv coordinates
+        let mut vertices = normals.map(|normal| vertex([0.0; 3], [0.0; 2], normal));
+        vertices[0].position = [1.0, 0.0, 0.0];
+        vertices[1].position = [0.0, 1.0, 0.0];
+        set_tangents(&mut vertices, &[0, 1, 2, 1, 2, 3]);
+        vertices.iter().for_each(|v| {
+            let [x, y, z, w] = v.tangent.map(f64::from);
+            let tangent = Vector3::new(x, y, z);
+            let normal = Vector3::from(v.normal.map(f64::from));
+            assert!(tangent.magnitude().near(&1.0));
+            assert!(tangent.dot(normal).so_small());
+            assert_eq!(w, 1.0);
+        });
+        assert_eq!(vertices[0].tangent, [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(vertices[3].tangent, [1.0, 0.0, 0.0, 1.0]);
+
+        // does not depend on the order of the faces.
+        let mut reordered = vertices;
+        set_tangents(&mut reordered, &[1, 2, 3, 0, 1, 2]);
+        vertices
+            .iter()
+            .zip(&reordered)
+            .for_each(|(v0, v1)| assert_eq!(v0.tangent, v1.tangent));
+    }
+}
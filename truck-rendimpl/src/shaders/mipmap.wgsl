@@ -0,0 +1,22 @@
+@group(0)
+@binding(0)
+var source: texture_2d<f32>;
+
+@vertex
+fn vs_main(@builtin(vertex_index) idx: u32) -> @builtin(position) vec4<f32> {
+    // the triangle covering the viewport
+    let uv = vec2<f32>(f32((idx << 1u) & 2u), f32(idx & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    // the box filter of the 2 x 2 texels, clamped for the odd sizes
+    let size = vec2<i32>(textureDimensions(source)) - vec2<i32>(1, 1);
+    let base = vec2<i32>(position.xy) * 2;
+    let c00 = textureLoad(source, min(base, size), 0);
+    let c10 = textureLoad(source, min(base + vec2<i32>(1, 0), size), 0);
+    let c01 = textureLoad(source, min(base + vec2<i32>(0, 1), size), 0);
+    let c11 = textureLoad(source, min(base + vec2<i32>(1, 1), size), 0);
+    return (c00 + c10 + c01 + c11) * 0.25;
+}
@@ -0,0 +1,61 @@
+struct Camera {
+    _matrix: mat4x4<f32>,
+    projection: mat4x4<f32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> camera: Camera;
+
+struct SceneInfo {
+    _bk_color: vec4<f32>,
+    resolution: vec2<u32>,
+    _time: f32,
+    _nlights: u32,
+}
+
+@group(0)
+@binding(2)
+var<uniform> info: SceneInfo;
+
+struct ModelMatrix {
+    model_matrix: mat4x4<f32>,
+}
+
+@group(1)
+@binding(0)
+var<uniform> model_matrix: ModelMatrix;
+
+struct PointInfo {
+    color: vec4<f32>,
+    point_size: f32,
+}
+
+@group(1)
+@binding(1)
+var<uniform> point_info: PointInfo;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec4<f32>,
+    @location(2) corner: vec2<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var position = camera.projection * model_matrix.model_matrix * vec4<f32>(in.position, 1.0);
+    // `corner` is in [-1, 1]^2, and one pixel is 2 / resolution in the normalized device coordinate.
+    let offset = in.corner * point_info.point_size / vec2<f32>(info.resolution);
+    position = vec4<f32>(position.xy + offset * position.w, position.zw);
+    return VertexOutput(position, in.color * point_info.color);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(pow(in.color.rgb, vec3<f32>(0.4545)), in.color.a);
+}
@@ -2,6 +2,7 @@ struct VertexInput {
     @location(0) position: vec3<f32>,
     @location(1) uv: vec2<f32>,
     @location(2) normal: vec3<f32>, 
+    @location(3) tangent: vec4<f32>,
 }
 
 struct Camera {
@@ -23,8 +24,15 @@ var<uniform> lights: Lights;
 
 struct SceneInfo {
     bk_color: vec4<f32>,
+    resolution: vec2<u32>,
     time: f32,
     nlights: u32,
+    ambient: vec4<f32>,
+    fog_color: vec4<f32>,
+    fog_density: f32,
+    fog_near: f32,
+    fog_far: f32,
+    fog_mode: u32,
 }
 
 @group(0)
@@ -41,6 +49,8 @@ var<uniform> model_matrix: ModelMatrix;
 
 struct ModelMaterial {
     material: Material,
+    alpha_cutoff: f32,
+    emissive: vec3<f32>,
 }
 
 @group(1)
@@ -55,68 +65,128 @@ var r_color: texture_2d<f32>;
 @binding(3)
 var r_sampler: sampler;
 
+// normal map with texture
+@group(1)
+@binding(4)
+var r_normal: texture_2d<f32>;
+
+@group(1)
+@binding(5)
+var r_normal_sampler: sampler;
+
+// normal map without texture
+@group(1)
+@binding(2)
+var r_nontex_normal: texture_2d<f32>;
+
+@group(1)
+@binding(3)
+var r_nontex_normal_sampler: sampler;
+
 struct VertexOutput {
     @builtin(position) gl_position: vec4<f32>,
     @location(0) position: vec3<f32>,
     @location(1) uv: vec2<f32>,
     @location(2) normal: vec3<f32>, 
+    @location(3) tangent: vec4<f32>,
 }
 
 @vertex
 fn vs_main(in: VertexInput) -> VertexOutput {
     let world_position = model_matrix.model_matrix * vec4<f32>(in.position, 1.0);
     let world_normal = model_matrix.model_matrix * vec4<f32>(in.normal, 0.0);
+    let world_tangent = model_matrix.model_matrix * vec4<f32>(in.tangent.xyz, 0.0);
     return VertexOutput(
         camera.projection * world_position,
         world_position.xyz,
         in.uv,
-        normalize(world_normal.xyz)
+        normalize(world_normal.xyz),
+        vec4<f32>(world_tangent.xyz, in.tangent.w),
     );
 }
 
 const e: vec2<f32> = vec2<f32>(1.0, 0.0);
 
-@fragment
-fn nontex_main(in: VertexInput) -> @location(0) vec4<f32> {
-    let camera_dir = normalize((camera.camera_matrix * e.yyyx).xyz - in.position);
-    let normal = normalize(in.normal);
+fn shading(position: vec3<f32>, normal: vec3<f32>, matr: Material) -> vec4<f32> {
+    let camera_dir = normalize((camera.camera_matrix * e.yyyx).xyz - position);
     var pre_color: vec3<f32> = vec3<f32>(0.0);
     for (var i: u32 = 0u; i < info.nlights; i = i + 1u) {
         pre_color = pre_color + microfacet_color(
-            in.position,
+            position,
             normal,
             lights.lights[i],
             camera_dir,
-            material.material,
+            matr,
         );
     }
+    pre_color = pre_color + matr.albedo.rgb * info.ambient.rgb;
     pre_color = clamp(pre_color, vec3<f32>(0.0), vec3<f32>(1.0));
     pre_color = background_correction(pre_color, info.bk_color.xyz, material.material);
-    pre_color = ambient_correction(pre_color, material.material);
+    pre_color = ambient_correction(pre_color, matr);
+    pre_color = pre_color + material.emissive;
+    pre_color = fog_correction(pre_color, position);
 
-    return vec4<f32>(pow(pre_color, vec3<f32>(0.4545)), material.material.albedo.a);
+    // the cutoff is zero unless the alpha mode is mask.
+    if (matr.albedo.a < material.alpha_cutoff) {
+        discard;
+    }
+    return vec4<f32>(pow(pre_color, vec3<f32>(0.4545)), matr.albedo.a);
 }
 
-@fragment
-fn tex_main(in: VertexInput) -> @location(0) vec4<f32> {
+// the distance fog by the depth in the view space
+fn fog_correction(pre_color: vec3<f32>, position: vec3<f32>) -> vec3<f32> {
+    let camera_position = (camera.camera_matrix * e.yyyx).xyz;
+    let eye_direction = -camera.camera_matrix[2].xyz;
+    let depth = dot(position - camera_position, eye_direction) - info.fog_near;
+    var ratio: f32;
+    if (info.fog_mode == 0u) {
+        let width = max(info.fog_far - info.fog_near, 1.0e-6);
+        ratio = info.fog_density * clamp(depth / width, 0.0, 1.0);
+    } else {
+        ratio = 1.0 - exp(-info.fog_density * max(depth, 0.0));
+    }
+    return mix(pre_color, info.fog_color.rgb, ratio);
+}
+
+fn texture_material(uv: vec2<f32>) -> Material {
     var matr: Material = material.material;
-    matr.albedo = textureSample(r_color, r_sampler, in.uv);
+    matr.albedo = textureSample(r_color, r_sampler, uv);
     matr.albedo = vec4<f32>(pow(matr.albedo.rgb, vec3<f32>(2.2)), matr.albedo.a);
-    let camera_dir = normalize((camera.camera_matrix * e.yyyx).xyz - in.position);
+    return matr;
+}
+
+// `texel` is the color of the tangent-space normal map: x: tangent, y: bitangent, z: normal.
+// Falls back to the vertex normal if the tangent is degenerate.
+fn mapped_normal(in: VertexInput, texel: vec3<f32>) -> vec3<f32> {
     let normal = normalize(in.normal);
-    var pre_color: vec3<f32> = vec3<f32>(0.0);
-    for (var i: u32 = 0u; i < info.nlights; i = i + 1u) {
-        pre_color = pre_color + microfacet_color(
-            in.position,
-            normal,
-            lights.lights[i],
-            camera_dir,
-            matr,
-        );
+    let tangent = in.tangent.xyz - dot(in.tangent.xyz, normal) * normal;
+    if (dot(tangent, tangent) < 1.0e-12) {
+        return normal;
     }
-    pre_color = clamp(pre_color, vec3<f32>(0.0), vec3<f32>(1.0));
-    pre_color = background_correction(pre_color, info.bk_color.xyz, material.material);
-    pre_color = ambient_correction(pre_color, matr);
+    let t = normalize(tangent);
+    let b = cross(normal, t) * in.tangent.w;
+    let n = texel * 2.0 - 1.0;
+    return normalize(n.x * t + n.y * b + n.z * normal);
+}
 
-    return vec4<f32>(pow(pre_color, vec3<f32>(0.4545)), matr.albedo.a);
+@fragment
+fn nontex_main(in: VertexInput) -> @location(0) vec4<f32> {
+    return shading(in.position, normalize(in.normal), material.material);
+}
+
+@fragment
+fn tex_main(in: VertexInput) -> @location(0) vec4<f32> {
+    return shading(in.position, normalize(in.normal), texture_material(in.uv));
+}
+
+@fragment
+fn nontex_normal_main(in: VertexInput) -> @location(0) vec4<f32> {
+    let texel = textureSample(r_nontex_normal, r_nontex_normal_sampler, in.uv).xyz;
+    return shading(in.position, mapped_normal(in, texel), material.material);
+}
+
+@fragment
+fn tex_normal_main(in: VertexInput) -> @location(0) vec4<f32> {
+    let texel = textureSample(r_normal, r_normal_sampler, in.uv).xyz;
+    return shading(in.position, mapped_normal(in, texel), texture_material(in.uv));
 }
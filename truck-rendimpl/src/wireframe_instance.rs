@@ -141,6 +141,15 @@ impl Rendered for WireFrameInstance {
         });
         Arc::new(pipeline)
     }
+    #[inline(always)]
+    fn pipeline_key(&self) -> Option<u64> {
+        Some(hash_pipeline_key((
+            self.shaders.vertex_module.global_id(),
+            self.shaders.vertex_entry,
+            self.shaders.fragment_module.global_id(),
+            self.shaders.fragment_entry,
+        )))
+    }
 }
 
 impl ToInstance<WireFrameInstance> for Vec<(Point3, Point3)> {
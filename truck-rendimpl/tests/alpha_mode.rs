@@ -0,0 +1,97 @@
+mod common;
+use truck_meshalgo::prelude::obj;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+const PLANE_OBJ: &str = "
+v -1.0 -1.0 0.0\nv 1.0 -1.0 0.0\nv 1.0 1.0 0.0\nv -1.0 1.0 0.0
+vn 0.0 0.0 1.0
+f 1//1 2//1 3//1 4//1
+";
+
+fn plane_state(z: f64, albedo: Vector4, alpha_mode: AlphaMode) -> PolygonState {
+    PolygonState {
+        matrix: Matrix4::from_translation(Vector3::new(0.0, 0.0, z)),
+        material: Material {
+            albedo,
+            alpha_mode,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn render_all(scene: &mut Scene, instances: &[&PolygonInstance]) -> Vec<u8> {
+    scene.add_objects(instances.iter().copied());
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    scene.clear_objects();
+    buffer
+}
+
+fn exec_alpha_mode_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let instance = wgpu::Instance::new(InstanceDescriptor {
+        backends: backend,
+        ..Default::default()
+    });
+    let handler = common::init_device(&instance);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: PICTURE_SIZE,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    let creator = scene.instance_creator();
+    let plane = obj::read(PLANE_OBJ.as_bytes()).unwrap();
+    let half_red = Vector4::new(1.0, 0.0, 0.0, 0.5);
+
+    let empty = render_all(&mut scene, &[]);
+    let opaque: PolygonInstance =
+        creator.create_instance(&plane, &plane_state(-2.0, half_red, AlphaMode::Opaque));
+    let opaque_buffer = render_all(&mut scene, &[&opaque]);
+    common::save_buffer(
+        out_dir.clone() + "alpha-opaque.png",
+        &opaque_buffer,
+        PICTURE_SIZE,
+    );
+    assert!(!common::same_buffer(&empty, &opaque_buffer));
+
+    // masked fragments are discarded only below the cutoff.
+    let mask = AlphaMode::Mask { cutoff: 0.6 };
+    let masked: PolygonInstance =
+        creator.create_instance(&plane, &plane_state(-2.0, half_red, mask));
+    assert!(common::same_buffer(
+        &empty,
+        &render_all(&mut scene, &[&masked])
+    ));
+    let mask = AlphaMode::Mask { cutoff: 0.4 };
+    let masked: PolygonInstance =
+        creator.create_instance(&plane, &plane_state(-2.0, half_red, mask));
+    assert!(common::same_buffer(
+        &opaque_buffer,
+        &render_all(&mut scene, &[&masked])
+    ));
+
+    // blended instances are drawn from back to front regardless of the order of addition.
+    let near: PolygonInstance =
+        creator.create_instance(&plane, &plane_state(-2.0, half_red, AlphaMode::Blend));
+    let half_blue = Vector4::new(0.0, 0.0, 1.0, 0.5);
+    let far: PolygonInstance =
+        creator.create_instance(&plane, &plane_state(-3.0, half_blue, AlphaMode::Blend));
+    let buffer0 = render_all(&mut scene, &[&near, &far]);
+    let buffer1 = render_all(&mut scene, &[&far, &near]);
+    common::save_buffer(out_dir + "alpha-blend.png", &buffer0, PICTURE_SIZE);
+    assert!(common::same_buffer(&buffer0, &buffer1));
+    assert!(!common::same_buffer(&opaque_buffer, &buffer0));
+}
+
+#[test]
+fn alpha_mode_test() { common::os_alt_exec_test(exec_alpha_mode_test) }
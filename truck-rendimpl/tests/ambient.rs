@@ -0,0 +1,66 @@
+mod common;
+use truck_meshalgo::prelude::obj;
+use truck_platform::*;
+use truck_rendimpl::*;
+
+const PICTURE_SIZE: (u32, u32) = (64, 64);
+
+const PLANE_OBJ: &str = "
+v -1.0 -1.0 -2.0\nv 1.0 -1.0 -2.0\nv 1.0 1.0 -2.0\nv -1.0 1.0 -2.0
+vn 0.0 0.0 1.0
+f 1//1 2//1 3//1 4//1
+";
+
+fn center_pixel(buffer: &[u8]) -> &[u8] {
+    let (w, h) = PICTURE_SIZE;
+    let idx = ((h / 2 * w + w / 2) * 4) as usize;
+    &buffer[idx..idx + 4]
+}
+
+#[test]
+fn ambient_test() {
+    let Some(handler) = pollster::block_on(DeviceHandler::headless()) else {
+        eprintln!("no adapter is available, skipped.");
+        return;
+    };
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            studio: StudioConfig {
+                // the light behind the plane
+                lights: vec![Light {
+                    position: Point3::new(0.0, 0.0, -10.0),
+                    color: Vector3::new(1.0, 1.0, 1.0),
+                    light_type: LightType::Point,
+                }],
+                ..Default::default()
+            },
+            render_texture: RenderTextureConfig {
+                canvas_size: PICTURE_SIZE,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    let creator = scene.instance_creator();
+    let plane = obj::read(PLANE_OBJ.as_bytes()).unwrap();
+    let state = PolygonState {
+        material: Material {
+            albedo: Vector4::new(1.0, 0.5, 0.25, 1.0),
+            ambient_ratio: 0.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let instance: PolygonInstance = creator.create_instance(&plane, &state);
+    let buffer = common::render_one(&mut scene, &instance);
+    assert_eq!(center_pixel(&buffer), [0, 0, 0, 255]);
+
+    // the albedo multiplied by the ambient color, in sRGB
+    scene.studio_config_mut().ambient = Vector3::new(0.5, 0.5, 1.0);
+    let buffer = common::render_one(&mut scene, &instance);
+    let answer = [0.5f64, 0.25, 0.25].map(|c| (c.powf(0.4545) * 255.0) as i32);
+    let pixel = center_pixel(&buffer);
+    (0..3).for_each(|i| assert!((pixel[i] as i32 - answer[i]).abs() <= 2, "{pixel:?}"));
+    assert_eq!(pixel[3], 255);
+}
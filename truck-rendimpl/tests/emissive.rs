@@ -0,0 +1,49 @@
+mod common;
+use truck_meshalgo::prelude::obj;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+const PLANE_OBJ: &str = "
+v -1.0 -1.0 -2.0\nv 1.0 -1.0 -2.0\nv 1.0 1.0 -2.0\nv -1.0 1.0 -2.0
+vn 0.0 0.0 1.0
+f 1//1 2//1 3//1 4//1
+";
+
+fn exec_emissive_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let instance = wgpu::Instance::new(InstanceDescriptor {
+        backends: backend,
+        ..Default::default()
+    });
+    let handler = common::init_device(&instance);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: PICTURE_SIZE,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    let creator = scene.instance_creator();
+    let plane = obj::read(PLANE_OBJ.as_bytes()).unwrap();
+    let mut instance: PolygonInstance = creator.create_instance(&plane, &Default::default());
+    let buffer0 = common::render_one(&mut scene, &instance);
+
+    instance.instance_state_mut().material.emissive = Vector3::new(0.0, 0.0, 0.0);
+    let buffer1 = common::render_one(&mut scene, &instance);
+    assert!(common::same_buffer(&buffer0, &buffer1));
+
+    instance.instance_state_mut().material.emissive = Vector3::new(0.5, 0.0, 0.0);
+    let buffer2 = common::render_one(&mut scene, &instance);
+    common::save_buffer(out_dir + "emissive.png", &buffer2, PICTURE_SIZE);
+    assert!(!common::same_buffer(&buffer0, &buffer2));
+}
+
+#[test]
+fn emissive_test() { common::os_alt_exec_test(exec_emissive_test) }
@@ -0,0 +1,81 @@
+mod common;
+use truck_meshalgo::prelude::obj;
+use truck_platform::*;
+use truck_rendimpl::*;
+
+const PICTURE_SIZE: (u32, u32) = (64, 64);
+
+// the plane whose depth is 2.0 from the default camera
+const PLANE_OBJ: &str = "
+v -1.0 -1.0 -2.0\nv 1.0 -1.0 -2.0\nv 1.0 1.0 -2.0\nv -1.0 1.0 -2.0
+vn 0.0 0.0 1.0
+f 1//1 2//1 3//1 4//1
+";
+
+fn center_pixel(buffer: &[u8]) -> [u8; 4] {
+    let (w, h) = PICTURE_SIZE;
+    let idx = ((h / 2 * w + w / 2) * 4) as usize;
+    [0, 1, 2, 3].map(|i| buffer[idx + i])
+}
+
+#[test]
+fn fog_test() {
+    let Some(handler) = pollster::block_on(DeviceHandler::headless()) else {
+        eprintln!("no adapter is available, skipped.");
+        return;
+    };
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            studio: StudioConfig {
+                // no lights, so the plane is black without fog.
+                lights: Vec::new(),
+                fog_color: Vector3::new(1.0, 0.0, 0.0),
+                ..Default::default()
+            },
+            render_texture: RenderTextureConfig {
+                canvas_size: PICTURE_SIZE,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    let creator = scene.instance_creator();
+    let plane = obj::read(PLANE_OBJ.as_bytes()).unwrap();
+    let state = PolygonState {
+        material: Material {
+            ambient_ratio: 0.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let instance: PolygonInstance = creator.create_instance(&plane, &state);
+    let buffer = common::render_one(&mut scene, &instance);
+    assert_eq!(center_pixel(&buffer), [0, 0, 0, 255]);
+
+    // the half of the fog, in sRGB
+    let half = (0.5f64.powf(0.4545) * 255.0) as i32;
+    let assert_half_fog = |pixel: [u8; 4]| {
+        assert!((pixel[0] as i32 - half).abs() <= 2, "{pixel:?}");
+        assert_eq!(pixel[1..], [0, 0, 255]);
+    };
+
+    let studio = scene.studio_config_mut();
+    studio.fog_mode = FogMode::Linear;
+    (studio.fog_density, studio.fog_near, studio.fog_far) = (1.0, 1.0, 3.0);
+    assert_half_fog(center_pixel(&common::render_one(&mut scene, &instance)));
+
+    // the linear fog is saturated at `fog_far`.
+    let studio = scene.studio_config_mut();
+    (studio.fog_density, studio.fog_near, studio.fog_far) = (0.5, 0.0, 1.0);
+    assert_half_fog(center_pixel(&common::render_one(&mut scene, &instance)));
+
+    let studio = scene.studio_config_mut();
+    studio.fog_mode = FogMode::Exponential;
+    (studio.fog_density, studio.fog_near) = (f64::ln(2.0), 1.0);
+    assert_half_fog(center_pixel(&common::render_one(&mut scene, &instance)));
+
+    scene.studio_config_mut().fog_density = 0.0;
+    let buffer = common::render_one(&mut scene, &instance);
+    assert_eq!(center_pixel(&buffer), [0, 0, 0, 255]);
+}
@@ -0,0 +1,50 @@
+mod common;
+use image::{DynamicImage, ImageBuffer, Rgba};
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+fn exec_upload_small_image_test(backend: Backends, _: &str) {
+    let instance = wgpu::Instance::new(InstanceDescriptor {
+        backends: backend,
+        ..Default::default()
+    });
+    let handler = common::init_device(&instance);
+    // The rows of 3 x 3 RGBA image have 12 bytes, which is not aligned to 256.
+    let image_buffer =
+        ImageBuffer::from_fn(3, 3, |i, j| Rgba([(i * 80) as u8, (j * 80) as u8, 0, 255]));
+    handler.device().push_error_scope(ErrorFilter::Validation);
+    let texture = image2texture::image2texture(&handler, &DynamicImage::ImageRgba8(image_buffer));
+    handler.device().poll(Maintain::Wait);
+    let error = pollster::block_on(handler.device().pop_error_scope());
+    assert!(error.is_none(), "{error:?}");
+    assert_eq!(texture.width(), 3);
+    assert_eq!(texture.height(), 3);
+    assert_eq!(texture.format(), TextureFormat::Rgba8Unorm);
+}
+
+#[test]
+fn upload_small_image_test() { common::os_alt_exec_test(exec_upload_small_image_test) }
+
+fn exec_generate_mipmaps_test(backend: Backends, _: &str) {
+    let instance = wgpu::Instance::new(InstanceDescriptor {
+        backends: backend,
+        ..Default::default()
+    });
+    let handler = common::init_device(&instance);
+    let image_buffer = ImageBuffer::from_fn(5, 2, |i, _| Rgba([(i * 50) as u8, 0, 0, 255]));
+    let image = DynamicImage::ImageRgba8(image_buffer);
+    handler.device().push_error_scope(ErrorFilter::Validation);
+    let texture = image2texture::image2texture_with_mips(&handler, &image);
+    assert_eq!(texture.mip_level_count(), 3);
+    let capped = image2texture::image2texture_with_mip_level_count(&handler, &image, 2);
+    assert_eq!(capped.mip_level_count(), 2);
+    let clamped = image2texture::image2texture_with_mip_level_count(&handler, &image, 100);
+    assert_eq!(clamped.mip_level_count(), 3);
+    handler.device().poll(Maintain::Wait);
+    let error = pollster::block_on(handler.device().pop_error_scope());
+    assert!(error.is_none(), "{error:?}");
+}
+
+#[test]
+fn generate_mipmaps_test() { common::os_alt_exec_test(exec_generate_mipmaps_test) }
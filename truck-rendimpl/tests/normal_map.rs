@@ -0,0 +1,72 @@
+mod common;
+use image::{DynamicImage, ImageBuffer, Rgba};
+use std::sync::Arc;
+use truck_meshalgo::prelude::obj;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+const PLANE_OBJ: &str = "
+v -1.0 -1.0 -2.0\nv 1.0 -1.0 -2.0\nv 1.0 1.0 -2.0\nv -1.0 1.0 -2.0
+vt 0.0 1.0\nvt 1.0 1.0\nvt 1.0 0.0\nvt 0.0 0.0
+vn 0.0 0.0 1.0
+f 1/1/1 2/2/1 3/3/1 4/4/1
+";
+
+fn normal_map(handler: &DeviceHandler, color: [u8; 3]) -> Arc<Texture> {
+    let [r, g, b] = color;
+    let image = ImageBuffer::from_pixel(4, 4, Rgba([r, g, b, 255]));
+    let texture = image2texture::image2texture(handler, &DynamicImage::ImageRgba8(image));
+    Arc::new(texture)
+}
+
+fn exec_normal_map_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let instance = wgpu::Instance::new(InstanceDescriptor {
+        backends: backend,
+        ..Default::default()
+    });
+    let handler = common::init_device(&instance);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: PICTURE_SIZE,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    let creator = scene.instance_creator();
+    let plane = obj::read(PLANE_OBJ.as_bytes()).unwrap();
+    let mut instance: PolygonInstance = creator.create_instance(&plane, &Default::default());
+    let buffer0 = common::render_one(&mut scene, &instance);
+    common::save_buffer(
+        out_dir.clone() + "without-normal-map.png",
+        &buffer0,
+        PICTURE_SIZE,
+    );
+
+    // the flat normal map does not change the normals.
+    instance.instance_state_mut().normal_texture =
+        Some(normal_map(scene.device_handler(), [128, 128, 255]));
+    let buffer1 = common::render_one(&mut scene, &instance);
+    common::save_buffer(
+        out_dir.clone() + "flat-normal-map.png",
+        &buffer1,
+        PICTURE_SIZE,
+    );
+    assert!(common::same_buffer(&buffer0, &buffer1));
+
+    instance.instance_state_mut().normal_texture =
+        Some(normal_map(scene.device_handler(), [218, 128, 218]));
+    let buffer2 = common::render_one(&mut scene, &instance);
+    common::save_buffer(out_dir + "tilted-normal-map.png", &buffer2, PICTURE_SIZE);
+    assert!(!common::same_buffer(&buffer0, &buffer2));
+}
+
+#[test]
+fn normal_map_test() { common::os_alt_exec_test(exec_normal_map_test) }
@@ -0,0 +1,71 @@
+mod common;
+use truck_meshalgo::prelude::obj;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+const PLANE_OBJ: &str = "
+v -1.0 -1.0 0.0\nv 1.0 -1.0 0.0\nv 1.0 1.0 0.0\nv -1.0 1.0 0.0
+vn 0.0 0.0 1.0
+f 1//1 2//1 3//1 4//1
+";
+
+fn exec_pipeline_cache_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let instance = wgpu::Instance::new(InstanceDescriptor {
+        backends: backend,
+        ..Default::default()
+    });
+    let handler = common::init_device(&instance);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: PICTURE_SIZE,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    let creator = scene.instance_creator();
+    let plane = obj::read(PLANE_OBJ.as_bytes()).unwrap();
+    let state = PolygonState {
+        matrix: Matrix4::from_translation(Vector3::new(0.0, 0.0, -2.0)),
+        ..Default::default()
+    };
+    let instance0: PolygonInstance = creator.create_instance(&plane, &state);
+    let mut instance1 = instance0.clone_instance();
+
+    scene.add_object(&instance0);
+    assert_eq!(scene.pipeline_cache_hits(), 0);
+    let buffer0 = pollster::block_on(scene.render_to_buffer());
+    scene.add_object(&instance1);
+    assert_eq!(scene.pipeline_cache_hits(), 1);
+    let buffer1 = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(out_dir + "pipeline-cache.png", &buffer1, PICTURE_SIZE);
+    assert!(common::same_buffer(&buffer0, &buffer1));
+
+    // another pipeline is created for another blend state.
+    instance1.instance_state_mut().material.alpha_mode = AlphaMode::Blend;
+    scene.update_pipeline(&instance1);
+    assert_eq!(scene.pipeline_cache_hits(), 1);
+    let instance2 = instance1.clone_instance();
+    scene.add_object(&instance2);
+    assert_eq!(scene.pipeline_cache_hits(), 2);
+    pollster::block_on(scene.render_to_buffer());
+
+    // the wireframes do not share the pipelines with the polygons.
+    let wireframe: WireFrameInstance = creator.create_instance(&plane, &Default::default());
+    scene.add_object(&wireframe);
+    assert_eq!(scene.pipeline_cache_hits(), 2);
+
+    scene.clear_pipeline_cache();
+    scene.add_object(&instance0.clone_instance());
+    assert_eq!(scene.pipeline_cache_hits(), 2);
+}
+
+#[test]
+fn pipeline_cache_test() { common::os_alt_exec_test(exec_pipeline_cache_test) }
@@ -0,0 +1,57 @@
+mod common;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+fn exec_point_cloud_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let instance = wgpu::Instance::new(InstanceDescriptor {
+        backends: backend,
+        ..Default::default()
+    });
+    let handler = common::init_device(&instance);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            render_texture: RenderTextureConfig {
+                canvas_size: PICTURE_SIZE,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    let creator = scene.instance_creator();
+    let empty = pollster::block_on(scene.render_to_buffer());
+
+    let points: Vec<(Point3, Vector3)> = (0..10)
+        .flat_map(|i| (0..10).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let (x, y) = (i as f64 / 9.0, j as f64 / 9.0);
+            let point = Point3::new(2.0 * x - 1.0, 2.0 * y - 1.0, -2.0);
+            (point, Vector3::new(x, y, 1.0))
+        })
+        .collect();
+    let mut instance: PointCloudInstance = creator.create_instance(&points, &Default::default());
+    let buffer0 = common::render_one(&mut scene, &instance);
+    common::save_buffer(out_dir.clone() + "point-cloud.png", &buffer0, PICTURE_SIZE);
+    assert!(!common::same_buffer(&empty, &buffer0));
+
+    // larger points cover more pixels.
+    instance.instance_state_mut().point_size = 10.0;
+    let buffer1 = common::render_one(&mut scene, &instance);
+    common::save_buffer(out_dir + "large-point-cloud.png", &buffer1, PICTURE_SIZE);
+    let count = |buffer: &[u8]| {
+        buffer
+            .chunks(4)
+            .zip(empty.chunks(4))
+            .filter(|(a, b)| a != b)
+            .count()
+    };
+    assert!(count(&buffer0) < count(&buffer1));
+}
+
+#[test]
+fn point_cloud_test() { common::os_alt_exec_test(exec_point_cloud_test) }
@@ -77,9 +77,11 @@ fn nontex_inst_state() -> PolygonState {
             reflectance: 0.29613,
             ambient_ratio: 0.92,
             background_ratio: 0.32,
-            alpha_blend: false,
+            alpha_mode: AlphaMode::Opaque,
+            emissive: Vector3::zero(),
         },
         texture: None,
+        normal_texture: None,
         backface_culling: true,
     }
 }
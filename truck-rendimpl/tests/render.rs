@@ -75,7 +75,8 @@ fn nontex_polygon(scene: &mut Scene, creator: &InstanceCreator) -> Vec<u8> {
                 reflectance: 0.25,
                 ambient_ratio: 0.02,
                 background_ratio: 0.0,
-                alpha_blend: false,
+                alpha_mode: AlphaMode::Opaque,
+                emissive: Vector3::zero(),
             },
             ..Default::default()
         },
@@ -137,7 +138,8 @@ fn tex_polygon(
                 reflectance: 0.25,
                 ambient_ratio: 0.02,
                 background_ratio: 0.0,
-                alpha_blend: false,
+                alpha_mode: AlphaMode::Opaque,
+                emissive: Vector3::zero(),
             },
             texture: Some(attach),
             ..Default::default()
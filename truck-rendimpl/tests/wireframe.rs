@@ -0,0 +1,30 @@
+mod common;
+use truck_meshalgo::prelude::obj;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const QUADS_OBJ: &str = "
+v 0.0 0.0 0.0\nv 1.0 0.0 0.0\nv 2.0 0.0 0.0\nv 0.0 1.0 0.0\nv 1.0 1.0 0.0\nv 2.0 1.0 0.0
+f 1 2 5 4\nf 2 3 6 5
+";
+
+fn exec_unique_edges_test(backend: Backends, _: &str) {
+    let instance = wgpu::Instance::new(InstanceDescriptor {
+        backends: backend,
+        ..Default::default()
+    });
+    let handler = common::init_device(&instance);
+    let scene = Scene::new(handler, &Default::default());
+    let mesh = obj::read(QUADS_OBJ.as_bytes()).unwrap();
+    let wireframe: WireFrameInstance = scene
+        .instance_creator()
+        .create_instance(&mesh, &Default::default());
+    let (_, strips) = wireframe.vertex_buffer(scene.device_handler());
+    // two quadrangles sharing one edge have seven edges.
+    let strips = strips.unwrap();
+    assert_eq!(strips.size(), 7 * 2 * std::mem::size_of::<u32>() as u64);
+}
+
+#[test]
+fn unique_edges_test() { common::os_alt_exec_test(exec_unique_edges_test) }
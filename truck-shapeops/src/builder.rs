@@ -0,0 +1,118 @@
+use crate::{errors::Error, *};
+use truck_base::{cgmath64::Point3, tolerance::TOLERANCE};
+use truck_topology::Solid;
+
+fn operate<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    operation: fn(&Solid<Point3, C, S>, &Solid<Point3, C, S>, f64) -> Option<Solid<Point3, C, S>>,
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Result<Solid<Point3, C, S>> {
+    if tol < TOLERANCE {
+        return Err(Error::TooSmallTolerance);
+    }
+    operation(solid0, solid1, tol).ok_or(Error::FailedToOperate)
+}
+
+/// Returns the union of two solids.
+///
+/// # Failures
+/// - If `tol` is less than `TOLERANCE`, returns [`Error::TooSmallTolerance`].
+/// - If the operation fails, e.g. the solids have coplanar faces, returns
+/// [`Error::FailedToOperate`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::{builder as shapeops, errors::Error};
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let moved = builder::translated(&cube, Vector3::new(0.5, 0.5, 0.5));
+///
+/// let union = shapeops::union(&cube, &moved, 0.05).unwrap();
+/// // three faces of each cube are cut into L-shapes, and the others are kept.
+/// assert_eq!(union.boundaries()[0].len(), 12);
+///
+/// assert_eq!(shapeops::union(&cube, &moved, 0.0), Err(Error::TooSmallTolerance));
+/// ```
+#[inline(always)]
+pub fn union<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Result<Solid<Point3, C, S>> {
+    operate(or, solid0, solid1, tol)
+}
+
+/// Returns the intersection of two solids.
+///
+/// # Failures
+/// - If `tol` is less than `TOLERANCE`, returns [`Error::TooSmallTolerance`].
+/// - If the operation fails, e.g. the solids have coplanar faces, returns
+/// [`Error::FailedToOperate`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::builder as shapeops;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let moved = builder::translated(&cube, Vector3::new(0.5, 0.5, 0.5));
+///
+/// let intersection = shapeops::intersection(&cube, &moved, 0.05).unwrap();
+/// // the cube [0.5, 1]^3
+/// assert_eq!(intersection.boundaries()[0].len(), 6);
+/// intersection.vertex_iter().for_each(|v| {
+///     let pt = v.point();
+///     assert!([pt.x, pt.y, pt.z].iter().all(|x| x.near(&0.5) || x.near(&1.0)));
+/// });
+/// ```
+#[inline(always)]
+pub fn intersection<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Result<Solid<Point3, C, S>> {
+    operate(and, solid0, solid1, tol)
+}
+
+/// Returns the difference of two solids, the part of `solid0` outside `solid1`.
+///
+/// # Failures
+/// - If `tol` is less than `TOLERANCE`, returns [`Error::TooSmallTolerance`].
+/// - If the operation fails, e.g. the solids have coplanar faces, returns
+/// [`Error::FailedToOperate`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::{builder as shapeops, errors::Error};
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let moved = builder::translated(&cube, Vector3::new(0.5, 0.5, 0.5));
+///
+/// let difference = shapeops::difference(&cube, &moved, 0.05).unwrap();
+/// // three faces cut into L-shapes, three faces kept, and three faces from `moved`
+/// assert_eq!(difference.boundaries()[0].len(), 9);
+///
+/// // the coplanar faces are not supported.
+/// let shifted = builder::translated(&cube, Vector3::new(0.5, 0.0, 0.0));
+/// assert_eq!(
+///     shapeops::difference(&cube, &shifted, 0.05),
+///     Err(Error::FailedToOperate),
+/// );
+/// ```
+#[inline(always)]
+pub fn difference<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Result<Solid<Point3, C, S>> {
+    operate(crate::difference, solid0, solid1, tol)
+}
@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+/// Shape operation errors
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum Error {
+    /// the tolerance is less than `TOLERANCE`.
+    #[error("The tolerance must be at least TOLERANCE.")]
+    TooSmallTolerance,
+    /// the intersection curves cannot be traced or the faces cannot be classified, e.g. the
+    /// solids have coplanar faces. cf. [the crate documentation](../index.html)
+    #[error("Failed to trace the intersection curves or to classify the faces.")]
+    FailedToOperate,
+}
+
+#[test]
+fn print_messages() {
+    use std::io::Write;
+    writeln!(
+        &mut std::io::stderr(),
+        "****** test of the expressions of error messages ******\n"
+    )
+    .unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::TooSmallTolerance).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::FailedToOperate).unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "*******************************************************"
+    )
+    .unwrap();
+}
@@ -1,4 +1,19 @@
 //! Crate for operationg shapes. Provides boolean operations to Solid, and shape healing for importing shapes from other CAD systems.
+//!
+//! # Boolean operations
+//! [`and`], [`or`] and [`difference`] trace the intersection curves of the faces of two solids,
+//! divide the faces along them, and classify the divided faces inside or outside the other solid.
+//! They return `None` on failure, and the functions in [`builder`] wrap them returning
+//! [`errors::Error`].
+//!
+//! `tol` is used both as the tessellation tolerance of the faces and as the distance under which
+//! two points of the intersection curves are identified. Geometric features smaller than `tol`
+//! cannot be resolved, and `tol` must be at least `TOLERANCE`.
+//!
+//! The operations fail if the intersection curves cannot be traced or the faces cannot be
+//! classified. In particular, the following configurations are not supported:
+//! - coplanar or coincident faces shared by the two solids,
+//! - vertices of one solid lying on the boundary of the other solid within `tol`.
 
 #![cfg_attr(not(debug_assertions), deny(warnings))]
 #![deny(clippy::all, rust_2018_idioms)]
@@ -13,8 +28,15 @@
     unused_qualifications
 )]
 
+/// the boolean operations returning `Result`
+pub mod builder;
+/// declare errors
+pub mod errors;
 mod healing;
 pub use healing::{RobustSplitClosedEdgesAndFaces, SplitClosedEdgesAndFaces};
 mod transversal;
-pub use transversal::{and, or, ShapeOpsCurve, ShapeOpsSurface};
+pub use transversal::{and, difference, or, ShapeOpsCurve, ShapeOpsSurface};
 mod alternative;
+
+/// `Result` with crate's errors.
+pub type Result<T> = std::result::Result<T, errors::Error>;
@@ -55,6 +55,9 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     nonpositive_tolerance!(tol);
     let poly_shell0 = shell0.triangulation(tol);
     let poly_shell1 = shell1.triangulation(tol);
+    // the inverted shell bounds the complement of a solid, which contains the infinity.
+    let offset0 = isize::from(poly_shell0.to_polygon().volume() < 0.0);
+    let offset1 = isize::from(poly_shell1.to_polygon().volume() < 0.0);
     let loops_store::LoopsStoreQuadruple {
         geom_loops_store0: loops_store0,
         geom_loops_store1: loops_store1,
@@ -70,9 +73,10 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
         let dir = hash::take_one_unit(pt);
         let count = poly_shell1.iter().try_fold(0, |count, face| {
             let poly = face.surface()?;
-            Some(count + poly.signed_crossing_faces(pt, dir))
+            let sign = if face.orientation() { 1 } else { -1 };
+            Some(count + sign * poly.signed_crossing_faces(pt, dir))
         })?;
-        if count >= 1 {
+        if count + offset1 >= 1 {
             and0.push(face);
         } else {
             or0.push(face);
@@ -85,9 +89,10 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
         let dir = hash::take_one_unit(pt);
         let count = poly_shell0.iter().try_fold(0, |count, face| {
             let poly = face.surface()?;
-            Some(count + poly.signed_crossing_faces(pt, dir))
+            let sign = if face.orientation() { 1 } else { -1 };
+            Some(count + sign * poly.signed_crossing_faces(pt, dir))
         })?;
-        if count >= 1 {
+        if count + offset0 >= 1 {
             and1.push(face);
         } else {
             or1.push(face);
@@ -99,7 +104,7 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     Some([and0, or0])
 }
 
-/// AND operation between two solids.
+/// AND operation between two solids, i.e. the intersection.
 pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
@@ -122,7 +127,7 @@ pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     Some(Solid::new(boundaries))
 }
 
-/// OR operation between two solids.
+/// OR operation between two solids, i.e. the union.
 pub fn or<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
@@ -145,5 +150,17 @@ pub fn or<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     Some(Solid::new(boundaries))
 }
 
+/// Difference of two solids, the part of `solid0` outside `solid1`.
+#[inline(always)]
+pub fn difference<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>> {
+    let mut solid1 = solid1.clone();
+    solid1.not();
+    and(solid0, &solid1, tol)
+}
+
 #[cfg(test)]
 mod tests;
@@ -19,3 +19,65 @@ fn punched_cube() {
     let file = std::fs::File::create("punched-cube.obj").unwrap();
     obj::write(&poly, file).unwrap();
 }
+
+#[test]
+fn cube_minus_cylinder() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::unit_z());
+
+    let v = builder::vertex(Point3::new(0.5, 0.25, -0.5));
+    let w = builder::rsweep(&v, Point3::new(0.5, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let cylinder = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    let diff = crate::difference(&cube, &cylinder, 0.05).unwrap();
+    assert_eq!(diff.boundaries().len(), 1);
+
+    let poly = diff.triangulation(0.01).to_polygon();
+    poly.positions().iter().for_each(|pt| {
+        assert!((0.0..=1.0).contains(&pt.x) && (0.0..=1.0).contains(&pt.y));
+        let radius = Vector2::new(pt.x - 0.5, pt.y - 0.5).magnitude();
+        assert!(radius > 0.25 - 0.05, "{pt:?}");
+    });
+}
+
+fn unit_cube() -> Solid {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::unit_z())
+}
+
+#[test]
+fn difference_of_disjoint_solids() {
+    let cube = unit_cube();
+    let far = builder::translated(&cube, Vector3::new(2.0, 0.5, 0.5));
+    let diff = crate::difference(&cube, &far, 0.05).unwrap();
+    assert_eq!(diff.boundaries().len(), 1);
+    assert_eq!(diff.boundaries()[0].len(), 6);
+    diff.vertex_iter().for_each(|v| {
+        let pt = v.point();
+        assert!([pt.x, pt.y, pt.z].iter().all(|x| (0.0..=1.0).contains(x)));
+    });
+}
+
+#[test]
+fn difference_making_cavity() {
+    let cube = unit_cube();
+    let small = builder::scaled(&cube, Point3::origin(), Vector3::new(0.5, 0.5, 0.5));
+    let small = builder::translated(&small, Vector3::new(0.25, 0.25, 0.25));
+    let diff = crate::difference(&cube, &small, 0.05).unwrap();
+    let boundaries = diff.boundaries();
+    assert_eq!(boundaries.len(), 2);
+    let volume = diff.triangulation(0.01).to_polygon().volume();
+    assert!(f64::abs(volume - 0.875) < 1.0e-6, "{volume}");
+}
+
+#[test]
+fn difference_of_coplanar_solids() {
+    // the faces at y = 0, y = 1, z = 0 and z = 1 are coplanar, which is not supported.
+    let cube = unit_cube();
+    let shifted = builder::translated(&cube, Vector3::new(0.5, 0.0, 0.0));
+    assert!(crate::difference(&cube, &shifted, 0.05).is_none());
+}
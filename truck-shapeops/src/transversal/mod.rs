@@ -4,4 +4,4 @@ mod integrate;
 mod intersection_curve;
 mod loops_store;
 mod polyline_construction;
-pub use integrate::{and, or, ShapeOpsCurve, ShapeOpsSurface};
+pub use integrate::{and, difference, or, ShapeOpsCurve, ShapeOpsSurface};
@@ -1,7 +1,7 @@
 #![allow(missing_docs, unused_qualifications)]
 
 use ruststep::{
-    ast::{DataSection, EntityInstance, Name, Parameter, SubSuperRecord},
+    ast::{DataSection, EntityInstance, Name, Parameter, Record, SubSuperRecord},
     primitive::Logical,
     tables::{EntityTable, IntoOwned, PlaceHolder},
     Holder,
@@ -30,6 +30,7 @@ pub struct Table {
     // curve
     pub line: HashMap<u64, LineHolder>,
     pub polyline: HashMap<u64, PolylineHolder>,
+    pub trimmed_curve: HashMap<u64, TrimmedCurveHolder>,
     pub b_spline_curve_with_knots: HashMap<u64, BSplineCurveWithKnotsHolder>,
     pub bezier_curve: HashMap<u64, BezierCurveHolder>,
     pub quasi_uniform_curve: HashMap<u64, QuasiUniformCurveHolder>,
@@ -66,10 +67,16 @@ pub struct Table {
     pub oriented_face: HashMap<u64, OrientedFaceHolder>,
     pub shell: HashMap<u64, ShellHolder>,
     pub oriented_shell: HashMap<u64, OrientedShellHolder>,
+    pub manifold_solid_brep: HashMap<u64, ManifoldSolidBrepHolder>,
 
     // others
     pub definitional_representation: HashMap<u64, DefinitionalRepresentationHolder>,
 
+    // units
+    pub length_unit: HashMap<u64, LengthUnit>,
+    pub length_measure_with_unit: HashMap<u64, LengthMeasureWithUnit>,
+    pub global_unit_assigned_context: HashMap<u64, Vec<u64>>,
+
     // dummy
     pub dummy: HashMap<u64, DummyHolder>,
 }
@@ -112,6 +119,10 @@ impl Table {
                 "POLYLINE" => {
                     self.polyline.insert(*id, Deserialize::deserialize(record)?);
                 }
+                "TRIMMED_CURVE" => {
+                    self.trimmed_curve
+                        .insert(*id, Deserialize::deserialize(record)?);
+                }
                 "B_SPLINE_CURVE_WITH_KNOTS" => {
                     self.b_spline_curve_with_knots
                         .insert(*id, Deserialize::deserialize(record)?);
@@ -288,6 +299,16 @@ impl Table {
                         }
                     }
                 }
+                "MANIFOLD_SOLID_BREP" => {
+                    self.manifold_solid_brep
+                        .insert(*id, Deserialize::deserialize(record)?);
+                }
+                "LENGTH_MEASURE_WITH_UNIT" => {
+                    if let Some(measure) = LengthMeasureWithUnit::from_parameter(&record.parameter)
+                    {
+                        self.length_measure_with_unit.insert(*id, measure);
+                    }
+                }
                 "DEFINITIONAL_REPRESENTATION" => {
                     if let Parameter::List(params) = &record.parameter {
                         if params.len() == 3 {
@@ -324,6 +345,7 @@ impl Table {
             } => {
                 use NonRationalBSplineCurveHolder as NRBC;
                 use NonRationalBSplineSurfaceHolder as NRBS;
+                self.push_units(*id, records);
                 if records.len() == 7 {
                     match (
                         records[0].name.as_str(),
@@ -606,6 +628,30 @@ impl Table {
         }
         Ok(())
     }
+    /// Records the length units and the unit contexts in complex entities.
+    /// The records are also kept in the dummy table since they may be referred as contexts.
+    fn push_units(&mut self, id: u64, records: &[Record]) {
+        if let Some(unit) = LengthUnit::from_records(records) {
+            self.length_unit.insert(id, unit);
+        }
+        let context = records
+            .iter()
+            .find(|record| record.name == "GLOBAL_UNIT_ASSIGNED_CONTEXT");
+        if let Some(record) = context {
+            if let Parameter::List(params) = &record.parameter {
+                if let Some(Parameter::List(units)) = params.first() {
+                    let units = units
+                        .iter()
+                        .filter_map(|unit| match unit {
+                            Parameter::Ref(Name::Entity(idx)) => Some(*idx),
+                            _ => None,
+                        })
+                        .collect();
+                    self.global_unit_assigned_context.insert(id, units);
+                }
+            }
+        }
+    }
     #[inline(always)]
     pub fn from_data_section(data_section: &DataSection) -> Table {
         Table::from_iter(&data_section.entities)
@@ -615,19 +661,126 @@ impl Table {
         let exchange = ruststep::parser::parse(step_str).ok()?;
         Some(Table::from_data_section(&exchange.data[0]))
     }
+    /// Creates the table, and returns the IDs of the failed entities with the error messages.
+    ///
+    /// # Remarks
+    /// The entity instances of `ruststep` do not hold their positions in the source,
+    /// so the messages are prefixed by the entity names instead of the line numbers.
+    pub fn from_iter_collect_errors<'a, I: IntoIterator<Item = &'a EntityInstance>>(
+        iter: I,
+    ) -> (Table, Vec<(u64, String)>) {
+        let mut res = Table::default();
+        let errors = iter
+            .into_iter()
+            .filter_map(|instance| {
+                let e = res.push_instance(instance).err()?;
+                Some(match instance {
+                    EntityInstance::Simple { id, record } => (*id, format!("{}: {e}", record.name)),
+                    EntityInstance::Complex { id, .. } => (*id, format!("complex entity: {e}")),
+                })
+            })
+            .collect();
+        (res, errors)
+    }
 }
 
 impl<'a> FromIterator<&'a EntityInstance> for Table {
     fn from_iter<I: IntoIterator<Item = &'a EntityInstance>>(iter: I) -> Table {
-        let mut res = Table::default();
-        iter.into_iter().for_each(|instance| {
-            res.push_instance(instance)
-                .unwrap_or_else(|e| eprintln!("{e}"))
-        });
+        let (res, errors) = Table::from_iter_collect_errors(iter);
+        errors
+            .into_iter()
+            .for_each(|(id, message)| eprintln!("#{id} {message}"));
         res
     }
 }
 
+/// The length unit given by the complex entity including `LENGTH_UNIT`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LengthUnit {
+    /// `SI_UNIT` with the ratio to the metre given by the prefix, e.g. `0.001` for `.MILLI.`.
+    SiUnit(f64),
+    /// `CONVERSION_BASED_UNIT` with the index of `LENGTH_MEASURE_WITH_UNIT`.
+    ConversionBasedUnit(u64),
+}
+
+fn si_prefix_ratio(prefix: &str) -> Option<f64> {
+    let exp = match prefix {
+        "EXA" => 18,
+        "PETA" => 15,
+        "TERA" => 12,
+        "GIGA" => 9,
+        "MEGA" => 6,
+        "KILO" => 3,
+        "HECTO" => 2,
+        "DECA" => 1,
+        "DECI" => -1,
+        "CENTI" => -2,
+        "MILLI" => -3,
+        "MICRO" => -6,
+        "NANO" => -9,
+        "PICO" => -12,
+        "FEMTO" => -15,
+        "ATTO" => -18,
+        _ => return None,
+    };
+    Some(f64::powi(10.0, exp))
+}
+
+impl LengthUnit {
+    fn from_records(records: &[Record]) -> Option<Self> {
+        let find = |name: &str| records.iter().find(|record| record.name == name);
+        find("LENGTH_UNIT")?;
+        if let Some(record) = find("SI_UNIT") {
+            let Parameter::List(params) = &record.parameter else {
+                return None;
+            };
+            match params.first()? {
+                Parameter::Enumeration(prefix) => Some(Self::SiUnit(si_prefix_ratio(prefix)?)),
+                _ => Some(Self::SiUnit(1.0)),
+            }
+        } else {
+            let Parameter::List(params) = &find("CONVERSION_BASED_UNIT")?.parameter else {
+                return None;
+            };
+            match params.get(1)? {
+                Parameter::Ref(Name::Entity(idx)) => Some(Self::ConversionBasedUnit(*idx)),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// `LENGTH_MEASURE_WITH_UNIT`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LengthMeasureWithUnit {
+    pub value_component: f64,
+    pub unit_component: u64,
+}
+
+impl LengthMeasureWithUnit {
+    fn from_parameter(parameter: &Parameter) -> Option<Self> {
+        let Parameter::List(params) = parameter else {
+            return None;
+        };
+        let value = match params.first()? {
+            Parameter::Typed { parameter, .. } => parameter.as_ref(),
+            value => value,
+        };
+        let value_component = match value {
+            Parameter::Real(x) => *x,
+            Parameter::Integer(x) => *x as f64,
+            _ => return None,
+        };
+        match params.get(1)? {
+            Parameter::Ref(Name::Entity(idx)) => Some(Self {
+                value_component,
+                unit_component: *idx,
+            }),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Holder)]
 #[holder(table = Table)]
 #[holder(field = dummy)]
@@ -936,6 +1089,8 @@ pub enum BoundedCurveAny {
     Polyline(Box<Polyline>),
     #[holder(use_place_holder)]
     BSplineCurve(Box<BSplineCurveAny>),
+    #[holder(use_place_holder)]
+    TrimmedCurve(Box<TrimmedCurve>),
 }
 
 impl TryFrom<&BoundedCurveAny> for Curve2D {
@@ -946,6 +1101,7 @@ impl TryFrom<&BoundedCurveAny> for Curve2D {
         Ok(match value {
             Polyline(x) => Self::Polyline(x.as_ref().into()),
             BSplineCurve(x) => x.as_ref().try_into()?,
+            TrimmedCurve(x) => x.as_ref().try_into()?,
         })
     }
 }
@@ -958,6 +1114,7 @@ impl TryFrom<&BoundedCurveAny> for Curve3D {
         Ok(match value {
             Polyline(x) => Self::Polyline(x.as_ref().into()),
             BSplineCurve(x) => x.as_ref().try_into()?,
+            TrimmedCurve(x) => x.as_ref().try_into()?,
         })
     }
 }
@@ -976,6 +1133,148 @@ impl<'a, P: From<&'a CartesianPoint>> From<&'a Polyline> for PolylineCurve<P> {
     fn from(poly: &'a Polyline) -> Self { Self(poly.points.iter().map(|pt| P::from(pt)).collect()) }
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Holder)]
+#[holder(table = Table)]
+#[holder(generate_deserialize)]
+pub enum TrimmingSelect {
+    #[holder(use_place_holder)]
+    CartesianPoint(CartesianPoint),
+    ParameterValue(f64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrimmingPreference {
+    Cartesian,
+    Parameter,
+    Unspecified,
+}
+
+#[test]
+fn deserialize_trimming_preference() {
+    let (_, p) = ruststep::parser::exchange::parameter(".CARTESIAN.").unwrap();
+    let x = TrimmingPreference::deserialize(&p).unwrap();
+    assert!(matches!(x, TrimmingPreference::Cartesian));
+    let (_, p) = ruststep::parser::exchange::parameter(".PARAMETER.").unwrap();
+    let x = TrimmingPreference::deserialize(&p).unwrap();
+    assert!(matches!(x, TrimmingPreference::Parameter));
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Holder)]
+#[holder(table = Table)]
+#[holder(field = trimmed_curve)]
+#[holder(generate_deserialize)]
+pub struct TrimmedCurve {
+    pub label: String,
+    #[holder(use_place_holder)]
+    pub basis_curve: CurveAny,
+    #[holder(use_place_holder)]
+    pub trim_1: Vec<TrimmingSelect>,
+    #[holder(use_place_holder)]
+    pub trim_2: Vec<TrimmingSelect>,
+    pub sense_agreement: bool,
+    pub master_representation: TrimmingPreference,
+}
+
+impl TrimmedCurve {
+    /// Returns the parameter of the basis curve at the trimming.
+    /// The master representation is preferred if both of the point and the parameter are given.
+    fn trimming_parameter<C>(
+        &self,
+        basis: &C,
+        trim: &[TrimmingSelect],
+    ) -> Result<f64, ExpressParseError>
+    where
+        C: SearchParameter<D1>,
+        C::Point: for<'a> From<&'a CartesianPoint>,
+    {
+        let parameter = trim.iter().find_map(|select| match select {
+            TrimmingSelect::ParameterValue(t) => Some(*t),
+            _ => None,
+        });
+        let point = trim.iter().find_map(|select| match select {
+            TrimmingSelect::CartesianPoint(pt) => Some(pt),
+            _ => None,
+        });
+        let from_point = |pt: &CartesianPoint| -> Result<f64, ExpressParseError> {
+            basis
+                .search_parameter(C::Point::from(pt), None, 100)
+                .ok_or_else(|| "the trimming point is not on the basis curve".into())
+        };
+        match (self.master_representation, parameter, point) {
+            (TrimmingPreference::Cartesian, _, Some(pt)) => from_point(pt),
+            (_, Some(t), _) => Ok(t),
+            (_, None, Some(pt)) => from_point(pt),
+            (_, None, None) => Err("the trimming is empty".into()),
+        }
+    }
+
+    fn parse<C>(&self, restrict: impl FnOnce(C, (f64, f64)) -> C) -> Result<C, ExpressParseError>
+    where
+        C: for<'a> TryFrom<&'a CurveAny, Error = ExpressParseError>
+            + ParametricCurve
+            + SearchParameter<D1>
+            + Invertible,
+        <C as SearchParameter<D1>>::Point: for<'a> From<&'a CartesianPoint>, {
+        let basis = C::try_from(&self.basis_curve)?;
+        let t1 = self.trimming_parameter(&basis, &self.trim_1)?;
+        let t2 = self.trimming_parameter(&basis, &self.trim_2)?;
+        let (t0, mut t1) = match self.sense_agreement {
+            true => (t1, t2),
+            false => (t2, t1),
+        };
+        // the trimmed curve on a closed curve may pass through the seam.
+        if let Some(period) = basis.period() {
+            if t1 <= t0 + TOLERANCE {
+                t1 += period;
+            }
+        }
+        let mut curve = restrict(basis, (t0, t1));
+        if !self.sense_agreement {
+            curve.invert();
+        }
+        Ok(curve)
+    }
+}
+
+fn cut_domain<C: Cut>(mut curve: C, (t0, t1): (f64, f64)) -> C {
+    curve.cut(t1);
+    curve.cut(t0)
+}
+
+fn split_domain<P: truck::control_point::ControlPoint<f64> + Tolerance>(
+    curve: BSplineCurve<P>,
+    (t0, t1): (f64, f64),
+) -> BSplineCurve<P> {
+    let curve = match curve.try_split(t0) {
+        Some((_, curve)) => curve,
+        None => curve,
+    };
+    match curve.try_split(t1) {
+        Some((curve, _)) => curve,
+        None => curve,
+    }
+}
+
+impl TryFrom<&TrimmedCurve> for Curve2D {
+    type Error = ExpressParseError;
+    fn try_from(value: &TrimmedCurve) -> Result<Self, Self::Error> {
+        value.parse(|curve, range| match curve {
+            Curve2D::BSplineCurve(curve) => Curve2D::BSplineCurve(split_domain(curve, range)),
+            curve => cut_domain(curve, range),
+        })
+    }
+}
+
+impl TryFrom<&TrimmedCurve> for Curve3D {
+    type Error = ExpressParseError;
+    fn try_from(value: &TrimmedCurve) -> Result<Self, Self::Error> {
+        value.parse(|curve, range| match curve {
+            Curve3D::BSplineCurve(curve) => Curve3D::BSplineCurve(split_domain(curve, range)),
+            curve => cut_domain(curve, range),
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BSplineCurveForm {
     PolylineForm,
@@ -1618,6 +1917,11 @@ impl From<&CylindricalSurface> for alias::CylindricalSurface {
     }
 }
 
+/// `toroidal_surface`
+///
+/// The torus is converted as it is even if `minor_radius` is not less than `major_radius`.
+/// Such a spindle torus intersects itself around the axis, where a point has two parameters
+/// and the parameter search returns one of them.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Holder)]
 #[holder(table = Table)]
 #[holder(field = toroidal_surface)]
@@ -2067,7 +2371,7 @@ impl EdgeCurve {
                     if v <= u + TOLERANCE {
                         v += 2.0 * PI;
                     }
-                    let circle = TrimmedCurve::new(UnitCircle::<Point2>::new(), (u, v));
+                    let circle = truck::TrimmedCurve::new(UnitCircle::<Point2>::new(), (u, v));
                     let mut ellipse = Processor::new(circle);
                     ellipse.transform_by(mat);
                     Curve2D::Conic(Conic2D::Ellipse(ellipse))
@@ -2090,7 +2394,7 @@ impl EdgeCurve {
                     if v <= u + TOLERANCE {
                         v += 2.0 * PI;
                     }
-                    let circle = TrimmedCurve::new(UnitCircle::<Point2>::new(), (u, v));
+                    let circle = truck::TrimmedCurve::new(UnitCircle::<Point2>::new(), (u, v));
                     let mut ellipse = Processor::new(circle);
                     ellipse.transform_by(mat);
                     Curve2D::Conic(Conic2D::Ellipse(ellipse))
@@ -2113,7 +2417,7 @@ impl EdgeCurve {
                             .search_nearest_parameter(q, None, 0)
                             .ok_or_else(|| "the point is not on hyparbola".to_string())?,
                     );
-                    let unit = TrimmedCurve::new(UnitHyperbola::<Point2>::new(), (u, v));
+                    let unit = truck::TrimmedCurve::new(UnitHyperbola::<Point2>::new(), (u, v));
                     let mut hyperbola = Processor::new(unit);
                     hyperbola.transform_by(mat);
                     Curve2D::Conic(Conic2D::Hyperbola(hyperbola))
@@ -2133,7 +2437,7 @@ impl EdgeCurve {
                             .search_nearest_parameter(q, None, 0)
                             .ok_or_else(|| "the point is not on parabola".to_string())?,
                     );
-                    let unit = TrimmedCurve::new(UnitHyperbola::<Point2>::new(), (u, v));
+                    let unit = truck::TrimmedCurve::new(UnitHyperbola::<Point2>::new(), (u, v));
                     let mut parabola = Processor::new(unit);
                     parabola.transform_by(mat);
                     Curve2D::Conic(Conic2D::Hyperbola(parabola))
@@ -2186,7 +2490,7 @@ impl EdgeCurve {
                     if v <= u + TOLERANCE {
                         v += 2.0 * PI;
                     }
-                    let circle = TrimmedCurve::new(UnitCircle::<Point3>::new(), (u, v));
+                    let circle = truck::TrimmedCurve::new(UnitCircle::<Point3>::new(), (u, v));
                     let mut ellipse = Processor::new(circle);
                     ellipse.transform_by(mat);
                     Curve3D::Conic(Conic3D::Ellipse(ellipse))
@@ -2213,7 +2517,7 @@ impl EdgeCurve {
                     if v <= u + TOLERANCE {
                         v += 2.0 * PI;
                     }
-                    let circle = TrimmedCurve::new(UnitCircle::<Point3>::new(), (u, v));
+                    let circle = truck::TrimmedCurve::new(UnitCircle::<Point3>::new(), (u, v));
                     let mut ellipse = Processor::new(circle);
                     ellipse.transform_by(mat);
                     Curve3D::Conic(Conic3D::Ellipse(ellipse))
@@ -2240,7 +2544,7 @@ impl EdgeCurve {
                     if v <= u + TOLERANCE {
                         v += 2.0 * PI;
                     }
-                    let unit = TrimmedCurve::new(UnitHyperbola::<Point3>::new(), (u, v));
+                    let unit = truck::TrimmedCurve::new(UnitHyperbola::<Point3>::new(), (u, v));
                     let mut hyperbola = Processor::new(unit);
                     hyperbola.transform_by(mat);
                     Curve3D::Conic(Conic3D::Hyperbola(hyperbola))
@@ -2260,7 +2564,7 @@ impl EdgeCurve {
                             .search_nearest_parameter(q, None, 0)
                             .ok_or_else(|| "the point is not on parabola".to_string())?,
                     );
-                    let unit = TrimmedCurve::new(UnitHyperbola::<Point3>::new(), (u, v));
+                    let unit = truck::TrimmedCurve::new(UnitHyperbola::<Point3>::new(), (u, v));
                     let mut parabola = Processor::new(unit);
                     parabola.transform_by(mat);
                     Curve3D::Conic(Conic3D::Hyperbola(parabola))
@@ -2496,6 +2800,27 @@ pub struct OrientedShell {
     pub orientation: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Holder)]
+#[holder(table = Table)]
+#[holder(field = manifold_solid_brep)]
+#[holder(generate_deserialize)]
+/// The outer boundary is a `CLOSED_SHELL`, which is parsed to [`Shell`].
+pub struct ManifoldSolidBrep {
+    pub label: String,
+    #[holder(use_place_holder)]
+    pub outer: Shell,
+}
+
+impl ManifoldSolidBrepHolder {
+    fn outer_holder(&self, table: &Table) -> Option<ShellHolder> {
+        match &self.outer {
+            PlaceHolder::Ref(Name::Entity(ref idx)) => table.shell.get(idx).cloned(),
+            PlaceHolder::Owned(x) => Some(x.clone()),
+            _ => None,
+        }
+    }
+}
+
 impl Table {
     fn place_holder_edge_any_to_index_and_edge_curve(
         &self,
@@ -2675,6 +3000,32 @@ impl Table {
             .collect()
     }
 
+    /// Returns the ratio of the length unit declared in the geometric context to the millimetre.
+    ///
+    /// Returns `1.0` if no length unit is declared, that is, the lengths are regarded as in millimetres.
+    pub fn unit_scale_to_mm(&self) -> f64 {
+        let mut contexts: Vec<_> = self.global_unit_assigned_context.iter().collect();
+        contexts.sort_by_key(|(idx, _)| **idx);
+        contexts
+            .into_iter()
+            .flat_map(|(_, units)| units)
+            .find_map(|idx| self.length_unit_to_metre(*idx))
+            .map_or(1.0, |ratio| ratio * 1000.0)
+    }
+
+    fn length_unit_to_metre(&self, idx: u64) -> Option<f64> {
+        match self.length_unit.get(&idx)? {
+            LengthUnit::SiUnit(ratio) => Some(*ratio),
+            LengthUnit::ConversionBasedUnit(measure) => {
+                let measure = self.length_measure_with_unit.get(measure)?;
+                match self.length_unit.get(&measure.unit_component)? {
+                    LengthUnit::SiUnit(ratio) => Some(measure.value_component * ratio),
+                    LengthUnit::ConversionBasedUnit(_) => None,
+                }
+            }
+        }
+    }
+
     pub fn to_compressed_shell(
         &self,
         shell: &ShellHolder,
@@ -2687,4 +3038,55 @@ impl Table {
             faces: self.shell_faces(shell, &eidx_map),
         })
     }
+
+    /// Converts the shell, scaled by [`Table::unit_scale_to_mm`] so that the lengths are in millimetres.
+    pub fn to_compressed_shell_in_mm(
+        &self,
+        shell: &ShellHolder,
+    ) -> Result<CompressedShell<Point3, Curve3D, Surface>, ExpressParseError> {
+        let mut shell = self.to_compressed_shell(shell)?;
+        self.scale_to_mm(&mut shell);
+        Ok(shell)
+    }
+
+    fn scale_to_mm(&self, shell: &mut CompressedShell<Point3, Curve3D, Surface>) {
+        let mat = Matrix4::from_scale(self.unit_scale_to_mm());
+        shell
+            .vertices
+            .iter_mut()
+            .for_each(|p| *p = mat.transform_point(*p));
+        shell
+            .edges
+            .iter_mut()
+            .for_each(|edge| edge.curve.transform_by(mat));
+        shell
+            .faces
+            .iter_mut()
+            .for_each(|face| face.surface.transform_by(mat));
+    }
+
+    pub fn to_compressed_solid(
+        &self,
+        solid: &ManifoldSolidBrepHolder,
+    ) -> Result<CompressedSolid<Point3, Curve3D, Surface>, ExpressParseError> {
+        let shell = solid
+            .outer_holder(self)
+            .ok_or("the outer shell of the solid is not found")?;
+        Ok(CompressedSolid {
+            boundaries: vec![self.to_compressed_shell(&shell)?],
+        })
+    }
+
+    /// Converts the solid, scaled by [`Table::unit_scale_to_mm`] so that the lengths are in millimetres.
+    pub fn to_compressed_solid_in_mm(
+        &self,
+        solid: &ManifoldSolidBrepHolder,
+    ) -> Result<CompressedSolid<Point3, Curve3D, Surface>, ExpressParseError> {
+        let mut solid = self.to_compressed_solid(solid)?;
+        solid
+            .boundaries
+            .iter_mut()
+            .for_each(|shell| self.scale_to_mm(shell));
+        Ok(solid)
+    }
 }
@@ -749,6 +749,74 @@ proptest! {
     }
 }
 
+fn exec_trimmed_circle(
+    org_coord: [f64; 3],
+    dir_array: [f64; 2],
+    ref_dir_array: [f64; 2],
+    radius: f64,
+    (t0, t1): (f64, f64),
+    sense: bool,
+) {
+    let origin = Point3::from(org_coord);
+    let z = dir_from_array(dir_array);
+    let ref_dir = dir_from_array(ref_dir_array);
+    if z.cross(ref_dir).so_small() || f64::abs(t0 - t1) < 0.1 {
+        return;
+    }
+    let circle_str = format!(
+        "#2 = CIRCLE('', #3, {radius}); #3 = AXIS2_PLACEMENT_3D('', #4, #5, #6); {}{}{}",
+        StepDisplay::new(origin, 4),
+        StepDisplay::new(VectorAsDirection(z), 5),
+        StepDisplay::new(VectorAsDirection(ref_dir.normalize()), 6),
+    );
+    let step_circle = step_to_entity::<CircleHolder>(&format!("DATA;{circle_str}ENDSEC;"));
+    let ellipse: alias::Ellipse<Point3, Matrix4> = (&step_circle).try_into().unwrap();
+    let (p, q) = (ellipse.subs(t0), ellipse.subs(t1));
+
+    // the end of the trimmed domain along the sense of the basis curve
+    let t_end = match (sense, t0 < t1) {
+        (true, false) => t1 + 2.0 * PI,
+        (false, true) => t1 - 2.0 * PI,
+        _ => t1,
+    };
+    let sense_str = if sense { ".T." } else { ".F." };
+    let parameters = format!(
+        "#1 = TRIMMED_CURVE('', #2, (PARAMETER_VALUE({})), (PARAMETER_VALUE({})), {sense_str}, .PARAMETER.);",
+        float_to_str(t0),
+        float_to_str(t1),
+    );
+    let points = format!(
+        "#1 = TRIMMED_CURVE('', #2, (#7), (#8), {sense_str}, .CARTESIAN.); {}{}",
+        StepDisplay::new(p, 7),
+        StepDisplay::new(q, 8),
+    );
+    [parameters, points].into_iter().for_each(|trimmed_str| {
+        let step_str = format!("DATA;{trimmed_str}{circle_str}ENDSEC;");
+        let step_trimmed = step_to_entity::<TrimmedCurveHolder>(&step_str);
+        let curve: Curve3D = (&step_trimmed).try_into().unwrap();
+        assert_near!(curve.front(), p);
+        assert_near!(curve.back(), q);
+        let (s0, s1) = curve.range_tuple();
+        let mid = ellipse.subs((t0 + t_end) / 2.0);
+        assert_near!(curve.subs((s0 + s1) / 2.0), mid);
+    });
+}
+
+proptest! {
+    #[test]
+    fn trimmed_circle(
+        org_coord in array::uniform3(-100.0f64..100.0f64),
+        dir_array in array::uniform2(0.0f64..1.0),
+        ref_dir_array in array::uniform2(0.0f64..1.0),
+        radius in 1.0e-2f64..100.0,
+        t0 in 0.0f64..(2.0 * PI),
+        t1 in 0.0f64..(2.0 * PI),
+        sense in prelude::any::<bool>(),
+    ) {
+        exec_trimmed_circle(org_coord, dir_array, ref_dir_array, radius, (t0, t1), sense)
+    }
+}
+
 fn exec_ellipse(
     org_coord: [f64; 3],
     dir_array: [f64; 2],
@@ -1751,3 +1819,91 @@ proptest! {
         exec_surface_of_revolution(point0_coord, point1_coord, org_coord, axis_array)
     }
 }
+
+fn exec_revolved_line_as_quadric(
+    org_coord: [f64; 3],
+    dir_array: [f64; 2],
+    ref_dir_array: [f64; 2],
+    radius: f64,
+    semi_angle: f64,
+) {
+    let p = Point3::from(org_coord);
+    let z = dir_from_array(dir_array);
+    let ref_dir = dir_from_array(ref_dir_array);
+    let v = z.cross(ref_dir);
+    let y = match v.so_small() {
+        true => return,
+        false => v.normalize(),
+    };
+    let x = y.cross(z).normalize();
+    let placement = format!(
+        "{}{}{}",
+        StepDisplay::new(p, 3),
+        StepDisplay::new(VectorAsDirection(z), 4),
+        StepDisplay::new(VectorAsDirection(ref_dir.normalize()), 5),
+    );
+    let revolution = |line: Line<Point3>| -> StepRevolutedCurve {
+        let step_str = format!(
+            "DATA;
+#1 = SURFACE_OF_REVOLUTION('', #5, #2);
+#2 = AXIS1_PLACEMENT('', #3, #4);
+{}{}{}ENDSEC;",
+            StepDisplay::new(p, 3),
+            StepDisplay::new(VectorAsDirection(z), 4),
+            StepDisplay::new(&line, 5),
+        );
+        let step_surface = step_to_entity::<SurfaceOfRevolutionHolder>(&step_str);
+        (&step_surface).try_into().unwrap()
+    };
+    let quadric = |entity: String| -> alias::ElementarySurface {
+        let step_str = format!(
+            "DATA;
+#1 = {entity};
+#2 = AXIS2_PLACEMENT_3D('', #3, #4, #5);
+{placement}ENDSEC;"
+        );
+        let step_surface = step_to_entity::<ElementarySurfaceAnyHolder>(&step_str);
+        (&step_surface).into()
+    };
+
+    // the line parallel to the axis and the line leaning by `semi_angle`
+    let base = p + x * radius;
+    let cylinder_line = Line(base, base + z);
+    let cone_line = Line(base, base + x * f64::tan(semi_angle) + z);
+    let pairs = [
+        (
+            revolution(cylinder_line),
+            quadric(format!("CYLINDRICAL_SURFACE('', #2, {radius})")),
+        ),
+        (
+            revolution(cone_line),
+            quadric(format!("CONICAL_SURFACE('', #2, {radius}, {semi_angle})")),
+        ),
+    ];
+    pairs.iter().for_each(|(revolution, quadric)| {
+        (0..=10)
+            .flat_map(move |i| (0..=10).map(move |j| (i, j)))
+            .for_each(|(i, j)| {
+                let u = 2.0 * PI * i as f64 / 10.0;
+                let v = j as f64 / 10.0;
+                let res = revolution.subs(u, v);
+                let ans = quadric.subs(u, v);
+                assert_near!(res, ans, "u:{u} v:{v} res:{res:?} ans:{ans:?}");
+                let (n0, n1) = (revolution.normal(u, v), quadric.normal(u, v));
+                assert_near!(n0, n1, "u:{u} v:{v} n0:{n0:?} n1:{n1:?}");
+            })
+    });
+}
+
+proptest! {
+    #[test]
+    fn revolved_line_as_quadric(
+        org_coord in array::uniform3(-100.0f64..100.0f64),
+        dir_array in array::uniform2(0.0f64..1.0f64),
+        ref_dir_array in array::uniform2(0.0f64..1.0f64),
+        radius in 1.0e-2f64..100.0f64,
+        semi_angle in 0.0f64..1.5f64,
+    ) {
+        exec_revolved_line_as_quadric(org_coord, dir_array, ref_dir_array, radius, semi_angle)
+    }
+}
@@ -84,6 +84,7 @@ fn read() {
 #110 = CLOSED_SHELL('ClosedShell', (#107, #108));
 #111 = ORIENTED_OPEN_SHELL('OrientedOpenShell', *, #109, .F.);
 #112 = ORIENTED_CLOSED_SHELL('OrientedClosedShell', *, #110, .T.);
+#113 = MANIFOLD_SOLID_BREP('ManifoldSolidBrep', #110);
 
 #999 = HOGE('Dummy', #110, 3);
 ENDSEC;
@@ -696,6 +697,13 @@ ENDSEC;
                 },
             ),
         ]),
+        manifold_solid_brep: HashMap::from_iter(vec![(
+            113,
+            ManifoldSolidBrepHolder {
+                label: "ManifoldSolidBrep".to_string(),
+                outer: PlaceHolder::Ref(Name::Entity(110)),
+            },
+        )]),
         dummy: HashMap::from_iter(vec![
             (
                 999,
@@ -718,3 +726,51 @@ Record { name: \"REPRESENTATION_CONTEXT\", parameter: List([String(\"2D SPACE\")
     };
     assert_eq!(table, ans_table);
 }
+
+#[test]
+fn collect_errors() {
+    let data_section = DataSection::from_str(
+        "DATA;
+#1 = CARTESIAN_POINT('Point', (0.1, 0.2, 0.3));
+#2 = CIRCLE('Circle', #3);
+#3 = AXIS2_PLACEMENT_3D('Axis2Placement3d', #1, $, $);
+ENDSEC;
+",
+    )
+    .unwrap();
+    let (table, errors) = Table::from_iter_collect_errors(&data_section.entities);
+    assert_eq!(table.cartesian_point.len(), 1);
+    assert_eq!(table.axis2_placement_3d.len(), 1);
+    assert!(table.circle.is_empty());
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 2);
+    assert!(errors[0].1.starts_with("CIRCLE: "), "{}", errors[0].1);
+}
+
+#[test]
+fn unit_scale() {
+    let data_section = DataSection::from_str(
+        "DATA;
+#1 = ( GEOMETRIC_REPRESENTATION_CONTEXT(3) GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#5))
+    GLOBAL_UNIT_ASSIGNED_CONTEXT((#2, #6, #7)) REPRESENTATION_CONTEXT('Context', '3D') );
+#2 = ( CONVERSION_BASED_UNIT('INCH', #3) LENGTH_UNIT() NAMED_UNIT(#8) );
+#3 = LENGTH_MEASURE_WITH_UNIT(LENGTH_MEASURE(25.4), #4);
+#4 = ( LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI., .METRE.) );
+#5 = UNCERTAINTY_MEASURE_WITH_UNIT(LENGTH_MEASURE(1.E-07), #2, 'distance_accuracy_value', '');
+#6 = ( NAMED_UNIT(*) PLANE_ANGLE_UNIT() SI_UNIT($, .RADIAN.) );
+#7 = ( NAMED_UNIT(*) SI_UNIT($, .STERADIAN.) SOLID_ANGLE_UNIT() );
+#8 = DIMENSIONAL_EXPONENTS(1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+ENDSEC;
+",
+    )
+    .unwrap();
+    let table = Table::from_data_section(&data_section);
+    assert_eq!(table.global_unit_assigned_context[&1], vec![2, 6, 7]);
+    assert!(matches!(table.length_unit[&4], LengthUnit::SiUnit(x) if (x - 0.001).abs() < 1.0e-15));
+    assert!((table.unit_scale_to_mm() - 25.4).abs() < 1.0e-10);
+    // the contexts are still referable
+    assert!(table.dummy.contains_key(&1));
+
+    let table = Table::from_data_section(&DataSection::from_str("DATA;ENDSEC;").unwrap());
+    assert_eq!(table.unit_scale_to_mm(), 1.0);
+}
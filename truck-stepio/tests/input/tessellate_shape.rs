@@ -34,3 +34,18 @@ fn tessellate_shape() {
         });
     });
 }
+
+#[test]
+fn extract_solids() {
+    STEP_FILES.iter().for_each(|name| {
+        let path = [STEP_DIRECTORY, name].concat();
+        let step_string = std::fs::read_to_string(path).unwrap();
+        let table = Table::from_step(&step_string).unwrap();
+        table.manifold_solid_brep.values().for_each(|step_solid| {
+            let csolid = table.to_compressed_solid(step_solid).unwrap();
+            let solid = truck_topology::Solid::extract(csolid).unwrap();
+            let shell = &solid.boundaries()[0];
+            assert_eq!(shell.shell_condition(), ShellCondition::Closed, "{name}");
+        });
+    });
+}
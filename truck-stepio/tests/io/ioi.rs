@@ -46,3 +46,33 @@ fn ioi() {
         });
     });
 }
+
+#[test]
+fn cube_round_trip() {
+    use truck_modeling::builder;
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::unit_z());
+    let csolid = cube.compress();
+    let step_string =
+        CompleteStepDisplay::new(StepModel::from(&csolid), Default::default()).to_string();
+
+    let table = Table::from_step(&step_string).unwrap();
+    assert_eq!(table.manifold_solid_brep.len(), 1);
+    let step_solid = table.manifold_solid_brep.values().next().unwrap();
+    let csolid = table.to_compressed_solid(step_solid).unwrap();
+    let cshell = &csolid.boundaries[0];
+    assert_eq!(cshell.vertices.len(), 8);
+    assert_eq!(cshell.edges.len(), 12);
+    assert_eq!(cshell.faces.len(), 6);
+    cube.vertex_iter().for_each(|v| {
+        assert!(cshell.vertices.iter().any(|p| p.near(&v.point())));
+    });
+
+    // the faces keep the orientation, so that the volume is positive.
+    let mut poly = csolid.triangulation(0.01).to_polygon();
+    poly.put_together_same_attrs(TOLERANCE * 50.0);
+    assert_eq!(poly.shell_condition(), ShellCondition::Closed);
+    assert_near!(poly.volume(), 1.0);
+}
@@ -1,5 +1,6 @@
 use crate::{errors::Error, *};
 use thiserror::Error;
+use truck_base::cgmath64::InnerSpace;
 
 impl<P, C> Edge<P, C> {
     /// Generates the edge from `front` to `back`.  
@@ -398,6 +399,20 @@ impl<P, C> Edge<P, C> {
         geom_front.near(&*top_front) && geom_back.near(&*top_back)
     }
 
+    /// Returns the length of the curve of the edge.
+    ///
+    /// The length is computed by the adaptive quadrature of the curve,
+    /// whose error is less than `tol`.
+    /// # Panics
+    /// Panics if `tol` is less than `TOLERANCE`.
+    #[inline(always)]
+    pub fn length(&self, tol: f64) -> f64
+    where
+        C: BoundedCurve,
+        C::Vector: InnerSpace<Scalar = f64>, {
+        self.curve.lock().length(tol)
+    }
+
     /// Cuts the edge at `vertex`.
     /// # Failures
     /// Returns `None` if:
@@ -1,3 +1,4 @@
+use crate::{EdgeID, FaceID, VertexID};
 use thiserror::Error;
 
 /// Topological Errors
@@ -115,6 +116,42 @@ pub enum Error {
     NotManifold,
 }
 
+/// A specific problem of a solid reported by [`Solid::check_consistency`].
+///
+/// [`Solid::check_consistency`]: crate::Solid::check_consistency
+#[derive(Debug, PartialEq, Error)]
+pub enum ConsistencyIssue<P, C, S> {
+    /// The point of the end vertex of the edge is not the end point of the curve.
+    #[error("The vertex {vertex:?} is not on the end of the curve of the edge {edge:?}.")]
+    VertexNotOnCurve {
+        /// the edge whose curve is inconsistent with the vertex
+        edge: EdgeID<C>,
+        /// the end vertex of the edge
+        vertex: VertexID<P>,
+    },
+    /// The curve of the boundary edge is not on the surface of the face.
+    #[error("The curve of the edge {edge:?} is not on the surface of the face {face:?}.")]
+    CurveNotOnSurface {
+        /// the face whose surface does not include the curve
+        face: FaceID<S>,
+        /// the boundary edge of the face
+        edge: EdgeID<C>,
+    },
+    /// The edge is not shared by exactly two faces in the shell.
+    #[error("The edge {0:?} is not shared by exactly two faces.")]
+    NonManifoldEdge(EdgeID<C>),
+    /// The edge has the same orientation in the two adjacent faces.
+    #[error("The edge {0:?} has the same orientation in the two adjacent faces.")]
+    InconsistentOrientation(EdgeID<C>),
+}
+
+impl<P, C, S> Clone for ConsistencyIssue<P, C, S> {
+    #[inline(always)]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<P, C, S> Copy for ConsistencyIssue<P, C, S> {}
+
 #[test]
 fn print_messages() {
     use std::io::Write;
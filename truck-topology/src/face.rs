@@ -1,5 +1,6 @@
 use crate::{errors::Error, wire::EdgeIter, *};
 use rustc_hash::FxHashMap as HashMap;
+use truck_base::cgmath64::InnerSpace;
 
 impl<P, C, S> Face<P, C, S> {
     /// Creates a new face by a wire.
@@ -1022,6 +1023,21 @@ where
     }
 }
 
+impl<P, C: BoundedCurve, S> Face<P, C, S>
+where C::Vector: InnerSpace<Scalar = f64>
+{
+    /// Returns the sum of the lengths of all the boundaries.
+    ///
+    /// The length of each edge is computed by [`Edge::length`], so the error is less than
+    /// `tol` times the number of edges.
+    /// # Panics
+    /// Panics if `tol` is less than `TOLERANCE`.
+    #[inline(always)]
+    pub fn perimeter(&self, tol: f64) -> f64 {
+        self.boundaries.iter().map(|wire| wire.length(tol)).sum()
+    }
+}
+
 impl<P, C, S> Clone for Face<P, C, S> {
     #[inline(always)]
     fn clone(&self) -> Face<P, C, S> {
@@ -93,6 +93,7 @@ use rclite::Arc;
 use std::sync::Arc;
 
 const SEARCH_PARAMETER_TRIALS: usize = 100;
+const SIMPLIFY_DIVISION: usize = 8;
 
 /// Vertex, the minimum topological unit.
 ///
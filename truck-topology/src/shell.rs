@@ -111,6 +111,49 @@ impl<P, C, S> Shell<P, C, S> {
         self.edge_iter().collect::<Boundaries<C>>().condition()
     }
 
+    /// Returns the edges shared by more than two faces, or by two faces with the same orientation.
+    /// The edges are ordered by their first appearance in the shell.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// use truck_topology::shell::ShellCondition;
+    /// let v = Vertex::news(&[(); 5]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[0], ()),
+    ///     Edge::new(&v[0], &v[3], ()),
+    ///     Edge::new(&v[3], &v[1], ()),
+    ///     Edge::new(&v[0], &v[4], ()),
+    ///     Edge::new(&v[4], &v[1], ()),
+    /// ];
+    /// // three faces share `edge[0]`.
+    /// let wire = vec![
+    ///     Wire::from_iter(vec![&edge[0], &edge[1], &edge[2]]),
+    ///     Wire::from_iter(vec![&edge[0].inverse(), &edge[3], &edge[4]]),
+    ///     Wire::from_iter(vec![&edge[0].inverse(), &edge[5], &edge[6]]),
+    /// ];
+    /// let mut shell: Shell<_, _, _> = wire.into_iter().map(|w| Face::new(vec![w], ())).collect();
+    /// assert_eq!(shell.shell_condition(), ShellCondition::Irregular);
+    /// assert_eq!(shell.non_manifold_edges(), vec![edge[0].clone()]);
+    ///
+    /// // two faces share `edge[0]` with the same orientation.
+    /// shell.pop();
+    /// shell[1].invert();
+    /// assert_eq!(shell.shell_condition(), ShellCondition::Regular);
+    /// assert_eq!(shell.non_manifold_edges(), vec![edge[0].clone()]);
+    ///
+    /// shell[1].invert();
+    /// assert!(shell.non_manifold_edges().is_empty());
+    /// ```
+    pub fn non_manifold_edges(&self) -> Vec<Edge<P, C>> {
+        let boundaries: Boundaries<C> = self.edge_iter().collect();
+        let mut ids: HashSet<_> = boundaries.non_manifold.into_iter().collect();
+        self.edge_iter()
+            .filter(|edge| ids.remove(&edge.id()))
+            .collect()
+    }
+
     /// Returns a vector of all boundaries as wires.
     /// # Examples
     /// ```
@@ -988,6 +1031,7 @@ impl std::ops::BitAnd for ShellCondition {
 struct Boundaries<C> {
     checked: HashSet<EdgeID<C>>,
     boundaries: HashMap<EdgeID<C>, bool>,
+    non_manifold: Vec<EdgeID<C>>,
     condition: ShellCondition,
 }
 
@@ -997,28 +1041,32 @@ impl<C> Boundaries<C> {
         Self {
             checked: Default::default(),
             boundaries: Default::default(),
+            non_manifold: Default::default(),
             condition: ShellCondition::Oriented,
         }
     }
 
     #[inline(always)]
     fn insert<P>(&mut self, edge: &Edge<P, C>) {
-        self.condition = self.condition
-            & match (
-                self.checked.insert(edge.id()),
-                self.boundaries.insert(edge.id(), edge.orientation()),
-            ) {
-                (true, None) => ShellCondition::Oriented,
-                (false, None) => ShellCondition::Irregular,
-                (true, Some(_)) => panic!("unexpected case!"),
-                (false, Some(ori)) => {
-                    self.boundaries.remove(&edge.id());
-                    match edge.orientation() == ori {
-                        true => ShellCondition::Regular,
-                        false => ShellCondition::Oriented,
-                    }
+        let condition = match (
+            self.checked.insert(edge.id()),
+            self.boundaries.insert(edge.id(), edge.orientation()),
+        ) {
+            (true, None) => ShellCondition::Oriented,
+            (false, None) => ShellCondition::Irregular,
+            (true, Some(_)) => panic!("unexpected case!"),
+            (false, Some(ori)) => {
+                self.boundaries.remove(&edge.id());
+                match edge.orientation() == ori {
+                    true => ShellCondition::Regular,
+                    false => ShellCondition::Oriented,
                 }
             }
+        };
+        if condition != ShellCondition::Oriented {
+            self.non_manifold.push(edge.id());
+        }
+        self.condition = self.condition & condition;
     }
 
     #[inline(always)]
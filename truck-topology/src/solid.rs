@@ -1,6 +1,8 @@
-use crate::errors::Error;
+use crate::errors::{ConsistencyIssue, Error};
 use crate::shell::ShellCondition;
 use crate::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::collections::hash_map::Entry;
 use std::vec::Vec;
 
 impl<P, C, S> Solid<P, C, S> {
@@ -135,15 +137,86 @@ impl<P, C, S> Solid<P, C, S> {
 
     /// Returns the consistence of the geometry of end vertices
     /// and the geometry of edge.
+    ///
+    /// This method is equivalent to `self.check_consistency().is_empty()`.
     #[inline(always)]
     pub fn is_geometric_consistent(&self) -> bool
     where
         P: Tolerance,
         C: BoundedCurve<Point = P>,
         S: IncludeCurve<C>, {
-        self.boundaries()
-            .iter()
-            .all(|shell| shell.is_geometric_consistent())
+        self.check_consistency().is_empty()
+    }
+
+    /// Returns all the specific problems of the solid with the IDs of the offending elements.
+    ///
+    /// The following problems are reported:
+    /// - the points of the end vertices of an edge are not the end points of its curve,
+    /// - the curve of a boundary edge is not on the surface of the face,
+    /// - an edge is not shared by exactly two faces in a shell, and
+    /// - an edge has the same orientation in the two adjacent faces.
+    ///
+    /// The first problem is reported once for each edge, and the others are reported in the order
+    /// of the faces and the edges in the boundaries.
+    pub fn check_consistency(&self) -> Vec<ConsistencyIssue<P, C, S>>
+    where
+        P: Tolerance,
+        C: BoundedCurve<Point = P>,
+        S: IncludeCurve<C>, {
+        let mut issues = Vec::new();
+        let mut checked = HashSet::default();
+        self.boundaries.iter().for_each(|shell| {
+            let mut uses = HashMap::<EdgeID<C>, (usize, bool)>::default();
+            let mut edge_ids = Vec::new();
+            shell.face_iter().for_each(|face| {
+                let surface = face.surface.lock();
+                face.boundary_iters()
+                    .into_iter()
+                    .flatten()
+                    .for_each(|edge| {
+                        if checked.insert(edge.id()) {
+                            let curve = edge.curve.lock();
+                            let ends = [
+                                (curve.front(), edge.absolute_front()),
+                                (curve.back(), edge.absolute_back()),
+                            ];
+                            ends.into_iter()
+                                .filter(|(point, vertex)| !point.near(&*vertex.point.lock()))
+                                .for_each(|(_, vertex)| {
+                                    issues.push(ConsistencyIssue::VertexNotOnCurve {
+                                        edge: edge.id(),
+                                        vertex: vertex.id(),
+                                    })
+                                });
+                        }
+                        if !surface.include(&*edge.curve.lock()) {
+                            issues.push(ConsistencyIssue::CurveNotOnSurface {
+                                face: face.id(),
+                                edge: edge.id(),
+                            });
+                        }
+                        match uses.entry(edge.id()) {
+                            Entry::Vacant(entry) => {
+                                entry.insert((1, edge.orientation()));
+                                edge_ids.push(edge.id());
+                            }
+                            Entry::Occupied(mut entry) => {
+                                let (count, orientation) = entry.get_mut();
+                                *count += 1;
+                                if *count == 2 && *orientation == edge.orientation() {
+                                    issues
+                                        .push(ConsistencyIssue::InconsistentOrientation(edge.id()));
+                                }
+                            }
+                        }
+                    });
+            });
+            edge_ids
+                .into_iter()
+                .filter(|id| uses[id].0 != 2)
+                .for_each(|id| issues.push(ConsistencyIssue::NonManifoldEdge(id)));
+        });
+        issues
     }
 
     /// Cuts one edge into two edges at vertex.
@@ -3,6 +3,7 @@ use rayon::prelude::*;
 use rustc_hash::FxHashSet as HashSet;
 use std::collections::{vec_deque, VecDeque};
 use std::iter::Peekable;
+use truck_base::cgmath64::{EuclideanSpace, InnerSpace, Rad};
 use truck_base::entry_map::FxEntryMap as EntryMap;
 
 impl<P, C> Wire<P, C> {
@@ -504,6 +505,61 @@ impl<P, C> Wire<P, C> {
         self.iter().all(|edge| edge.is_geometric_consistent())
     }
 
+    /// Returns the sum of the lengths of the edges.
+    ///
+    /// The length of each edge is computed by [`Edge::length`], so the error is less than
+    /// `tol` times the number of edges.
+    /// # Panics
+    /// Panics if `tol` is less than `TOLERANCE`.
+    #[inline(always)]
+    pub fn length(&self, tol: f64) -> f64
+    where
+        C: BoundedCurve,
+        C::Vector: InnerSpace<Scalar = f64>, {
+        self.iter().map(|edge| edge.length(tol)).sum()
+    }
+
+    /// Merges the consecutive edges whose curves are on the same line or circle,
+    /// and returns the number of removed edges.
+    ///
+    /// Two consecutive edges are merged into one edge by [`Edge::concat`] if the tangent vectors
+    /// at the shared vertex make an angle less than `angle_tol`, and if both curves are within
+    /// `tol` of the osculating line or circle of the former curve at the shared vertex.
+    /// Hence, the shape of the wire does not change, and the edges are never merged across corners.
+    /// The end vertices of a non-closed wire are kept, whereas the front vertex of a closed wire
+    /// may be removed.
+    ///
+    /// # Remarks
+    /// The merged edges are replaced by new edges, which are not shared with the other wires.
+    ///
+    /// # Panics
+    /// `tol` must be more than `TOLERANCE`.
+    pub fn simplify(&mut self, angle_tol: Rad<f64>, tol: f64) -> usize
+    where
+        P: Debug + EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+        C: Concat<C, Point = P, Output = C> + Invertible + ParameterTransform,
+        C::Vector: InnerSpace<Scalar = f64>, {
+        nonpositive_tolerance!(tol);
+        let closed = self.is_closed();
+        let (mut removed, mut idx) = (0, 0);
+        while self.len() > 1 && idx + 1 < self.len() + closed as usize {
+            let next = (idx + 1) % self.len();
+            let edge = match on_same_circle(&self[idx], &self[next], angle_tol, tol) {
+                true => self[idx].concat(&self[next]).ok(),
+                false => None,
+            };
+            match edge {
+                Some(edge) => {
+                    self.swap_subwire_into_edges(idx, edge);
+                    idx = usize::min(idx, self.len() - 1);
+                    removed += 1;
+                }
+                None => idx += 1,
+            }
+        }
+        removed
+    }
+
     /// Creates display struct for debugging the wire.
     /// # Examples
     /// ```
@@ -541,6 +597,51 @@ impl<P, C> Wire<P, C> {
     }
 }
 
+/// Returns whether the curves of `edge0` and `edge1` are within `tol` of the osculating line
+/// or circle of `edge0` at the shared vertex.
+fn on_same_circle<P, C>(
+    edge0: &Edge<P, C>,
+    edge1: &Edge<P, C>,
+    angle_tol: Rad<f64>,
+    tol: f64,
+) -> bool
+where
+    P: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C: BoundedCurve<Point = P> + Invertible,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    let (curve0, curve1) = (edge0.oriented_curve(), edge1.oriented_curve());
+    let t = curve0.range_tuple().1;
+    let (der0, der1) = (curve0.der(t), curve1.der(curve1.range_tuple().0));
+    if der0.magnitude2() < TOLERANCE2
+        || der1.magnitude2() < TOLERANCE2
+        || der0.angle(der1) >= angle_tol
+    {
+        return false;
+    }
+    let (point, tangent, der2) = (curve0.subs(t), der0.normalize(), curve0.der2(t));
+    let curvature = (der2 - tangent * der2.dot(tangent)) / der0.magnitude2();
+    let distance = |q: P| {
+        let vec = q - point;
+        let vec_t = vec.dot(tangent);
+        if curvature.magnitude2() < TOLERANCE2 {
+            return (vec - tangent * vec_t).magnitude();
+        }
+        let radius = 1.0 / curvature.magnitude();
+        let normal = curvature * radius;
+        let vec_n = vec.dot(normal);
+        let off_plane = (vec - tangent * vec_t - normal * vec_n).magnitude();
+        f64::hypot(f64::hypot(vec_t, vec_n - radius) - radius, off_plane)
+    };
+    [curve0, curve1].iter().all(|curve| {
+        let (t0, t1) = curve.range_tuple();
+        (0..=SIMPLIFY_DIVISION).all(|i| {
+            let t = t0 + (t1 - t0) * i as f64 / SIMPLIFY_DIVISION as f64;
+            distance(curve.subs(t)) < tol
+        })
+    })
+}
+
 type EdgeEntryMapForTryMapping<'a, P, C, Q, D, KF, KV> =
     EntryMap<EdgeID<C>, Option<Edge<Q, D>>, KF, KV, &'a Edge<P, C>>;
 type EdgeEntryMapForMapping<'a, P, C, Q, D, KF, KV> =
@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+
+use truck_geometry::prelude::*;
+use truck_topology::*;
+
+pub type Curve = NurbsCurve<Vector4>;
+
+/// the line segment from `p` to `q`
+pub fn line_curve(p: Point3, q: Point3) -> Curve {
+    NurbsCurve::from(BSplineCurve::new(KnotVec::bezier_knot(1), vec![p, q]))
+}
+
+/// the quarter arc from `p` to `q` turning around `center`
+pub fn quarter_arc_curve(center: Point3, p: Point3, q: Point3) -> Curve {
+    let mid = p + (q - center);
+    let w = f64::sqrt(0.5);
+    NurbsCurve::new(BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            p.to_homogeneous(),
+            mid.to_homogeneous() * w,
+            q.to_homogeneous(),
+        ],
+    ))
+}
+
+pub fn line(v0: &Vertex<Point3>, v1: &Vertex<Point3>) -> Edge<Point3, Curve> {
+    Edge::new(v0, v1, line_curve(v0.point(), v1.point()))
+}
+
+/// the quarter arc from `v0` to `v1` turning around the origin
+pub fn quarter_arc(v0: &Vertex<Point3>, v1: &Vertex<Point3>) -> Edge<Point3, Curve> {
+    let curve = quarter_arc_curve(Point3::origin(), v0.point(), v1.point());
+    Edge::new(v0, v1, curve)
+}
+
+/// the planar face bounded by `edges`, whose plane passes through the first three vertices
+pub fn plane_face(edges: Vec<Edge<Point3, Curve>>) -> Face<Point3, Curve, Plane> {
+    let wire = Wire::from(edges);
+    let p: Vec<Point3> = wire.vertex_iter().take(3).map(|v| v.point()).collect();
+    Face::new(vec![wire], Plane::new(p[0], p[1], p[2]))
+}
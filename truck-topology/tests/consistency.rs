@@ -0,0 +1,160 @@
+use truck_geometry::prelude::*;
+use truck_topology::{errors::ConsistencyIssue, *};
+
+mod common;
+use common::*;
+
+type Cube = (
+    Vec<Vertex<Point3>>,
+    Vec<Edge<Point3, Curve>>,
+    Solid<Point3, Curve, Plane>,
+);
+
+fn cube() -> Cube {
+    let v = Vertex::news([
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    ]);
+    let e = vec![
+        line(&v[0], &v[1]),
+        line(&v[1], &v[2]),
+        line(&v[2], &v[3]),
+        line(&v[3], &v[0]),
+        line(&v[0], &v[4]),
+        line(&v[1], &v[5]),
+        line(&v[2], &v[6]),
+        line(&v[3], &v[7]),
+        line(&v[4], &v[5]),
+        line(&v[5], &v[6]),
+        line(&v[6], &v[7]),
+        line(&v[7], &v[4]),
+    ];
+    let shell: Shell<_, _, _> = vec![
+        plane_face(vec![
+            e[3].inverse(),
+            e[2].inverse(),
+            e[1].inverse(),
+            e[0].inverse(),
+        ]),
+        plane_face(vec![
+            e[0].clone(),
+            e[5].clone(),
+            e[8].inverse(),
+            e[4].inverse(),
+        ]),
+        plane_face(vec![
+            e[1].clone(),
+            e[6].clone(),
+            e[9].inverse(),
+            e[5].inverse(),
+        ]),
+        plane_face(vec![
+            e[2].clone(),
+            e[7].clone(),
+            e[10].inverse(),
+            e[6].inverse(),
+        ]),
+        plane_face(vec![
+            e[3].clone(),
+            e[4].clone(),
+            e[11].inverse(),
+            e[7].inverse(),
+        ]),
+        plane_face(vec![
+            e[8].clone(),
+            e[9].clone(),
+            e[10].clone(),
+            e[11].clone(),
+        ]),
+    ]
+    .into();
+    (v, e, Solid::new(vec![shell]))
+}
+
+#[test]
+fn consistent_solid() {
+    let (_, _, solid) = cube();
+    assert!(solid.check_consistency().is_empty());
+    assert!(solid.is_geometric_consistent());
+}
+
+#[test]
+fn vertex_not_on_curve() {
+    let (v, e, solid) = cube();
+    v[0].set_point(Point3::new(0.0, 0.0, -0.1));
+    let issues = solid.check_consistency();
+    assert_eq!(issues.len(), 3);
+    [&e[0], &e[3], &e[4]].into_iter().for_each(|edge| {
+        let issue = ConsistencyIssue::VertexNotOnCurve {
+            edge: edge.id(),
+            vertex: v[0].id(),
+        };
+        assert!(issues.contains(&issue));
+    });
+    assert!(!solid.is_geometric_consistent());
+}
+
+#[test]
+fn curve_not_on_surface() {
+    let (v, e, solid) = cube();
+    let curve = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![v[0].point(), Point3::new(0.5, -0.5, -0.5), v[1].point()],
+    );
+    e[0].set_curve(NurbsCurve::from(curve));
+    let issues = solid.check_consistency();
+    let faces: Vec<_> = solid.boundaries()[0]
+        .face_iter()
+        .map(|face| face.id())
+        .collect();
+    assert_eq!(
+        issues,
+        vec![
+            ConsistencyIssue::CurveNotOnSurface {
+                face: faces[0],
+                edge: e[0].id(),
+            },
+            ConsistencyIssue::CurveNotOnSurface {
+                face: faces[1],
+                edge: e[0].id(),
+            },
+        ],
+    );
+}
+
+#[test]
+fn inconsistent_orientation() {
+    let (_, e, solid) = cube();
+    let mut shell = solid.into_boundaries().pop().unwrap();
+    shell[0].invert();
+    let solid = Solid::new_unchecked(vec![shell]);
+    let issues = solid.check_consistency();
+    assert_eq!(issues.len(), 4);
+    (0..4).for_each(|i| {
+        let issue = ConsistencyIssue::InconsistentOrientation(e[i].id());
+        assert!(issues.contains(&issue));
+    });
+}
+
+#[test]
+fn non_manifold_edge() {
+    let (_, e, solid) = cube();
+    let mut shell = solid.into_boundaries().pop().unwrap();
+    let face = shell.pop().unwrap();
+    shell.push(face.clone());
+    shell.push(face.inverse());
+    shell.push(face);
+    let solid = Solid::new_unchecked(vec![shell]);
+    // the edges of the top face are shared by four faces.
+    let issues = solid.check_consistency();
+    let expected: Vec<_> = (8..12)
+        .map(|i| ConsistencyIssue::NonManifoldEdge(e[i].id()))
+        .collect();
+    assert_eq!(issues, expected);
+}
@@ -0,0 +1,49 @@
+use std::f64::consts::PI;
+use truck_geometry::prelude::*;
+use truck_topology::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn edge_and_wire_length() {
+    let v = Vertex::news([
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(-2.0, 0.0, 0.0),
+        Point3::new(0.0, -2.0, 0.0),
+    ]);
+    let circle: Wire<_, _> = (0..4)
+        .map(|i| quarter_arc(&v[i], &v[(i + 1) % 4]))
+        .collect();
+    assert!((circle[0].length(1.0e-6) - PI).abs() < 1.0e-6);
+    assert!((circle[0].inverse().length(1.0e-6) - PI).abs() < 1.0e-6);
+    assert!((circle.length(1.0e-6) - 4.0 * PI).abs() < 4.0e-6);
+
+    let edge = line(&v[0], &v[1]);
+    assert!((edge.length(1.0e-6) - 2.0 * f64::sqrt(2.0)).abs() < 1.0e-6);
+    assert!(Wire::<Point3, Curve>::new().length(1.0e-6) == 0.0);
+}
+
+#[test]
+fn face_perimeter() {
+    let p = [
+        Point3::new(-3.0, -3.0, 0.0),
+        Point3::new(3.0, -3.0, 0.0),
+        Point3::new(3.0, 3.0, 0.0),
+        Point3::new(-3.0, 3.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+    ];
+    let v = Vertex::news(p);
+    let square: Wire<_, _> = (0..4).map(|i| line(&v[i], &v[(i + 1) % 4])).collect();
+    let hole: Wire<_, _> = (0..4)
+        .map(|i| quarter_arc(&v[4 + i], &v[4 + (i + 1) % 4]))
+        .collect();
+    let plane = Plane::new(p[0], p[1], p[3]);
+    let face = Face::new(vec![square, hole.inverse()], plane);
+    let perimeter = 24.0 + 2.0 * PI;
+    assert!((face.perimeter(1.0e-6) - perimeter).abs() < 8.0e-6);
+}
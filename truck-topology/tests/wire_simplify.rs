@@ -0,0 +1,96 @@
+use std::f64::consts::PI;
+use truck_geometry::prelude::*;
+use truck_topology::*;
+
+mod common;
+use common::*;
+
+fn sample_wire() -> Wire<Point3, Curve> {
+    let p = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(3.0, 1.0, 0.0),
+        Point3::new(2.0, 2.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+    ];
+    let v = Vertex::news(p);
+    let center = Point3::new(2.0, 1.0, 0.0);
+    vec![
+        Edge::new(&v[0], &v[1], line_curve(p[0], p[1])),
+        Edge::new(&v[1], &v[2], line_curve(p[1], p[2])),
+        Edge::new(&v[2], &v[3], quarter_arc_curve(center, p[2], p[3])),
+        Edge::new(&v[4], &v[3], quarter_arc_curve(center, p[4], p[3])).inverse(),
+        Edge::new(&v[4], &v[5], line_curve(p[4], p[5])),
+        Edge::new(&v[5], &v[0], line_curve(p[5], p[0])),
+    ]
+    .into()
+}
+
+fn assert_same_shape(wire0: &Wire<Point3, Curve>, wire1: &Wire<Point3, Curve>) {
+    let distance = |pt: Point3, wire: &Wire<Point3, Curve>| {
+        wire.edge_iter()
+            .map(|edge| {
+                let curve = edge.curve();
+                let t = algo::curve::presearch(&curve, pt, curve.range_tuple(), 100);
+                let t = curve.search_nearest_parameter(pt, Some(t), 100).unwrap();
+                curve.subs(t).distance(pt)
+            })
+            .fold(f64::INFINITY, f64::min)
+    };
+    wire0.edge_iter().for_each(|edge| {
+        let curve = edge.curve();
+        let (t0, t1) = curve.range_tuple();
+        (0..=10).for_each(|i| {
+            let pt = curve.subs(t0 + (t1 - t0) * i as f64 / 10.0);
+            assert!(distance(pt, wire1) < TOLERANCE);
+        });
+    });
+}
+
+#[test]
+fn simplify_closed_wire() {
+    let wire = sample_wire();
+    let mut simplified = wire.clone();
+    // the straight edges and the arcs are merged, but not across the corners and
+    // not between the line and the arc.
+    assert_eq!(simplified.simplify(Rad(0.01), 1.0e-4), 2);
+    assert_eq!(simplified.len(), 4);
+    assert!(simplified.is_closed());
+    assert!(simplified.is_geometric_consistent());
+    let points: Vec<Point3> = simplified.vertex_iter().map(|v| v.point()).collect();
+    assert_near!(points[0], Point3::new(0.0, 0.0, 0.0));
+    assert_near!(points[1], Point3::new(2.0, 0.0, 0.0));
+    assert_near!(points[2], Point3::new(2.0, 2.0, 0.0));
+    assert_near!(points[3], Point3::new(0.0, 2.0, 0.0));
+    assert_same_shape(&wire, &simplified);
+    assert_same_shape(&simplified, &wire);
+    assert_eq!(simplified.simplify(Rad(0.01), 1.0e-4), 0);
+
+    // merged over the front vertex of the closed wire
+    let mut rotated = wire.clone();
+    rotated.rotate_left(1);
+    assert_eq!(rotated.simplify(Rad(0.01), 1.0e-4), 2);
+    assert_eq!(rotated.len(), 4);
+    assert!(rotated.is_closed());
+    assert!(rotated
+        .vertex_iter()
+        .all(|v| !v.point().near(&wire[1].front().point())));
+    assert_same_shape(&wire, &rotated);
+
+    // the corners are kept even if the angle tolerance is loose.
+    let mut wire = sample_wire();
+    assert_eq!(wire.simplify(Rad(PI), 1.0e-4), 2);
+}
+
+#[test]
+fn simplify_open_wire() {
+    let mut wire = sample_wire();
+    let _ = wire.split_off(5);
+    let front = wire.front_vertex().unwrap().clone();
+    let back = wire.back_vertex().unwrap().clone();
+    assert_eq!(wire.simplify(Rad(0.01), 1.0e-4), 2);
+    assert_eq!(wire.len(), 3);
+    assert_eq!(wire.front_vertex(), Some(&front));
+    assert_eq!(wire.back_vertex(), Some(&back));
+}